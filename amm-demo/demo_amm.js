@@ -5,6 +5,10 @@
  * 1. Low Risk -> Swap Allowed
  * 2. Medium Risk -> Swap Limited
  * 3. High Risk -> Swap Frozen
+ *
+ * Deployments that configure a Review band (`review_max` in
+ * `set_score_bands`) get a fourth outcome: the swap is held with
+ * "pending manual review" instead of frozen.
  */
 
 import * as StellarSdk from '@stellar/stellar-sdk';
@@ -50,6 +54,7 @@ async function main() {
                 console.log(`❌ BLOCKED`);
                 // Extract error
                 if (sim.error.includes("Amount exceeds risk limit")) return "LIMIT";
+                if (sim.error.includes("pending manual review")) return "REVIEW";
                 if (sim.error.includes("Account is frozen")) return "FROZEN";
                 return "FAIL";
             }
@@ -1,39 +1,6 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol};
-
-// 1. Define the enum exactly as it is in the Sentinel SDK
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum RiskDecision {
-    Allow,
-    Limit(u32),
-    Freeze,
-}
-
-// 2. Define the client interface for calling Sentinel
-// We don't strictly need the trait if we just use env.invoke_contract, 
-// but let's use a helper for cleaner code.
-pub struct SentinelClient<'a> {
-    env: &'a Env,
-    contract_id: Address,
-}
-
-impl<'a> SentinelClient<'a> {
-    pub fn new(env: &'a Env, contract_id: &Address) -> Self {
-        Self {
-            env,
-            contract_id: contract_id.clone(),
-        }
-    }
-
-    pub fn check_permission(&self, wallet: &Address) -> RiskDecision {
-        self.env.invoke_contract(
-            &self.contract_id,
-            &Symbol::new(&self.env, "check_permission"), 
-            soroban_sdk::vec![&self.env, wallet.to_val()],
-        )
-    }
-}
+use sentinel_client::{RiskDecision, SentinelClient};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
 
 #[contract]
 pub struct MockAMM;
@@ -48,13 +15,7 @@ impl MockAMM {
         let sentinel_id: Address = env.storage().instance().get(&symbol_short!("sentinel"))
             .expect("AMM not initialized");
         
-        // Manual invoke to avoid client gen issues
-        // "check_permission" is longer than 9 chars, so we use Symbol::new
-        let decision: RiskDecision = env.invoke_contract(
-            &sentinel_id,
-            &Symbol::new(&env, "check_permission"), 
-            soroban_sdk::vec![&env, user.to_val()]
-        );
+        let decision = SentinelClient::new(&env, &sentinel_id).check_permission(&user);
         
         match decision {
             RiskDecision::Allow => {
@@ -70,6 +31,11 @@ impl MockAMM {
                      symbol_short!("WARNING") 
                 }
             },
+            RiskDecision::Review => {
+                // Temporary hold: tell the user to retry after review, don't treat as a ban
+                env.events().publish((symbol_short!("SWAP"),), (user, amount, "HELD_REVIEW"));
+                panic!("Swap blocked: Account is pending manual review");
+            },
             RiskDecision::Freeze => {
                 env.events().publish((symbol_short!("SWAP"),), (user, amount, "BLOCKED_FRZ"));
                 panic!("Swap blocked: Account is frozen by Sentinel");
@@ -14,11 +14,11 @@ sys.path.append(os.path.abspath(os.path.join(os.path.dirname(__file__), '../orac
 sys.path.append(os.path.abspath(os.path.join(os.path.dirname(__file__), '..')))
 
 try:
-    from oracle.crypto import load_keys, sign_payload, canonical_json
+    from oracle.crypto import load_keys, sign_payload, risk_payload, canonical_json
 except ImportError:
     # Try alternate path structure if running from different cwd
     sys.path.append(os.path.abspath(os.path.join(os.path.dirname(__file__), '../../oracle')))
-    from crypto import load_keys, sign_payload, canonical_json
+    from crypto import load_keys, sign_payload, risk_payload, canonical_json
 
 def update_risk(score):
     print(f"Updating risk score to: {score}")
@@ -31,11 +31,7 @@ def update_risk(score):
     real_wallet = "GBGNKU6A27K5CITQQFIBA4EJZRXHF5PCDFDSL7T6JUTZ3LOONVM3QPXT"
     timestamp = int(time.time())
     
-    payload = {
-        "wallet": real_wallet,
-        "risk_score": int(score),
-        "timestamp": timestamp
-    }
+    payload = risk_payload(real_wallet, int(score), timestamp)
     
     # Sign
     signature_hex = sign_payload(payload, signing_key)
@@ -22,7 +22,18 @@ pub trait Contract {
         env: soroban_sdk::Env,
         wallet: soroban_sdk::Address,
     ) -> RiskDecision;
-    fn get_oracle_pubkey(env: soroban_sdk::Env) -> PublicKey;
+    fn is_initialized(env: soroban_sdk::Env) -> bool;
+    fn get_oracle_pubkey(env: soroban_sdk::Env) -> Option<PublicKey>;
+}
+
+impl Client<'_> {
+    /// Oracle public key, panicking if the SDK is not initialized
+    /// 
+    /// Kept for callers written against the old panicking `get_oracle_pubkey`.
+    pub fn expect_oracle_pubkey(&self) -> PublicKey {
+        self.get_oracle_pubkey()
+            .expect("SDK not initialized - call initialize() first")
+    }
 }
 #[soroban_sdk::contracttype(export = false)]
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -38,11 +49,82 @@ pub struct RiskPayload {
     pub timestamp: u64,
     pub wallet: soroban_sdk::Address,
 }
-#[soroban_sdk::contracttype(export = false)]
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
-pub enum RiskDecision {
-    Allow,
-    Limit(u32),
-    Freeze,
+/// Event names and typed event data (see the `sentinel-events` crate)
+pub use sentinel_events as events;
+/// Decision returned by `check_permission` and carried in event data
+pub use sentinel_events::RiskDecision;
+
+/// Protocol actions referenced by the `severity` table (bit flags)
+pub mod action {
+    /// Open a new position (trade, LP, loan)
+    pub const OPEN: u32 = 1 << 0;
+    /// Close or reduce an existing position
+    pub const CLOSE: u32 = 1 << 1;
+    /// Deposit funds or collateral
+    pub const DEPOSIT: u32 = 1 << 2;
+    /// Withdraw funds or collateral
+    pub const WITHDRAW: u32 = 1 << 3;
+    /// Take on new debt
+    pub const BORROW: u32 = 1 << 4;
+    /// Repay debt
+    pub const REPAY: u32 = 1 << 5;
+    /// Transfer assets to another address
+    pub const TRANSFER: u32 = 1 << 6;
+    
+    /// No action permitted
+    pub const NONE: u32 = 0;
+    /// Every action
+    pub const ALL: u32 = OPEN | CLOSE | DEPOSIT | WITHDRAW | BORROW | REPAY | TRANSFER;
 }
 
+/// Canonical severity-to-action mapping
+/// 
+/// Recommended treatment per score band, so independent integrators
+/// converge on the same user experience. Bands are checked in order;
+/// the amount cap of a `Limit` decision still applies on top.
+pub mod severity {
+    use super::action;
+    
+    /// Recommended actions for scores `min..=max`
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct SeverityBand {
+        /// Lowest score in the band
+        pub min: u32,
+        /// Highest score in the band
+        pub max: u32,
+        /// Actions integrators should permit (`action` bits)
+        pub allowed: u32,
+    }
+    
+    /// 0-49: no restriction
+    pub const LOW: SeverityBand = SeverityBand { min: 0, max: 49, allowed: action::ALL };
+    /// 50-79: no new debt; everything else within the Limit cap
+    pub const ELEVATED: SeverityBand = SeverityBand {
+        min: 50,
+        max: 79,
+        allowed: action::ALL & !action::BORROW,
+    };
+    /// 80-89: block new positions and outflows, allow closes and repayment
+    pub const HIGH: SeverityBand = SeverityBand {
+        min: 80,
+        max: 89,
+        allowed: action::CLOSE | action::DEPOSIT | action::REPAY,
+    };
+    /// 90-100: block everything
+    pub const CRITICAL: SeverityBand = SeverityBand { min: 90, max: 100, allowed: action::NONE };
+    
+    /// Every band, in ascending score order
+    pub const TABLE: [SeverityBand; 4] = [LOW, ELEVATED, HIGH, CRITICAL];
+    
+    /// Recommended actions for a score (`NONE` for out-of-range scores)
+    pub const fn allowed_actions(score: u32) -> u32 {
+        let mut i = 0;
+        while i < TABLE.len() {
+            if score >= TABLE[i].min && score <= TABLE[i].max {
+                return TABLE[i].allowed;
+            }
+            i += 1;
+        }
+        action::NONE
+    }
+}
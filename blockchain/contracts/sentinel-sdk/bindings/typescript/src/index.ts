@@ -90,7 +90,7 @@ export interface RiskPayload {
 /**
  * Decision returned to protocols about what action to take
  */
-export type RiskDecision = { tag: "Allow", values: void } | { tag: "Limit", values: readonly [u32] } | { tag: "Freeze", values: void };
+export type RiskDecision = { tag: "Allow", values: void } | { tag: "Limit", values: readonly [u32] } | { tag: "Review", values: void } | { tag: "Freeze", values: void };
 
 export interface Client {
   /**
@@ -166,7 +166,7 @@ export interface Client {
    * * `wallet` - Address to check
    * 
    * # Returns
-   * * `RiskDecision` - Allow, Limit(amount), or Freeze
+   * * `RiskDecision` - Allow, Limit(amount), Review, or Freeze
    * 
    * # Default Behavior
    * * If wallet is unknown, returns `Allow` (innocent until proven risky)
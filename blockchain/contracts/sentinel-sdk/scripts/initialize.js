@@ -34,8 +34,9 @@ async function initializeContract() {
             throw new Error('Invalid public key length');
         }
 
-        // 3. Create ScVal parameter
+        // 3. Create ScVal parameters (deployer becomes admin)
         console.log('\n[3/6] Encoding as ScVal...');
+        const adminParam = new StellarSdk.Address(keypair.publicKey()).toScVal();
         const param = StellarSdk.xdr.ScVal.scvBytes(oracleKeyBuffer);
 
         // 4. Connect to RPC - FIXED: use sdk.rpc not sdk.SorobanRpc
@@ -52,7 +53,7 @@ async function initializeContract() {
             fee: StellarSdk.BASE_FEE,
             networkPassphrase: NETWORK_PASSPHRASE,
         })
-            .addOperation(contract.call('initialize', param))
+            .addOperation(contract.call('initialize', adminParam, param))
             .setTimeout(30)
             .build();
 
@@ -7,7 +7,7 @@ import sys
 import os
 sys.path.insert(0, os.path.join(os.path.dirname(__file__), '..', '..', '..', 'oracle'))
 
-from crypto import load_keys, sign_payload
+from crypto import load_keys, sign_payload, risk_payload
 import json
 import time
 
@@ -31,11 +31,7 @@ def main():
     test_risk_score = 87
     timestamp = int(time.time())
     
-    payload = {
-        "wallet": test_wallet,
-        "risk_score": test_risk_score,
-        "timestamp": timestamp
-    }
+    payload = risk_payload(test_wallet, test_risk_score, timestamp)
     
     print(f"\n[2] Payload to sign:")
     print(f"  {json.dumps(payload, indent=2)}")
@@ -0,0 +1,32 @@
+//! Admin authority for compliance actions.
+//!
+//! The admin is set once at initialization and gates actions that bypass
+//! the Oracle pipeline (manual overrides).
+
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::types::DataKey;
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the admin address (read-only)
+    /// 
+    /// # Panics
+    /// * If SDK not initialized
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("SDK not initialized - call initialize() first")
+    }
+}
+
+impl SentinelSDK {
+    /// Require authorization from the admin
+    pub(crate) fn require_admin(env: &Env) -> Address {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+        admin
+    }
+}
@@ -0,0 +1,294 @@
+//! Multi-Oracle score aggregation.
+//!
+//! With a single Oracle key, whoever holds it can freeze or unfreeze any
+//! wallet. The admin may instead register several Oracle keys whose
+//! scores are aggregated: each submits with `submit_oracle_score`, its
+//! latest state per wallet is kept separately, and the decision pipeline
+//! payload ranked at the configured quantile (the median by default)
+//! becomes the wallet's risk state once a majority of the registered
+//! Oracles has scored it. A single compromised Oracle then cannot move
+//! the decision.
+//!
+//! Payloads are ranked by worst score (overall or category), and the
+//! ranked payload is stored whole through the same path as `submit_risk`
+//! (events, subscribers, history, statistics, jump guard), so its
+//! quarantine and reason codes come from an Oracle whose score was
+//! selected. While aggregation is enabled the live Oracle cannot write
+//! the risk state; changes to the Oracle set or quantile apply to a
+//! wallet from its next aggregated submission.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::audit;
+use crate::crypto::require_signature;
+use crate::events;
+use crate::invariants;
+use crate::percentage::BPS_DENOMINATOR;
+use crate::pipeline;
+use crate::types::{worst_score, ConfigKey, DataKey, PublicKey, RiskPayload, RiskState, Signature};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Maximum number of aggregated Oracles
+pub const MAX_AGGREGATED_ORACLES: u32 = 7;
+
+/// Default quantile, in basis points (the median)
+pub const DEFAULT_AGGREGATION_QUANTILE: u32 = 5_000;
+
+/// Latest payload an aggregated Oracle submitted for a wallet
+fn oracle_payload(env: &Env, oracle: &PublicKey, wallet: &Address) -> Option<RiskPayload> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OracleScore(oracle.clone(), wallet.clone()))
+}
+
+/// Payload at the configured quantile, once a majority of Oracles scored
+fn aggregate(env: &Env, oracles: &Vec<PublicKey>, wallet: &Address) -> Option<RiskPayload> {
+    let mut payloads: Vec<RiskPayload> = Vec::new(env);
+    let mut ranked = [(0u32, 0u32); MAX_AGGREGATED_ORACLES as usize];
+    for oracle in oracles.iter() {
+        if let Some(payload) = oracle_payload(env, &oracle, wallet) {
+            let score = worst_score(payload.risk_score, &payload.category_scores);
+            ranked[payloads.len() as usize] = (score, payloads.len());
+            payloads.push_back(payload);
+        }
+    }
+    
+    let scored = payloads.len();
+    if scored * 2 <= oracles.len() {
+        return None;
+    }
+    
+    let ranked = &mut ranked[..scored as usize];
+    ranked.sort_unstable();
+    let quantile = SentinelSDK::get_aggregation_quantile(env.clone());
+    let rank = (scored - 1) * quantile / BPS_DENOMINATOR;
+    payloads.get(ranked[rank as usize].1)
+}
+
+impl SentinelSDK {
+    /// Whether aggregated Oracles own the risk state
+    pub(crate) fn scores_aggregated(env: &Env) -> bool {
+        env.storage().instance().has(&DataKey::AggregatedOracles)
+    }
+    
+    /// Store a wallet's aggregated payload as its risk state
+    /// 
+    /// A no-op without a majority or if the aggregate is already stored.
+    fn apply_aggregate(env: &Env, wallet: &Address) {
+        let oracles = Self::get_aggregated_oracles(env.clone());
+        let Some(payload) = aggregate(env, &oracles, wallet) else {
+            return;
+        };
+        if Self::is_duplicate(env, &payload) {
+            return;
+        }
+        Self::apply_guarded(env, &payload);
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Register an Oracle key whose scores are aggregated (admin only)
+    /// 
+    /// The first registration hands the risk state to aggregated scores.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If the key is already registered
+    /// * If `MAX_AGGREGATED_ORACLES` keys are already registered
+    pub fn add_aggregated_oracle(env: Env, oracle: PublicKey) {
+        let admin = Self::require_admin(&env);
+        
+        let mut oracles = Self::get_aggregated_oracles(env.clone());
+        let old = oracles.clone();
+        if oracles.contains(&oracle) {
+            panic!("Oracle already aggregated");
+        }
+        if oracles.len() >= MAX_AGGREGATED_ORACLES {
+            panic!("Too many aggregated Oracles: max 7");
+        }
+        oracles.push_back(oracle.clone());
+        env.storage().instance().set(&DataKey::AggregatedOracles, &oracles);
+        
+        audit::record(&env, &admin, "add_aggregated_oracle", old, oracles);
+        env.events().publish((topic::AGG_ADD,), oracle);
+    }
+    
+    /// Stop aggregating an Oracle's scores (admin only)
+    /// 
+    /// Its stored scores are ignored from then on. Removing the last key
+    /// hands the risk state back to the live Oracle.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If the key is not registered
+    pub fn remove_aggregated_oracle(env: Env, oracle: PublicKey) {
+        let admin = Self::require_admin(&env);
+        
+        let mut oracles = Self::get_aggregated_oracles(env.clone());
+        let old = oracles.clone();
+        let index = oracles.first_index_of(&oracle).expect("Oracle not aggregated");
+        oracles.remove(index);
+        if oracles.is_empty() {
+            env.storage().instance().remove(&DataKey::AggregatedOracles);
+        } else {
+            env.storage().instance().set(&DataKey::AggregatedOracles, &oracles);
+        }
+        
+        audit::record(&env, &admin, "remove_aggregated_oracle", old, oracles);
+        env.events().publish((topic::AGG_DEL,), oracle);
+    }
+    
+    /// List the aggregated Oracle keys (read-only; empty if disabled)
+    pub fn get_aggregated_oracles(env: Env) -> Vec<PublicKey> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AggregatedOracles)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+    
+    /// Get the aggregation quantile in basis points (read-only)
+    pub fn get_aggregation_quantile(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::AggregationQuantile))
+            .unwrap_or(DEFAULT_AGGREGATION_QUANTILE)
+    }
+    
+    /// Set the quantile scores are aggregated at (admin only)
+    /// 
+    /// 0 takes the lowest reported score, 10000 the highest, 5000 the
+    /// median. Ranks round down, so with an even number of scores the
+    /// median is the lower middle one.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `quantile` is above 10000
+    pub fn set_aggregation_quantile(env: Env, quantile: u32) {
+        let admin = Self::require_admin(&env);
+        
+        if quantile > BPS_DENOMINATOR {
+            panic!("Quantile must be 0-10000");
+        }
+        let old = Self::get_aggregation_quantile(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Config(ConfigKey::AggregationQuantile), &quantile);
+        audit::record(&env, &admin, "set_aggregation_quantile", old, quantile);
+        env.events().publish((topic::AGG_QUANT,), quantile);
+    }
+    
+    /// Submit an aggregated Oracle's signed score
+    /// 
+    /// Verified against `oracle` and validated like `submit_risk`, with
+    /// ordering checked against this Oracle's previous score. Rejected
+    /// payloads emit `REJECTED` and leave the state unchanged. Once a
+    /// majority has scored the wallet, the payload at the quantile is
+    /// applied like `submit_risk` (jump guard, events, subscribers).
+    /// 
+    /// # Arguments
+    /// * `oracle` - Registered key that signed the payload
+    /// * `payload` - Risk data (wallet, score, timestamp)
+    /// * `signature` - Signature by `oracle`
+    /// 
+    /// # Panics
+    /// * If `oracle` is not aggregated
+    /// * `InvalidSignature` if the signature does not verify
+    /// * `UnsupportedPayloadVersion` for unknown payload versions
+    pub fn submit_oracle_score(env: Env, oracle: PublicKey, payload: RiskPayload, signature: Signature) {
+        if !Self::get_aggregated_oracles(env.clone()).contains(&oracle) {
+            panic!("Oracle not aggregated");
+        }
+        require_signature(&env, &payload, &signature, &oracle);
+        
+        let stored = Self::get_oracle_score(env.clone(), oracle.clone(), payload.wallet.clone());
+        let valid = Self::validate_timestamp(&env, &payload, stored.as_ref())
+            .and_then(|_| Self::validate_scores(&payload));
+        if let Err(error) = valid {
+            events::publish_rejected(&env, &payload, error);
+            return;
+        }
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::OracleScore(oracle.clone(), payload.wallet.clone()), &payload);
+        env.events().publish(
+            (topic::ORCL_SCR, oracle),
+            (payload.wallet.clone(), payload.risk_score, payload.timestamp),
+        );
+        
+        Self::apply_aggregate(&env, &payload.wallet);
+        invariants::check_contract(&env);
+        invariants::check_wallet(&env, &payload.wallet);
+    }
+    
+    /// Get an aggregated Oracle's latest state for a wallet (read-only)
+    pub fn get_oracle_score(env: Env, oracle: PublicKey, wallet: Address) -> Option<RiskState> {
+        oracle_payload(&env, &oracle, &wallet)
+            .map(|payload| RiskState::from_payload(&payload, &pipeline::score_bands(&env)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::types::{RiskDecision, PAYLOAD_VERSION_V2};
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{testutils::Address as _, BytesN, Map};
+    
+    fn submit(env: &Env, client: &SentinelSDKClient, key: &SigningKey, wallet: &Address, score: u32) {
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: score,
+            timestamp: 0,
+            reason_codes: Vec::new(env),
+            freeze_duration: 0,
+            category_scores: Map::new(env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(env, &[0; 32]),
+        };
+        let message = crate::crypto::serialize_canonical_json(env, &client.address, &payload);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        let signature = BytesN::from_array(env, &key.sign(&message).to_bytes());
+        let oracle = BytesN::from_array(env, &key.verifying_key().to_bytes());
+        client.submit_oracle_score(&oracle, &payload, &signature);
+    }
+    
+    #[test]
+    fn test_single_oracle_cannot_freeze() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let keys = [1u8, 2, 3].map(|seed| SigningKey::from_bytes(&[seed; 32]));
+        for key in keys.iter() {
+            client.add_aggregated_oracle(&BytesN::from_array(&env, &key.verifying_key().to_bytes()));
+        }
+        
+        let wallet = Address::generate(&env);
+        submit(&env, &client, &keys[0], &wallet, 95);
+        assert_eq!(client.get_risk(&wallet), None);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        
+        submit(&env, &client, &keys[1], &wallet, 10);
+        assert_eq!(client.get_risk(&wallet).unwrap().risk_score, 10);
+        submit(&env, &client, &keys[2], &wallet, 60);
+        assert_eq!(client.get_risk(&wallet).unwrap().risk_score, 60);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(5000));
+        assert_eq!(client.get_risk_history(&wallet).len(), 2);
+        
+        // The quantile applies from the wallet's next aggregated submission
+        client.set_aggregation_quantile(&10_000);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(5000));
+        submit(&env, &client, &keys[1], &wallet, 10);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+    }
+}
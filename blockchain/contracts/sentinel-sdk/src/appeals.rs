@@ -0,0 +1,365 @@
+//! Wallet appeals with an anti-griefing bond.
+//!
+//! A wallet that is limited, under review or frozen, or whose freeze is
+//! still in its grace period, and believes it should not be files an appeal for manual
+//! review; the admin, or the Oracle with a signed ruling, lifts the
+//! treatment, reduces it to a Limit, or rejects the appeal. If a bond is
+//! configured the wallet stakes it: it is returned when the ruling goes
+//! the wallet's way and forfeited to the insurance pool if the appeal is
+//! rejected as frivolous, which keeps the review queue usable at scale.
+//! Requires the `APPEALS` feature.
+//!
+//! This is also the dispute flow for frozen wallets: `file_appeal` opens a
+//! dispute and `resolve_appeal` / `oracle_resolve_appeal` resolve it.
+//! There are no separate `open_dispute` / `resolve_dispute` entry points.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, token, Address, Env};
+
+use crate::audit;
+use crate::crypto::appeal_ruling_message;
+use crate::grace::pending_freeze;
+use crate::types::{
+    feature, Appeal, AppealBondConfig, AppealOutcome, AppealStatus, ConfigKey, DataKey, RiskDecision, Signature,
+    TransitionCause,
+};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Configure the appeal bond (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `amount` is negative
+    pub fn set_appeal_bond(env: Env, config: AppealBondConfig) {
+        let admin = Self::require_admin(&env);
+        
+        if config.amount < 0 {
+            panic!("Appeal bond must not be negative");
+        }
+        
+        let old = Self::get_appeal_bond(env.clone());
+        env.storage().instance().set(&DataKey::Config(ConfigKey::AppealBond), &config);
+        audit::record(&env, &admin, "set_appeal_bond", old, config.clone());
+        env.events().publish((topic::APBND_SET,), config);
+    }
+    
+    /// Get the appeal bond configuration (read-only)
+    pub fn get_appeal_bond(env: Env) -> Option<AppealBondConfig> {
+        env.storage().instance().get(&DataKey::Config(ConfigKey::AppealBond))
+    }
+    
+    /// File an appeal against a wallet's current treatment
+    /// 
+    /// # Arguments
+    /// * `wallet` - Appealing wallet (must authorize; stakes the bond if
+    ///   one is configured)
+    /// 
+    /// # Panics
+    /// * If appeals are not enabled
+    /// * If the wallet is not currently limited, under review, frozen or
+    ///   pending a freeze
+    /// * If the wallet already has a pending appeal
+    pub fn file_appeal(env: Env, wallet: Address) {
+        wallet.require_auth();
+        Self::require_feature(&env, feature::APPEALS);
+        
+        match Self::check_permission(env.clone(), wallet.clone()) {
+            RiskDecision::Limit(_) | RiskDecision::Review | RiskDecision::Freeze => {}
+            _ if pending_freeze(&env, &wallet).is_some() => {}
+            _ => panic!("Only limited, reviewed or frozen wallets can appeal"),
+        }
+        if let Some(appeal) = Self::get_appeal(env.clone(), wallet.clone()) {
+            if appeal.status == AppealStatus::Pending {
+                panic!("Appeal already pending");
+            }
+        }
+        
+        let (token, bond) = match Self::get_appeal_bond(env.clone()).filter(|c| c.amount > 0) {
+            Some(config) => {
+                token::Client::new(&env, &config.token).transfer(
+                    &wallet,
+                    &env.current_contract_address(),
+                    &config.amount,
+                );
+                (Some(config.token), config.amount)
+            }
+            None => (None, 0),
+        };
+        
+        let appeal = Appeal {
+            token,
+            bond,
+            filed_at: env.ledger().timestamp(),
+            guardian_cosigned: false,
+            status: AppealStatus::Pending,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Appeal(wallet.clone()), &appeal);
+        
+        env.events().publish((topic::APPEAL,), (wallet, appeal.bond));
+    }
+    
+    /// Rule on a pending appeal (admin only)
+    /// 
+    /// `Upheld` and `Downgrade` refund the bond and pin the wallet to the
+    /// new decision by override for `PIN_DURATION`; `Rejected` (frivolous)
+    /// forfeits the bond to the insurance pool.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If the wallet has no pending appeal
+    pub fn resolve_appeal(env: Env, wallet: Address, outcome: AppealOutcome) {
+        let admin = Self::require_admin(&env);
+        let appeal = Self::pending_appeal(&env, &wallet);
+        Self::rule_on_appeal(&env, &wallet, appeal, outcome, admin);
+    }
+    
+    /// Rule on a pending appeal with an Oracle-signed ruling
+    /// 
+    /// Same effect as `resolve_appeal`. The Oracle signs the canonical
+    /// ruling JSON (see `sentinel-canonical`), which names the appeal by
+    /// its `filed_at`, so a ruling cannot be replayed on a later appeal.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Wallet whose pending appeal is ruled on
+    /// * `outcome` - Oracle's ruling
+    /// * `signature` - Oracle signature over the ruling (ignored in
+    ///   Soroban-auth mode, where the Oracle address must authorize)
+    /// 
+    /// # Panics
+    /// * If the wallet has no pending appeal
+    /// * `InvalidSignature` if the signature does not verify
+    pub fn oracle_resolve_appeal(env: Env, wallet: Address, outcome: AppealOutcome, signature: Signature) {
+        let appeal = Self::pending_appeal(&env, &wallet);
+        let message = appeal_ruling_message(&env, &wallet, appeal.filed_at, &outcome);
+        Self::authenticate_message(&env, &message, &signature);
+        
+        let oracle = Self::get_oracle_address(env.clone()).unwrap_or_else(|| env.current_contract_address());
+        Self::rule_on_appeal(&env, &wallet, appeal, outcome, oracle);
+    }
+    
+    /// Get a wallet's most recent appeal (read-only)
+    pub fn get_appeal(env: Env, wallet: Address) -> Option<Appeal> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Appeal(wallet))
+    }
+}
+
+impl SentinelSDK {
+    /// The wallet's appeal, if it is pending
+    /// 
+    /// # Panics
+    /// * If the wallet has no pending appeal
+    fn pending_appeal(env: &Env, wallet: &Address) -> Appeal {
+        Self::get_appeal(env.clone(), wallet.clone())
+            .filter(|appeal| appeal.status == AppealStatus::Pending)
+            .expect("No pending appeal")
+    }
+    
+    /// Settle the bond, pin the ruled decision and close the appeal
+    /// (authorization checked by caller)
+    fn rule_on_appeal(env: &Env, wallet: &Address, mut appeal: Appeal, outcome: AppealOutcome, resolver: Address) {
+        let decision = match outcome {
+            AppealOutcome::Upheld => Some(RiskDecision::Allow),
+            AppealOutcome::Downgrade(limit) => Some(RiskDecision::Limit(limit)),
+            AppealOutcome::Rejected => None,
+        };
+        
+        let recipient = match decision {
+            Some(_) => wallet.clone(),
+            None => Self::get_appeal_bond(env.clone())
+                .map(|config| config.pool)
+                .unwrap_or_else(|| wallet.clone()),
+        };
+        if let Some(token) = appeal.token.clone() {
+            token::Client::new(env, &token).transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &appeal.bond,
+            );
+        }
+        
+        if let Some(decision) = decision {
+            Self::pin_decision(env, wallet, decision, resolver.clone(), TransitionCause::Appeal);
+        }
+        
+        appeal.status = AppealStatus::Resolved(outcome.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Appeal(wallet.clone()), &appeal);
+        
+        audit::record(env, &resolver, "resolve_appeal", (), (wallet.clone(), outcome.clone()));
+        env.events().publish(
+            (topic::APPEAL_RS,),
+            (wallet.clone(), outcome, appeal.bond, recipient)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::overrides::PIN_DURATION;
+    use ed25519_dalek::{Signer, SigningKey};
+    use crate::testutils::TimeTravel;
+    use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, BytesN};
+    
+    fn setup(env: &Env) -> (SentinelSDKClient<'_>, Address, Address) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        client.set_features(&(feature::DEFAULT | feature::APPEALS));
+        
+        let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+        let pool = Address::generate(env);
+        client.set_appeal_bond(&AppealBondConfig { token: token.clone(), amount: 500, pool: pool.clone() });
+        (client, token, pool)
+    }
+    
+    #[test]
+    fn test_upheld_appeal_refunds_bond_and_allows() {
+        let env = Env::default();
+        let (client, token, _) = setup(&env);
+        let wallet = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&wallet, &1_000);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.file_appeal(&wallet);
+        assert_eq!(token::Client::new(&env, &token).balance(&wallet), 500);
+        
+        client.resolve_appeal(&wallet, &AppealOutcome::Upheld);
+        assert_eq!(token::Client::new(&env, &token).balance(&wallet), 1_000);
+        assert_eq!(
+            client.get_appeal(&wallet).unwrap().status,
+            AppealStatus::Resolved(AppealOutcome::Upheld)
+        );
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(client.transition_log(&wallet).last().unwrap().cause, TransitionCause::Appeal);
+        
+        // The ruling lapses and the wallet's own treatment applies again
+        env.advance_secs(PIN_DURATION);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(client.get_override(&wallet).unwrap().expires_at, PIN_DURATION);
+    }
+    
+    #[test]
+    fn test_frivolous_appeal_forfeits_bond_to_pool() {
+        let env = Env::default();
+        let (client, token, pool) = setup(&env);
+        let wallet = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&wallet, &1_000);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Limit(100), &0);
+        client.file_appeal(&wallet);
+        client.resolve_appeal(&wallet, &AppealOutcome::Rejected);
+        
+        assert_eq!(token::Client::new(&env, &token).balance(&wallet), 500);
+        assert_eq!(token::Client::new(&env, &token).balance(&pool), 500);
+        assert_eq!(
+            client.get_appeal(&wallet).unwrap().status,
+            AppealStatus::Resolved(AppealOutcome::Rejected)
+        );
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(100));
+    }
+    
+    #[test]
+    fn test_appeal_without_bond_downgrades_freeze() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_features(&(feature::DEFAULT | feature::APPEALS));
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.file_appeal(&wallet);
+        assert_eq!(client.get_appeal(&wallet).unwrap().token, None);
+        
+        client.resolve_appeal(&wallet, &AppealOutcome::Downgrade(250));
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(250));
+        
+        env.advance_secs(PIN_DURATION);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+    }
+    
+    #[test]
+    fn test_oracle_signed_ruling_resolves_appeal() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        client.set_features(&(feature::DEFAULT | feature::APPEALS));
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.file_appeal(&wallet);
+        
+        let sign = |outcome: &AppealOutcome| {
+            let filed_at = client.get_appeal(&wallet).unwrap().filed_at;
+            let message = appeal_ruling_message(&env, &wallet, filed_at, outcome);
+            let message: std::vec::Vec<u8> = message.iter().collect();
+            BytesN::from_array(&env, &key.sign(&message).to_bytes())
+        };
+        let signature = sign(&AppealOutcome::Upheld);
+        assert!(client
+            .try_oracle_resolve_appeal(&wallet, &AppealOutcome::Downgrade(1), &signature)
+            .is_err());
+        
+        client.oracle_resolve_appeal(&wallet, &AppealOutcome::Upheld, &signature);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(client.get_override(&wallet).unwrap().set_by, contract_id);
+        assert!(client
+            .try_oracle_resolve_appeal(&wallet, &AppealOutcome::Upheld, &signature)
+            .is_err());
+    }
+    
+    #[test]
+    fn test_wallet_under_review_can_appeal() {
+        let env = Env::default();
+        let (client, token, _) = setup(&env);
+        let wallet = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&wallet, &1_000);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Review, &0);
+        client.file_appeal(&wallet);
+        client.resolve_appeal(&wallet, &AppealOutcome::Upheld);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Appeal already pending")]
+    fn test_one_pending_appeal_per_wallet() {
+        let env = Env::default();
+        let (client, token, _) = setup(&env);
+        let wallet = Address::generate(&env);
+        StellarAssetClient::new(&env, &token).mint(&wallet, &1_000);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.file_appeal(&wallet);
+        client.file_appeal(&wallet);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Only limited, reviewed or frozen wallets can appeal")]
+    fn test_allowed_wallet_cannot_appeal() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+        client.file_appeal(&Address::generate(&env));
+    }
+}
@@ -0,0 +1,129 @@
+//! Per-asset limits.
+//!
+//! A `Limit` decision carries one amount, which is meaningless across
+//! assets with different decimals and risk profiles. The admin may instead
+//! configure a map of asset contract → max amount: a limited wallet is
+//! then capped per listed asset and unrestricted in unlisted ones (e.g.
+//! 100 USDC but unlimited XLM). `check_permission_for_asset` answers for
+//! one asset; `check_permission` keeps returning a single `Limit`, the
+//! worst case over the listed assets. With no map configured, `Limit`
+//! keeps its single amount everywhere.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, Map};
+
+use crate::audit;
+use crate::pipeline;
+use crate::types::{ConfigKey, DataKey, RiskDecision};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Configured asset limits (empty = single Limit amount)
+pub(crate) fn asset_limits(env: &Env) -> Map<Address, u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::AssetLimits))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Collapse a `Limit` to the strictest configured asset limit
+pub(crate) fn worst_case_limit(env: &Env, decision: RiskDecision) -> RiskDecision {
+    match decision {
+        RiskDecision::Limit(cap) => {
+            let worst = asset_limits(env).values().iter().min().unwrap_or(cap);
+            RiskDecision::Limit(worst)
+        }
+        other => other,
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Check permission for a wallet on one asset
+    /// 
+    /// Runs the configured pipeline like `check_permission`. A `Limit` is
+    /// then resolved against the asset limits: a listed asset is limited
+    /// to its configured amount and an unlisted one is allowed. Without
+    /// configured asset limits the decision is returned unchanged.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Address to check
+    /// * `asset` - Token contract the operation moves
+    pub fn check_permission_for_asset(env: Env, wallet: Address, asset: Address) -> RiskDecision {
+        let decision = pipeline::evaluate(&env, &wallet);
+        let limits = asset_limits(&env);
+        match decision {
+            RiskDecision::Limit(_) if !limits.is_empty() => match limits.get(asset) {
+                Some(max) => RiskDecision::Limit(max),
+                None => RiskDecision::Allow,
+            },
+            other => other,
+        }
+    }
+    
+    /// Get the configured asset limits (read-only)
+    pub fn get_asset_limits(env: Env) -> Map<Address, u32> {
+        asset_limits(&env)
+    }
+    
+    /// Set per-asset limits for limited wallets (admin only)
+    /// 
+    /// Replaces the whole map; an empty map restores the single Limit
+    /// amount for every asset.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    pub fn set_asset_limits(env: Env, limits: Map<Address, u32>) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = asset_limits(&env);
+        Self::apply_asset_limits(&env, limits.clone());
+        audit::record(&env, &admin, "set_asset_limits", old, limits);
+    }
+}
+
+impl SentinelSDK {
+    /// Store the per-asset limits (authorization checked by caller)
+    pub(crate) fn apply_asset_limits(env: &Env, limits: Map<Address, u32>) {
+        let storage = env.storage().instance();
+        if limits.is_empty() {
+            storage.remove(&DataKey::Config(ConfigKey::AssetLimits));
+        } else {
+            storage.set(&DataKey::Config(ConfigKey::AssetLimits), &limits);
+        }
+        env.events().publish((topic::ASSET_LIM,), limits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{map, testutils::Address as _, BytesN};
+    
+    #[test]
+    fn test_limit_resolves_per_asset() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let (usdc, eurc, xlm) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Limit(5000), &0);
+        assert_eq!(client.check_permission_for_asset(&wallet, &xlm), RiskDecision::Limit(5000));
+        
+        client.set_asset_limits(&map![&env, (usdc.clone(), 100), (eurc.clone(), 250)]);
+        assert_eq!(client.check_permission_for_asset(&wallet, &usdc), RiskDecision::Limit(100));
+        assert_eq!(client.check_permission_for_asset(&wallet, &eurc), RiskDecision::Limit(250));
+        assert_eq!(client.check_permission_for_asset(&wallet, &xlm), RiskDecision::Allow);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(100));
+        
+        // Other decisions are not asset-specific
+        let unknown = Address::generate(&env);
+        assert_eq!(client.check_permission_for_asset(&unknown, &usdc), RiskDecision::Allow);
+        
+        client.set_asset_limits(&Map::new(&env));
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(5000));
+    }
+}
@@ -0,0 +1,149 @@
+//! Audit log of privileged actions.
+//!
+//! Every admin, role-holder and guardian action is recorded on-chain with
+//! who acted, the entrypoint, when, and the value before and after (as
+//! XDR-encoded `ScVal`s, so one entry type covers every setting). Events
+//! carry the same information, but keeping it in contract storage lets
+//! auditors page through it with `get_audit_log` without an archival RPC.
+//! Up to the last `MAX_AUDIT_ENTRIES` actions are kept, in persistent
+//! buckets reused in a ring, so the oldest drop out a bucket at a time.
+
+use soroban_sdk::{contractimpl, xdr::ToXdr, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::index::MAX_PAGE_SIZE;
+use crate::types::{AuditEntry, AuditKey, AuditPage};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Entries per storage bucket
+pub const AUDIT_BUCKET_SIZE: u32 = 10;
+
+/// Most recent actions kept (a multiple of `AUDIT_BUCKET_SIZE`)
+pub const MAX_AUDIT_ENTRIES: u32 = 1_000;
+
+/// Number of actions ever recorded
+fn audit_count(env: &Env) -> u32 {
+    env.storage().instance().get(&AuditKey::AuditCount).unwrap_or(0)
+}
+
+/// `seq` of the oldest entry still kept
+fn oldest_kept(count: u32) -> u32 {
+    count.saturating_sub(MAX_AUDIT_ENTRIES).div_ceil(AUDIT_BUCKET_SIZE) * AUDIT_BUCKET_SIZE
+}
+
+fn bucket(env: &Env, id: u32) -> Vec<AuditEntry> {
+    env.storage()
+        .persistent()
+        .get(&AuditKey::AuditBucket(id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append a privileged action to the audit log
+/// 
+/// Pass `()` for a value that does not apply (e.g. nothing set before).
+pub(crate) fn record(
+    env: &Env,
+    actor: &Address,
+    action: &str,
+    old: impl IntoVal<Env, Val>,
+    new: impl IntoVal<Env, Val>,
+) {
+    let seq = audit_count(env);
+    let entry = AuditEntry {
+        seq,
+        actor: actor.clone(),
+        action: Symbol::new(env, action),
+        old_value: old.into_val(env).to_xdr(env),
+        new_value: new.into_val(env).to_xdr(env),
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    };
+    
+    // A bucket's first slot starts it afresh, dropping the entries it held
+    // one ring ago
+    let id = seq % MAX_AUDIT_ENTRIES / AUDIT_BUCKET_SIZE;
+    let mut entries = if seq.is_multiple_of(AUDIT_BUCKET_SIZE) { Vec::new(env) } else { bucket(env, id) };
+    entries.push_back(entry);
+    
+    env.storage().persistent().set(&AuditKey::AuditBucket(id), &entries);
+    env.storage().instance().set(&AuditKey::AuditCount, &(seq + 1));
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Page through the audit log, oldest first (read-only)
+    /// 
+    /// `cursor` is the `seq` of the first entry wanted (0 for the oldest
+    /// kept); entries that have rotated out are skipped. Continue with
+    /// `next` until it is `None`.
+    /// 
+    /// # Panics
+    /// * If `limit` is 0 or exceeds `MAX_PAGE_SIZE`
+    pub fn get_audit_log(env: Env, cursor: u32, limit: u32) -> AuditPage {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            panic!("Page limit must be within 1-100");
+        }
+        
+        let count = audit_count(&env);
+        let start = cursor.max(oldest_kept(count));
+        let end = start.saturating_add(limit).min(count);
+        let mut entries = Vec::new(&env);
+        let mut current = Vec::new(&env);
+        let mut current_id = u32::MAX;
+        
+        for seq in start..end {
+            let id = seq % MAX_AUDIT_ENTRIES / AUDIT_BUCKET_SIZE;
+            if id != current_id {
+                current = bucket(&env, id);
+                current_id = id;
+            }
+            entries.push_back(current.get_unchecked(seq % AUDIT_BUCKET_SIZE));
+        }
+        
+        AuditPage {
+            entries,
+            next: if end < count { Some(end) } else { None },
+        }
+    }
+    
+    /// Number of privileged actions ever recorded (read-only)
+    pub fn get_audit_count(env: Env) -> u32 {
+        audit_count(&env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::freshness::DEFAULT_REPLAY_WINDOW;
+    use soroban_sdk::{testutils::Address as _, xdr::FromXdr, BytesN};
+    
+    #[test]
+    fn test_admin_actions_are_logged_with_old_and_new_values() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+        
+        client.set_replay_window(&600);
+        let entry = client.get_audit_log(&0, &10).entries.get(0).unwrap();
+        assert_eq!((entry.seq, entry.actor, entry.action), (0, admin, Symbol::new(&env, "set_replay_window")));
+        assert_eq!(u64::from_xdr(&env, &entry.old_value), Ok(DEFAULT_REPLAY_WINDOW));
+        assert_eq!(u64::from_xdr(&env, &entry.new_value), Ok(600));
+        
+        // Only the most recent entries are kept
+        env.budget().reset_unlimited();
+        env.as_contract(&contract_id, || {
+            for _ in 0..MAX_AUDIT_ENTRIES {
+                record(&env, &Address::generate(&env), "test", (), ());
+            }
+        });
+        assert_eq!(client.get_audit_count(), MAX_AUDIT_ENTRIES + 1);
+        let page = client.get_audit_log(&0, &100);
+        assert_eq!(page.entries.get(0).unwrap().seq, AUDIT_BUCKET_SIZE);
+        assert_eq!(page.next, Some(AUDIT_BUCKET_SIZE + 100));
+        assert_eq!(client.get_audit_log(&MAX_AUDIT_ENTRIES, &100).next, None);
+    }
+}
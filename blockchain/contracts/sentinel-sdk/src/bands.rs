@@ -0,0 +1,54 @@
+//! Score bands for risk decisions.
+//!
+//! Pure `no_std` constants shared by the contract, the browser
+//! verification build and the in-contract verifier, so UI previews and
+//! in-process checks use the same bands as the chain.
+
+/// Highest valid risk score
+pub const MAX_RISK_SCORE: u32 = 100;
+
+/// Highest score that is still allowed
+pub const ALLOW_MAX_SCORE: u32 = 49;
+
+/// Highest score that is limited (above this wallets are frozen)
+pub const LIMIT_MAX_SCORE: u32 = 79;
+
+/// Highest score held for manual review (above this wallets are frozen)
+///
+/// Equal to `LIMIT_MAX_SCORE`: the Review band is empty until the admin
+/// configures one.
+pub const REVIEW_MAX_SCORE: u32 = LIMIT_MAX_SCORE;
+
+/// Limit amount applied to the Limit band (stroops)
+pub const DEFAULT_LIMIT_AMOUNT: u32 = 5000;
+
+/// Decision band a score falls into
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Band {
+    Allow,
+    Limit,
+    Review,
+    Freeze,
+}
+
+/// Map a score to its band under the given thresholds
+///
+/// Returns `None` if the score is above `MAX_RISK_SCORE`.
+pub fn band_for_score(
+    risk_score: u32,
+    allow_max: u32,
+    limit_max: u32,
+    review_max: u32,
+) -> Option<Band> {
+    if risk_score > MAX_RISK_SCORE {
+        None
+    } else if risk_score <= allow_max {
+        Some(Band::Allow)
+    } else if risk_score <= limit_max {
+        Some(Band::Limit)
+    } else if risk_score <= review_max {
+        Some(Band::Review)
+    } else {
+        Some(Band::Freeze)
+    }
+}
@@ -0,0 +1,175 @@
+//! Batched Oracle submissions.
+//!
+//! A contract cannot read the host's remaining resource budget, so
+//! `submit_risk_batch` meters itself against a conservative storage write
+//! budget instead. When the budget runs out it persists a cursor and emits
+//! `PARTIAL` rather than trapping, so the work already done is kept and the
+//! Oracle resubmits the same batch to continue from the resume point.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, panic_with_error, xdr::ToXdr, Env, Vec};
+
+use crate::crypto::{require_signature, signed_message};
+use crate::events;
+use crate::types::{BatchCursor, DataKey, RiskPayload, Signature};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Storage entry writes one invocation of `submit_risk_batch` may spend
+pub const BATCH_WRITE_BUDGET: u32 = 60;
+
+/// Entries written per stored payload (state, history, transition log)
+pub const WRITES_PER_SUBMISSION: u32 = 3;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Submit many signed risk scores in one call
+    /// 
+    /// Each payload is handled like `submit_risk`: invalid contents emit
+    /// `REJECTED` and are skipped, duplicates of the stored payload are
+    /// skipped silently. If the write budget runs out, the resume point is
+    /// stored and a `PARTIAL` event is emitted; calling again with the same
+    /// payloads continues from there.
+    /// 
+    /// # Arguments
+    /// * `payloads` - Risk data (at most `MAX_BATCH_SIZE`)
+    /// * `signatures` - Oracle signature for each payload, in order
+    ///   (ignored in Soroban-auth mode, where the Oracle address authorizes
+    ///   the whole batch)
+    /// 
+    /// # Returns
+    /// * Index of the first unprocessed payload (`payloads.len()` when done)
+    /// 
+    /// # Panics
+    /// * If batching is not enabled or the batch is too large
+    /// * If the payload and signature counts differ
+    /// * `InvalidSignature` if any processed signature does not verify
+    pub fn submit_risk_batch(
+        env: Env,
+        payloads: Vec<RiskPayload>,
+        signatures: Vec<Signature>,
+    ) -> u32 {
+        Self::require_batch_size(&env, payloads.len());
+        if payloads.len() != signatures.len() {
+            panic!("Payload and signature counts differ");
+        }
+        
+        let total = payloads.len();
+        let batch_hash = env.crypto().sha256(&payloads.clone().to_xdr(&env)).to_bytes();
+        let start = match Self::get_batch_cursor(env.clone()) {
+            Some(cursor) if cursor.batch_hash == batch_hash => cursor.next,
+            _ => 0,
+        };
+        
+        // Soroban-auth mode authorizes the whole batch once
+        let oracle_pubkey = match Self::get_oracle_address(env.clone()) {
+            Some(oracle) => {
+                oracle.require_auth();
+                None
+            }
+            None => Some(Self::oracle_pubkey(&env)),
+        };
+        let mut budget = BATCH_WRITE_BUDGET;
+        
+        for i in start..total {
+            if budget < WRITES_PER_SUBMISSION {
+                let cursor = BatchCursor { batch_hash, next: i, total };
+                env.storage().instance().set(&DataKey::BatchCursor, &cursor);
+                env.events().publish((topic::PARTIAL,), cursor);
+                return i;
+            }
+            
+            let payload = payloads.get_unchecked(i);
+            let signature = signatures.get_unchecked(i);
+            match &oracle_pubkey {
+                Some(key) => require_signature(&env, &payload, &signature, key),
+                None => {
+                    if let Err(error) = signed_message(&env, &payload) {
+                        panic_with_error!(&env, error);
+                    }
+                }
+            }
+            if Self::is_duplicate(&env, &payload) {
+                continue;
+            }
+            
+            if let Err(error) = Self::validate_payload(&env, &payload) {
+                events::publish_rejected(&env, &payload, error);
+                continue;
+            }
+            if Self::apply_risk(&env, &payload) {
+                budget -= WRITES_PER_SUBMISSION;
+            }
+        }
+        
+        env.storage().instance().remove(&DataKey::BatchCursor);
+        total
+    }
+    
+    /// Get the resume point of an unfinished batch (read-only)
+    pub fn get_batch_cursor(env: Env) -> Option<BatchCursor> {
+        env.storage().instance().get(&DataKey::BatchCursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::sign_for;
+    use soroban_sdk::Map;
+    use crate::types::PAYLOAD_VERSION_V2;
+    use ed25519_dalek::SigningKey;
+    use soroban_sdk::{
+        testutils::{Address as _, Events, Ledger},
+        Address, BytesN, IntoVal,
+    };
+    
+    #[test]
+    fn test_batch_resumes_after_partial() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        
+        let per_call = BATCH_WRITE_BUDGET / WRITES_PER_SUBMISSION;
+        let total = per_call + 5;
+        let mut payloads = Vec::new(&env);
+        let mut signatures = Vec::new(&env);
+        for _ in 0..total {
+            let payload = RiskPayload {
+                version: PAYLOAD_VERSION_V2,
+                wallet: Address::generate(&env),
+                risk_score: 30,
+                timestamp: 1_000,
+                reason_codes: Vec::new(&env),
+                freeze_duration: 0,
+                category_scores: Map::new(&env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(&env, &[0; 32]),
+            };
+            signatures.push_back(sign_for(&client, &key, &payload));
+            payloads.push_back(payload);
+        }
+        
+        assert_eq!(client.submit_risk_batch(&payloads, &signatures), per_call);
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (topic::PARTIAL,).into_val(&env));
+        assert_eq!(client.get_batch_cursor().unwrap().next, per_call);
+        assert!(client.get_risk(&payloads.get(per_call - 1).unwrap().wallet).is_some());
+        assert!(client.get_risk(&payloads.get(per_call).unwrap().wallet).is_none());
+        
+        // Same batch again continues from the cursor
+        assert_eq!(client.submit_risk_batch(&payloads, &signatures), total);
+        assert_eq!(client.get_batch_cursor(), None);
+        assert!(client.get_risk(&payloads.get(total - 1).unwrap().wallet).is_some());
+    }
+}
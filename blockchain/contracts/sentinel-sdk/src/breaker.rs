@@ -0,0 +1,194 @@
+//! Freeze-rate circuit breaker.
+//!
+//! A compromised or misbehaving Oracle pipeline shows up as a burst of new
+//! freezes. The admin may configure a ceiling on new Freeze decisions per
+//! rolling window (e.g. 500 per hour); when a stored score pushes the
+//! count over it, the breaker trips, `BRK_TRIP` is emitted and
+//! `check_permission` returns the configured fallback decision for every
+//! wallet until the admin resets it. Overrides still win.
+//!
+//! The rolling count is estimated from two fixed windows: the current
+//! one plus the previous one weighted by how much of it still overlaps.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, FreezeBreaker, FreezeWindow, RiskDecision};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Fallback decision, if the breaker has tripped
+pub(crate) fn breaker_decision(env: &Env) -> Option<RiskDecision> {
+    SentinelSDK::get_breaker_tripped(env.clone())?;
+    SentinelSDK::get_freeze_breaker(env.clone()).map(|breaker| breaker.decision)
+}
+
+/// Panic unless the breaker window is positive
+pub(crate) fn check_freeze_breaker(breaker: &FreezeBreaker) {
+    if breaker.window == 0 {
+        panic!("Breaker window must be positive");
+    }
+}
+
+impl SentinelSDK {
+    /// Store or clear the freeze-rate breaker (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If `window` is 0
+    pub(crate) fn apply_freeze_breaker(env: &Env, breaker: Option<FreezeBreaker>) {
+        let storage = env.storage().instance();
+        match &breaker {
+            Some(config) => {
+                check_freeze_breaker(config);
+                storage.set(&DataKey::Config(ConfigKey::FreezeBreaker), config);
+            }
+            None => {
+                storage.remove(&DataKey::Config(ConfigKey::FreezeBreaker));
+                storage.remove(&DataKey::FreezeWindow);
+                storage.remove(&DataKey::BreakerTripped);
+            }
+        }
+        env.events().publish((topic::BRK_SET,), breaker);
+    }
+    
+    /// Count a stored decision that newly freezes a wallet, tripping the
+    /// breaker once the rolling count exceeds the ceiling
+    pub(crate) fn count_freeze(env: &Env, previous: Option<&RiskDecision>, current: &RiskDecision) {
+        if *current != RiskDecision::Freeze || previous == Some(&RiskDecision::Freeze) {
+            return;
+        }
+        let Some(breaker) = Self::get_freeze_breaker(env.clone()) else {
+            return;
+        };
+        
+        let now = env.ledger().timestamp();
+        let mut counts: FreezeWindow = env
+            .storage()
+            .instance()
+            .get(&DataKey::FreezeWindow)
+            .unwrap_or(FreezeWindow { started_at: now, current: 0, previous: 0 });
+        let elapsed = now.saturating_sub(counts.started_at);
+        if elapsed >= breaker.window {
+            let skipped = elapsed / breaker.window;
+            counts.previous = if skipped == 1 { counts.current } else { 0 };
+            counts.current = 0;
+            counts.started_at += skipped * breaker.window;
+        }
+        counts.current += 1;
+        env.storage().instance().set(&DataKey::FreezeWindow, &counts);
+        
+        let into_window = now - counts.started_at;
+        let overlap = counts.previous as u64 * (breaker.window - into_window) / breaker.window;
+        let rolling = counts.current as u64 + overlap;
+        if rolling > breaker.max_freezes as u64 && Self::get_breaker_tripped(env.clone()).is_none() {
+            env.storage().instance().set(&DataKey::BreakerTripped, &now);
+            env.events().publish(
+                (topic::BRK_TRIP,),
+                (rolling, breaker.max_freezes, breaker.window)
+            );
+        }
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the freeze-rate breaker configuration, if any (read-only)
+    pub fn get_freeze_breaker(env: Env) -> Option<FreezeBreaker> {
+        env.storage().instance().get(&DataKey::Config(ConfigKey::FreezeBreaker))
+    }
+    
+    /// Set or remove the freeze-rate breaker (admin only)
+    /// 
+    /// Removing it also clears a tripped breaker and the freeze counts.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If `window` is 0
+    pub fn set_freeze_breaker(env: Env, breaker: Option<FreezeBreaker>) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_freeze_breaker(env.clone());
+        Self::apply_freeze_breaker(&env, breaker.clone());
+        audit::record(&env, &admin, "set_freeze_breaker", old, breaker);
+    }
+    
+    /// Unix timestamp the breaker tripped, if it is tripped (read-only)
+    pub fn get_breaker_tripped(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::BreakerTripped)
+    }
+    
+    /// Reset a tripped breaker and its freeze counts (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    pub fn reset_freeze_breaker(env: Env) {
+        let admin = Self::require_admin(&env);
+        
+        let old = Self::get_breaker_tripped(env.clone());
+        env.storage().instance().remove(&DataKey::BreakerTripped);
+        env.storage().instance().remove(&DataKey::FreezeWindow);
+        
+        audit::record(&env, &admin, "reset_freeze_breaker", old, ());
+        env.events().publish((topic::BRK_RESET,), admin);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::{TimeTravel, HOUR};
+    use crate::types::{RiskPayload, PAYLOAD_VERSION_V2};
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Map, Vec};
+    
+    #[test]
+    fn test_freeze_burst_trips_breaker() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.set_time(1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        client.set_freeze_breaker(&Some(FreezeBreaker {
+            max_freezes: 2,
+            window: HOUR,
+            decision: RiskDecision::Limit(100),
+        }));
+        
+        let bystander = Address::generate(&env);
+        for _ in 0..3 {
+            let payload = RiskPayload {
+                version: PAYLOAD_VERSION_V2,
+                wallet: Address::generate(&env),
+                risk_score: 95,
+                timestamp: 1_000,
+                reason_codes: Vec::new(&env),
+                freeze_duration: 0,
+                category_scores: Map::new(&env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(&env, &[0; 32]),
+            };
+            assert_eq!(client.get_breaker_tripped(), None);
+            
+            let message = crate::crypto::serialize_canonical_json(&env, &client.address, &payload);
+            let message: std::vec::Vec<u8> = message.iter().collect();
+            client.submit_risk(&payload, &BytesN::from_array(&env, &key.sign(&message).to_bytes()));
+        }
+        
+        assert_eq!(client.get_breaker_tripped(), Some(1_000));
+        assert_eq!(client.check_permission(&bystander), RiskDecision::Limit(100));
+        
+        env.advance_secs(HOUR);
+        assert_eq!(client.check_permission(&bystander), RiskDecision::Limit(100));
+        client.reset_freeze_breaker();
+        assert_eq!(client.check_permission(&bystander), RiskDecision::Allow);
+    }
+}
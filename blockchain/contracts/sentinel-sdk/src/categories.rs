@@ -0,0 +1,134 @@
+//! Per-category risk decisions.
+//!
+//! The Oracle may score a wallet separately for AML, fraud, sanctions and
+//! bot activity in the same signed payload. `check_permission` keeps
+//! returning the worst case over all scores; `check_permission_category`
+//! answers for a single category. Requires the `CATEGORIES` feature.
+
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::pipeline;
+use crate::types::{feature, RiskCategory, RiskDecision};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Check permission for a wallet on one risk category
+    /// 
+    /// Runs the configured pipeline on the category's score; active
+    /// overrides still win. Scored wallets without a score in this category
+    /// are treated as 0; never-scored wallets get the unknown default.
+    /// 
+    /// # Panics
+    /// * If the `CATEGORIES` feature is not enabled
+    pub fn check_permission_category(env: Env, wallet: Address, category: RiskCategory) -> RiskDecision {
+        Self::require_feature(&env, feature::CATEGORIES);
+        pipeline::evaluate_category(&env, &wallet, category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::sign_for;
+    use crate::types::{RiskPayload, PAYLOAD_VERSION_V2};
+    use ed25519_dalek::SigningKey;
+    use soroban_sdk::{map, testutils::Address as _, BytesN, Vec};
+    
+    #[test]
+    fn test_category_decisions_and_worst_case() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        client.set_features(&(feature::DEFAULT | feature::CATEGORIES));
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 20,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: map![&env, (RiskCategory::Aml, 10), (RiskCategory::Sanctions, 95)],
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &key, &payload));
+        
+        assert_eq!(client.check_permission_category(&wallet, &RiskCategory::Aml), RiskDecision::Allow);
+        assert_eq!(
+            client.check_permission_category(&wallet, &RiskCategory::Sanctions),
+            RiskDecision::Freeze
+        );
+        assert_eq!(client.check_permission_category(&wallet, &RiskCategory::Fraud), RiskDecision::Allow);
+        
+        // Backward-compatible view is the worst case
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.get_risk(&wallet).unwrap().risk_score, 20);
+    }
+    
+    #[test]
+    #[cfg(not(feature = "events-minimal"))]
+    fn test_category_topics_emitted_on_transition_only() {
+        use soroban_sdk::{testutils::{Events, Ledger}, IntoVal};
+        
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 100);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        client.set_features(&(feature::DEFAULT | feature::CATEGORIES));
+        
+        let wallet = Address::generate(&env);
+        let mut payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 20,
+            timestamp: 90,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: map![&env, (RiskCategory::Aml, 10), (RiskCategory::Sanctions, 95)],
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &key, &payload));
+        let first = env.events().all().len();
+        
+        // Sanctions drops to Allow; AML stays Allow
+        payload.timestamp = 95;
+        payload.category_scores = map![&env, (RiskCategory::Aml, 12), (RiskCategory::Sanctions, 5)];
+        client.submit_risk(&payload, &sign_for(&client, &key, &payload));
+        
+        // Only the second submission's events: the first score always publishes
+        let topics: std::vec::Vec<_> = env
+            .events()
+            .all()
+            .iter()
+            .skip(first as usize)
+            .map(|(_, topics, _)| topics)
+            .collect();
+        let topic = |category: RiskCategory| -> Vec<soroban_sdk::Val> {
+            (category.event_topic(), wallet.clone()).into_val(&env)
+        };
+        assert!(topics.contains(&topic(RiskCategory::Sanctions)));
+        assert!(!topics.contains(&topic(RiskCategory::Aml)));
+    }
+}
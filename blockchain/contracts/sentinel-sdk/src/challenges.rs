@@ -0,0 +1,323 @@
+//! Permissionless freshness challenges.
+//!
+//! The Oracle operator posts a bond in a token. Anyone holding a signed
+//! Oracle payload that is newer than a wallet's stale on-chain state can
+//! submit it through `challenge_freshness`; the newer state is applied and
+//! the challenger is paid a small reward out of the bond. This crowdsources
+//! keeping critical wallets' states current.
+//!
+//! Only states older than the staleness threshold can be challenged, so
+//! nobody earns a reward by front-running the Oracle's own submission of a
+//! payload it just signed.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, panic_with_error, token, Address, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, OracleBond, RiskPayload, Signature};
+use crate::{events, SentinelSDK, SentinelSDKClient};
+
+/// Reward paid per successful challenge, in basis points of the bond
+pub const CHALLENGE_REWARD_BPS: i128 = 10;
+
+/// Default age a stored state must reach before it can be challenged (1 day)
+pub const DEFAULT_CHALLENGE_STALENESS: u64 = 86_400;
+
+/// Smallest configurable staleness threshold (1 hour)
+pub const MIN_CHALLENGE_STALENESS: u64 = 3_600;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Post or top up the Oracle operator bond
+    /// 
+    /// The first deposit fixes the operator and bond token; later deposits
+    /// must come from the same operator in the same token.
+    /// 
+    /// # Arguments
+    /// * `operator` - Oracle operator funding the bond (must authorize)
+    /// * `token` - Token contract the bond is held in
+    /// * `amount` - Amount to transfer into the bond
+    /// 
+    /// # Panics
+    /// * If amount is not positive
+    /// * If a bond exists for a different operator or token
+    pub fn post_oracle_bond(env: Env, operator: Address, token: Address, amount: i128) {
+        operator.require_auth();
+        
+        if amount <= 0 {
+            panic!("Bond amount must be positive");
+        }
+        
+        let mut bond = match Self::get_oracle_bond(env.clone()) {
+            Some(bond) => {
+                if bond.operator != operator || bond.token != token {
+                    panic!("Bond already posted by another operator or token");
+                }
+                bond
+            }
+            None => OracleBond {
+                operator: operator.clone(),
+                token: token.clone(),
+                amount: 0,
+            },
+        };
+        
+        token::Client::new(&env, &token).transfer(
+            &operator,
+            &env.current_contract_address(),
+            &amount,
+        );
+        
+        bond.amount += amount;
+        env.storage().instance().set(&DataKey::Config(ConfigKey::ChallengeBond), &bond);
+        
+        env.events().publish(
+            (topic::BONDED,),
+            (operator, amount, bond.amount)
+        );
+    }
+    
+    /// Get the current Oracle bond (read-only)
+    /// 
+    /// # Returns
+    /// * `Some(OracleBond)` if a bond has been posted
+    /// * `None` otherwise
+    pub fn get_oracle_bond(env: Env) -> Option<OracleBond> {
+        env.storage().instance().get(&DataKey::Config(ConfigKey::ChallengeBond))
+    }
+    
+    /// Get the age a stored state must reach to be challenged (read-only)
+    pub fn get_challenge_staleness(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::ChallengeStaleness))
+            .unwrap_or(DEFAULT_CHALLENGE_STALENESS)
+    }
+    
+    /// Set the age a stored state must reach to be challenged (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `seconds` is below `MIN_CHALLENGE_STALENESS`
+    pub fn set_challenge_staleness(env: Env, seconds: u64) {
+        let admin = Self::require_admin(&env);
+        
+        if seconds < MIN_CHALLENGE_STALENESS {
+            panic!("Challenge staleness must be at least 3600 seconds");
+        }
+        
+        let old = Self::get_challenge_staleness(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Config(ConfigKey::ChallengeStaleness), &seconds);
+        audit::record(&env, &admin, "set_challenge_staleness", old, seconds);
+        env.events().publish((topic::CHAL_SET,), (old, seconds));
+    }
+    
+    /// Challenge a wallet's stale on-chain state with a newer Oracle payload
+    /// 
+    /// The stored state must be older than the staleness threshold (see
+    /// `set_challenge_staleness`) and the payload signed by the Oracle and
+    /// strictly newer than it. The payload is then validated and applied
+    /// like `submit_risk` (replay window, rate limit, score ranges, jump
+    /// guard). On success the challenger receives `CHALLENGE_REWARD_BPS`
+    /// of the remaining bond.
+    /// 
+    /// # Arguments
+    /// * `challenger` - Address receiving the reward (must authorize)
+    /// * `payload` - Newer Oracle-signed risk data
+    /// * `signature` - Ed25519 signature from Oracle
+    /// 
+    /// # Returns
+    /// * Reward paid to the challenger (0 if the payload is rejected or held
+    ///   by the jump guard)
+    /// 
+    /// # Panics
+    /// * `InvalidSignature` if the signature does not verify
+    /// * If the wallet has no stored state or the payload is not newer
+    /// * If the stored state is not older than the staleness threshold
+    pub fn challenge_freshness(
+        env: Env,
+        challenger: Address,
+        payload: RiskPayload,
+        signature: Signature,
+    ) -> i128 {
+        challenger.require_auth();
+        
+        let stored = Self::get_risk(env.clone(), payload.wallet.clone())
+            .expect("No on-chain state to challenge");
+        
+        if payload.timestamp <= stored.last_updated {
+            panic!("Challenge payload is not newer than on-chain state");
+        }
+        let age = env.ledger().timestamp().saturating_sub(stored.last_updated);
+        if age < Self::get_challenge_staleness(env.clone()) {
+            panic!("On-chain state is not stale");
+        }
+        
+        if let Err(error) = Self::authenticate_payload(&env, &payload, &signature) {
+            panic_with_error!(&env, error);
+        }
+        
+        if let Err(error) = Self::validate_payload(&env, &payload) {
+            events::publish_rejected(&env, &payload, error);
+            return 0;
+        }
+        
+        if !Self::apply_risk(&env, &payload) {
+            return 0;
+        }
+        
+        let reward = Self::pay_challenge_reward(&env, &challenger);
+        
+        env.events().publish(
+            (topic::CHALLENGE,),
+            (
+                challenger,
+                payload.wallet.clone(),
+                stored.last_updated,
+                payload.timestamp,
+                reward,
+            )
+        );
+        
+        reward
+    }
+}
+
+impl SentinelSDK {
+    /// Pay the challenge reward out of the bond (0 if unbonded)
+    fn pay_challenge_reward(env: &Env, challenger: &Address) -> i128 {
+        let mut bond = match Self::get_oracle_bond(env.clone()) {
+            Some(bond) => bond,
+            None => return 0,
+        };
+        
+        let reward = bond.amount * CHALLENGE_REWARD_BPS / 10_000;
+        if reward <= 0 {
+            return 0;
+        }
+        
+        token::Client::new(env, &bond.token).transfer(
+            &env.current_contract_address(),
+            challenger,
+            &reward,
+        );
+        
+        bond.amount -= reward;
+        env.storage().instance().set(&DataKey::Config(ConfigKey::ChallengeBond), &bond);
+        
+        reward
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::sign_for;
+    use soroban_sdk::Map;
+    use crate::types::{RiskDecision, PAYLOAD_VERSION_V2};
+    use ed25519_dalek::SigningKey;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token::StellarAssetClient,
+        BytesN, Vec,
+    };
+    
+    #[test]
+    fn test_newer_payload_wins_challenge_and_pays_reward() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let oracle_key = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+        client.initialize(&Address::generate(&env), &oracle_key);
+        
+        // Bond 100_000 units
+        let operator = Address::generate(&env);
+        let token_admin = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(token_admin).address();
+        StellarAssetClient::new(&env, &token).mint(&operator, &100_000);
+        client.post_oracle_bond(&operator, &token, &100_000);
+        
+        // Oracle pushes an old state, later publishes a newer one off-chain
+        let wallet = Address::generate(&env);
+        let old = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 10,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&old, &sign_for(&client, &key, &old));
+        
+        // Not stale yet: the Oracle's own submission cannot be front-run
+        env.ledger().with_mut(|li| li.timestamp = 10_000);
+        let mut newer = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 90,
+            timestamp: 10_000,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        
+        let challenger = Address::generate(&env);
+        assert!(client
+            .try_challenge_freshness(&challenger, &newer, &sign_for(&client, &key, &newer))
+            .is_err());
+        
+        env.ledger().with_mut(|li| li.timestamp = DEFAULT_CHALLENGE_STALENESS);
+        newer.timestamp = DEFAULT_CHALLENGE_STALENESS;
+        let reward = client.challenge_freshness(&challenger, &newer, &sign_for(&client, &key, &newer));
+        
+        assert_eq!(reward, 100);
+        assert_eq!(token::Client::new(&env, &token).balance(&challenger), 100);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.get_oracle_bond().unwrap().amount, 99_900);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Challenge payload is not newer")]
+    fn test_equal_timestamp_challenge_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let oracle_key = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+        client.initialize(&Address::generate(&env), &oracle_key);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet,
+            risk_score: 10,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let signature = sign_for(&client, &key, &payload);
+        client.submit_risk(&payload, &signature);
+        
+        client.challenge_freshness(&Address::generate(&env), &payload, &signature);
+    }
+}
@@ -0,0 +1,130 @@
+//! Compaction policy for per-wallet logs.
+//!
+//! Risk history and transition logs keep their most recent entries in
+//! full. Older entries are thinned to one per month (the last one of that
+//! month) and at most `MAX_ARCHIVED_MONTHS` of those are kept, so a wallet
+//! scored for years stays bounded while its long-term trajectory remains
+//! visible. Compaction runs lazily on every append; `compact(wallet)`
+//! applies it on demand, e.g. to logs written before the policy existed.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::history::MAX_HISTORY;
+use crate::transitions::MAX_TRANSITIONS;
+use crate::types::{DataKey, RiskState, Transition};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Length of an archive month in seconds (30 days)
+pub const MONTH: u64 = 30 * 86_400;
+
+/// Maximum monthly entries kept beyond the recent ones (3 years)
+pub const MAX_ARCHIVED_MONTHS: u32 = 36;
+
+/// Apply the compaction policy to an oldest-first log
+/// 
+/// Keeps the last `recent` entries as-is; of the rest, keeps the last
+/// entry of each month, at most `MAX_ARCHIVED_MONTHS` of them.
+pub(crate) fn compact_log<T, F>(env: &Env, log: &Vec<T>, recent: u32, timestamp: F) -> Vec<T>
+where
+    T: soroban_sdk::IntoVal<Env, soroban_sdk::Val> + soroban_sdk::TryFromVal<Env, soroban_sdk::Val>,
+    F: Fn(&T) -> u64,
+{
+    if log.len() <= recent {
+        return log.clone();
+    }
+    let split = log.len() - recent;
+    
+    let mut archive: Vec<T> = Vec::new(env);
+    for i in 0..split {
+        let entry = log.get_unchecked(i);
+        let month = timestamp(&entry) / MONTH;
+        // Keep only the last entry of each month
+        let superseded = i + 1 < split && timestamp(&log.get_unchecked(i + 1)) / MONTH == month;
+        if !superseded {
+            archive.push_back(entry);
+        }
+    }
+    while archive.len() > MAX_ARCHIVED_MONTHS {
+        archive.pop_front();
+    }
+    
+    archive.append(&log.slice(split..));
+    archive
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Compact a wallet's risk history and transition log
+    /// 
+    /// Permissionless maintenance; the result is the same as the lazy
+    /// compaction applied on writes.
+    /// 
+    /// # Returns
+    /// * Number of entries removed
+    pub fn compact(env: Env, wallet: Address) -> u32 {
+        let history = Self::get_risk_history(env.clone(), wallet.clone());
+        let compacted_history = compact_log(&env, &history, MAX_HISTORY, |s: &RiskState| s.last_updated);
+        
+        let log = Self::transition_log(env.clone(), wallet.clone());
+        let compacted_log = compact_log(&env, &log, MAX_TRANSITIONS, |t: &Transition| t.timestamp);
+        
+        let removed = (history.len() - compacted_history.len()) + (log.len() - compacted_log.len());
+        if removed > 0 {
+            env.storage()
+                .persistent()
+                .set(&DataKey::History(wallet.clone()), &compacted_history);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Transitions(wallet.clone()), &compacted_log);
+            env.events().publish((topic::COMPACT,), (wallet, removed));
+        }
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::vec;
+    
+    #[test]
+    fn test_keeps_recent_and_one_per_month() {
+        let env = Env::default();
+        
+        // Two entries in month 0, three in month 1, then 3 recent
+        let log: Vec<u64> = vec![
+            &env,
+            1,
+            2,
+            MONTH + 1,
+            MONTH + 2,
+            MONTH + 3,
+            5 * MONTH,
+            5 * MONTH + 1,
+            5 * MONTH + 2,
+        ];
+        let compacted = compact_log(&env, &log, 3, |t: &u64| *t);
+        assert_eq!(
+            compacted,
+            vec![&env, 2, MONTH + 3, 5 * MONTH, 5 * MONTH + 1, 5 * MONTH + 2]
+        );
+        
+        // Already compact: unchanged
+        assert_eq!(compact_log(&env, &compacted, 3, |t: &u64| *t), compacted);
+    }
+    
+    #[test]
+    fn test_archive_is_bounded() {
+        let env = Env::default();
+        
+        let mut log: Vec<u64> = Vec::new(&env);
+        for month in 0..MAX_ARCHIVED_MONTHS as u64 + 10 {
+            log.push_back(month * MONTH);
+        }
+        let compacted = compact_log(&env, &log, 2, |t: &u64| *t);
+        
+        assert_eq!(compacted.len(), MAX_ARCHIVED_MONTHS + 2);
+        assert_eq!(compacted.get(0), Some(8 * MONTH));
+    }
+}
@@ -0,0 +1,43 @@
+//! Signer conformance check.
+//!
+//! `conformance_hash` commits to the canonical serialization parameters
+//! (payload format version, domain tag, timestamp units). The off-chain
+//! signer computes the same hash at startup and refuses to run against a
+//! deployment that disagrees, instead of producing signatures the contract
+//! silently rejects.
+
+use soroban_sdk::{contractimpl, Bytes, BytesN, Env};
+
+use crate::canonical::write_conformance_descriptor;
+use crate::crypto::BytesSink;
+use crate::types::CURRENT_PAYLOAD_VERSION;
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// SHA-256 of this deployment's serialization parameters (read-only)
+    pub fn conformance_hash(env: Env) -> BytesN<32> {
+        let mut descriptor = Bytes::new(&env);
+        write_conformance_descriptor(&mut BytesSink(&mut descriptor), CURRENT_PAYLOAD_VERSION);
+        env.crypto().sha256(&descriptor).to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_conformance_hash_matches_published_vector() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        
+        // sha256("sentinel-conformance|format=2|domain=sentinel-risk|timestamp=s")
+        let expected = BytesN::from_array(&env, &[
+            243, 250, 195, 248, 162, 155, 150, 241, 177, 142, 138, 191, 122, 248, 54, 209,
+            76, 74, 52, 251, 23, 20, 154, 220, 184, 255, 165, 181, 151, 12, 207, 142,
+        ]);
+        assert_eq!(client.conformance_hash(), expected);
+    }
+}
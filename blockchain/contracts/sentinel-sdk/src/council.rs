@@ -0,0 +1,175 @@
+//! Guardian council for emergency Oracle key replacement.
+//!
+//! Key rotation normally runs through a trial and, with a timelock
+//! configured, a queue delay. If the Oracle key leaks that is far too
+//! slow: the attacker can sign scores the whole time. The admin may name
+//! an M-of-N council of guardian addresses; once M of them approve the
+//! same replacement key, it becomes the live Oracle key immediately,
+//! bypassing the trial and the timelock. Each guardian approves one key
+//! at a time, and every approval is stored with the council and emitted,
+//! so the replacement is auditable on-chain.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, Map, Vec};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, GuardianCouncil, PublicKey};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Largest guardian council
+pub const MAX_COUNCIL_GUARDIANS: u32 = 10;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the guardian council and its pending approvals (read-only)
+    pub fn get_guardian_council(env: Env) -> Option<GuardianCouncil> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::GuardianCouncil))
+    }
+    
+    /// Set the guardian council (admin only)
+    /// 
+    /// Replaces the council and drops pending approvals; an empty guardian
+    /// list removes the council.
+    /// 
+    /// # Arguments
+    /// * `guardians` - Addresses that may approve a replacement key
+    /// * `threshold` - Approvals needed to replace the Oracle key
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If there are more than `MAX_COUNCIL_GUARDIANS` guardians or one
+    ///   is listed twice
+    /// * If the threshold is 0 or above the number of guardians
+    pub fn set_guardian_council(env: Env, guardians: Vec<Address>, threshold: u32) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_guardian_council(env.clone());
+        Self::apply_guardian_council(&env, guardians.clone(), threshold);
+        audit::record(&env, &admin, "set_guardian_council", old, (guardians, threshold));
+    }
+    
+    /// Approve replacing the live Oracle key with `new_key` (guardian only)
+    /// 
+    /// Supersedes the guardian's earlier approval, if any. Once the
+    /// threshold of guardians approve the same key it becomes the live
+    /// Oracle key at once, any key trial is abandoned and pending
+    /// approvals are cleared.
+    /// 
+    /// # Returns
+    /// * Guardians approving `new_key`, including this one
+    /// 
+    /// # Panics
+    /// * If no council is configured, or `guardian` is not on it or has
+    ///   not authorized the call
+    /// * In Soroban-auth mode (see `initialize_with_oracle_address`)
+    /// * If `new_key` is already the live key
+    pub fn approve_oracle_replacement(env: Env, guardian: Address, new_key: PublicKey) -> u32 {
+        guardian.require_auth();
+        
+        let mut council = Self::get_guardian_council(env.clone()).expect("No guardian council configured");
+        if !council.guardians.contains(&guardian) {
+            panic!("Not a council guardian");
+        }
+        if Self::get_oracle_address(env.clone()).is_some() {
+            panic!("Oracle key replacement is not available in Soroban-auth mode");
+        }
+        let old_key = Self::oracle_pubkey(&env);
+        if new_key == old_key {
+            panic!("Key is already the live Oracle key");
+        }
+        
+        let old = council.approvals.get(guardian.clone());
+        council.approvals.set(guardian.clone(), new_key.clone());
+        audit::record(&env, &guardian, "approve_oracle_replacement", old, new_key.clone());
+        let mut approvers = Vec::new(&env);
+        for (approver, key) in council.approvals.iter() {
+            if key == new_key {
+                approvers.push_back(approver);
+            }
+        }
+        env.events().publish(
+            (topic::RPL_APPRV, guardian),
+            (new_key.clone(), approvers.len(), council.threshold),
+        );
+        
+        let storage = env.storage().instance();
+        if approvers.len() >= council.threshold {
+            council.approvals = Map::new(&env);
+            storage.set(&DataKey::Oracle, &new_key);
+            storage.remove(&DataKey::KeyTrial);
+            env.events().publish((topic::ORCL_RPL,), (old_key, new_key, approvers.clone()));
+        }
+        storage.set(&DataKey::Config(ConfigKey::GuardianCouncil), &council);
+        
+        approvers.len()
+    }
+}
+
+impl SentinelSDK {
+    /// Replace the guardian council (authorization checked by caller)
+    pub(crate) fn apply_guardian_council(env: &Env, guardians: Vec<Address>, threshold: u32) {
+        let storage = env.storage().instance();
+        if guardians.is_empty() {
+            storage.remove(&DataKey::Config(ConfigKey::GuardianCouncil));
+            env.events().publish((topic::COUNCIL,), (guardians, 0u32));
+            return;
+        }
+        
+        if guardians.len() > MAX_COUNCIL_GUARDIANS {
+            panic!("Too many guardians: max 10");
+        }
+        for (i, guardian) in guardians.iter().enumerate() {
+            if guardians.first_index_of(&guardian) != Some(i as u32) {
+                panic!("Duplicate guardian");
+            }
+        }
+        if threshold == 0 || threshold > guardians.len() {
+            panic!("Threshold must be between 1 and the number of guardians");
+        }
+        
+        let council = GuardianCouncil {
+            guardians: guardians.clone(),
+            threshold,
+            approvals: Map::new(env),
+        };
+        storage.set(&DataKey::Config(ConfigKey::GuardianCouncil), &council);
+        env.events().publish((topic::COUNCIL,), (guardians, threshold));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, vec, BytesN};
+    
+    #[test]
+    fn test_council_replaces_key_past_timelock() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let old_key = BytesN::from_array(&env, &[1u8; 32]);
+        client.initialize(&Address::generate(&env), &old_key);
+        
+        let (a, b, c) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+        client.set_guardian_council(&vec![&env, a.clone(), b.clone(), c.clone()], &2);
+        client.set_timelock_delay(&86_400);
+        
+        let new_key = BytesN::from_array(&env, &[2u8; 32]);
+        let other_key = BytesN::from_array(&env, &[3u8; 32]);
+        assert!(client.try_approve_oracle_replacement(&Address::generate(&env), &new_key).is_err());
+        
+        // Split approvals do not reach the threshold
+        assert_eq!(client.approve_oracle_replacement(&a, &new_key), 1);
+        assert_eq!(client.approve_oracle_replacement(&b, &other_key), 1);
+        assert_eq!(client.get_oracle_pubkey(), Some(old_key));
+        assert_eq!(client.get_guardian_council().unwrap().approvals.get(a), Some(new_key.clone()));
+        
+        assert_eq!(client.approve_oracle_replacement(&c, &new_key), 2);
+        assert_eq!(client.get_oracle_pubkey(), Some(new_key));
+        assert!(client.get_guardian_council().unwrap().approvals.is_empty());
+    }
+}
@@ -3,103 +3,346 @@
 //! This module handles Ed25519 signature verification using Soroban's crypto primitives.
 //! Implementation matches the Oracle's canonical JSON serialization format.
 
-use soroban_sdk::{Bytes, Env, Address, symbol_short, xdr::ToXdr};
-use crate::types::{RiskPayload, Signature, PublicKey};
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use soroban_sdk::{panic_with_error, Bytes, BytesN, Env, Address, Symbol, Vec, xdr::ToXdr};
+use crate::canonical::{
+    write_appeal_ruling_json, write_labels_json, write_merkle_root_json, write_payload_json, ByteSink,
+    OptionalFields, MAX_WALLET_LEN,
+};
+use crate::errors::SentinelError;
+use crate::types::{
+    AppealOutcome, MerkleLeaf, P256PublicKey, RiskCategory, RiskPayload, Signature, PublicKey, PAYLOAD_VERSION_V2,
+};
+use crate::SentinelSDK;
 
-/// Verify Ed25519 signature from Oracle
+/// Verify an Oracle signature over a payload
 /// 
 /// This function verifies that the payload was signed by the Oracle's private key.
 /// The signature verification process:
-/// 1. Serialize payload to canonical JSON (matching Oracle's format)
-/// 2. Verify signature using Soroban's ed25519_verify
+/// 1. Build the signed message for the payload's version
+/// 2. Verify signature with the key's scheme (see `verify_message`)
 /// 
 /// # Arguments
 /// * `env` - Soroban environment
 /// * `payload` - Risk data (wallet, score, timestamp)
-/// * `signature` - 64-byte Ed25519 signature from Oracle
-/// * `public_key` - 32-byte Ed25519 public key of Oracle
+/// * `signature` - 64-byte signature from Oracle
+/// * `public_key` - 32-byte Oracle key id
 /// 
-/// # Returns
-/// * `true` if signature is valid
-/// * Panics if signature is invalid (ed25519_verify panics on invalid sigs)
+/// # Errors
+/// * `UnsupportedPayloadVersion` if the version is unknown
+/// * `InvalidSignature` if the signature does not verify
 pub fn verify_signature(
     env: &Env,
     payload: &RiskPayload,
     signature: &Signature,
     public_key: &PublicKey,
-) -> bool {
-    // Serialize payload to canonical JSON matching Oracle's format
-    let message = serialize_canonical_json(env, payload);
+) -> Result<(), SentinelError> {
+    // Build the message matching the Oracle's format for this version
+    let message = signed_message(env, payload)?;
     
-    // DEBUG: Emit the exact message being verified
+    // DEBUG: Emit the exact message being verified (feature `debug-events`)
     // This allows us to see exactly what the contract constructed
+    #[cfg(feature = "debug-events")]
     env.events().publish(
-        (symbol_short!("DBG_MSG"),),
+        (sentinel_events::topic::DBG_MSG,),
         message.clone()
     );
     
-    // Verify using ED25519
-    env.crypto().ed25519_verify(public_key, &message, signature);
+    verify_message(env, &message, signature, public_key)
+}
+
+/// Verify an Oracle signature over a payload, trapping on failure
+/// 
+/// # Panics
+/// * `UnsupportedPayloadVersion` if the version is unknown
+/// * `InvalidSignature` if the signature does not verify
+pub(crate) fn require_signature(env: &Env, payload: &RiskPayload, signature: &Signature, public_key: &PublicKey) {
+    if let Err(error) = verify_signature(env, payload, signature, public_key) {
+        panic_with_error!(env, error);
+    }
+}
+
+/// Verify an Oracle signature over raw message bytes
+/// 
+/// Keys registered with `register_p256_key` verify as ECDSA secp256r1
+/// over the SHA-256 of the message (low-S form); every other key verifies
+/// as Ed25519 over the message itself.
+/// 
+/// The host's `ed25519_verify` and `secp256r1_verify` trap on a bad
+/// signature, so both schemes are checked in contract code instead:
+/// costlier, but callers can report a bad signature as an error.
+/// 
+/// # Errors
+/// * `InvalidSignature` if the signature does not verify
+pub(crate) fn verify_message(
+    env: &Env,
+    message: &Bytes,
+    signature: &Signature,
+    public_key: &PublicKey,
+) -> Result<(), SentinelError> {
+    let valid = match SentinelSDK::p256_key(env, public_key) {
+        Some(p256_key) => verify_p256(env, message, signature, &p256_key),
+        None => verify_ed25519(message, signature, public_key),
+    };
+    if !valid {
+        return Err(SentinelError::InvalidSignature);
+    }
+    Ok(())
+}
+
+/// Ed25519 check; weak (small-order) keys never verify
+fn verify_ed25519(message: &Bytes, signature: &Signature, public_key: &PublicKey) -> bool {
+    let Ok(key) = ed25519_dalek::VerifyingKey::from_bytes(&public_key.to_array()) else {
+        return false;
+    };
+    if key.is_weak() {
+        return false;
+    }
+    let signature = ed25519_dalek::Signature::from_bytes(&signature.to_array());
+    let Ok(mut verifier) = key.verify_stream(&signature) else {
+        return false;
+    };
     
-    // If we reach here, signature is valid
-    true
+    // Feed the message from the host in fixed-size chunks
+    let mut chunk = [0u8; 64];
+    let mut offset = 0;
+    while offset < message.len() {
+        let end = message.len().min(offset + chunk.len() as u32);
+        let part = &mut chunk[..(end - offset) as usize];
+        message.slice(offset..end).copy_into_slice(part);
+        verifier.update(&*part);
+        offset = end;
+    }
+    
+    verifier.finalize_and_verify().is_ok()
+}
+
+/// ECDSA secp256r1 check over the SHA-256 of the message; high-S
+/// signatures never verify, as on the host
+fn verify_p256(env: &Env, message: &Bytes, signature: &Signature, key: &P256PublicKey) -> bool {
+    let Ok(key) = p256::ecdsa::VerifyingKey::from_sec1_bytes(&key.to_array()) else {
+        return false;
+    };
+    let Ok(signature) = p256::ecdsa::Signature::from_slice(&signature.to_array()) else {
+        return false;
+    };
+    if signature.normalize_s().is_some() {
+        return false;
+    }
+    
+    let digest = env.crypto().sha256(message);
+    key.verify_prehash(&digest.to_array(), &signature).is_ok()
+}
+
+/// Build the exact bytes the Oracle signed for a payload
+/// 
+/// Dispatches on `payload.version`. New schemas (extra fields, new signing
+/// formats) get a new arm here, leaving existing Oracle deployments on
+/// their original format. The version is part of every accepted message,
+/// so the format picked here is one the Oracle signed.
+/// 
+/// v1 messages carried neither the version nor the deployment and are no
+/// longer accepted: their signatures were valid on every deployment.
+/// 
+/// # Errors
+/// * `UnsupportedPayloadVersion` if the version is unknown
+pub(crate) fn signed_message(env: &Env, payload: &RiskPayload) -> Result<Bytes, SentinelError> {
+    match payload.version {
+        PAYLOAD_VERSION_V2 => Ok(serialize_canonical_json(env, &env.current_contract_address(), payload)),
+        _ => Err(SentinelError::UnsupportedPayloadVersion),
+    }
 }
 
 /// Serialize RiskPayload to canonical JSON format (matching Oracle)
 /// 
-/// Format: {"risk_score":87,"timestamp":1737718800,"wallet":"GXXX..."}
+/// Format: {"domain":"sentinel-risk:CXXX...","risk_score":87,"timestamp":1737718800,"version":2,"wallet":"GXXX..."}
+/// 
+/// With reason codes:
+/// {"domain":"sentinel-risk:CXXX...","reason_codes":[1,3],"risk_score":87,"timestamp":1737718800,"version":2,"wallet":"GXXX..."}
 /// 
-/// Key points:
-/// - Sorted keys (alphabetically: risk_score, timestamp, wallet)
-/// - No whitespace
-/// - Compact separators (, and :)
+/// `contract` is the Sentinel deployment the payload is meant for; its
+/// address already commits to the network.
+/// 
+/// The byte layout lives in the `sentinel-canonical` crate (shared with
+/// the verifiers and checked against golden vectors); this function only
+/// adapts Soroban types to it.
 /// 
 /// This MUST match exactly what the Oracle signs in Python:
 /// ```python
 /// json.dumps(data, sort_keys=True, separators=(',', ':'))
 /// ```
-/// 
-/// NOTE: This is a simplified implementation that works for test addresses.
-/// For production, wallet address serialization may need adjustment.
-fn serialize_canonical_json(env: &Env, payload: &RiskPayload) -> Bytes {
+pub(crate) fn serialize_canonical_json(env: &Env, contract: &Address, payload: &RiskPayload) -> Bytes {
     let mut result = Bytes::new(env);
     
-    // Start JSON object
-    result.append(&Bytes::from_slice(env, b"{"));
+    let mut wallet = [0u8; MAX_WALLET_LEN];
+    let wallet_len = copy_address_string(env, &payload.wallet, &mut wallet);
+    let mut domain = [0u8; MAX_WALLET_LEN];
+    let domain_len = copy_address_string(env, contract, &mut domain);
+    
+    let category_scores = RiskCategory::ALL
+        .iter()
+        .filter_map(|category| {
+            payload
+                .category_scores
+                .get(*category)
+                .map(|score| (category.json_key(), score))
+        });
+    
+    write_payload_json(
+        &mut BytesSink(&mut result),
+        category_scores,
+        &domain[..domain_len],
+        OptionalFields {
+            confidence: payload.confidence,
+            evidence_hash: Some(payload.evidence_hash.to_array()).filter(|hash| *hash != [0; 32]),
+            freeze_duration: payload.freeze_duration,
+            model_version: payload.model_version,
+        },
+        payload.reason_codes.iter(),
+        payload.risk_score,
+        payload.timestamp,
+        payload.version,
+        &wallet[..wallet_len],
+    );
     
-    // Field 1: "risk_score":87
-    result.append(&Bytes::from_slice(env, b"\"risk_score\":"));
-    append_u32_as_bytes(&mut result, env, payload.risk_score);
+    result
+}
+
+/// Digest identifying a payload's contents: `sha256(canonical JSON)`
+pub(crate) fn payload_digest(env: &Env, payload: &RiskPayload) -> BytesN<32> {
+    let message = serialize_canonical_json(env, &env.current_contract_address(), payload);
+    env.crypto().sha256(&message).to_bytes()
+}
+
+/// Build the exact bytes the Oracle signs for a label assignment
+pub(crate) fn labels_message(env: &Env, wallet: &Address, labels: &Vec<Symbol>, timestamp: u64) -> Bytes {
+    let mut result = Bytes::new(env);
     
-    // Separator
-    result.append(&Bytes::from_slice(env, b","));
+    let mut wallet_buf = [0u8; MAX_WALLET_LEN];
+    let wallet_len = copy_address_string(env, wallet, &mut wallet_buf);
     
-    // Field 2: "timestamp":1737718800
-    result.append(&Bytes::from_slice(env, b"\"timestamp\":"));
-    append_u64_as_bytes(&mut result, env, payload.timestamp);
+    let labels = labels.iter().map(|label| SymbolBuf::new(env, &label));
+    write_labels_json(&mut BytesSink(&mut result), labels, timestamp, &wallet_buf[..wallet_len]);
     
-    // Separator  
-    result.append(&Bytes::from_slice(env, b","));
+    result
+}
+
+/// Build the exact bytes the Oracle signs for a ruling on an appeal
+pub(crate) fn appeal_ruling_message(env: &Env, wallet: &Address, filed_at: u64, outcome: &AppealOutcome) -> Bytes {
+    let mut result = Bytes::new(env);
     
-    // Field 3: "wallet":"GBXXX..."
-    result.append(&Bytes::from_slice(env, b"\"wallet\":\""));    
-    // Serialize wallet address - convert Address to its Stellar string representation
-    append_address_as_string(&mut result, env, &payload.wallet);
+    let mut wallet_buf = [0u8; MAX_WALLET_LEN];
+    let wallet_len = copy_address_string(env, wallet, &mut wallet_buf);
     
-    // Close wallet value and JSON object
-    result.append(&Bytes::from_slice(env, b"\"}"));
+    let (outcome, limit): (&[u8], _) = match outcome {
+        AppealOutcome::Upheld => (b"upheld", None),
+        AppealOutcome::Downgrade(limit) => (b"downgrade", Some(*limit)),
+        AppealOutcome::Rejected => (b"rejected", None),
+    };
+    write_appeal_ruling_json(&mut BytesSink(&mut result), filed_at, limit, outcome, &wallet_buf[..wallet_len]);
     
     result
 }
 
-/// Serialize Stellar Address to string format
-/// Converts Address to "GBXXX..." format matching Oracle's serialization
-fn append_address_as_string(bytes: &mut Bytes, env: &Env, address: &Address) {
+/// Build the exact bytes the Oracle signs for a Merkle-root attestation
+pub(crate) fn merkle_root_message(env: &Env, root: &BytesN<32>, timestamp: u64) -> Bytes {
+    let mut result = Bytes::new(env);
+    write_merkle_root_json(&mut BytesSink(&mut result), &root.to_array(), timestamp);
+    result
+}
+
+/// Hash a Merkle leaf: `sha256(0x00 || canonical JSON)`
+/// 
+/// The JSON is the current payload layout without optional fields, i.e.
+/// {"domain":"sentinel-risk:CXXX...","risk_score":87,"timestamp":1737718800,"version":2,"wallet":"GXXX..."},
+/// bound to this deployment like signed payloads. The 0x00 prefix keeps
+/// leaves distinct from inner nodes.
+pub(crate) fn merkle_leaf_hash(env: &Env, leaf: &MerkleLeaf) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &[0x00]);
+    
+    let mut wallet = [0u8; MAX_WALLET_LEN];
+    let wallet_len = copy_address_string(env, &leaf.wallet, &mut wallet);
+    let mut domain = [0u8; MAX_WALLET_LEN];
+    let domain_len = copy_address_string(env, &env.current_contract_address(), &mut domain);
+    write_payload_json(
+        &mut BytesSink(&mut preimage),
+        core::iter::empty(),
+        &domain[..domain_len],
+        OptionalFields::default(),
+        core::iter::empty(),
+        leaf.risk_score,
+        leaf.timestamp,
+        PAYLOAD_VERSION_V2,
+        &wallet[..wallet_len],
+    );
+    
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+/// Hash two Merkle nodes: `sha256(0x01 || min(a, b) || max(a, b))`
+/// 
+/// Sorting the pair means proofs carry no left/right flags.
+pub(crate) fn merkle_node_hash(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    
+    let mut preimage = Bytes::from_array(env, &[0x01]);
+    preimage.append(&low.clone().into());
+    preimage.append(&high.clone().into());
+    
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+/// A Symbol's characters copied out of the host
+struct SymbolBuf {
+    buf: [u8; 32],
+    len: usize,
+}
+
+impl SymbolBuf {
+    /// Copy a Symbol's characters via its XDR form
+    /// 
+    /// XDR format for ScVal::Symbol: 4 bytes tag, 4 bytes length
+    /// (big-endian), then the characters.
+    fn new(env: &Env, symbol: &Symbol) -> Self {
+        let xdr_bytes = symbol.to_xdr(env);
+        let mut len_bytes = [0u8; 4];
+        for (i, slot) in len_bytes.iter_mut().enumerate() {
+            *slot = xdr_bytes.get(4 + i as u32).unwrap_or(0);
+        }
+        let len = (u32::from_be_bytes(len_bytes) as usize).min(32);
+        
+        let mut buf = [0u8; 32];
+        for (i, slot) in buf.iter_mut().enumerate().take(len) {
+            *slot = xdr_bytes.get(8 + i as u32).unwrap_or(0);
+        }
+        SymbolBuf { buf, len }
+    }
+}
+
+impl AsRef<[u8]> for SymbolBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+/// Adapter that lets `sentinel-canonical` write into Soroban `Bytes`
+pub(crate) struct BytesSink<'a>(pub(crate) &'a mut Bytes);
+
+impl ByteSink for BytesSink<'_> {
+    fn put(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+/// Copy a Stellar Address's string form ("GBXXX...") into `buf`
+/// 
+/// Returns the number of bytes written.
+fn copy_address_string(env: &Env, address: &Address, buf: &mut [u8; MAX_WALLET_LEN]) -> usize {
     // Convert Address to its string representation (GBXXX... format)
     let addr_str = address.to_string();
     
-    // Get the actual string length (Stellar addresses are 56 characters)
-    let str_len = addr_str.len();
+    // Stellar addresses are 56 characters (69 for muxed accounts)
+    let str_len = (addr_str.len() as usize).min(MAX_WALLET_LEN);
     
     // Use XDR serialization to get bytes from Soroban String
     // XDR format for ScVal::String:
@@ -110,113 +353,154 @@ fn append_address_as_string(bytes: &mut Bytes, env: &Env, address: &Address) {
     let xdr_bytes = addr_str.to_xdr(env);
     
     // Skip the first 8 bytes (Tag + Length) to get actual string content
-    for i in 0..str_len {
-        if let Some(b) = xdr_bytes.get(8 + i) {
-            bytes.append(&Bytes::from_slice(env, &[b]));
-        }
-    }
-}
-
-/// Convert u32 to decimal ASCII bytes (no_std compatible)
-fn append_u32_as_bytes(bytes: &mut Bytes, env: &Env, mut value: u32) {
-    if value == 0 {
-        bytes.append(&Bytes::from_slice(env, b"0"));
-        return;
+    for (i, slot) in buf.iter_mut().enumerate().take(str_len) {
+        *slot = xdr_bytes.get(8 + i as u32).unwrap_or(0);
     }
     
-    // Build digits in reverse
-    let mut digits = [0u8; 10]; // u32 max is 10 digits
-    let mut i = 0;
-    
-    while value > 0 {
-        digits[i] = (b'0' + (value % 10) as u8);
-        value /= 10;
-        i += 1;
-    }
-    
-    // Append in correct order
-    while i > 0 {
-        i -= 1;
-        let digit_slice = [digits[i]];
-        bytes.append(&Bytes::from_slice(env, &digit_slice));
-    }
+    str_len
 }
 
-/// Convert u64 to decimal ASCII bytes (no_std compatible)
-fn append_u64_as_bytes(bytes: &mut Bytes, env: &Env, mut value: u64) {
-    if value == 0 {
-        bytes.append(&Bytes::from_slice(env, b"0"));
-        return;
-    }
+#[cfg(test)]
+mod tests {
+    extern crate std;
     
-    // Build digits in reverse
-    let mut digits = [0u8; 20]; // u64 max is 20 digits
-    let mut i = 0;
+    use super::*;
+    use soroban_sdk::Map;
+    use soroban_sdk::{testutils::Address as _, vec, Env, Address, BytesN, String, Vec};
     
-    while value > 0 {
-        digits[i] = (b'0' + (value % 10) as u8);
-        value /= 10;
-        i += 1;
-    }
+    /// Contract id of the golden vectors in `sentinel-canonical`
+    const VECTOR_CONTRACT: &str = "CDG43TONZXG43TONZXG43TONZXG43TONZXG43TONZXG43TONZXG42EVB";
     
-    // Append in correct order
-    while i > 0 {
-        i -= 1;
-        let digit_slice = [digits[i]];
-        bytes.append(&Bytes::from_slice(env, &digit_slice));
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::{Env, Address, BytesN};
     
     #[test]
-    fn test_u32_to_bytes() {
+    fn test_canonical_json_structure() {
         let env = Env::default();
-        let mut bytes = Bytes::new(&env);
         
-        append_u32_as_bytes(&mut bytes, &env, 87);
+        // Create test payload
+        let contract = Address::from_string(&String::from_str(&env, VECTOR_CONTRACT));
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: Address::generate(&env),
+            risk_score: 87,
+            timestamp: 1737718800,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        
+        // Serialize
+        let json_bytes = serialize_canonical_json(&env, &contract, &payload);
+        let json_vec: std::vec::Vec<u8> = json_bytes.iter().collect();
+        
+        // Should start with the deployment's domain
+        let domain = std::format!("{{\"domain\":\"sentinel-risk:{}\",", VECTOR_CONTRACT);
+        assert!(json_vec.starts_with(domain.as_bytes()));
         
-        let vec = bytes.to_vec();
-        assert_eq!(vec, b"87");
+        // Should have score, timestamp and version
+        assert!(contains(&json_vec, b"\"risk_score\":87,"));
+        assert!(contains(&json_vec, b",\"timestamp\":1737718800"));
+        assert!(contains(&json_vec, b",\"version\":2,"));
+        
+        // Should end with "}
+        assert_eq!(&json_vec[json_vec.len()-2..], b"\"}");
     }
     
     #[test]
-    fn test_u64_to_bytes() {
+    fn test_canonical_json_with_reason_codes() {
         let env = Env::default();
-        let mut bytes = Bytes::new(&env);
         
-        append_u64_as_bytes(&mut bytes, &env, 1737718800);
+        let contract = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: Address::generate(&env),
+            risk_score: 87,
+            timestamp: 1737718800,
+            reason_codes: vec![&env, 1, 3],
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        
+        let json_vec: std::vec::Vec<u8> = serialize_canonical_json(&env, &contract, &payload).iter().collect();
         
-        let vec = bytes.to_vec();
-        assert_eq!(vec, b"1737718800");
+        // Sorted keys put reason_codes right after the domain
+        assert!(contains(&json_vec, b"\",\"reason_codes\":[1,3],\"risk_score\":87"));
     }
     
     #[test]
-    fn test_canonical_json_structure() {
+    fn test_domain_binds_the_deployment() {
         let env = Env::default();
         
-        // Create test payload
-        let wallet = Address::generate(&env);
         let payload = RiskPayload {
-            wallet: wallet.clone(),
+            version: PAYLOAD_VERSION_V2,
+            wallet: Address::generate(&env),
             risk_score: 87,
             timestamp: 1737718800,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
         };
         
-        // Serialize
-        let json_bytes = serialize_canonical_json(&env, &payload);
-        let json_vec = json_bytes.to_vec();
+        assert_ne!(
+            serialize_canonical_json(&env, &Address::generate(&env), &payload),
+            serialize_canonical_json(&env, &Address::generate(&env), &payload)
+        );
+    }
+    
+    #[test]
+    fn test_v1_is_unsupported() {
+        let env = Env::default();
+        
+        let payload = RiskPayload {
+            version: 1,
+            wallet: Address::generate(&env),
+            risk_score: 87,
+            timestamp: 1737718800,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
         
-        // Should start with {"risk_score":87
-        assert_eq!(&json_vec[0..15], b"{\"risk_score\":87");
+        assert_eq!(
+            signed_message(&env, &payload),
+            Err(SentinelError::UnsupportedPayloadVersion)
+        );
+    }
+    
+    #[test]
+    fn test_unknown_version_is_unsupported() {
+        let env = Env::default();
         
-        // Should have timestamp
-        assert!(json_vec.windows(21).any(|w| w == b",\"timestamp\":1737718800"));
+        let payload = RiskPayload {
+            version: 99,
+            wallet: Address::generate(&env),
+            risk_score: 87,
+            timestamp: 1737718800,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
         
-        // Should end with "}
-        assert_eq!(&json_vec[json_vec.len()-2..], b"\"}");
+        assert_eq!(
+            signed_message(&env, &payload),
+            Err(SentinelError::UnsupportedPayloadVersion)
+        );
     }
 }
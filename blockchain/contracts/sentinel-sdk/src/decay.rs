@@ -0,0 +1,161 @@
+//! On-chain risk score decay.
+//!
+//! Wallets that stop misbehaving gradually regain access even if the
+//! Oracle goes quiet: the effective score drops by `points` for every full
+//! `period` seconds since the last Oracle update. Decay is disabled until
+//! the admin configures a non-zero rate.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, DecayConfig};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Current decay configuration (disabled by default)
+pub(crate) fn decay_config(env: &Env) -> DecayConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::Decay))
+        .unwrap_or(DecayConfig { points: 0, period: 0 })
+}
+
+/// Apply decay to a stored score
+///
+/// `effective = stored - points * (elapsed / period)`, floored at 0.
+pub(crate) fn effective_score(env: &Env, stored_score: u32, last_updated: u64) -> u32 {
+    let config = decay_config(env);
+    if config.points == 0 || config.period == 0 {
+        return stored_score;
+    }
+    
+    let elapsed = env.ledger().timestamp().saturating_sub(last_updated);
+    let periods = elapsed / config.period;
+    let decayed = periods.saturating_mul(config.points as u64);
+    
+    stored_score.saturating_sub(decayed.min(u32::MAX as u64) as u32)
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get a wallet's score after decay (read-only)
+    /// 
+    /// Decays the score the pipeline decides on: the worst of the overall
+    /// and category scores.
+    /// 
+    /// # Returns
+    /// * `Some(score)` if wallet has been scored
+    /// * `None` if wallet is unknown
+    pub fn get_effective_score(env: Env, wallet: Address) -> Option<u32> {
+        let state = Self::get_risk(env.clone(), wallet)?;
+        Some(effective_score(&env, state.worst_score(), state.last_updated))
+    }
+    
+    /// Get the decay configuration (read-only)
+    pub fn get_decay(env: Env) -> DecayConfig {
+        decay_config(&env)
+    }
+    
+    /// Set the decay rate (admin only)
+    /// 
+    /// # Arguments
+    /// * `config` - `points` removed per full `period` seconds; `points: 0`
+    ///   disables decay
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If `points` is non-zero and `period` is 0
+    pub fn set_decay(env: Env, config: DecayConfig) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_decay(env.clone());
+        Self::apply_decay(&env, config.clone());
+        audit::record(&env, &admin, "set_decay", old, config);
+    }
+}
+
+/// Panic unless a non-zero decay rate has a positive period
+pub(crate) fn check_decay(config: &DecayConfig) {
+    if config.points != 0 && config.period == 0 {
+        panic!("Decay period must be positive");
+    }
+}
+
+impl SentinelSDK {
+    /// Store the decay rate (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If `points` is non-zero and `period` is 0
+    pub(crate) fn apply_decay(env: &Env, config: DecayConfig) {
+        check_decay(&config);
+        env.storage().instance().set(&DataKey::Config(ConfigKey::Decay), &config);
+        env.events().publish((topic::DECAY_SET,), config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RiskCategory, RiskPayload, PAYLOAD_VERSION_V2};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{map, BytesN, Vec};
+    
+    #[test]
+    fn test_effective_score_decays_per_full_period() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        
+        env.as_contract(&contract_id, || {
+            // Disabled by default
+            env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+            assert_eq!(effective_score(&env, 90, 0), 90);
+            
+            // 5 points per day
+            env.storage()
+                .instance()
+                .set(&DataKey::Config(ConfigKey::Decay), &DecayConfig { points: 5, period: 86_400 });
+            
+            env.ledger().with_mut(|li| li.timestamp = 86_399);
+            assert_eq!(effective_score(&env, 90, 0), 90);
+            
+            env.ledger().with_mut(|li| li.timestamp = 2 * 86_400);
+            assert_eq!(effective_score(&env, 90, 0), 80);
+            
+            // Floors at zero
+            env.ledger().with_mut(|li| li.timestamp = 100 * 86_400);
+            assert_eq!(effective_score(&env, 90, 0), 0);
+        });
+    }
+    
+    #[test]
+    fn test_effective_score_decays_the_worst_category() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_decay(&DecayConfig { points: 5, period: 86_400 });
+        
+        let wallet = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            let payload = RiskPayload {
+                version: PAYLOAD_VERSION_V2,
+                wallet: wallet.clone(),
+                risk_score: 10,
+                timestamp: 0,
+                reason_codes: Vec::new(&env),
+                freeze_duration: 0,
+                category_scores: map![&env, (RiskCategory::Sanctions, 90)],
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(&env, &[0; 32]),
+            };
+            SentinelSDK::store_risk(&env, &payload);
+        });
+        
+        env.ledger().with_mut(|li| li.timestamp = 2 * 86_400);
+        assert_eq!(client.get_effective_score(&wallet), Some(80));
+        assert_eq!(client.check_permission(&wallet), client.preview_decision(&80));
+    }
+}
@@ -0,0 +1,213 @@
+//! Independent risk domains.
+//!
+//! One deployment normally runs a single scoring pipeline: one Oracle key,
+//! one score per wallet. The admin may also register named domains (e.g.
+//! `aml`, `bots`), each owned by its own Oracle key. Domain payloads are
+//! submitted with `submit_risk_domain`, verified against that domain's key
+//! and stored apart from the main score, so `check_permission_domain`
+//! answers per domain while `check_permission` is unaffected.
+//!
+//! The domain is not part of the signed message: give every domain its
+//! own Oracle key so payloads cannot be replayed across domains.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::audit;
+use crate::crypto::require_signature;
+use crate::events;
+use crate::pipeline;
+use crate::types::{DataKey, PublicKey, RiskDecision, RiskPayload, RiskState, Signature};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Maximum number of registered domains
+pub const MAX_DOMAINS: u32 = 16;
+
+/// Wallet's stored state in a domain
+pub(crate) fn domain_state(env: &Env, domain: &Symbol, wallet: &Address) -> Option<RiskState> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DomainRisk(domain.clone(), wallet.clone()))
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Register a risk domain or replace its Oracle key (admin only)
+    /// 
+    /// # Arguments
+    /// * `domain` - Namespace such as `aml` or `bots`
+    /// * `oracle_pubkey` - Ed25519 key (or registered P-256 key id) that
+    ///   signs the domain's payloads
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If `MAX_DOMAINS` domains are already registered
+    pub fn register_domain(env: Env, domain: Symbol, oracle_pubkey: PublicKey) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_domain_oracle(env.clone(), domain.clone());
+        Self::apply_domain(&env, domain.clone(), oracle_pubkey.clone());
+        audit::record(&env, &admin, "register_domain", old, (domain, oracle_pubkey));
+    }
+    
+    /// List registered risk domains (read-only)
+    pub fn get_domains(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Domains)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+    
+    /// Get the Oracle key owning a domain (read-only; `None` if unregistered)
+    pub fn get_domain_oracle(env: Env, domain: Symbol) -> Option<PublicKey> {
+        env.storage().instance().get(&DataKey::DomainOracle(domain))
+    }
+    
+    /// Submit a signed risk score to a domain
+    /// 
+    /// Verified against the domain's Oracle key and validated like
+    /// `submit_risk` (replay window, clock skew, ordering against the
+    /// domain's stored score, score and confidence ranges). Rejected
+    /// payloads emit `REJECTED` and leave the state unchanged. The main
+    /// score, history and statistics are not touched.
+    /// 
+    /// Emits `RISK_UPD` and the decision event with the domain as third topic.
+    /// With the jump guard configured, a jump to Freeze in the domain is held
+    /// like in `submit_risk` (see `confirm_domain_score_jump`).
+    /// 
+    /// # Panics
+    /// * If the domain is not registered
+    /// * `InvalidSignature` if the signature does not verify
+    /// * `UnsupportedPayloadVersion` for unknown payload versions
+    pub fn submit_risk_domain(env: Env, domain: Symbol, payload: RiskPayload, signature: Signature) {
+        let key = Self::get_domain_oracle(env.clone(), domain.clone())
+            .expect("Unknown risk domain");
+        require_signature(&env, &payload, &signature, &key);
+        
+        let stored = domain_state(&env, &domain, &payload.wallet);
+        let valid = Self::validate_timestamp(&env, &payload, stored.as_ref())
+            .and_then(|_| Self::validate_scores(&payload));
+        if let Err(error) = valid {
+            events::publish_rejected(&env, &payload, error);
+            return;
+        }
+        
+        if Self::hold_domain_score_jump(&env, &domain, &payload) {
+            return;
+        }
+        Self::store_domain_risk(&env, &domain, &payload);
+    }
+    
+    /// Query a wallet's risk state in a domain (read-only)
+    pub fn get_risk_domain(env: Env, wallet: Address, domain: Symbol) -> Option<RiskState> {
+        domain_state(&env, &domain, &wallet)
+    }
+    
+    /// Check permission for a wallet in one domain (read-only)
+    /// 
+    /// Runs the configured pipeline on the domain's score; overrides,
+    /// freezes and the other wallet-wide stages still apply. Wallets the
+    /// domain never scored get the unknown default.
+    /// 
+    /// # Panics
+    /// * If the domain is not registered
+    pub fn check_permission_domain(env: Env, wallet: Address, domain: Symbol) -> RiskDecision {
+        if Self::get_domain_oracle(env.clone(), domain.clone()).is_none() {
+            panic!("Unknown risk domain");
+        }
+        
+        let state = domain_state(&env, &domain, &wallet);
+        pipeline::evaluate_domain(&env, &wallet, state.as_ref())
+    }
+}
+
+impl SentinelSDK {
+    /// Register a domain or replace its Oracle key (authorization checked
+    /// by caller)
+    /// 
+    /// # Panics
+    /// * If `MAX_DOMAINS` domains are already registered
+    pub(crate) fn apply_domain(env: &Env, domain: Symbol, oracle_pubkey: PublicKey) {
+        let mut domains = Self::get_domains(env.clone());
+        if !domains.contains(&domain) {
+            if domains.len() >= MAX_DOMAINS {
+                panic!("Too many domains: max 16");
+            }
+            domains.push_back(domain.clone());
+            env.storage().instance().set(&DataKey::Domains, &domains);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::DomainOracle(domain.clone()), &oracle_pubkey);
+        env.events().publish((topic::DOM_REG, domain), oracle_pubkey);
+    }
+    
+    /// Store a validated payload as the wallet's state in a domain
+    pub(crate) fn store_domain_risk(env: &Env, domain: &Symbol, payload: &RiskPayload) {
+        let stored = domain_state(env, domain, &payload.wallet);
+        let state = RiskState::from_payload(payload, &pipeline::score_bands(env));
+        env.storage()
+            .persistent()
+            .set(&DataKey::DomainRisk(domain.clone(), payload.wallet.clone()), &state);
+        
+        let old_decision = stored.map_or(RiskDecision::Allow, |state| state.decision);
+        events::publish_risk_update(env, &payload.wallet, Some(domain), old_decision, &state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::sign_for;
+    use crate::types::PAYLOAD_VERSION_V2;
+    use ed25519_dalek::SigningKey;
+    use soroban_sdk::{testutils::Address as _, BytesN, Map};
+    
+    fn public_key(env: &Env, key: &SigningKey) -> PublicKey {
+        BytesN::from_array(env, &key.verifying_key().to_bytes())
+    }
+    
+    #[test]
+    fn test_domains_score_independently() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let (aml_key, bots_key) = (SigningKey::from_bytes(&[1u8; 32]), SigningKey::from_bytes(&[2u8; 32]));
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let (aml, bots) = (Symbol::new(&env, "aml"), Symbol::new(&env, "bots"));
+        client.register_domain(&aml, &public_key(&env, &aml_key));
+        client.register_domain(&bots, &public_key(&env, &bots_key));
+        assert_eq!(client.get_domains().len(), 2);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 90,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk_domain(&bots, &payload, &sign_for(&client, &bots_key, &payload));
+        
+        assert_eq!(client.check_permission_domain(&wallet, &bots), RiskDecision::Freeze);
+        assert_eq!(client.check_permission_domain(&wallet, &aml), RiskDecision::Allow);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(client.get_risk_domain(&wallet, &bots).unwrap().risk_score, 90);
+        assert_eq!(client.get_risk(&wallet), None);
+        
+        // The aml Oracle cannot write into the bots domain
+        assert!(client
+            .try_submit_risk_domain(&aml, &payload, &sign_for(&client, &bots_key, &payload))
+            .is_err());
+    }
+}
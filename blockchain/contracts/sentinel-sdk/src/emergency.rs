@@ -0,0 +1,176 @@
+//! Emergency global freeze.
+//!
+//! During an active exploit the admin can make `check_permission` return
+//! Freeze for every wallet, or for every wallet scored at or above a
+//! floor, with one call. The freeze lifts by itself after the configured
+//! duration so a forgotten switch cannot halt integrators indefinitely.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, symbol_short, Address, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, GlobalFreeze, GlobalFreezeConfig, Role};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Default lifetime of a global freeze (24 hours)
+pub const DEFAULT_GLOBAL_FREEZE_DURATION: u64 = 86_400;
+
+/// Engaged global freeze, if one is in effect now
+pub(crate) fn active_global_freeze(env: &Env) -> Option<GlobalFreeze> {
+    env.storage()
+        .instance()
+        .get::<_, GlobalFreeze>(&DataKey::GlobalFreeze)
+        .filter(|freeze| env.ledger().timestamp() < freeze.expires_at)
+}
+
+/// Whether the global freeze applies to a wallet with this score
+pub(crate) fn globally_frozen(env: &Env, score: Option<u32>) -> bool {
+    match active_global_freeze(env) {
+        Some(freeze) if freeze.score_floor == 0 => true,
+        Some(freeze) => score.is_some_and(|score| score >= freeze.score_floor),
+        None => false,
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Engage or lift the emergency global freeze (`Pauser` role)
+    /// 
+    /// Engaging uses the current `GlobalFreezeConfig` and restarts its
+    /// expiry; lifting takes effect immediately.
+    /// 
+    /// # Panics
+    /// * If caller does not hold the `Pauser` role
+    pub fn global_freeze(env: Env, caller: Address, enable: bool) {
+        Self::require_role(&env, &caller, Role::Pauser);
+        
+        let old = active_global_freeze(&env);
+        if enable {
+            let config = Self::get_global_freeze_config(env.clone());
+            let now = env.ledger().timestamp();
+            let freeze = GlobalFreeze {
+                score_floor: config.score_floor,
+                started_at: now,
+                expires_at: now.saturating_add(config.duration),
+            };
+            env.storage().instance().set(&DataKey::GlobalFreeze, &freeze);
+            audit::record(&env, &caller, "global_freeze", old, freeze.clone());
+            env.events().publish(
+                (topic::GLOB_FRZ, symbol_short!("engaged")),
+                (caller, freeze.score_floor, freeze.expires_at),
+            );
+        } else {
+            env.storage().instance().remove(&DataKey::GlobalFreeze);
+            audit::record(&env, &caller, "global_freeze", old, ());
+            env.events().publish((topic::GLOB_FRZ, symbol_short!("lifted")), caller);
+        }
+    }
+    
+    /// The global freeze in effect, if any (read-only)
+    pub fn get_global_freeze(env: Env) -> Option<GlobalFreeze> {
+        active_global_freeze(&env)
+    }
+    
+    /// Get the parameters used when engaging the global freeze (read-only)
+    pub fn get_global_freeze_config(env: Env) -> GlobalFreezeConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::GlobalFreeze))
+            .unwrap_or(GlobalFreezeConfig {
+                score_floor: 0,
+                duration: DEFAULT_GLOBAL_FREEZE_DURATION,
+            })
+    }
+    
+    /// Set the parameters used when engaging the global freeze (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `duration` is 0 or `score_floor` exceeds 100
+    pub fn set_global_freeze_config(env: Env, config: GlobalFreezeConfig) {
+        let admin = Self::require_admin(&env);
+        
+        if config.duration == 0 {
+            panic!("Global freeze duration must be positive");
+        }
+        if config.score_floor > 100 {
+            panic!("Score floor must be within 0-100");
+        }
+        
+        let old = Self::get_global_freeze_config(env.clone());
+        env.storage().instance().set(&DataKey::Config(ConfigKey::GlobalFreeze), &config);
+        audit::record(&env, &admin, "set_global_freeze_config", old, config.clone());
+        env.events().publish((topic::GFRZ_CFG,), config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutils::{TimeTravel, HOUR};
+    use crate::types::RiskDecision;
+    use soroban_sdk::{testutils::Address as _, Address, BytesN};
+    
+    fn setup(env: &Env) -> SentinelSDKClient<'_> {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        client
+    }
+    
+    #[test]
+    fn test_global_freeze_applies_and_expires() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_global_freeze_config(&GlobalFreezeConfig { score_floor: 0, duration: 2 * HOUR });
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Allow, &0);
+        client.global_freeze(&client.get_admin(), &true);
+        
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.check_permission(&Address::generate(&env)), RiskDecision::Freeze);
+        assert!(client.healthcheck(&wallet).global_freeze);
+        
+        env.advance_hours(2);
+        assert_eq!(client.get_global_freeze(), None);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+    }
+    
+    #[test]
+    fn test_score_floor_spares_low_risk_wallets() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_global_freeze_config(&GlobalFreezeConfig { score_floor: 60, duration: HOUR });
+        
+        let contract_id = client.address.clone();
+        let risky = Address::generate(&env);
+        let safe = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            for (wallet, score) in [(&risky, 65u32), (&safe, 20u32)] {
+                let payload = crate::types::RiskPayload {
+                    version: crate::types::PAYLOAD_VERSION_V2,
+                    wallet: wallet.clone(),
+                    risk_score: score,
+                    timestamp: 0,
+                    reason_codes: soroban_sdk::Vec::new(&env),
+                    freeze_duration: 0,
+                    category_scores: soroban_sdk::Map::new(&env),
+                    model_version: 0,
+                    confidence: 0,
+                    evidence_hash: BytesN::from_array(&env, &[0; 32]),
+                };
+                SentinelSDK::store_risk(&env, &payload);
+            }
+        });
+        
+        client.global_freeze(&client.get_admin(), &true);
+        assert_eq!(client.check_permission(&risky), RiskDecision::Freeze);
+        assert_eq!(client.check_permission(&safe), RiskDecision::Allow);
+        
+        client.global_freeze(&client.get_admin(), &false);
+        assert_eq!(client.check_permission(&risky), RiskDecision::Limit(5000));
+    }
+}
@@ -0,0 +1,125 @@
+//! Risk data deletion.
+//!
+//! Serves right-to-erasure requests and clears test data on public
+//! networks. Admin overrides and the transition log are compliance records
+//! and are kept.
+
+use sentinel_events::{ErasureEvent, RiskEvent};
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::ttl::remove_risk_entry;
+use crate::types::{DataKey, RiskDecision};
+use crate::{schema, SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Delete a wallet's Oracle data (admin only)
+    /// 
+    /// Removes the stored risk state, its history and any shadow score;
+    /// the wallet is then treated as unknown by the Oracle stages.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Address whose data is erased
+    /// * `force` - Erase even while a Freeze override is active
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If an active override freezes the wallet and `force` is false
+    pub fn clear_risk(env: Env, wallet: Address, force: bool) {
+        let admin = Self::require_admin(&env);
+        
+        if !force && Self::active_override(&env, &wallet) == Some(RiskDecision::Freeze) {
+            panic!("Wallet is frozen by override - pass force to delete");
+        }
+        
+        let mut old_decision = RiskDecision::Allow;
+        if let Some(state) = schema::load_risk_state(&env, &wallet) {
+            Self::forget_stats(&env, &state.decision);
+            old_decision = state.decision;
+        }
+        remove_risk_entry(&env, &wallet);
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::History(wallet.clone()));
+        storage.remove(&DataKey::ShadowRisk(wallet.clone()));
+        
+        audit::record(&env, &admin, "clear_risk", (), (wallet.clone(), force));
+        let data = ErasureEvent { old_decision: old_decision.into(), admin, force };
+        RiskEvent::Erased { wallet, data }.publish(&env);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Map;
+    use crate::types::RiskState;
+    use soroban_sdk::{testutils::Address as _, BytesN, Vec};
+    
+    fn setup(env: &Env) -> (Address, SentinelSDKClient<'_>) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        (contract_id, client)
+    }
+    
+    fn seed(env: &Env, contract_id: &Address, wallet: &Address) {
+        env.as_contract(contract_id, || {
+            let state = RiskState {
+                risk_score: 90,
+                last_updated: 0,
+                decision: RiskDecision::Freeze,
+                reason_codes: Vec::new(env),
+                freeze_until: 0,
+                category_scores: Map::new(env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(env, &[0; 32]),
+            };
+            env.storage().persistent().set(&DataKey::Risk(wallet.clone()), &state);
+            SentinelSDK::record_history(env, wallet, &state);
+        });
+    }
+    
+    #[test]
+    fn test_clear_risk_erases_state_and_history() {
+        let env = Env::default();
+        let (contract_id, client) = setup(&env);
+        let wallet = Address::generate(&env);
+        seed(&env, &contract_id, &wallet);
+        
+        client.clear_risk(&wallet, &false);
+        
+        assert_eq!(client.get_risk(&wallet), None);
+        assert_eq!(client.get_risk_history(&wallet).len(), 0);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Wallet is frozen by override")]
+    fn test_clear_risk_refuses_active_freeze_override() {
+        let env = Env::default();
+        let (contract_id, client) = setup(&env);
+        let wallet = Address::generate(&env);
+        seed(&env, &contract_id, &wallet);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.clear_risk(&wallet, &false);
+    }
+    
+    #[test]
+    fn test_clear_risk_forced_keeps_override() {
+        let env = Env::default();
+        let (contract_id, client) = setup(&env);
+        let wallet = Address::generate(&env);
+        seed(&env, &contract_id, &wallet);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.clear_risk(&wallet, &true);
+        
+        assert_eq!(client.get_risk(&wallet), None);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+    }
+}
@@ -0,0 +1,30 @@
+//! Error codes for the Sentinel SDK contract.
+//!
+//! Codes are stable and are surfaced both as contract errors and as the
+//! `code` field of `REJECTED` events, so keep existing values unchanged
+//! when adding new variants.
+
+use soroban_sdk::contracterror;
+
+/// Reasons the SDK can refuse an operation
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SentinelError {
+    /// Payload timestamp is older than the replay window
+    PayloadTooOld = 1,
+    /// Risk score is outside 0-100
+    RiskScoreOutOfRange = 2,
+    /// Payload version is not supported by this deployment
+    UnsupportedPayloadVersion = 3,
+    /// Payload timestamp is ahead of the ledger beyond the skew tolerance
+    PayloadFromFuture = 4,
+    /// Payload timestamp is older than the wallet's stored score
+    OutOfOrderUpdate = 5,
+    /// Wallet already received its allowed submissions this window
+    RateLimited = 6,
+    /// Signature does not verify against the Oracle key
+    InvalidSignature = 7,
+    /// Model confidence is above 10000 basis points
+    ConfidenceOutOfRange = 8,
+}
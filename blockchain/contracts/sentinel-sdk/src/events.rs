@@ -0,0 +1,136 @@
+//! Event helpers for the Sentinel SDK contract.
+//!
+//! Keeps topic names and data layouts for diagnostic events in one place
+//! so the Oracle and indexers can rely on a stable shape. Names and the
+//! wallet risk stream (`RiskEvent`) come from the `sentinel-events` crate,
+//! which indexers decode with.
+//!
+//! Which events the per-score stream publishes is a build choice (see
+//! `VERBOSE`); decision events, `DEC_CHG`, rejections and administrative
+//! events are always published.
+
+use sentinel_events::{
+    DecisionChangeEvent, OutOfOrderEvent, RejectionEvent, RiskEvent, RiskUpdateEvent,
+};
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::errors::SentinelError;
+use crate::schema;
+use crate::types::{RiskCategory, RiskDecision, RiskPayload, RiskState, ScoreBands};
+
+/// Whether the per-score risk stream is published in full
+///
+/// `events-minimal` builds leave out `RISK_UPD` and the category topics,
+/// which is enough for a deployment alerting on decisions: every score
+/// then costs one event, plus `DEC_CHG` on a flip, and the unused
+/// publishing code is left out of the WASM. Rejections (`REJECTED`,
+/// `OUT_ORDER`) are published either way so failed submissions always
+/// leave a trace. `events-full` (the default) wins when both are enabled,
+/// so feature unification can never silently drop events.
+pub(crate) const VERBOSE: bool = cfg!(feature = "events-full") || !cfg!(feature = "events-minimal");
+
+/// Emit `RISK_UPD` and the decision event for a stored score, plus
+/// `DEC_CHG` if the score-based decision flipped
+///
+/// Topics: (`RISK_UPD` | `FROZEN` | `REVIEW` | `LIMITED` | `ALLOWED` |
+/// `DEC_CHG`, wallet), with the domain as third topic for domain scores,
+/// so indexers can follow one wallet without decoding data.
+/// Data: `RiskUpdateEvent` (`DecisionChangeEvent` for `DEC_CHG`)
+///
+/// Monitoring can alert on `DEC_CHG` alone instead of de-duplicating
+/// every score refresh. `RISK_UPD` is not emitted without `VERBOSE`.
+pub fn publish_risk_update(
+    env: &Env,
+    wallet: &Address,
+    domain: Option<&Symbol>,
+    old_decision: RiskDecision,
+    state: &RiskState,
+) {
+    let data = RiskUpdateEvent {
+        risk_score: state.risk_score,
+        timestamp: state.last_updated,
+        old_decision: old_decision.into(),
+        new_decision: state.decision.clone().into(),
+    };
+    let (wallet, domain) = (wallet.clone(), domain.cloned());
+    
+    if VERBOSE {
+        RiskEvent::Updated { wallet: wallet.clone(), domain: domain.clone(), data: data.clone() }.publish(env);
+    }
+    RiskEvent::Decided { wallet: wallet.clone(), domain: domain.clone(), data: data.clone() }.publish(env);
+    
+    if data.old_decision != data.new_decision {
+        let data = DecisionChangeEvent {
+            old_decision: data.old_decision,
+            new_decision: data.new_decision,
+            risk_score: data.risk_score,
+        };
+        RiskEvent::DecisionChanged { wallet, domain, data }.publish(env);
+    }
+}
+
+/// Emit a `REJECTED` event for a payload that failed validation
+///
+/// Topics: (`REJECTED`, wallet)
+/// Data: `RejectionEvent`
+pub fn publish_rejected(env: &Env, payload: &RiskPayload, error: SentinelError) {
+    let data = RejectionEvent {
+        error_code: error as u32,
+        risk_score: payload.risk_score,
+        timestamp: payload.timestamp,
+        ledger_timestamp: env.ledger().timestamp(),
+    };
+    RiskEvent::Rejected { wallet: payload.wallet.clone(), data }.publish(env);
+    
+    if error == SentinelError::OutOfOrderUpdate {
+        publish_out_of_order(env, payload);
+    }
+}
+
+/// Emit an `OUT_ORDER` event for a payload older than the stored state
+///
+/// Topics: (`OUT_ORDER`, wallet)
+/// Data: `OutOfOrderEvent`
+///
+/// Lets the Oracle detect delivery-ordering problems without parsing the
+/// generic `REJECTED` stream.
+fn publish_out_of_order(env: &Env, payload: &RiskPayload) {
+    let stored_timestamp = schema::load_risk_state(env, &payload.wallet)
+        .map(|state| state.last_updated)
+        .unwrap_or(0);
+    let data = OutOfOrderEvent { timestamp: payload.timestamp, stored_timestamp };
+    RiskEvent::OutOfOrder { wallet: payload.wallet.clone(), data }.publish(env);
+}
+
+/// Emit a category topic for every category whose decision changed
+///
+/// Topics: (`CAT_AML` | `CAT_BOT` | `CAT_FRAUD` | `CAT_SANC`, wallet), so
+/// subscribers with a narrow mandate can filter by topic alone.
+/// Data: (category score, category decision)
+///
+/// A category missing from a scored state counts as 0, matching
+/// `check_permission_category`; a first score always emits. Not emitted
+/// without `VERBOSE`.
+pub fn publish_category_transitions(
+    env: &Env,
+    wallet: &Address,
+    previous: Option<&RiskState>,
+    current: &RiskState,
+    bands: &ScoreBands,
+) {
+    if !VERBOSE {
+        return;
+    }
+    
+    for category in RiskCategory::ALL {
+        let score = current.category_scores.get(category).unwrap_or(0);
+        let decision = bands.decide(score);
+        
+        let unchanged = previous
+            .map(|state| bands.decide(state.category_scores.get(category).unwrap_or(0)) == decision)
+            .unwrap_or(false);
+        if !unchanged {
+            env.events().publish((category.event_topic(), wallet.clone()), (score, decision));
+        }
+    }
+}
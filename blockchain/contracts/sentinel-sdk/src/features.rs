@@ -0,0 +1,99 @@
+//! Per-deployment feature registry.
+//!
+//! Optional subsystems are recorded as a bitmap (see `types::feature`) so
+//! clients, bindings and integrator crates can adapt to a deployment
+//! programmatically. Fresh deployments start with `feature::DEFAULT`; the
+//! admin updates the bitmap when an upgrade activates a subsystem.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Env};
+
+use crate::audit;
+use crate::types::{feature, ConfigKey, DataKey};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the bitmap of active subsystems (read-only)
+    pub fn enabled_features(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::Features))
+            .unwrap_or(feature::DEFAULT)
+    }
+    
+    /// Replace the feature bitmap (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `features` contains bits this version does not know
+    pub fn set_features(env: Env, features: u32) {
+        let admin = Self::require_admin(&env);
+        
+        if features & !feature::ALL != 0 {
+            panic!("Unknown feature bits");
+        }
+        
+        let old = Self::enabled_features(env.clone());
+        env.storage().instance().set(&DataKey::Config(ConfigKey::Features), &features);
+        audit::record(&env, &admin, "set_features", old, features);
+        env.events().publish((topic::FEAT_SET,), features);
+    }
+}
+
+impl SentinelSDK {
+    /// Whether a subsystem is active on this deployment
+    pub(crate) fn has_feature(env: &Env, flag: u32) -> bool {
+        Self::enabled_features(env.clone()) & flag == flag
+    }
+    
+    /// Panic unless a subsystem is active on this deployment
+    pub(crate) fn require_feature(env: &Env, flag: u32) {
+        if !Self::has_feature(env, flag) {
+            panic!("Feature not enabled");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, vec, Address, BytesN};
+    
+    fn setup(env: &Env) -> SentinelSDKClient<'_> {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        client
+    }
+    
+    #[test]
+    fn test_features_default_and_update() {
+        let env = Env::default();
+        let client = setup(&env);
+        assert_eq!(client.enabled_features(), feature::DEFAULT);
+        
+        client.set_features(&(feature::BATCHING | feature::APPEALS));
+        assert_eq!(client.enabled_features(), feature::BATCHING | feature::APPEALS);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Feature not enabled")]
+    fn test_batching_disabled_rejects_batch_calls() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_features(&0);
+        
+        client.check_permission_batch(&vec![&env, Address::generate(&env)]);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Unknown feature bits")]
+    fn test_unknown_bits_rejected() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_features(&(1 << 31));
+    }
+}
@@ -0,0 +1,224 @@
+//! Payload timestamp bounds.
+//!
+//! `submit_risk` rejects payloads signed longer ago than the replay window
+//! and payloads dated too far ahead of the ledger clock. Without the
+//! forward bound a future-dated payload would be accepted and then count
+//! as fresh until the ledger caught up with it. Both bounds are admin
+//! settable so high-latency Oracle pipelines or stricter deployments can
+//! tune them.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, SdkConfig};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Default maximum payload age (5 minutes)
+pub const DEFAULT_REPLAY_WINDOW: u64 = 300;
+
+/// Smallest configurable replay window (30 seconds)
+pub const MIN_REPLAY_WINDOW: u64 = 30;
+
+/// Largest configurable replay window (1 hour)
+pub const MAX_REPLAY_WINDOW: u64 = 3_600;
+
+/// Default tolerance for payloads dated ahead of the ledger (1 minute)
+pub const DEFAULT_MAX_CLOCK_SKEW: u64 = 60;
+
+/// Upper bound on the configurable forward skew (1 hour)
+pub const MAX_CLOCK_SKEW_LIMIT: u64 = 3_600;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the maximum payload age accepted by `submit_risk` (read-only)
+    pub fn get_replay_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::ReplayWindow))
+            .unwrap_or(DEFAULT_REPLAY_WINDOW)
+    }
+    
+    /// Set the maximum payload age accepted by `submit_risk` (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `seconds` is outside `MIN_REPLAY_WINDOW..=MAX_REPLAY_WINDOW`
+    pub fn set_replay_window(env: Env, seconds: u64) {
+        let admin = Self::require_admin(&env);
+        
+        if !(MIN_REPLAY_WINDOW..=MAX_REPLAY_WINDOW).contains(&seconds) {
+            panic!("Replay window must be within 30-3600 seconds");
+        }
+        
+        let old = Self::get_replay_window(env.clone());
+        env.storage().instance().set(&DataKey::Config(ConfigKey::ReplayWindow), &seconds);
+        audit::record(&env, &admin, "set_replay_window", old, seconds);
+        env.events().publish((topic::RPLY_SET,), (old, seconds));
+    }
+    
+    /// Get the deployment's tunable configuration in one call (read-only)
+    pub fn get_config(env: Env) -> SdkConfig {
+        SdkConfig {
+            admin: Self::get_admin(env.clone()),
+            features: Self::enabled_features(env.clone()),
+            score_bands: Self::get_score_bands(env.clone()),
+            replay_window: Self::get_replay_window(env.clone()),
+            max_clock_skew: Self::get_max_clock_skew(env),
+        }
+    }
+    
+    /// Get how far ahead of the ledger clock a payload may be dated (read-only)
+    pub fn get_max_clock_skew(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::ClockSkew))
+            .unwrap_or(DEFAULT_MAX_CLOCK_SKEW)
+    }
+    
+    /// Set how far ahead of the ledger clock a payload may be dated (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `seconds` exceeds `MAX_CLOCK_SKEW_LIMIT`
+    pub fn set_max_clock_skew(env: Env, seconds: u64) {
+        let admin = Self::require_admin(&env);
+        
+        if seconds > MAX_CLOCK_SKEW_LIMIT {
+            panic!("Clock skew tolerance too large: max 3600 seconds");
+        }
+        
+        let old = Self::get_max_clock_skew(env.clone());
+        env.storage().instance().set(&DataKey::Config(ConfigKey::ClockSkew), &seconds);
+        audit::record(&env, &admin, "set_max_clock_skew", old, seconds);
+        env.events().publish((topic::SKEW_SET,), seconds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::errors::SentinelError;
+    use crate::types::{RiskPayload, PAYLOAD_VERSION_V2};
+    use sentinel_events::RejectionEvent;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{
+        testutils::{Address as _, Events, Ledger},
+        Address, BytesN, IntoVal, Map, Vec,
+    };
+    
+    fn submit_at(env: &Env, client: &SentinelSDKClient, key: &SigningKey, wallet: &Address, timestamp: u64) {
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 90,
+            timestamp,
+            reason_codes: Vec::new(env),
+            freeze_duration: 0,
+            category_scores: Map::new(env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(env, &[0; 32]),
+        };
+        let message = crate::crypto::serialize_canonical_json(env, &client.address, &payload);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        client.submit_risk(&payload, &BytesN::from_array(env, &key.sign(&message).to_bytes()));
+    }
+    
+    #[test]
+    fn test_forward_skew_boundaries() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 10_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        
+        // Exactly at the tolerance is accepted
+        let edge = Address::generate(&env);
+        submit_at(&env, &client, &key, &edge, 10_000 + DEFAULT_MAX_CLOCK_SKEW);
+        assert!(client.get_risk(&edge).is_some());
+        
+        // One second past it is rejected
+        let beyond = Address::generate(&env);
+        submit_at(&env, &client, &key, &beyond, 10_000 + DEFAULT_MAX_CLOCK_SKEW + 1);
+        assert_eq!(client.get_risk(&beyond), None);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let data: RejectionEvent = data.into_val(&env);
+        assert_eq!(data.error_code, SentinelError::PayloadFromFuture as u32);
+        
+        // Zero tolerance only accepts payloads dated at or before the ledger
+        client.set_max_clock_skew(&0);
+        let strict = Address::generate(&env);
+        submit_at(&env, &client, &key, &strict, 10_001);
+        assert_eq!(client.get_risk(&strict), None);
+        submit_at(&env, &client, &key, &strict, 10_000);
+        assert!(client.get_risk(&strict).is_some());
+    }
+    
+    #[test]
+    fn test_replay_window_is_configurable() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 10_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        assert_eq!(client.get_config().replay_window, DEFAULT_REPLAY_WINDOW);
+        
+        // 10 minutes old: rejected by default, accepted once widened
+        let wallet = Address::generate(&env);
+        submit_at(&env, &client, &key, &wallet, 9_400);
+        assert_eq!(client.get_risk(&wallet), None);
+        
+        client.set_replay_window(&900);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let data: (u64, u64) = data.into_val(&env);
+        assert_eq!(data, (DEFAULT_REPLAY_WINDOW, 900));
+        
+        submit_at(&env, &client, &key, &wallet, 9_400);
+        assert!(client.get_risk(&wallet).is_some());
+        
+        let config = client.get_config();
+        assert_eq!(config.replay_window, 900);
+        assert_eq!(config.max_clock_skew, DEFAULT_MAX_CLOCK_SKEW);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Replay window must be within")]
+    fn test_replay_window_bounds_enforced() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        client.set_replay_window(&(MIN_REPLAY_WINDOW - 1));
+    }
+    
+    #[test]
+    #[should_panic(expected = "Clock skew tolerance too large")]
+    fn test_skew_limit_enforced() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        client.set_max_clock_skew(&(MAX_CLOCK_SKEW_LIMIT + 1));
+    }
+}
@@ -0,0 +1,192 @@
+//! Grace period before Oracle freezes take effect.
+//!
+//! A model false positive should not lock a user out mid-operation. With
+//! a grace period configured, a wallet newly frozen by the Oracle is in
+//! `PendingFreeze` for that many seconds: `check_permission` keeps
+//! returning its pre-freeze decision, so the user can withdraw their own
+//! funds or file an appeal, and integrators that want to react early can
+//! read `get_freeze_status`. Admin overrides take effect immediately.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::pipeline::{self, Evaluation, StageResult};
+use crate::types::{ConfigKey, DataKey, FreezeStatus, RiskDecision, TransitionCause};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Grace period in seconds (0 = freezes apply immediately)
+pub(crate) fn freeze_grace(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::FreezeGrace))
+        .unwrap_or(0)
+}
+
+/// Pre-freeze decision and freeze end of grace, if the wallet's latest
+/// Oracle freeze is still within its grace period
+pub(crate) fn pending_freeze(env: &Env, wallet: &Address) -> Option<(RiskDecision, u64)> {
+    let grace = freeze_grace(env);
+    if grace == 0 {
+        return None;
+    }
+    
+    let log = SentinelSDK::transition_log(env.clone(), wallet.clone());
+    let last = log.last()?;
+    if last.decision != RiskDecision::Freeze || last.cause != TransitionCause::Oracle {
+        return None;
+    }
+    
+    let effective_at = last.timestamp.saturating_add(grace);
+    if env.ledger().timestamp() >= effective_at {
+        return None;
+    }
+    
+    let before = match log.len() {
+        0 | 1 => pipeline::unknown_default(env),
+        len => log.get_unchecked(len - 2).decision,
+    };
+    Some((before, effective_at))
+}
+
+/// Replace a Freeze still in its grace period with the pre-freeze decision
+pub(crate) fn grace_stage(env: &Env, eval: &mut Evaluation) -> StageResult {
+    if eval.decision == Some(RiskDecision::Freeze) {
+        if let Some((before, _)) = pending_freeze(env, &eval.wallet) {
+            eval.decision = Some(before);
+        }
+    }
+    StageResult::Continue
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Whether a wallet is frozen, pending a freeze, or neither (read-only)
+    pub fn get_freeze_status(env: Env, wallet: Address) -> FreezeStatus {
+        if let Some((_, effective_at)) = pending_freeze(&env, &wallet) {
+            if Self::active_override(&env, &wallet).is_none() {
+                return FreezeStatus::PendingFreeze(effective_at);
+            }
+        }
+        
+        match Self::check_permission(env, wallet) {
+            RiskDecision::Freeze => FreezeStatus::Frozen,
+            _ => FreezeStatus::NotFrozen,
+        }
+    }
+    
+    /// Get the freeze grace period in seconds (read-only)
+    pub fn get_freeze_grace(env: Env) -> u64 {
+        freeze_grace(&env)
+    }
+    
+    /// Set the freeze grace period in seconds (admin only)
+    /// 
+    /// Takes effect only where the `Grace` stage is in the pipeline.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    pub fn set_freeze_grace(env: Env, seconds: u64) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_freeze_grace(env.clone());
+        Self::apply_freeze_grace(&env, seconds);
+        audit::record(&env, &admin, "set_freeze_grace", old, seconds);
+    }
+}
+
+impl SentinelSDK {
+    /// Store the freeze grace period (authorization checked by caller)
+    pub(crate) fn apply_freeze_grace(env: &Env, seconds: u64) {
+        env.storage().instance().set(&DataKey::Config(ConfigKey::FreezeGrace), &seconds);
+        env.events().publish((topic::GRACE_SET,), seconds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Map;
+    use crate::types::{feature, AppealStatus, RiskPayload, PAYLOAD_VERSION_V2};
+    use crate::testutils::TimeTravel;
+    use soroban_sdk::{testutils::Address as _, BytesN, Vec};
+    
+    /// Store an Oracle score for the wallet at the current ledger time
+    fn store(env: &Env, contract_id: &Address, wallet: &Address, score: u32) {
+        env.as_contract(contract_id, || {
+            let payload = RiskPayload {
+                version: PAYLOAD_VERSION_V2,
+                wallet: wallet.clone(),
+                risk_score: score,
+                timestamp: env.ledger().timestamp(),
+                reason_codes: Vec::new(env),
+                freeze_duration: 0,
+                category_scores: Map::new(env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(env, &[0; 32]),
+            };
+            SentinelSDK::store_risk(env, &payload);
+        });
+    }
+    
+    #[test]
+    fn test_new_freeze_pending_during_grace() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.set_time(1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_freeze_grace(&600);
+        
+        let wallet = Address::generate(&env);
+        store(&env, &contract_id, &wallet, 60);
+        env.advance_secs(100);
+        store(&env, &contract_id, &wallet, 95);
+        
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(5000));
+        assert_eq!(client.get_freeze_status(&wallet), FreezeStatus::PendingFreeze(1_700));
+        
+        env.advance_secs(600);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.get_freeze_status(&wallet), FreezeStatus::Frozen);
+    }
+    
+    #[test]
+    fn test_pending_freeze_can_be_appealed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_features(&(feature::DEFAULT | feature::APPEALS));
+        client.set_freeze_grace(&600);
+        
+        let wallet = Address::generate(&env);
+        store(&env, &contract_id, &wallet, 10);
+        assert!(client.try_file_appeal(&wallet).is_err());
+        
+        store(&env, &contract_id, &wallet, 95);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        client.file_appeal(&wallet);
+        assert_eq!(client.get_appeal(&wallet).map(|appeal| appeal.status), Some(AppealStatus::Pending));
+    }
+    
+    #[test]
+    fn test_override_freeze_is_immediate() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_freeze_grace(&600);
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        assert_eq!(client.get_freeze_status(&wallet), FreezeStatus::Frozen);
+    }
+}
@@ -0,0 +1,155 @@
+//! Wallet guardians.
+//!
+//! A wallet may name a guardian (a second device, a trusted contact, a
+//! multisig) that can lift its self-lock without waiting for the cooldown
+//! and co-sign its appeals. The guardian cannot be changed while the
+//! wallet is self-locked, so a thief holding the key cannot swap in their
+//! own guardian to undo the lock.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::pipeline;
+use crate::types::{AppealStatus, DataKey, TransitionCause};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Designate (or with `None`, remove) a wallet's guardian
+    /// 
+    /// # Panics
+    /// * If `wallet` has not authorized the call
+    /// * If the wallet is self-locked
+    pub fn set_guardian(env: Env, wallet: Address, guardian: Option<Address>) {
+        wallet.require_auth();
+        
+        if Self::self_locked_at(env.clone(), wallet.clone()).is_some() {
+            panic!("Cannot change guardian while self-locked");
+        }
+        
+        let key = DataKey::Guardian(wallet.clone());
+        match &guardian {
+            Some(guardian) => env.storage().persistent().set(&key, guardian),
+            None => env.storage().persistent().remove(&key),
+        }
+        env.events().publish((topic::GRD_SET,), (wallet, guardian));
+    }
+    
+    /// Get a wallet's guardian (read-only)
+    pub fn get_guardian(env: Env, wallet: Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Guardian(wallet))
+    }
+    
+    /// Lift a wallet's self-lock as its guardian (no cooldown)
+    /// 
+    /// # Panics
+    /// * If the wallet has no guardian, or it has not authorized the call
+    /// * If the wallet is not self-locked
+    pub fn guardian_unfreeze(env: Env, wallet: Address) {
+        let guardian = Self::require_guardian(&env, &wallet);
+        
+        if Self::self_locked_at(env.clone(), wallet.clone()).is_none() {
+            panic!("Wallet is not self-locked");
+        }
+        
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SelfLock(wallet.clone()));
+        let decision = pipeline::evaluate(&env, &wallet);
+        Self::record_transition(&env, &wallet, decision, TransitionCause::SelfLock, Some(guardian.clone()));
+        
+        env.events().publish((topic::GRD_UNFZ,), (wallet, guardian));
+    }
+    
+    /// Co-sign a wallet's pending appeal as its guardian
+    /// 
+    /// # Panics
+    /// * If the wallet has no guardian, or it has not authorized the call
+    /// * If the wallet has no pending appeal
+    pub fn guardian_cosign_appeal(env: Env, wallet: Address) {
+        let guardian = Self::require_guardian(&env, &wallet);
+        
+        let mut appeal = Self::get_appeal(env.clone(), wallet.clone())
+            .filter(|appeal| appeal.status == AppealStatus::Pending)
+            .expect("No pending appeal");
+        appeal.guardian_cosigned = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Appeal(wallet.clone()), &appeal);
+        
+        env.events().publish((topic::GRD_COSGN,), (wallet, guardian));
+    }
+}
+
+impl SentinelSDK {
+    /// Load the wallet's guardian and require its authorization
+    fn require_guardian(env: &Env, wallet: &Address) -> Address {
+        let guardian = Self::get_guardian(env.clone(), wallet.clone()).expect("No guardian set");
+        guardian.require_auth();
+        guardian
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{feature, RiskDecision};
+    use soroban_sdk::{testutils::Address as _, BytesN};
+    
+    fn setup(env: &Env) -> SentinelSDKClient<'_> {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        client
+    }
+    
+    #[test]
+    fn test_guardian_lifts_self_lock_without_cooldown() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_self_lock_cooldown(&86_400);
+        
+        let wallet = Address::generate(&env);
+        let guardian = Address::generate(&env);
+        client.set_guardian(&wallet, &Some(guardian.clone()));
+        client.self_freeze(&wallet);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        
+        client.guardian_unfreeze(&wallet);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(
+            client.transition_log(&wallet).last().unwrap().actor,
+            Some(guardian)
+        );
+    }
+    
+    #[test]
+    fn test_guardian_cosigns_appeal() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_features(&(feature::DEFAULT | feature::APPEALS));
+        
+        let wallet = Address::generate(&env);
+        client.set_guardian(&wallet, &Some(Address::generate(&env)));
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.file_appeal(&wallet);
+        
+        client.guardian_cosign_appeal(&wallet);
+        assert!(client.get_appeal(&wallet).unwrap().guardian_cosigned);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Cannot change guardian while self-locked")]
+    fn test_guardian_fixed_while_locked() {
+        let env = Env::default();
+        let client = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        client.self_freeze(&wallet);
+        client.set_guardian(&wallet, &Some(Address::generate(&env)));
+    }
+}
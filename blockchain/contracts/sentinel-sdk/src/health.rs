@@ -0,0 +1,115 @@
+//! Integration self-check.
+//!
+//! Consumer contracts call `healthcheck` from their own init or upgrade
+//! routines and fail fast if the SDK is misconfigured for them (not
+//! registered, unexpected policy, stale Oracle, missing feature, global
+//! freeze engaged) instead of discovering it on the first user transaction.
+
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::emergency::active_global_freeze;
+use crate::heartbeat::record_oracle_activity;
+use crate::types::{DataKey, HealthReport};
+use crate::{SentinelSDK, SentinelSDKClient, STALE_AFTER_SECONDS};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Report the SDK's configuration as seen by a consumer (read-only)
+    /// 
+    /// # Arguments
+    /// * `consumer` - Integrating contract
+    pub fn healthcheck(env: Env, consumer: Address) -> HealthReport {
+        let policy_id = Self::get_integrator_policy(env.clone(), consumer);
+        let last_oracle_update: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LastOracleUpdate)
+            .unwrap_or(0);
+        let now = env.ledger().timestamp();
+        
+        HealthReport {
+            initialized: env.storage().instance().has(&DataKey::Admin),
+            registered: policy_id.is_some(),
+            policy_id,
+            policy_version: policy_id.map(|id| Self::policy_version(&env, id)).unwrap_or(0),
+            last_oracle_update,
+            oracle_stale: last_oracle_update == 0
+                || now.saturating_sub(last_oracle_update) > STALE_AFTER_SECONDS,
+            features: Self::enabled_features(env.clone()),
+            global_freeze: active_global_freeze(&env).is_some(),
+        }
+    }
+}
+
+impl SentinelSDK {
+    /// Remember when the Oracle, and its live key, last delivered an update
+    pub(crate) fn touch_oracle_update(env: &Env) {
+        env.storage()
+            .instance()
+            .set(&DataKey::LastOracleUpdate, &env.ledger().timestamp());
+        if let Some(key) = Self::get_oracle_pubkey(env.clone()) {
+            record_oracle_activity(env, &key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Map;
+    use crate::types::{feature, Policy, RiskPayload, ScoreBands, PAYLOAD_VERSION_V2};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, BytesN, Vec};
+    
+    #[test]
+    fn test_healthcheck_reports_registration_and_freshness() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let consumer = Address::generate(&env);
+        
+        let report = client.healthcheck(&consumer);
+        assert!(!report.initialized);
+        assert!(!report.registered);
+        assert!(report.oracle_stale);
+        
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        let bands = ScoreBands { allow_max: 30, limit_max: 70, review_max: 70, limit_amount: 100 };
+        client.set_policy(&3, &Policy::from_bands(bands.clone()));
+        client.set_policy(&3, &Policy::from_bands(bands));
+        client.register_integrator(&client.get_admin(), &consumer, &3);
+        
+        env.as_contract(&contract_id, || {
+            let payload = RiskPayload {
+                version: PAYLOAD_VERSION_V2,
+                wallet: Address::generate(&env),
+                risk_score: 10,
+                timestamp: 1_000,
+                reason_codes: Vec::new(&env),
+                freeze_duration: 0,
+                category_scores: Map::new(&env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(&env, &[0; 32]),
+            };
+            SentinelSDK::store_risk(&env, &payload);
+        });
+        
+        let report = client.healthcheck(&consumer);
+        assert_eq!(
+            report,
+            HealthReport {
+                initialized: true,
+                registered: true,
+                policy_id: Some(3),
+                policy_version: 2,
+                last_oracle_update: 1_000,
+                oracle_stale: false,
+                features: feature::DEFAULT,
+                global_freeze: false,
+            }
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Oracle liveness tracking.
+//!
+//! Every accepted submission records when its signing key was last
+//! active. If the Oracle goes silent for longer than a configured
+//! threshold, deployments can have `check_permission` fall back to a
+//! degraded-mode decision instead of serving ever older scores; overrides
+//! still win.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Env};
+
+use crate::audit;
+use crate::pipeline::{Evaluation, StageResult};
+use crate::types::{ConfigKey, DataKey, DegradedMode, PublicKey};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Record that a key delivered an accepted submission now
+pub(crate) fn record_oracle_activity(env: &Env, key: &PublicKey) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OracleActivity(key.clone()), &env.ledger().timestamp());
+}
+
+/// Degraded-mode decision, if the Oracle has been silent past the threshold
+/// 
+/// Silence is measured from the last accepted submission by any key; a
+/// deployment that has never received one is not degraded.
+pub(crate) fn degraded_stage(env: &Env, _eval: &mut Evaluation) -> StageResult {
+    let Some(mode) = SentinelSDK::get_degraded_mode(env.clone()) else {
+        return StageResult::Continue;
+    };
+    let last: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::LastOracleUpdate)
+        .unwrap_or(0);
+    
+    if last != 0 && env.ledger().timestamp().saturating_sub(last) > mode.silence_threshold {
+        StageResult::Settled(mode.decision)
+    } else {
+        StageResult::Continue
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Ledger timestamp of the live Oracle key's last accepted submission
+    /// (read-only; the Oracle address's in Soroban-auth mode)
+    pub fn last_oracle_activity(env: Env) -> Option<u64> {
+        match Self::get_oracle_pubkey(env.clone()) {
+            Some(key) => Self::oracle_activity(env, key),
+            None => env.storage().instance().get(&DataKey::LastOracleUpdate),
+        }
+    }
+    
+    /// Ledger timestamp of a key's last accepted submission, live or shadow
+    /// (read-only)
+    pub fn oracle_activity(env: Env, key: PublicKey) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::OracleActivity(key))
+    }
+    
+    /// Get the degraded-mode configuration, if any (read-only)
+    pub fn get_degraded_mode(env: Env) -> Option<DegradedMode> {
+        env.storage().instance().get(&DataKey::Config(ConfigKey::DegradedMode))
+    }
+    
+    /// Set or remove the degraded-mode fallback (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If `silence_threshold` is 0
+    pub fn set_degraded_mode(env: Env, mode: Option<DegradedMode>) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_degraded_mode(env.clone());
+        Self::apply_degraded_mode(&env, mode.clone());
+        audit::record(&env, &admin, "set_degraded_mode", old, mode);
+    }
+}
+
+/// Panic unless the silence threshold is positive
+pub(crate) fn check_degraded_mode(mode: &DegradedMode) {
+    if mode.silence_threshold == 0 {
+        panic!("Silence threshold must be positive");
+    }
+}
+
+impl SentinelSDK {
+    /// Store or clear the degraded-mode fallback (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If `silence_threshold` is 0
+    pub(crate) fn apply_degraded_mode(env: &Env, mode: Option<DegradedMode>) {
+        let key = DataKey::Config(ConfigKey::DegradedMode);
+        match &mode {
+            Some(config) => {
+                check_degraded_mode(config);
+                env.storage().instance().set(&key, config);
+            }
+            None => env.storage().instance().remove(&key),
+        }
+        env.events().publish((topic::DEGR_SET,), mode);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::{TimeTravel, HOUR};
+    use crate::types::{RiskDecision, RiskPayload, PAYLOAD_VERSION_V2};
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Map, Vec};
+    
+    #[test]
+    fn test_activity_recorded_and_degraded_after_silence() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.set_time(1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let pubkey = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+        client.initialize(&Address::generate(&env), &pubkey);
+        client.set_degraded_mode(&Some(DegradedMode {
+            silence_threshold: HOUR,
+            decision: RiskDecision::Limit(100),
+        }));
+        
+        // Never heard from: not degraded
+        let wallet = Address::generate(&env);
+        assert_eq!(client.last_oracle_activity(), None);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 10,
+            timestamp: 1_000,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let message = crate::crypto::serialize_canonical_json(&env, &client.address, &payload);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        client.submit_risk(&payload, &BytesN::from_array(&env, &key.sign(&message).to_bytes()));
+        assert_eq!(client.last_oracle_activity(), Some(1_000));
+        assert_eq!(client.oracle_activity(&pubkey), Some(1_000));
+        
+        env.advance_secs(HOUR);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        
+        env.advance_secs(1);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(100));
+        
+        // Overrides still win
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Allow, &0);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+    }
+}
@@ -0,0 +1,83 @@
+//! Per-wallet risk history.
+//!
+//! Every stored Oracle update is also appended to the wallet's history so
+//! disputes and audits can show how a wallet's score evolved, not only the
+//! latest value. The last `MAX_HISTORY` snapshots are kept in full; older
+//! ones are compacted to one per month (see `compaction`).
+
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::compaction::compact_log;
+use crate::types::{DataKey, RiskState};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Number of recent snapshots kept in full per wallet
+pub const MAX_HISTORY: u32 = 10;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get a wallet's past risk states, oldest first (read-only)
+    /// 
+    /// The last entry equals `get_risk(wallet)`.
+    pub fn get_risk_history(env: Env, wallet: Address) -> Vec<RiskState> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::History(wallet))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
+
+impl SentinelSDK {
+    /// Append a snapshot and compact older ones
+    pub(crate) fn record_history(env: &Env, wallet: &Address, state: &RiskState) {
+        let mut history = Self::get_risk_history(env.clone(), wallet.clone());
+        history.push_back(state.clone());
+        let history = compact_log(env, &history, MAX_HISTORY, |s: &RiskState| s.last_updated);
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::History(wallet.clone()), &history);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{BytesN, Map};
+    use crate::types::RiskDecision;
+    use soroban_sdk::testutils::Address as _;
+    
+    #[test]
+    fn test_history_keeps_recent_snapshots_in_order() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let wallet = Address::generate(&env);
+        
+        assert_eq!(client.get_risk_history(&wallet).len(), 0);
+        
+        env.as_contract(&contract_id, || {
+            for i in 0..MAX_HISTORY + 3 {
+                let state = RiskState {
+                    risk_score: i,
+                    last_updated: i as u64,
+                    decision: RiskDecision::Allow,
+                    reason_codes: Vec::new(&env),
+                    freeze_until: 0,
+                    category_scores: Map::new(&env),
+                    model_version: 0,
+                    confidence: 0,
+                    evidence_hash: BytesN::from_array(&env, &[0; 32]),
+                };
+                SentinelSDK::record_history(&env, &wallet, &state);
+            }
+        });
+        
+        // Recent snapshots plus one for the (single) archived month
+        let history = client.get_risk_history(&wallet);
+        assert_eq!(history.len(), MAX_HISTORY + 1);
+        assert_eq!(history.get(0).unwrap().risk_score, 2);
+        assert_eq!(history.get(1).unwrap().risk_score, 3);
+        assert_eq!(history.last().unwrap().risk_score, MAX_HISTORY + 2);
+    }
+}
@@ -0,0 +1,197 @@
+//! On-chain index of scored wallets.
+//!
+//! Wallets are appended in the order they are first scored, in persistent
+//! buckets of `INDEX_BUCKET_SIZE`, so compliance dashboards and auditors
+//! can page through affected wallets on-chain instead of rebuilding the
+//! set from events. The index is capped at `MAX_INDEXED_WALLETS`; wallets
+//! first scored past the cap are not indexed (`IDX_FULL` is emitted).
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::pipeline;
+use crate::types::{DataKey, RiskDecision, WalletPage};
+use crate::{schema, SentinelSDK, SentinelSDKClient};
+
+/// Wallets per storage bucket
+pub const INDEX_BUCKET_SIZE: u32 = 100;
+
+/// Most wallets the index holds
+pub const MAX_INDEXED_WALLETS: u32 = 100_000;
+
+/// Most index positions scanned by one page
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Number of indexed wallets
+fn indexed_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::IndexedCount).unwrap_or(0)
+}
+
+fn bucket(env: &Env, id: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IndexBucket(id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Scan up to `limit` index positions from `cursor`, keeping the wallets
+/// that match `keep`
+fn page(env: &Env, cursor: u32, limit: u32, keep: impl Fn(&Address) -> bool) -> WalletPage {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        panic!("Page limit must be within 1-100");
+    }
+    
+    let count = indexed_count(env);
+    let end = cursor.saturating_add(limit).min(count);
+    let mut wallets = Vec::new(env);
+    let mut current = Vec::new(env);
+    let mut current_id = u32::MAX;
+    
+    for position in cursor..end {
+        let id = position / INDEX_BUCKET_SIZE;
+        if id != current_id {
+            current = bucket(env, id);
+            current_id = id;
+        }
+        let wallet = current.get_unchecked(position % INDEX_BUCKET_SIZE);
+        if keep(&wallet) {
+            wallets.push_back(wallet);
+        }
+    }
+    
+    WalletPage {
+        wallets,
+        next: if end < count { Some(end) } else { None },
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Page through wallets that currently have a risk state (read-only)
+    /// 
+    /// Scans `limit` index positions starting at `cursor` (0 for the first
+    /// page); erased or expired wallets are skipped, so a page can hold
+    /// fewer than `limit` wallets. Continue with `next` until it is `None`.
+    /// 
+    /// # Panics
+    /// * If `limit` is 0 or exceeds `MAX_PAGE_SIZE`
+    pub fn list_scored(env: Env, cursor: u32, limit: u32) -> WalletPage {
+        page(&env, cursor, limit, |wallet| schema::load_risk_state(&env, wallet).is_some())
+    }
+    
+    /// Page through wallets `check_permission` currently freezes (read-only)
+    /// 
+    /// Paged like `list_scored`. Only indexed wallets are considered, so a
+    /// wallet frozen by override without ever being scored is not listed.
+    /// 
+    /// # Panics
+    /// * If `limit` is 0 or exceeds `MAX_PAGE_SIZE`
+    pub fn list_frozen(env: Env, cursor: u32, limit: u32) -> WalletPage {
+        page(&env, cursor, limit, |wallet| {
+            pipeline::evaluate(&env, wallet) == RiskDecision::Freeze
+        })
+    }
+    
+    /// Number of wallets in the index (read-only)
+    pub fn indexed_wallets(env: Env) -> u32 {
+        indexed_count(&env)
+    }
+}
+
+impl SentinelSDK {
+    /// Append a wallet to the index the first time it is scored
+    pub(crate) fn index_wallet(env: &Env, wallet: &Address) {
+        let member = DataKey::Indexed(wallet.clone());
+        if env.storage().persistent().has(&member) {
+            return;
+        }
+        
+        let count = indexed_count(env);
+        if count >= MAX_INDEXED_WALLETS {
+            env.events().publish((topic::IDX_FULL,), wallet.clone());
+            return;
+        }
+        
+        let id = count / INDEX_BUCKET_SIZE;
+        let mut wallets = bucket(env, id);
+        wallets.push_back(wallet.clone());
+        env.storage().persistent().set(&DataKey::IndexBucket(id), &wallets);
+        env.storage().persistent().set(&member, &count);
+        env.storage().instance().set(&DataKey::IndexedCount, &(count + 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RiskPayload, PAYLOAD_VERSION_V2};
+    use soroban_sdk::{testutils::Address as _, BytesN, Map};
+    
+    fn score(env: &Env, contract_id: &Address, wallet: &Address, risk_score: u32) {
+        env.as_contract(contract_id, || {
+            let payload = RiskPayload {
+                version: PAYLOAD_VERSION_V2,
+                wallet: wallet.clone(),
+                risk_score,
+                timestamp: 0,
+                reason_codes: Vec::new(env),
+                freeze_duration: 0,
+                category_scores: Map::new(env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(env, &[0; 32]),
+            };
+            SentinelSDK::store_risk(env, &payload);
+        });
+    }
+    
+    #[test]
+    fn test_pages_span_buckets_and_filter_frozen() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let mut frozen = Vec::new(&env);
+        for i in 0..INDEX_BUCKET_SIZE + 10 {
+            let wallet = Address::generate(&env);
+            let risk_score = if i % 10 == 0 { 95 } else { 10 };
+            score(&env, &contract_id, &wallet, risk_score);
+            if risk_score == 95 {
+                frozen.push_back(wallet.clone());
+            }
+            // Rescoring does not index twice
+            score(&env, &contract_id, &wallet, risk_score);
+        }
+        assert_eq!(client.indexed_wallets(), INDEX_BUCKET_SIZE + 10);
+        
+        let first = client.list_scored(&0, &MAX_PAGE_SIZE);
+        assert_eq!(first.wallets.len(), MAX_PAGE_SIZE);
+        assert_eq!(first.next, Some(MAX_PAGE_SIZE));
+        let second = client.list_scored(&first.next.unwrap(), &MAX_PAGE_SIZE);
+        assert_eq!(second.wallets.len(), 10);
+        assert_eq!(second.next, None);
+        
+        let mut listed = Vec::new(&env);
+        let mut cursor = Some(0);
+        while let Some(at) = cursor {
+            let page = client.list_frozen(&at, &50);
+            listed.append(&page.wallets);
+            cursor = page.next;
+        }
+        assert_eq!(listed, frozen);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Page limit must be within 1-100")]
+    fn test_page_limit_enforced() {
+        let env = Env::default();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.list_scored(&0, &(MAX_PAGE_SIZE + 1));
+    }
+}
@@ -0,0 +1,176 @@
+//! Integrator registry.
+//!
+//! Protocols are registered against an admin-defined policy (see
+//! `policies`) so `check_permission_for` can apply their risk appetite: an
+//! AMM may tolerate `Limit`, a lending protocol may freeze from score 60.
+//! Registration is approved by an `IntegratorManager` and consented to by
+//! the protocol; only registered integrators may meter limits, report
+//! usage or subscribe to decision changes.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::pipeline;
+use crate::types::{DataKey, RiskDecision, Role};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Register a protocol against a policy (`IntegratorManager` role)
+    /// 
+    /// Re-registering an integrator moves it to the new policy.
+    /// 
+    /// # Arguments
+    /// * `caller` - Approving `IntegratorManager`; must authorize
+    /// * `contract` - Integrator address; must authorize the call
+    /// * `policy_id` - Existing policy to apply to this integrator
+    /// 
+    /// # Panics
+    /// * If `caller` does not hold the `IntegratorManager` role
+    /// * If `contract` has not authorized the call
+    /// * If the policy does not exist
+    pub fn register_integrator(env: Env, caller: Address, contract: Address, policy_id: u32) {
+        Self::require_role(&env, &caller, Role::IntegratorManager);
+        contract.require_auth();
+        
+        if Self::get_policy(env.clone(), policy_id).is_none() {
+            panic!("Unknown policy");
+        }
+        
+        let old = Self::get_integrator_policy(env.clone(), contract.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Integrator(contract.clone()), &policy_id);
+        audit::record(&env, &caller, "register_integrator", old, (contract.clone(), policy_id));
+        env.events().publish((topic::INTEG_REG,), (contract, policy_id));
+    }
+    
+    /// Remove a registered integrator (`IntegratorManager` role)
+    /// 
+    /// # Panics
+    /// * If `caller` does not hold the `IntegratorManager` role
+    /// * If `contract` is not registered
+    pub fn remove_integrator(env: Env, caller: Address, contract: Address) {
+        Self::require_role(&env, &caller, Role::IntegratorManager);
+        
+        let old = Self::get_integrator_policy(env.clone(), contract.clone()).expect("Integrator not registered");
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Integrator(contract.clone()));
+        audit::record(&env, &caller, "remove_integrator", (contract.clone(), old), ());
+        env.events().publish((topic::INTEG_DEL,), contract);
+    }
+    
+    /// Get the policy an integrator registered with (read-only)
+    pub fn get_integrator_policy(env: Env, contract: Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Integrator(contract))
+    }
+    
+    /// Check a wallet under an integrator's policy
+    /// 
+    /// Unregistered integrators get the global `check_permission` result.
+    /// 
+    /// # Arguments
+    /// * `integrator` - Protocol the decision is for
+    /// * `wallet` - Address to check
+    pub fn check_permission_for(env: Env, integrator: Address, wallet: Address) -> RiskDecision {
+        let policy = Self::get_integrator_policy(env.clone(), integrator)
+            .and_then(|policy_id| Self::get_policy(env.clone(), policy_id));
+        
+        match policy {
+            Some(policy) => pipeline::evaluate_with_policy(&env, &wallet, policy),
+            None => pipeline::evaluate(&env, &wallet),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Map;
+    use crate::types::{Policy, RiskState, ScoreBands};
+    use soroban_sdk::{testutils::Address as _, BytesN, Vec};
+    
+    #[test]
+    fn test_integrator_policy_changes_thresholds() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let wallet = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            let state = RiskState {
+                risk_score: 65,
+                last_updated: 0,
+                decision: RiskDecision::Limit(5000),
+                reason_codes: Vec::new(&env),
+                freeze_until: 0,
+                category_scores: Map::new(&env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(&env, &[0; 32]),
+            };
+            env.storage().persistent().set(&DataKey::Risk(wallet.clone()), &state);
+        });
+        
+        // Lending protocol freezes from score 60
+        client.set_policy(
+            &1,
+            &Policy::from_bands(ScoreBands { allow_max: 39, limit_max: 59, review_max: 59, limit_amount: 1000 }),
+        );
+        let lending = Address::generate(&env);
+        client.register_integrator(&client.get_admin(), &lending, &1);
+        
+        let amm = Address::generate(&env);
+        
+        assert_eq!(client.get_integrator_policy(&lending), Some(1));
+        assert_eq!(client.check_permission_for(&lending, &wallet), RiskDecision::Freeze);
+        assert_eq!(client.check_permission_for(&amm, &wallet), RiskDecision::Limit(5000));
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(5000));
+    }
+    
+    #[test]
+    #[should_panic(expected = "Unknown policy")]
+    fn test_register_requires_existing_policy() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+        client.register_integrator(&admin, &Address::generate(&env), &7);
+    }
+    
+    #[test]
+    fn test_registration_needs_integrator_manager() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_policy(
+            &1,
+            &Policy::from_bands(ScoreBands { allow_max: 30, limit_max: 70, review_max: 70, limit_amount: 1000 }),
+        );
+        
+        let protocol = Address::generate(&env);
+        assert!(client.try_register_integrator(&protocol, &protocol, &1).is_err());
+        
+        let manager = Address::generate(&env);
+        client.grant_role(&admin, &Role::IntegratorManager, &manager);
+        client.register_integrator(&manager, &protocol, &1);
+        assert_eq!(client.get_integrator_policy(&protocol), Some(1));
+        
+        client.remove_integrator(&manager, &protocol);
+        assert_eq!(client.get_integrator_policy(&protocol), None);
+    }
+}
@@ -0,0 +1,140 @@
+//! Storage invariant checks (enabled by the `strict-invariants` feature).
+//!
+//! Mutating entrypoints call into this module after writing state. In
+//! tests and staging builds every check re-reads storage and traps loudly
+//! on a violation; in release builds without the feature the checks
+//! compile to nothing.
+
+use soroban_sdk::{Address, Env};
+
+#[cfg(any(test, feature = "strict-invariants"))]
+use soroban_sdk::Vec;
+
+#[cfg(any(test, feature = "strict-invariants"))]
+use crate::index::INDEX_BUCKET_SIZE;
+#[cfg(any(test, feature = "strict-invariants"))]
+use crate::types::{DataKey, PipelineStage, PublicKey, RiskDecision, RiskState};
+
+/// Check invariants for a single wallet's stored risk state
+///
+/// - Score is within 0-100
+/// - Stored decision matches the score under the current score bands
+/// - Only Freeze decisions carry a quarantine `freeze_until`
+/// - An indexed wallet's position is below the index count and its
+///   bucket holds the wallet at that position
+#[cfg(any(test, feature = "strict-invariants"))]
+pub fn check_wallet(env: &Env, wallet: &Address) {
+    check_index(env, wallet);
+    
+    let state: Option<RiskState> = crate::schema::load_risk_state(env, wallet);
+    
+    if let Some(state) = state {
+        if state.worst_score() > 100 {
+            panic!("Invariant violated: stored risk score out of range");
+        }
+        if state.decision != crate::pipeline::score_bands(env).decide(state.worst_score()) {
+            panic!("Invariant violated: stored decision does not match score");
+        }
+        if state.freeze_until != 0 && state.decision != RiskDecision::Freeze {
+            panic!("Invariant violated: quarantine set on non-frozen wallet");
+        }
+    }
+}
+
+#[cfg(any(test, feature = "strict-invariants"))]
+fn check_index(env: &Env, wallet: &Address) {
+    let Some(position) = env.storage().persistent().get::<_, u32>(&DataKey::Indexed(wallet.clone())) else {
+        return;
+    };
+    let count: u32 = env.storage().instance().get(&DataKey::IndexedCount).unwrap_or(0);
+    if position >= count {
+        panic!("Invariant violated: index position beyond index count");
+    }
+    
+    let bucket: Option<Vec<Address>> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::IndexBucket(position / INDEX_BUCKET_SIZE));
+    if bucket.and_then(|bucket| bucket.get(position % INDEX_BUCKET_SIZE)).as_ref() != Some(wallet) {
+        panic!("Invariant violated: index bucket does not hold wallet");
+    }
+}
+
+/// Check contract-level invariants
+///
+/// - Oracle key (or address) is present once any state has been written
+/// - Score bands are ordered within 0-100
+/// - The decision pipeline includes `OracleScore`
+#[cfg(any(test, feature = "strict-invariants"))]
+pub fn check_contract(env: &Env) {
+    let oracle: Option<PublicKey> = env.storage().instance().get(&DataKey::Oracle);
+    
+    if oracle.is_none() && !env.storage().instance().has(&DataKey::OracleAddress) {
+        panic!("Invariant violated: oracle key missing after mutation");
+    }
+    if !crate::pipeline::score_bands(env).is_valid() {
+        panic!("Invariant violated: score bands out of order");
+    }
+    if !crate::pipeline::stages(env).contains(PipelineStage::OracleScore) {
+        panic!("Invariant violated: pipeline lacks OracleScore");
+    }
+}
+
+#[cfg(not(any(test, feature = "strict-invariants")))]
+#[inline(always)]
+pub fn check_wallet(_env: &Env, _wallet: &Address) {}
+
+#[cfg(not(any(test, feature = "strict-invariants")))]
+#[inline(always)]
+pub fn check_contract(_env: &Env) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{BytesN, Map};
+    use crate::SentinelSDK;
+    use soroban_sdk::testutils::Address as _;
+    
+    #[test]
+    #[should_panic(expected = "Invariant violated: stored decision does not match score")]
+    fn test_mismatched_decision_traps() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let wallet = Address::generate(&env);
+        
+        env.as_contract(&contract_id, || {
+            let corrupted = RiskState {
+                risk_score: 95,
+                last_updated: 0,
+                decision: RiskDecision::Allow,
+                reason_codes: soroban_sdk::Vec::new(&env),
+                freeze_until: 0,
+                category_scores: Map::new(&env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(&env, &[0; 32]),
+            };
+            env.storage().persistent().set(&DataKey::Risk(wallet.clone()), &corrupted);
+            
+            check_wallet(&env, &wallet);
+        });
+    }
+    
+    #[test]
+    #[should_panic(expected = "Invariant violated: index bucket does not hold wallet")]
+    fn test_inconsistent_index_traps() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let wallet = Address::generate(&env);
+        
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&DataKey::Indexed(wallet.clone()), &0u32);
+            env.storage().instance().set(&DataKey::IndexedCount, &1u32);
+            env.storage()
+                .persistent()
+                .set(&DataKey::IndexBucket(0), &soroban_sdk::vec![&env, Address::generate(&env)]);
+            
+            check_wallet(&env, &wallet);
+        });
+    }
+}
@@ -0,0 +1,380 @@
+//! Score-jump anomaly guard.
+//!
+//! A single bad model run could push many wallets from 0 to 95 at once
+//! and freeze them all. With the guard configured, a payload whose worst
+//! score moves more than the allowed delta away from the stored one, and
+//! that would newly freeze the wallet, is held instead of applied
+//! (`JUMP_HELD`). It takes effect once the configured second Oracle key
+//! signs the same payload (`confirm_score_jump`) or the admin co-signs it
+//! (`cosign_score_jump`).
+//! Wallets without a stored score are not guarded.
+//!
+//! Every path that applies a fresh Oracle score goes through the guard:
+//! `submit_risk`, batches, Merkle proofs, freshness challenges and
+//! aggregated scores via `apply_risk`, and domain scores against the
+//! wallet's state in that domain (`confirm_domain_score_jump` and
+//! friends).
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::audit;
+use crate::crypto::require_signature;
+use crate::domains::domain_state;
+use crate::pipeline;
+use crate::schema;
+use crate::types::{
+    ConfigKey, DataKey, JumpGuardConfig, RiskDecision, RiskPayload, RiskState, Signature, WalletKey,
+};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Worst scores before and after, if moving from `stored` to `payload`
+/// is a guarded jump to Freeze
+fn guarded_jump(env: &Env, payload: &RiskPayload, stored: Option<RiskState>) -> Option<(u32, u32)> {
+    let max_delta = SentinelSDK::get_jump_guard(env.clone())?.max_delta;
+    let stored = stored?;
+    
+    let state = RiskState::from_payload(payload, &pipeline::score_bands(env));
+    if state.decision != RiskDecision::Freeze || stored.decision == RiskDecision::Freeze {
+        return None;
+    }
+    if state.worst_score().abs_diff(stored.worst_score()) <= max_delta {
+        return None;
+    }
+    Some((stored.worst_score(), state.worst_score()))
+}
+
+/// Panic if the confirmer is the live Oracle key
+pub(crate) fn check_jump_guard(env: &Env, config: &JumpGuardConfig) {
+    if SentinelSDK::get_oracle_pubkey(env.clone()) == Some(config.confirmer.clone()) {
+        panic!("Jump confirmer must differ from the live Oracle key");
+    }
+}
+
+impl SentinelSDK {
+    /// Hold a validated payload if it is a guarded jump to Freeze
+    /// 
+    /// # Returns
+    /// * `true` if the payload was held and must not be stored
+    pub(crate) fn hold_score_jump(env: &Env, payload: &RiskPayload) -> bool {
+        let stored = schema::load_risk_state(env, &payload.wallet);
+        let Some((old, new)) = guarded_jump(env, payload, stored) else {
+            return false;
+        };
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::HeldScore(payload.wallet.clone()), payload);
+        env.events().publish((topic::JUMP_HELD,), (payload.wallet.clone(), old, new));
+        true
+    }
+    
+    /// Hold a validated domain payload if it is a guarded jump to Freeze
+    /// 
+    /// Same rule as `hold_score_jump`, measured against the wallet's
+    /// stored state in the domain.
+    /// 
+    /// # Returns
+    /// * `true` if the payload was held and must not be stored
+    pub(crate) fn hold_domain_score_jump(env: &Env, domain: &Symbol, payload: &RiskPayload) -> bool {
+        let stored = domain_state(env, domain, &payload.wallet);
+        let Some((old, new)) = guarded_jump(env, payload, stored) else {
+            return false;
+        };
+        
+        env.storage()
+            .persistent()
+            .set(&WalletKey::HeldDomainScore(domain.clone(), payload.wallet.clone()), payload);
+        env.events().publish(
+            (topic::JUMP_HELD, domain.clone()),
+            (payload.wallet.clone(), old, new)
+        );
+        true
+    }
+    
+    /// Store a held payload and clear the hold
+    fn release_score_jump(env: &Env, payload: &RiskPayload) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::HeldScore(payload.wallet.clone()));
+        
+        if let Some(stored) = schema::load_risk_state(env, &payload.wallet) {
+            if payload.timestamp < stored.last_updated {
+                panic!("Held score is older than the stored score");
+            }
+        }
+        
+        Self::store_and_notify(env, payload);
+    }
+    
+    /// Store a held domain payload and clear the hold
+    fn release_domain_score_jump(env: &Env, domain: &Symbol, payload: &RiskPayload) {
+        env.storage()
+            .persistent()
+            .remove(&WalletKey::HeldDomainScore(domain.clone(), payload.wallet.clone()));
+        
+        if let Some(stored) = domain_state(env, domain, &payload.wallet) {
+            if payload.timestamp < stored.last_updated {
+                panic!("Held score is older than the stored score");
+            }
+        }
+        
+        Self::store_domain_risk(env, domain, payload);
+    }
+    
+    /// Store or clear the jump guard (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If the confirmer is the live Oracle key
+    pub(crate) fn apply_jump_guard(env: &Env, config: Option<JumpGuardConfig>) {
+        let storage = env.storage().instance();
+        match &config {
+            Some(config) => {
+                check_jump_guard(env, config);
+                storage.set(&DataKey::Config(ConfigKey::JumpGuard), config);
+            }
+            None => storage.remove(&DataKey::Config(ConfigKey::JumpGuard)),
+        }
+        env.events().publish((topic::JUMP_GRD,), config);
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the score-jump guard configuration (read-only)
+    /// 
+    /// # Returns
+    /// * `Some(config)` if the jump guard is configured
+    /// * `None` if every valid payload is applied directly
+    pub fn get_jump_guard(env: Env) -> Option<JumpGuardConfig> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::JumpGuard))
+    }
+    
+    /// Configure the score-jump guard (admin only)
+    /// 
+    /// # Arguments
+    /// * `config` - Allowed delta and confirming Oracle key, or `None` to
+    ///   disable the guard
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If the confirmer is the live Oracle key
+    pub fn set_jump_guard(env: Env, config: Option<JumpGuardConfig>) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_jump_guard(env.clone());
+        Self::apply_jump_guard(&env, config.clone());
+        audit::record(&env, &admin, "set_jump_guard", old, config);
+    }
+    
+    /// Get the payload held for a wallet, if any (read-only)
+    pub fn get_held_score(env: Env, wallet: Address) -> Option<RiskPayload> {
+        env.storage().persistent().get(&DataKey::HeldScore(wallet))
+    }
+    
+    /// Apply a held payload signed by the confirming Oracle
+    /// 
+    /// # Arguments
+    /// * `wallet` - Wallet whose held payload is confirmed
+    /// * `signature` - Confirmer's signature over the held payload
+    /// 
+    /// # Panics
+    /// * If no payload is held for the wallet
+    /// * If the jump guard is not configured
+    /// * `InvalidSignature` if the signature does not verify
+    /// * If a newer score was stored since the payload was held
+    pub fn confirm_score_jump(env: Env, wallet: Address, signature: Signature) {
+        let payload = Self::get_held_score(env.clone(), wallet.clone()).expect("No score held for wallet");
+        let config = Self::get_jump_guard(env.clone()).expect("Jump guard not configured");
+        require_signature(&env, &payload, &signature, &config.confirmer);
+        
+        Self::release_score_jump(&env, &payload);
+        env.events().publish((topic::JUMP_OK,), (wallet, config.confirmer));
+    }
+    
+    /// Apply a held payload on the admin's authority (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If no payload is held for the wallet
+    /// * If a newer score was stored since the payload was held
+    pub fn cosign_score_jump(env: Env, wallet: Address) {
+        let admin = Self::require_admin(&env);
+        let payload = Self::get_held_score(env.clone(), wallet.clone()).expect("No score held for wallet");
+        
+        Self::release_score_jump(&env, &payload);
+        audit::record(&env, &admin, "cosign_score_jump", (), payload);
+        env.events().publish((topic::JUMP_OK,), (wallet, admin));
+    }
+    
+    /// Discard the payload held for a wallet (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    pub fn discard_score_jump(env: Env, wallet: Address) {
+        let admin = Self::require_admin(&env);
+        
+        let old = Self::get_held_score(env.clone(), wallet.clone());
+        env.storage().persistent().remove(&DataKey::HeldScore(wallet.clone()));
+        audit::record(&env, &admin, "discard_score_jump", old, ());
+        env.events().publish((topic::JUMP_DROP,), (wallet, admin));
+    }
+    
+    /// Get the domain payload held for a wallet, if any (read-only)
+    pub fn get_held_domain_score(env: Env, domain: Symbol, wallet: Address) -> Option<RiskPayload> {
+        env.storage()
+            .persistent()
+            .get(&WalletKey::HeldDomainScore(domain, wallet))
+    }
+    
+    /// Apply a held domain payload signed by the confirming Oracle
+    /// 
+    /// # Panics
+    /// * If no payload is held for the wallet in the domain
+    /// * If the jump guard is not configured
+    /// * `InvalidSignature` if the signature does not verify
+    /// * If a newer domain score was stored since the payload was held
+    pub fn confirm_domain_score_jump(env: Env, domain: Symbol, wallet: Address, signature: Signature) {
+        let payload = Self::get_held_domain_score(env.clone(), domain.clone(), wallet.clone())
+            .expect("No score held for wallet");
+        let config = Self::get_jump_guard(env.clone()).expect("Jump guard not configured");
+        require_signature(&env, &payload, &signature, &config.confirmer);
+        
+        Self::release_domain_score_jump(&env, &domain, &payload);
+        env.events().publish((topic::JUMP_OK, domain), (wallet, config.confirmer));
+    }
+    
+    /// Apply a held domain payload on the admin's authority (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If no payload is held for the wallet in the domain
+    /// * If a newer domain score was stored since the payload was held
+    pub fn cosign_domain_score_jump(env: Env, domain: Symbol, wallet: Address) {
+        let admin = Self::require_admin(&env);
+        let payload = Self::get_held_domain_score(env.clone(), domain.clone(), wallet.clone())
+            .expect("No score held for wallet");
+        
+        Self::release_domain_score_jump(&env, &domain, &payload);
+        audit::record(&env, &admin, "cosign_domain_score_jump", (), (domain.clone(), payload));
+        env.events().publish((topic::JUMP_OK, domain), (wallet, admin));
+    }
+    
+    /// Discard the domain payload held for a wallet (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    pub fn discard_domain_score_jump(env: Env, domain: Symbol, wallet: Address) {
+        let admin = Self::require_admin(&env);
+        
+        let old = Self::get_held_domain_score(env.clone(), domain.clone(), wallet.clone());
+        env.storage()
+            .persistent()
+            .remove(&WalletKey::HeldDomainScore(domain.clone(), wallet.clone()));
+        audit::record(&env, &admin, "discard_domain_score_jump", old, domain.clone());
+        env.events().publish((topic::JUMP_DROP, domain), (wallet, admin));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::sign_for;
+    use crate::types::PAYLOAD_VERSION_V2;
+    use ed25519_dalek::SigningKey;
+    use soroban_sdk::{testutils::Address as _, BytesN, Map, Vec};
+    
+    #[test]
+    fn test_jump_to_freeze_needs_second_oracle() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let (live, second) = (SigningKey::from_bytes(&[7u8; 32]), SigningKey::from_bytes(&[8u8; 32]));
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &live.verifying_key().to_bytes()),
+        );
+        client.set_jump_guard(&Some(JumpGuardConfig {
+            max_delta: 50,
+            confirmer: BytesN::from_array(&env, &second.verifying_key().to_bytes()),
+        }));
+        
+        let wallet = Address::generate(&env);
+        let mut payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 0,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &live, &payload));
+        
+        payload.risk_score = 95;
+        payload.timestamp = 1;
+        client.submit_risk(&payload, &sign_for(&client, &live, &payload));
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(client.get_held_score(&wallet), Some(payload.clone()));
+        
+        // The live key cannot confirm its own jump
+        assert!(client.try_confirm_score_jump(&wallet, &sign_for(&client, &live, &payload)).is_err());
+        
+        client.confirm_score_jump(&wallet, &sign_for(&client, &second, &payload));
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.get_held_score(&wallet), None);
+    }
+    
+    #[test]
+    fn test_domain_jump_to_freeze_is_held() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let bots_key = SigningKey::from_bytes(&[9u8; 32]);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_jump_guard(&Some(JumpGuardConfig {
+            max_delta: 50,
+            confirmer: BytesN::from_array(&env, &[1u8; 32]),
+        }));
+        let bots = Symbol::new(&env, "bots");
+        client.register_domain(&bots, &BytesN::from_array(&env, &bots_key.verifying_key().to_bytes()));
+        
+        let wallet = Address::generate(&env);
+        let mut payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 0,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk_domain(&bots, &payload, &sign_for(&client, &bots_key, &payload));
+        
+        payload.risk_score = 95;
+        payload.timestamp = 1;
+        client.submit_risk_domain(&bots, &payload, &sign_for(&client, &bots_key, &payload));
+        assert_eq!(client.check_permission_domain(&wallet, &bots), RiskDecision::Allow);
+        assert_eq!(client.get_held_domain_score(&bots, &wallet), Some(payload.clone()));
+        assert_eq!(client.get_held_score(&wallet), None);
+        
+        client.cosign_domain_score_jump(&bots, &wallet);
+        assert_eq!(client.check_permission_domain(&wallet, &bots), RiskDecision::Freeze);
+        assert_eq!(client.get_held_domain_score(&bots, &wallet), None);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+    }
+}
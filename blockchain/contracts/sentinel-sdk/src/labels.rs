@@ -0,0 +1,142 @@
+//! Wallet labels registry.
+//!
+//! The Oracle tags wallets with labels such as `mixer`, `exchange` or
+//! `sanctioned` so integrators can build rules beyond the three-way
+//! decision. Assignments are Oracle-signed canonical JSON and replace the
+//! wallet's previous labels.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, panic_with_error, Address, Env, Symbol, Vec};
+
+use crate::crypto::labels_message;
+use crate::errors::SentinelError;
+use crate::types::{DataKey, LabelSet, Signature};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Maximum labels per wallet
+pub const MAX_LABELS: u32 = 16;
+
+/// Oldest accepted label assignment, in seconds
+const MAX_LABEL_AGE: u64 = 300;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Replace a wallet's labels with an Oracle-signed assignment
+    /// 
+    /// The Oracle signs `{"labels":[...],"timestamp":T,"wallet":"G..."}`.
+    /// An empty list clears the wallet's labels.
+    /// 
+    /// # Panics
+    /// * If more than `MAX_LABELS` labels are given
+    /// * If the assignment is too old or not newer than the stored one
+    /// * If the signature is invalid
+    pub fn set_labels(
+        env: Env,
+        wallet: Address,
+        labels: Vec<Symbol>,
+        timestamp: u64,
+        signature: Signature,
+    ) {
+        if labels.len() > MAX_LABELS {
+            panic!("Too many labels: max 16");
+        }
+        
+        let now = env.ledger().timestamp();
+        if now > timestamp && now - timestamp > MAX_LABEL_AGE {
+            panic_with_error!(&env, SentinelError::PayloadTooOld);
+        }
+        if let Some(current) = Self::get_label_set(&env, &wallet) {
+            if timestamp <= current.timestamp {
+                panic!("Label assignment is not newer than stored labels");
+            }
+        }
+        
+        let message = labels_message(&env, &wallet, &labels, timestamp);
+        Self::authenticate_message(&env, &message, &signature);
+        
+        let set = LabelSet { labels: labels.clone(), timestamp };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Labels(wallet.clone()), &set);
+        
+        env.events().publish((topic::LABELS,), (wallet, labels));
+    }
+    
+    /// Get a wallet's labels (read-only; empty if none)
+    pub fn get_labels(env: Env, wallet: Address) -> Vec<Symbol> {
+        Self::get_label_set(&env, &wallet)
+            .map(|set| set.labels)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+    
+    /// Check whether a wallet carries a label (read-only)
+    pub fn has_label(env: Env, wallet: Address, label: Symbol) -> bool {
+        Self::get_labels(env, wallet).contains(label)
+    }
+}
+
+impl SentinelSDK {
+    fn get_label_set(env: &Env, wallet: &Address) -> Option<LabelSet> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Labels(wallet.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{symbol_short, testutils::Address as _, vec, BytesN};
+    
+    fn sign(env: &Env, key: &SigningKey, wallet: &Address, labels: &Vec<Symbol>, ts: u64) -> Signature {
+        let message = labels_message(env, wallet, labels, ts);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        BytesN::from_array(env, &key.sign(&message).to_bytes())
+    }
+    
+    #[test]
+    fn test_signed_labels_round_trip() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        
+        let wallet = Address::generate(&env);
+        let labels = vec![&env, symbol_short!("exchange"), symbol_short!("mixer")];
+        client.set_labels(&wallet, &labels, &1, &sign(&env, &key, &wallet, &labels, 1));
+        
+        assert_eq!(client.get_labels(&wallet), labels);
+        assert!(client.has_label(&wallet, &symbol_short!("mixer")));
+        assert!(!client.has_label(&wallet, &symbol_short!("sanction")));
+        
+        // Empty list clears
+        let none = Vec::new(&env);
+        client.set_labels(&wallet, &none, &2, &sign(&env, &key, &wallet, &none, 2));
+        assert_eq!(client.get_labels(&wallet).len(), 0);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn test_labels_signed_by_other_key_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        
+        let wallet = Address::generate(&env);
+        let labels = vec![&env, symbol_short!("mixer")];
+        let forged = SigningKey::from_bytes(&[8u8; 32]);
+        client.set_labels(&wallet, &labels, &1, &sign(&env, &forged, &wallet, &labels, 1));
+    }
+}
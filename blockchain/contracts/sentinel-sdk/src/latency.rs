@@ -0,0 +1,113 @@
+//! On-chain decision latency metrics.
+//!
+//! Every stored payload contributes the delay between the Oracle signing
+//! it and the ledger accepting it, so operators can watch end-to-end
+//! pipeline latency from chain state alone. The aggregates are O(1) in
+//! storage: lifetime count/total/min/max plus an exponential moving
+//! average for recent behaviour.
+
+use soroban_sdk::{contractimpl, Env};
+
+use crate::audit;
+use crate::types::{DataKey, LatencyStats};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Shift applied to the moving average (weight 1/8 per sample)
+const EMA_SHIFT: u32 = 3;
+
+impl LatencyStats {
+    /// Fold one latency sample into the aggregates
+    pub fn record(&mut self, latency: u64) {
+        if self.count == 0 {
+            self.min = latency;
+            self.max = latency;
+            self.recent_avg = latency;
+        } else {
+            self.min = self.min.min(latency);
+            self.max = self.max.max(latency);
+            // avg += (sample - avg) / 8, without going negative
+            self.recent_avg = self.recent_avg - (self.recent_avg >> EMA_SHIFT)
+                + (latency >> EMA_SHIFT);
+        }
+        self.count += 1;
+        self.total = self.total.saturating_add(latency);
+        self.last = latency;
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Oracle-to-ledger latency aggregates (read-only)
+    pub fn latency_stats(env: Env) -> LatencyStats {
+        env.storage()
+            .instance()
+            .get(&DataKey::Latency)
+            .unwrap_or_default()
+    }
+    
+    /// Clear the latency aggregates (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    pub fn reset_latency_stats(env: Env) {
+        let admin = Self::require_admin(&env);
+        let old = Self::latency_stats(env.clone());
+        env.storage().instance().remove(&DataKey::Latency);
+        audit::record(&env, &admin, "reset_latency_stats", old, ());
+    }
+}
+
+impl SentinelSDK {
+    /// Record the latency of a payload stored now
+    /// 
+    /// Payloads timestamped ahead of the ledger count as 0.
+    pub(crate) fn record_latency(env: &Env, payload_timestamp: u64) {
+        let mut stats = Self::latency_stats(env.clone());
+        stats.record(env.ledger().timestamp().saturating_sub(payload_timestamp));
+        env.storage().instance().set(&DataKey::Latency, &stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN};
+    
+    #[test]
+    fn test_stats_aggregate_samples() {
+        let mut stats = LatencyStats::default();
+        for latency in [8, 16, 4] {
+            stats.record(latency);
+        }
+        
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.total, 28);
+        assert_eq!(stats.min, 4);
+        assert_eq!(stats.max, 16);
+        assert_eq!(stats.last, 4);
+        // 8 -> 8 - 1 + 2 = 9 -> 9 - 1 + 0 = 8
+        assert_eq!(stats.recent_avg, 8);
+    }
+    
+    #[test]
+    fn test_latency_recorded_on_store() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        env.as_contract(&contract_id, || {
+            SentinelSDK::record_latency(&env, 988);
+            SentinelSDK::record_latency(&env, 1_005);
+        });
+        
+        let stats = client.latency_stats();
+        assert_eq!((stats.count, stats.min, stats.max, stats.last), (2, 0, 12, 0));
+        
+        client.reset_latency_stats();
+        assert_eq!(client.latency_stats(), LatencyStats::default());
+    }
+}
@@ -30,6 +30,7 @@ let decision = sentinel.check_permission(user_wallet);
 match decision {
     RiskDecision::Allow => proceed_with_transaction(),
     RiskDecision::Limit(max) => enforce_limit(max),
+    RiskDecision::Review => panic!("Held for review by Sentinel"),
     RiskDecision::Freeze => panic!("Blocked by Sentinel"),
 }
 ```
@@ -45,15 +46,93 @@ ML Engine → Oracle (Ed25519 Sign) → Sentinel SDK → Protocols
 ```
 */
 
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+use sentinel_events::topic;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
 
 mod types;
+mod schema;
+mod packing;
+mod bands;
+use sentinel_canonical as canonical;
 mod crypto;
+mod oracle_auth;
+mod schemes;
+mod domains;
+mod aggregation;
+mod errors;
+mod events;
+mod invariants;
+mod challenges;
+mod slashing;
+mod admin;
+mod audit;
+mod roles;
+mod timelock;
+mod overrides;
+mod self_lock;
+mod guardians;
+mod emergency;
+mod freshness;
+mod jump_guard;
+mod ratelimit;
+mod ttl;
+mod index;
+mod stats;
+mod heartbeat;
+mod breaker;
+mod pipeline;
+mod transitions;
+mod rotation;
+mod council;
+mod decay;
+mod grace;
+mod history;
+mod compaction;
+mod erasure;
+mod features;
+mod policies;
+mod integrators;
+mod liquidation;
+mod appeals;
+mod remediation;
+mod operations;
+mod metering;
+mod assets;
+mod percentage;
+mod velocity;
+mod subscriptions;
+mod requests;
+mod health;
+mod latency;
+mod categories;
+mod labels;
+mod batch;
+mod merkle;
+mod conformance;
 
-use types::{RiskState, RiskDecision, RiskPayload, Signature, PublicKey};
-use crypto::verify_signature;
+#[cfg(test)]
+mod testutils;
+
+use types::{
+    feature, worst_score, Assessment, ConfigKey, DataKey, FillResult, PairDecision, RiskDetails, RiskState, RiskDecision,
+    RiskPayload, Signature, PublicKey, TransitionCause, WalletKey,
+};
+use errors::SentinelError;
+
+/// Maximum number of wallets accepted by batch queries
+///
+/// Bounds the storage reads per invocation so batch calls stay well
+/// within Soroban's per-transaction resource limits.
+pub const MAX_BATCH_SIZE: u32 = 50;
 
+/// Age after which a stored score is reported as stale (24 hours)
+pub const STALE_AFTER_SECONDS: u64 = 86_400;
 
+/// Highest model confidence a payload may carry (100% in basis points)
+pub const MAX_CONFIDENCE_BPS: u32 = 10_000;
+
+/// Ledgers a stored payload's digest is kept for duplicate detection (~1 day)
+pub const DUPLICATE_WINDOW: u32 = ttl::LEDGERS_PER_DAY;
 
 /// Sentinel SDK Contract
 #[contract]
@@ -62,102 +141,120 @@ pub struct SentinelSDK;
 #[contractimpl]
 impl SentinelSDK {
     
-    /// Initialize the SDK with Oracle's public key
+    /// Initialize the SDK with an admin and the Oracle's public key
     /// 
     /// This must be called once after deployment.
-    /// The Oracle public key is immutable after initialization.
+    /// The Oracle public key only changes through a key trial (`start_key_trial`).
     /// 
     /// # Arguments
+    /// * `admin` - Address allowed to perform compliance actions (overrides)
     /// * `oracle_pubkey` - Ed25519 public key from Oracle service
     /// 
     /// # Panics
     /// * If already initialized
-    pub fn initialize(env: Env, oracle_pubkey: PublicKey) {
+    pub fn initialize(env: Env, admin: Address, oracle_pubkey: PublicKey) {
         let storage = env.storage().instance();
         
-        // Check if already initialized
-        if storage.has(&symbol_short!("oracle")) {
+        // Check if already initialized, under either trust model
+        if Self::is_initialized(env.clone()) {
             panic!("SDK already initialized");
         }
         
-        // Store Oracle public key
-        storage.set(&symbol_short!("oracle"), &oracle_pubkey);
+        // Store admin and Oracle public key
+        storage.set(&DataKey::Admin, &admin);
+        storage.set(&DataKey::Oracle, &oracle_pubkey);
+        storage.set(&DataKey::Config(ConfigKey::Features), &feature::DEFAULT);
         
         // Emit initialization event
         env.events().publish(
-            (symbol_short!("SDK_INIT"),),
-            oracle_pubkey
+            (topic::SDK_INIT,),
+            (admin, oracle_pubkey)
         );
+        
+        invariants::check_contract(&env);
     }
     
     /// Submit signed risk score from Oracle
     /// 
-    /// Only the Oracle can call this (verified by signature).
+    /// Only the Oracle can call this (verified by signature, or by the
+    /// Oracle address's authorization in Soroban-auth mode, where
+    /// `signature` is ignored).
     /// Updates the on-chain risk state for a wallet.
     /// 
+    /// Payloads that carry a valid signature but fail validation do not
+    /// trap: a `REJECTED` event with the error code and offending values is
+    /// emitted and the stored state is left unchanged.
+    /// 
+    /// Resubmitting the payload that is already stored (same wallet,
+    /// timestamp and score, e.g. an Oracle retry) is still authenticated,
+    /// then returns early without storing or emitting anything.
+    /// 
+    /// With the jump guard configured, a payload that would freeze a wallet
+    /// after a score jump above the allowed delta is held until a second
+    /// Oracle or the admin confirms it (see `set_jump_guard`).
+    /// 
     /// # Arguments
     /// * `payload` - Risk data (wallet, score, timestamp)
     /// * `signature` - Ed25519 signature from Oracle
     /// 
+    /// # Errors
+    /// * `InvalidSignature` if the signature does not verify (simulate
+    ///   `verify_payload` to pre-check)
+    /// * `UnsupportedPayloadVersion` for unknown payload versions
+    /// 
     /// # Panics
-    /// * If signature is invalid
-    /// * If timestamp is too old (>5 minutes)
-    /// * If risk score is out of range (0-100)
+    /// * If the Oracle address did not authorize (Soroban-auth mode)
+    /// * If aggregated Oracles own the risk state (see `submit_oracle_score`)
+    /// 
+    /// # Rejects
+    /// * `PayloadTooOld` - timestamp is older than the replay window
+    ///   (5 minutes unless changed with `set_replay_window`)
+    /// * `PayloadFromFuture` - timestamp is ahead of the ledger by more than
+    ///   the clock-skew tolerance (see `set_max_clock_skew`)
+    /// * `OutOfOrderUpdate` - timestamp is older than the stored score (also
+    ///   emits `OUT_ORDER`)
+    /// * `RateLimited` - wallet exceeded the submission rate limit (see
+    ///   `set_rate_limit`)
+    /// * `RiskScoreOutOfRange` - risk or category score is out of range (0-100)
+    /// * `ConfidenceOutOfRange` - confidence is above 10000 bps
     pub fn submit_risk(
         env: Env,
         payload: RiskPayload,
         signature: Signature,
-    ) {
-        // 1. Get Oracle public key
-        let oracle_pubkey = Self::get_oracle_pubkey(&env);
+    ) -> Result<(), SentinelError> {
+        // 1-2. Verify the Oracle signature (or authorization)
+        Self::authenticate_payload(&env, &payload, &signature)?;
         
-        // 2. Verify signature
-        if !verify_signature(&env, &payload, &signature, &oracle_pubkey) {
-            panic!("Invalid Oracle signature");
+        // Oracle retries of an already stored payload are a no-op
+        if Self::is_duplicate(&env, &payload) {
+            return Ok(());
         }
         
-        // 3. Check timestamp freshness (prevent replay attacks)
-        let current_time = env.ledger().timestamp();
-        let max_age: u64 = 300; // 5 minutes
-        
-        if current_time > payload.timestamp && (current_time - payload.timestamp) > max_age {
-            panic!("Payload too old - potential replay attack");
-        }
-        
-        // 4. Validate risk score
-        if payload.risk_score > 100 {
-            panic!("Invalid risk score: must be 0-100");
+        // 3. Validate payload contents (freshness, score range)
+        if let Err(error) = Self::validate_payload(&env, &payload) {
+            events::publish_rejected(&env, &payload, error);
+            return Ok(());
         }
         
-        // 5. Create and store risk state
-        let risk_state = RiskState::from_payload(&payload);
-        env.storage().persistent().set(&payload.wallet, &risk_state);
-        
-        // 6. Emit events based on decision
-        env.events().publish(
-            (symbol_short!("RISK_UPD"),),
-            (payload.wallet.clone(), payload.risk_score, payload.timestamp)
-        );
-        
-        match risk_state.decision {
-            RiskDecision::Freeze => {
-                env.events().publish(
-                    (symbol_short!("FROZEN"),),
-                    (payload.wallet.clone(), payload.risk_score)
-                );
-            },
-            RiskDecision::Limit(limit) => {
-                env.events().publish(
-                    (symbol_short!("LIMITED"),),
-                    (payload.wallet.clone(), payload.risk_score, limit)
-                );
-            },
-            RiskDecision::Allow => {
-                env.events().publish(
-                    (symbol_short!("ALLOWED"),),
-                    (payload.wallet.clone(), payload.risk_score)
-                );
-            }
+        // 4. Hold suspicious jumps to Freeze, or store and notify
+        Self::apply_risk(&env, &payload);
+        Ok(())
+    }
+    
+    /// Check an Oracle signature without submitting (read-only)
+    /// 
+    /// Lets the Oracle submitter rule out a bad signature before paying
+    /// for `submit_risk`. Never traps on a bad signature.
+    /// 
+    /// # Returns
+    /// * `true` if `signature` is the live Oracle key's signature over the
+    ///   payload
+    /// * `false` otherwise, including for unsupported payload versions and
+    ///   in Soroban-auth mode (no key)
+    pub fn verify_payload(env: Env, payload: RiskPayload, signature: Signature) -> bool {
+        match Self::get_oracle_pubkey(env.clone()) {
+            Some(key) => crypto::verify_signature(&env, &payload, &signature, &key).is_ok(),
+            None => false,
         }
     }
     
@@ -173,7 +270,32 @@ impl SentinelSDK {
     /// * `Some(RiskState)` if wallet has been scored
     /// * `None` if wallet is unknown (treat as Allow)
     pub fn get_risk(env: Env, wallet: Address) -> Option<RiskState> {
-        env.storage().persistent().get(&wallet)
+        schema::load_risk_state(&env, &wallet)
+    }
+    
+    /// Explain a wallet's current treatment (read-only)
+    /// 
+    /// Returns the decision together with the Oracle reason codes
+    /// (mixer exposure, sanctions match, ...) so compliance teams can show
+    /// why a wallet was limited or frozen. The evidence hash lets disputes
+    /// and regulators check the off-chain report was not altered since.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Address to query
+    /// 
+    /// # Returns
+    /// * `Some(RiskDetails)` if wallet has been scored
+    /// * `None` if wallet is unknown
+    pub fn get_risk_details(env: Env, wallet: Address) -> Option<RiskDetails> {
+        let state = Self::get_risk(env.clone(), wallet.clone())?;
+        
+        Some(RiskDetails {
+            decision: Self::check_permission(env, wallet),
+            risk_score: state.risk_score,
+            last_updated: state.last_updated,
+            reason_codes: state.reason_codes,
+            evidence_hash: state.evidence_hash,
+        })
     }
     
     /// Check permission decision for a wallet (SDK core function)
@@ -185,17 +307,138 @@ impl SentinelSDK {
     /// * `wallet` - Address to check
     /// 
     /// # Returns
-    /// * `RiskDecision` - Allow, Limit(amount), or Freeze
+    /// * `RiskDecision` - Allow, Limit(amount), Review, or Freeze
     /// 
     /// # Default Behavior
-    /// * If wallet is unknown, returns `Allow` (innocent until proven risky)
+    /// * Runs the configured decision pipeline (see `get_pipeline`);
+    ///   by default an active admin override wins, then the Oracle score
+    /// * If wallet is unknown, returns the admin-configured unknown default
+    ///   (`Allow` unless changed; see `set_unknown_default`)
+    /// * With per-asset limits configured, `Limit` carries the strictest
+    ///   asset limit (see `check_permission_for_asset`)
     pub fn check_permission(env: Env, wallet: Address) -> RiskDecision {
-        match Self::get_risk(env, wallet.clone()) {
-            Some(risk_state) => risk_state.decision,
-            None => RiskDecision::Allow, // Unknown wallets are allowed
+        assets::worst_case_limit(&env, pipeline::evaluate(&env, &wallet))
+    }
+    
+    /// Query risk states for many wallets in one call
+    /// 
+    /// Intended for protocols that settle many users per invocation
+    /// (batch auctions, payroll) to avoid N cross-contract calls.
+    /// 
+    /// # Arguments
+    /// * `wallets` - Addresses to query (at most `MAX_BATCH_SIZE`)
+    /// 
+    /// # Returns
+    /// * One entry per wallet, in input order (`None` if never scored)
+    /// 
+    /// # Panics
+    /// * If batching is not enabled (see `enabled_features`)
+    /// * If more than `MAX_BATCH_SIZE` wallets are requested
+    pub fn get_risk_batch(env: Env, wallets: Vec<Address>) -> Vec<Option<RiskState>> {
+        Self::require_batch_size(&env, wallets.len());
+        
+        let mut states = Vec::new(&env);
+        for wallet in wallets.iter() {
+            states.push_back(Self::get_risk(env.clone(), wallet));
+        }
+        states
+    }
+    
+    /// Check permission decisions for many wallets in one call
+    /// 
+    /// # Arguments
+    /// * `wallets` - Addresses to check (at most `MAX_BATCH_SIZE`)
+    /// 
+    /// # Returns
+    /// * One decision per wallet, in input order
+    /// 
+    /// # Panics
+    /// * If batching is not enabled (see `enabled_features`)
+    /// * If more than `MAX_BATCH_SIZE` wallets are requested
+    pub fn check_permission_batch(env: Env, wallets: Vec<Address>) -> Vec<RiskDecision> {
+        Self::require_batch_size(&env, wallets.len());
+        
+        let mut decisions = Vec::new(&env);
+        for wallet in wallets.iter() {
+            decisions.push_back(Self::check_permission(env.clone(), wallet));
+        }
+        decisions
+    }
+    
+    /// Check both sides of a trade in one call
+    /// 
+    /// DEXs and escrows care about both counterparties; this saves a
+    /// second cross-contract call.
+    /// 
+    /// # Arguments
+    /// * `a` - First wallet
+    /// * `b` - Second wallet
+    /// 
+    /// # Returns
+    /// * `PairDecision` - each side's decision and the strictest of the two
+    pub fn check_pair(env: Env, a: Address, b: Address) -> PairDecision {
+        let decision_a = Self::check_permission(env.clone(), a);
+        let decision_b = Self::check_permission(env, b);
+        
+        PairDecision {
+            combined: decision_a.clone().strictest(decision_b.clone()),
+            a: decision_a,
+            b: decision_b,
+        }
+    }
+    
+    /// Get score, decision and freshness for a wallet in one call
+    /// 
+    /// Saves integrators a `get_risk` + `check_permission` round-trip and
+    /// the ledger-time math needed to judge freshness.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Address to assess
+    /// 
+    /// # Returns
+    /// * `Assessment` - unknown wallets report `is_known: false`, the
+    ///   unknown-wallet default decision and `is_stale: false`
+    pub fn get_assessment(env: Env, wallet: Address) -> Assessment {
+        let now = env.ledger().timestamp();
+        
+        match Self::get_risk(env.clone(), wallet.clone()) {
+            Some(state) => {
+                let age_seconds = now.saturating_sub(state.last_updated);
+                Assessment {
+                    is_known: true,
+                    decision: Self::check_permission(env, wallet),
+                    risk_score: state.risk_score,
+                    last_updated: state.last_updated,
+                    age_seconds,
+                    is_stale: age_seconds > STALE_AFTER_SECONDS,
+                }
+            }
+            None => Assessment {
+                is_known: false,
+                decision: Self::check_permission(env, wallet),
+                risk_score: 0,
+                last_updated: 0,
+                age_seconds: 0,
+                is_stale: false,
+            },
         }
     }
     
+    /// Enforce a decision against a requested amount (pure helper)
+    /// 
+    /// Standard partial-fill semantics for integrators: `Allow` fills in
+    /// full, `Limit(cap)` fills up to `cap`, `Review` and `Freeze` reject.
+    /// 
+    /// # Arguments
+    /// * `amount` - Amount the wallet wants to move
+    /// * `decision` - Decision from `check_permission`
+    /// 
+    /// # Returns
+    /// * `Full`, `Partial(cap)` or `Rejected`
+    pub fn apply_limit(_env: Env, amount: i128, decision: RiskDecision) -> FillResult {
+        decision.apply_limit(amount)
+    }
+    
     /// Check if wallet is frozen (convenience function)
     /// 
     /// # Arguments
@@ -211,27 +454,241 @@ impl SentinelSDK {
         )
     }
     
+    /// Check whether the SDK has been initialized (read-only)
+    /// 
+    /// # Returns
+    /// * `true` once either initializer has run (including deployments whose
+    ///   keys predate `DataKey` and have not been migrated yet)
+    /// * `false` otherwise
+    pub fn is_initialized(env: Env) -> bool {
+        let storage = env.storage().instance();
+        storage.has(&DataKey::Oracle)
+            || storage.has(&DataKey::OracleAddress)
+            || schema::has_legacy_keys(&env)
+    }
+    
     /// Get Oracle's public key (read-only)
     /// 
-    /// Returns the Ed25519 public key used to verify Oracle signatures.
+    /// Returns the key used to verify Oracle signatures (an Ed25519 key, or
+    /// the id of a registered P-256 key).
+    /// 
+    /// # Returns
+    /// * `Some(key)` - Oracle's public key
+    /// * `None` if the SDK is not initialized or runs in Soroban-auth mode
+    pub fn get_oracle_pubkey(env: Env) -> Option<PublicKey> {
+        env.storage().instance().get(&DataKey::Oracle)
+    }
+}
+
+impl SentinelSDK {
+    /// Apply a verified, validated live Oracle payload to the wallet's
+    /// risk state (see `apply_guarded`)
     /// 
     /// # Returns
-    /// * Oracle's public key
+    /// * `true` if the payload was stored, `false` if it was held
     /// 
     /// # Panics
-    /// * If SDK not initialized
-    pub fn get_oracle_pubkey(env: &Env) -> PublicKey {
+    /// * If aggregated Oracles own the risk state
+    pub(crate) fn apply_risk(env: &Env, payload: &RiskPayload) -> bool {
+        if Self::scores_aggregated(env) {
+            panic!("Scores are aggregated: submit with submit_oracle_score");
+        }
+        Self::apply_guarded(env, payload)
+    }
+    
+    /// Apply a verified, validated payload to the wallet's risk state
+    /// 
+    /// Shared by every path that applies a fresh Oracle score: a guarded
+    /// jump to Freeze is held (see `hold_score_jump`), anything else is
+    /// stored and subscribers are told if the decision flipped.
+    /// 
+    /// # Returns
+    /// * `true` if the payload was stored, `false` if it was held
+    pub(crate) fn apply_guarded(env: &Env, payload: &RiskPayload) -> bool {
+        if Self::hold_score_jump(env, payload) {
+            return false;
+        }
+        
+        Self::store_and_notify(env, payload);
+        true
+    }
+    
+    /// Store a payload and notify subscribers, bypassing the jump guard
+    pub(crate) fn store_and_notify(env: &Env, payload: &RiskPayload) {
+        let old = pipeline::evaluate(env, &payload.wallet);
+        Self::store_risk(env, payload);
+        
+        let new = pipeline::evaluate(env, &payload.wallet);
+        Self::notify_subscribers(env, &payload.wallet, old, new);
+    }
+    
+    /// Store a validated payload as the wallet's risk state
+    /// 
+    /// Emits `RISK_UPD` plus the decision-specific event (and `DEC_CHG` if
+    /// the decision flipped), appends to the wallet's history and
+    /// re-checks invariants. Shared by every path that writes Oracle data.
+    pub(crate) fn store_risk(env: &Env, payload: &RiskPayload) -> RiskState {
+        let previous = schema::load_risk_state(env, &payload.wallet);
+        
+        // Create and store risk state
+        let bands = pipeline::score_bands(env);
+        let risk_state = RiskState::from_payload(payload, &bands);
+        schema::store_risk_state(env, &payload.wallet, &risk_state);
+        Self::record_payload_digest(env, payload);
+        Self::record_history(env, &payload.wallet, &risk_state);
+        Self::index_wallet(env, &payload.wallet);
+        Self::record_stats(env, previous.as_ref().map(|state| &state.decision), &risk_state.decision);
+        Self::count_freeze(env, previous.as_ref().map(|state| &state.decision), &risk_state.decision);
+        Self::clear_score_request(env, &payload.wallet);
+        Self::touch_oracle_update(env);
+        Self::record_submission(env, &payload.wallet);
+        Self::record_latency(env, payload.timestamp);
+        
+        events::publish_risk_update(
+            env,
+            &payload.wallet,
+            None,
+            previous.as_ref().map_or(RiskDecision::Allow, |state| state.decision.clone()),
+            &risk_state,
+        );
+        
+        Self::record_transition(
+            env,
+            &payload.wallet,
+            risk_state.decision.clone(),
+            TransitionCause::Oracle,
+            None,
+        );
+        
+        if Self::has_feature(env, feature::CATEGORIES) {
+            events::publish_category_transitions(
+                env,
+                &payload.wallet,
+                previous.as_ref(),
+                &risk_state,
+                &bands,
+            );
+        }
+        
+        invariants::check_contract(env);
+        invariants::check_wallet(env, &payload.wallet);
+        
+        risk_state
+    }
+    
+    /// Validate a verified payload before it is stored
+    pub(crate) fn validate_payload(env: &Env, payload: &RiskPayload) -> Result<(), SentinelError> {
+        let stored = schema::load_risk_state(env, &payload.wallet);
+        Self::validate_timestamp(env, payload, stored.as_ref())?;
+        
+        if Self::rate_limited(env, &payload.wallet) {
+            return Err(SentinelError::RateLimited);
+        }
+        
+        Self::validate_scores(payload)
+    }
+    
+    /// Check a payload's timestamp against the ledger and the stored state
+    pub(crate) fn validate_timestamp(
+        env: &Env,
+        payload: &RiskPayload,
+        stored: Option<&RiskState>,
+    ) -> Result<(), SentinelError> {
+        // Check timestamp freshness (prevent replay attacks)
+        let current_time = env.ledger().timestamp();
+        let max_age = Self::get_replay_window(env.clone());
+        
+        if current_time > payload.timestamp && (current_time - payload.timestamp) > max_age {
+            return Err(SentinelError::PayloadTooOld);
+        }
+        
+        // Reject payloads dated beyond the forward clock-skew tolerance
+        let max_skew = Self::get_max_clock_skew(env.clone());
+        if payload.timestamp > current_time.saturating_add(max_skew) {
+            return Err(SentinelError::PayloadFromFuture);
+        }
+        
+        // Never let a delayed older score overwrite a fresher one
+        if let Some(stored) = stored {
+            if payload.timestamp < stored.last_updated {
+                return Err(SentinelError::OutOfOrderUpdate);
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Check a payload's scores and confidence are in range
+    pub(crate) fn validate_scores(payload: &RiskPayload) -> Result<(), SentinelError> {
+        // Validate overall and category scores
+        if worst_score(payload.risk_score, &payload.category_scores) > 100 {
+            return Err(SentinelError::RiskScoreOutOfRange);
+        }
+        
+        if payload.confidence > MAX_CONFIDENCE_BPS {
+            return Err(SentinelError::ConfidenceOutOfRange);
+        }
+        
+        Ok(())
+    }
+    
+    /// Whether the payload is the one last stored for its wallet
+    /// 
+    /// Compares a digest of the whole payload, so a corrected payload with
+    /// the same score and timestamp (say, new reason codes) still lands.
+    /// Call only after the payload has been verified.
+    pub(crate) fn is_duplicate(env: &Env, payload: &RiskPayload) -> bool {
         env.storage()
-            .instance()
-            .get(&symbol_short!("oracle"))
-            .expect("SDK not initialized - call initialize() first")
+            .temporary()
+            .get::<_, BytesN<32>>(&WalletKey::PayloadDigest(payload.wallet.clone()))
+            .is_some_and(|digest| digest == crypto::payload_digest(env, payload))
+    }
+    
+    /// Remember the digest of a stored payload for `is_duplicate`
+    fn record_payload_digest(env: &Env, payload: &RiskPayload) {
+        let key = WalletKey::PayloadDigest(payload.wallet.clone());
+        env.storage().temporary().set(&key, &crypto::payload_digest(env, payload));
+        env.storage().temporary().extend_ttl(&key, DUPLICATE_WINDOW, DUPLICATE_WINDOW);
+    }
+    
+    /// Reject batches that exceed `MAX_BATCH_SIZE`
+    pub(crate) fn require_batch_size(env: &Env, len: u32) {
+        Self::require_feature(env, feature::BATCHING);
+        
+        if len > MAX_BATCH_SIZE {
+            panic!("Batch too large: max 50 wallets");
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+    
     use super::*;
-    use soroban_sdk::{Env, BytesN};
+    use soroban_sdk::Map;
+    use crate::testutils::{assert_fresh, assert_stale, expire_entry, sign_for};
+    use crate::types::PAYLOAD_VERSION_V2;
+    use sentinel_events::{OutOfOrderEvent, RejectionEvent};
+    use ed25519_dalek::SigningKey;
+    use soroban_sdk::{
+        testutils::{Address as _, Events, Ledger},
+        vec, Env, BytesN, IntoVal, TryFromVal, Val,
+        xdr::{Limits, ReadXdr, ScSpecEntry, ScSpecTypeDef},
+    };
+    
+    /// Register the contract, initialize it with a test Oracle key and
+    /// return the client together with the signing key
+    fn setup(env: &Env) -> (SentinelSDKClient<'_>, SigningKey) {
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let oracle_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+        client.initialize(&Address::generate(env), &oracle_key);
+        
+        (client, signing_key)
+    }
     
     #[test]
     fn test_initialize() {
@@ -241,13 +698,16 @@ mod tests {
         
         // Generate test Oracle key
         let oracle_key = BytesN::from_array(&env, &[0u8; 32]);
+        assert!(!client.is_initialized());
+        assert_eq!(client.get_oracle_pubkey(), None);
         
         // Initialize
-        client.initialize(&oracle_key);
+        client.initialize(&Address::generate(&env), &oracle_key);
+        assert!(client.is_initialized());
         
         // Verify Oracle key is stored
         let stored_key = client.get_oracle_pubkey();
-        assert_eq!(stored_key, oracle_key);
+        assert_eq!(stored_key, Some(oracle_key));
     }
     
     #[test]
@@ -260,10 +720,10 @@ mod tests {
         let oracle_key = BytesN::from_array(&env, &[0u8; 32]);
         
         // First initialization
-        client.initialize(&oracle_key);
+        client.initialize(&Address::generate(&env), &oracle_key);
         
         // Second initialization should panic
-        client.initialize(&oracle_key);
+        client.initialize(&Address::generate(&env), &oracle_key);
     }
     
     #[test]
@@ -274,7 +734,7 @@ mod tests {
         
         // Initialize SDK
         let oracle_key = BytesN::from_array(&env, &[0u8; 32]);
-        client.initialize(&oracle_key);
+        client.initialize(&Address::generate(&env), &oracle_key);
         
         // Check unknown wallet
         let unknown_wallet = Address::generate(&env);
@@ -292,10 +752,631 @@ mod tests {
         
         // Initialize SDK
         let oracle_key = BytesN::from_array(&env, &[0u8; 32]);
-        client.initialize(&oracle_key);
+        client.initialize(&Address::generate(&env), &oracle_key);
         
         // Unknown wallet should not be frozen
         let wallet = Address::generate(&env);
-        assert_eq!(client.is_frozen(&wallet), false);
+        assert!(!client.is_frozen(&wallet));
+    }
+    
+    /// The published integration client must decode what the contract returns
+    #[test]
+    fn test_sentinel_client_matches_contract() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        let sentinel = sentinel_client::SentinelClient::new(&env, &client.address);
+        
+        let wallet = Address::generate(&env);
+        assert_eq!(sentinel.get_risk(&wallet), None);
+        
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 60,
+            timestamp: 0,
+            reason_codes: vec![&env, 7],
+            freeze_duration: 0,
+            category_scores: Map::from_array(&env, [(types::RiskCategory::Fraud, 60)]),
+            model_version: 3,
+            confidence: 9_000,
+            evidence_hash: BytesN::from_array(&env, &[1; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        
+        let state = sentinel.get_risk(&wallet).unwrap();
+        assert_eq!(state.risk_score, 60);
+        assert_eq!(state.decision, client.check_permission(&wallet).into());
+        assert_eq!(state.category_scores.get(sentinel_client::RiskCategory::Fraud), Some(60));
+        assert_eq!(sentinel.check_permission_for_asset(&wallet, &Address::generate(&env)), state.decision);
+        assert_eq!(sentinel.enforce_limit(&wallet, 1), state.decision);
+        assert!(!sentinel.is_frozen(&wallet));
+        
+        let fill = sentinel.check_operation(&wallet, &sentinel_client::OperationKind::Withdraw, &1);
+        assert_converts(&env, fill, client.check_operation(&wallet, &types::OperationKind::Withdraw, &1));
+    }
+    
+    /// Assert that a `sentinel-client` value converts to the contract's value
+    fn assert_converts<T, U>(env: &Env, theirs: T, ours: U)
+    where
+        T: IntoVal<Env, Val>,
+        U: TryFromVal<Env, Val> + PartialEq + core::fmt::Debug,
+    {
+        let converted = U::try_from_val(env, &theirs.into_val(env));
+        assert_eq!(converted.ok(), Some(ours));
+    }
+    
+    /// Name, parameters and outputs of a function spec, without its docs
+    fn signature(spec: &[u8]) -> (std::string::String, std::vec::Vec<(std::string::String, ScSpecTypeDef)>, std::vec::Vec<ScSpecTypeDef>) {
+        let ScSpecEntry::FunctionV0(function) = ScSpecEntry::from_xdr(spec, Limits::none()).unwrap() else {
+            panic!("not a function spec");
+        };
+        let inputs = function.inputs.iter().map(|input| (input.name.to_utf8_string_lossy(), input.type_.clone())).collect();
+        (function.name.to_utf8_string_lossy(), inputs, function.outputs.to_vec())
+    }
+    
+    #[test]
+    fn test_sentinel_client_interface_matches_contract() {
+        use sentinel_client::contract::SentinelSpec;
+        
+        let specs = [
+            (SentinelSpec::spec_xdr_check_permission().to_vec(), SentinelSDK::spec_xdr_check_permission().to_vec()),
+            (SentinelSpec::spec_xdr_check_permission_for_asset().to_vec(), SentinelSDK::spec_xdr_check_permission_for_asset().to_vec()),
+            (SentinelSpec::spec_xdr_check_operation().to_vec(), SentinelSDK::spec_xdr_check_operation().to_vec()),
+            (SentinelSpec::spec_xdr_get_risk().to_vec(), SentinelSDK::spec_xdr_get_risk().to_vec()),
+            (SentinelSpec::spec_xdr_is_frozen().to_vec(), SentinelSDK::spec_xdr_is_frozen().to_vec()),
+        ];
+        for (client_spec, contract_spec) in specs {
+            assert_eq!(signature(&client_spec), signature(&contract_spec));
+        }
+        
+        // The client's types have no spec of their own; every variant
+        // must still convert to the contract's value
+        let env = Env::default();
+        let categories = [
+            (sentinel_client::RiskCategory::Aml, types::RiskCategory::Aml),
+            (sentinel_client::RiskCategory::BotActivity, types::RiskCategory::BotActivity),
+            (sentinel_client::RiskCategory::Fraud, types::RiskCategory::Fraud),
+            (sentinel_client::RiskCategory::Sanctions, types::RiskCategory::Sanctions),
+        ];
+        for (theirs, ours) in categories {
+            assert_converts(&env, theirs, ours);
+        }
+        let kinds = [
+            (sentinel_client::OperationKind::Swap, types::OperationKind::Swap),
+            (sentinel_client::OperationKind::Withdraw, types::OperationKind::Withdraw),
+            (sentinel_client::OperationKind::Deposit, types::OperationKind::Deposit),
+            (sentinel_client::OperationKind::Borrow, types::OperationKind::Borrow),
+            (sentinel_client::OperationKind::Transfer, types::OperationKind::Transfer),
+        ];
+        for (theirs, ours) in kinds {
+            assert_converts(&env, theirs, ours);
+        }
+        let fills = [
+            (sentinel_client::FillResult::Full, types::FillResult::Full),
+            (sentinel_client::FillResult::Partial(250), types::FillResult::Partial(250)),
+            (sentinel_client::FillResult::Rejected, types::FillResult::Rejected),
+        ];
+        for (theirs, ours) in fills {
+            assert_converts(&env, theirs, ours);
+        }
+    }
+    
+    #[test]
+    fn test_batch_queries_preserve_order() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        
+        let oracle_key = BytesN::from_array(&env, &[0u8; 32]);
+        client.initialize(&Address::generate(&env), &oracle_key);
+        
+        let wallets = vec![&env, Address::generate(&env), Address::generate(&env)];
+        
+        let states = client.get_risk_batch(&wallets);
+        assert_eq!(states.len(), 2);
+        assert_eq!(states.get(0).unwrap(), None);
+        
+        let decisions = client.check_permission_batch(&wallets);
+        assert_eq!(decisions, vec![&env, RiskDecision::Allow, RiskDecision::Allow]);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Batch too large: max 50 wallets")]
+    fn test_batch_size_is_bounded() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        
+        let mut wallets = Vec::new(&env);
+        for _ in 0..MAX_BATCH_SIZE {
+            wallets.push_back(Address::generate(&env));
+        }
+        assert_eq!(client.check_permission_batch(&wallets).len(), MAX_BATCH_SIZE);
+        
+        wallets.push_back(Address::generate(&env));
+        client.check_permission_batch(&wallets);
+    }
+    
+    #[test]
+    fn test_stale_payload_is_rejected_with_event() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        env.ledger().with_mut(|li| li.timestamp = 10_000);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 90,
+            timestamp: 1_000,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let signature = sign_for(&client, &signing_key, &payload);
+        
+        client.submit_risk(&payload, &signature);
+        
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(topics, (topic::REJECTED, wallet.clone()).into_val(&env));
+        let data: RejectionEvent = data.into_val(&env);
+        assert_eq!(data.error_code, SentinelError::PayloadTooOld as u32);
+        assert_eq!((data.risk_score, data.timestamp, data.ledger_timestamp), (90, 1_000, 10_000));
+        assert_eq!(client.get_risk(&wallet), None);
+    }
+    
+    #[test]
+    fn test_out_of_range_score_is_rejected() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 150,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let signature = sign_for(&client, &signing_key, &payload);
+        
+        client.submit_risk(&payload, &signature);
+        
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (topic::REJECTED, wallet.clone()).into_val(&env));
+        assert_eq!(client.get_risk(&wallet), None);
+    }
+    
+    #[test]
+    #[cfg(not(feature = "events-minimal"))]
+    fn test_risk_events_are_keyed_by_wallet() {
+        use sentinel_events::{DecisionChangeEvent, RiskUpdateEvent};
+        
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        let mut payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 10,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        
+        payload.risk_score = 90;
+        payload.timestamp = 1;
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        
+        let events = env.events().all();
+        let (_, topics, data) = events.get(events.len() - 3).unwrap();
+        assert_eq!(topics, (topic::RISK_UPD, wallet.clone()).into_val(&env));
+        let data: RiskUpdateEvent = data.into_val(&env);
+        assert_eq!(data.old_decision, RiskDecision::Allow.into());
+        assert_eq!(data.new_decision, RiskDecision::Freeze.into());
+        
+        let (_, topics, _) = events.get(events.len() - 2).unwrap();
+        assert_eq!(topics, (topic::FROZEN, wallet.clone()).into_val(&env));
+        
+        let (_, topics, data) = events.last().unwrap();
+        assert_eq!(topics, (topic::DEC_CHG, wallet.clone()).into_val(&env));
+        let data: DecisionChangeEvent = data.into_val(&env);
+        assert_eq!(data, DecisionChangeEvent {
+            old_decision: RiskDecision::Allow.into(),
+            new_decision: RiskDecision::Freeze.into(),
+            risk_score: 90,
+        });
+        
+        // A refresh that keeps the decision does not emit DEC_CHG
+        payload.risk_score = 95;
+        payload.timestamp = 2;
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (topic::FROZEN, wallet).into_val(&env));
+    }
+    
+    #[test]
+    #[cfg(all(feature = "events-minimal", not(feature = "events-full")))]
+    fn test_minimal_events_leave_out_score_updates() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        let mut payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 10,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let events = env.events().all().len();
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        let all = env.events().all();
+        assert_eq!(all.len(), events + 1);
+        assert_eq!(all.last().unwrap().1, (topic::ALLOWED, wallet.clone()).into_val(&env));
+        
+        payload.risk_score = 90;
+        payload.timestamp = 1;
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        let all = env.events().all();
+        assert_eq!(all.len(), events + 3);
+        assert_eq!(all.get(events + 1).unwrap().1, (topic::FROZEN, wallet.clone()).into_val(&env));
+        assert_eq!(all.get(events + 2).unwrap().1, (topic::DEC_CHG, wallet).into_val(&env));
+    }
+    
+    #[test]
+    fn test_older_payload_cannot_overwrite_fresher_score() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        
+        let wallet = Address::generate(&env);
+        let mut payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 90,
+            timestamp: 950,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        
+        // A delayed score signed earlier arrives late
+        payload.risk_score = 10;
+        payload.timestamp = 900;
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        
+        let state = client.get_risk(&wallet).unwrap();
+        assert_eq!((state.risk_score, state.last_updated), (90, 950));
+        
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(topics, (topic::OUT_ORDER, wallet.clone()).into_val(&env));
+        let data: OutOfOrderEvent = data.into_val(&env);
+        assert_eq!((data.timestamp, data.stored_timestamp), (900, 950));
+    }
+    
+    #[test]
+    fn test_bad_signature_is_typed_error() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: Address::generate(&env),
+            risk_score: 60,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let signature = sign_for(&client, &signing_key, &payload);
+        assert!(client.verify_payload(&payload, &signature));
+        
+        let mut tampered = payload.clone();
+        tampered.risk_score = 10;
+        assert!(!client.verify_payload(&tampered, &signature));
+        assert_eq!(
+            client.try_submit_risk(&tampered, &signature),
+            Err(Ok(SentinelError::InvalidSignature))
+        );
+        assert_eq!(client.get_risk(&payload.wallet), None);
+    }
+    
+    #[test]
+    fn test_duplicate_submission_is_silent_noop() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 60,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let signature = sign_for(&client, &signing_key, &payload);
+        client.submit_risk(&payload, &signature);
+        let events = env.events().all().len();
+        
+        // Only the signature check runs again (`DBG_MSG` under `debug-events`)
+        client.submit_risk(&payload, &signature);
+        let debug_events = if cfg!(feature = "debug-events") { 1 } else { 0 };
+        assert_eq!(env.events().all().len(), events + debug_events);
+        assert_eq!(client.get_risk_history(&wallet).len(), 1);
+    }
+    
+    #[test]
+    fn test_corrected_payload_is_not_a_duplicate() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        let mut payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 60,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        
+        payload.reason_codes = vec![&env, 3u32];
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        assert_eq!(client.get_risk_details(&wallet).unwrap().reason_codes, payload.reason_codes);
+        assert_eq!(client.get_risk_history(&wallet).len(), 2);
+    }
+    
+    /// CPU instructions `submit_risk` may spend on a first score
+    /// 
+    /// Measured at ~0.95M without `debug-events`; raise deliberately when a
+    /// change needs more. Contract-side signature checks run natively in
+    /// unit tests and are not metered here.
+    #[cfg(not(feature = "debug-events"))]
+    const SUBMIT_RISK_CPU_CEILING: u64 = 1_050_000;
+    
+    #[test]
+    #[cfg(not(feature = "debug-events"))]
+    fn test_submit_risk_cost_without_debug_events() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: Address::generate(&env),
+            risk_score: 60,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let signature = sign_for(&client, &signing_key, &payload);
+        
+        env.budget().reset_default();
+        client.submit_risk(&payload, &signature);
+        let cpu = env.budget().cpu_instruction_cost();
+        
+        let debug_events = env
+            .events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| *topics == (topic::DBG_MSG,).into_val(&env))
+            .count();
+        assert_eq!(debug_events, 0);
+        assert!(cpu <= SUBMIT_RISK_CPU_CEILING, "submit_risk used {} CPU instructions", cpu);
+    }
+    
+    #[test]
+    fn test_assessment_reports_age_and_staleness() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 60,
+            timestamp: 1_000,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        assert_fresh(&client, &wallet);
+        
+        expire_entry(&env, &client, &wallet);
+        assert_stale(&client, &wallet);
+        
+        let assessment = client.get_assessment(&wallet);
+        assert!(assessment.is_known);
+        assert_eq!(assessment.decision, RiskDecision::Limit(5000));
+        assert_eq!(assessment.risk_score, 60);
+        assert_eq!(assessment.age_seconds, STALE_AFTER_SECONDS + 1);
+        assert!(assessment.is_stale);
+        
+        let unknown = client.get_assessment(&Address::generate(&env));
+        assert!(!unknown.is_known);
+        assert_eq!(unknown.decision, RiskDecision::Allow);
+    }
+    
+    #[test]
+    fn test_reason_codes_surface_in_details() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 85,
+            timestamp: 0,
+            reason_codes: vec![&env, 1u32, 2u32],
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        
+        let details = client.get_risk_details(&wallet).unwrap();
+        assert_eq!(details.decision, RiskDecision::Freeze);
+        assert_eq!(details.reason_codes, payload.reason_codes);
+        assert_eq!(client.get_risk_details(&Address::generate(&env)), None);
+    }
+    
+    #[test]
+    fn test_model_provenance_and_evidence_are_signed_and_stored() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        let mut payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 60,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 7,
+            confidence: 9_500,
+            evidence_hash: BytesN::from_array(&env, &[0xab; 32]),
+        };
+        let signature = sign_for(&client, &signing_key, &payload);
+        
+        let mut tampered = payload.clone();
+        tampered.confidence = 10_000;
+        assert!(!client.verify_payload(&tampered, &signature));
+        
+        client.submit_risk(&payload, &signature);
+        let state = client.get_risk(&wallet).unwrap();
+        assert_eq!((state.model_version, state.confidence), (7, 9_500));
+        assert_eq!(client.get_risk_details(&wallet).unwrap().evidence_hash, payload.evidence_hash);
+        
+        tampered = payload.clone();
+        tampered.evidence_hash = BytesN::from_array(&env, &[0; 32]);
+        assert!(!client.verify_payload(&tampered, &signature));
+        
+        payload.timestamp = 1;
+        payload.confidence = MAX_CONFIDENCE_BPS + 1;
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (topic::REJECTED, wallet.clone()).into_val(&env));
+        assert_eq!(client.get_risk(&wallet).unwrap().confidence, 9_500);
+    }
+    
+    #[test]
+    fn test_quarantine_freeze_lapses_at_boundary() {
+        let env = Env::default();
+        let (client, signing_key) = setup(&env);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 90,
+            timestamp: 1_000,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 3_600,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &sign_for(&client, &signing_key, &payload));
+        assert_eq!(client.get_risk(&wallet).unwrap().freeze_until, 4_600);
+        
+        // One second before expiry: still frozen
+        env.ledger().with_mut(|li| li.timestamp = 4_599);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        
+        // At expiry: released to the default Limit
+        env.ledger().with_mut(|li| li.timestamp = 4_600);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(5000));
+    }
+    
+    #[test]
+    fn test_apply_limit_partial_fill() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        
+        assert_eq!(client.apply_limit(&1_000_000, &RiskDecision::Allow), FillResult::Full);
+        assert_eq!(client.apply_limit(&4_000, &RiskDecision::Limit(5000)), FillResult::Full);
+        assert_eq!(client.apply_limit(&5_000, &RiskDecision::Limit(5000)), FillResult::Full);
+        assert_eq!(
+            client.apply_limit(&8_000, &RiskDecision::Limit(5000)),
+            FillResult::Partial(5000)
+        );
+        assert_eq!(client.apply_limit(&8_000, &RiskDecision::Limit(0)), FillResult::Rejected);
+        assert_eq!(client.apply_limit(&1, &RiskDecision::Freeze), FillResult::Rejected);
+        assert_eq!(client.apply_limit(&0, &RiskDecision::Allow), FillResult::Rejected);
+    }
+    
+    #[test]
+    fn test_check_pair_returns_strictest() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, _) = setup(&env);
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        
+        let pair = client.check_pair(&a, &b);
+        assert_eq!(pair.combined, RiskDecision::Allow);
+        
+        client.set_override(&client.get_admin(), &b, &RiskDecision::Limit(300), &0);
+        let pair = client.check_pair(&a, &b);
+        assert_eq!(pair.a, RiskDecision::Allow);
+        assert_eq!(pair.b, RiskDecision::Limit(300));
+        assert_eq!(pair.combined, RiskDecision::Limit(300));
+        
+        client.set_override(&client.get_admin(), &a, &RiskDecision::Limit(100), &0);
+        assert_eq!(client.check_pair(&a, &b).combined, RiskDecision::Limit(100));
+        
+        client.set_override(&client.get_admin(), &a, &RiskDecision::Freeze, &0);
+        assert_eq!(client.check_pair(&a, &b).combined, RiskDecision::Freeze);
     }
 }
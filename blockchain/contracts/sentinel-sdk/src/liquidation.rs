@@ -0,0 +1,117 @@
+//! Freeze-aware liquidation protection.
+//!
+//! A freeze stops a borrower from topping up collateral, so without a
+//! grace rule it could be weaponized to force unfair liquidations. Lending
+//! consumers query `liquidation_allowed` before liquidating; the admin
+//! chooses whether freezes delay liquidations and for how long.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, LiquidationRule, RiskDecision};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Current liquidation rule (freezes do not delay liquidations by default)
+pub(crate) fn liquidation_rule(env: &Env) -> LiquidationRule {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::LiquidationRule))
+        .unwrap_or(LiquidationRule::Allowed)
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Whether a borrower may be liquidated now (read-only)
+    /// 
+    /// Wallets that are not frozen can always be liquidated. For frozen
+    /// wallets the admin-configured rule applies; a delay counts from the
+    /// ledger time of the most recent transition into Freeze.
+    pub fn liquidation_allowed(env: Env, wallet: Address) -> bool {
+        if Self::check_permission(env.clone(), wallet.clone()) != RiskDecision::Freeze {
+            return true;
+        }
+        
+        match liquidation_rule(&env) {
+            LiquidationRule::Allowed => true,
+            LiquidationRule::Delayed(grace) => {
+                let frozen_since = Self::frozen_since(&env, &wallet);
+                env.ledger().timestamp() >= frozen_since.saturating_add(grace)
+            }
+        }
+    }
+    
+    /// Get the liquidation rule for frozen wallets (read-only)
+    pub fn get_liquidation_rule(env: Env) -> LiquidationRule {
+        liquidation_rule(&env)
+    }
+    
+    /// Set the liquidation rule for frozen wallets (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    pub fn set_liquidation_rule(env: Env, rule: LiquidationRule) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = liquidation_rule(&env);
+        Self::apply_liquidation_rule(&env, rule.clone());
+        audit::record(&env, &admin, "set_liquidation_rule", old, rule);
+    }
+}
+
+impl SentinelSDK {
+    /// Store the liquidation rule (authorization checked by caller)
+    pub(crate) fn apply_liquidation_rule(env: &Env, rule: LiquidationRule) {
+        env.storage().instance().set(&DataKey::Config(ConfigKey::LiquidationRule), &rule);
+        env.events().publish((topic::LIQ_SET,), rule);
+    }
+    
+    /// When the wallet's current freeze began
+    /// 
+    /// Uses the transition log; falls back to the last Oracle update for
+    /// freezes the log does not explain.
+    fn frozen_since(env: &Env, wallet: &Address) -> u64 {
+        if let Some(last) = Self::transition_log(env.clone(), wallet.clone()).last() {
+            if last.decision == RiskDecision::Freeze {
+                return last.timestamp;
+            }
+        }
+        
+        Self::get_risk(env.clone(), wallet.clone()).map_or(0, |state| state.last_updated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        BytesN,
+    };
+    
+    #[test]
+    fn test_frozen_borrower_liquidation_is_delayed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        
+        // Default: freezes do not block liquidations
+        assert!(client.liquidation_allowed(&wallet));
+        
+        client.set_liquidation_rule(&LiquidationRule::Delayed(86_400));
+        assert!(!client.liquidation_allowed(&wallet));
+        
+        env.ledger().with_mut(|li| li.timestamp = 1_000 + 86_400);
+        assert!(client.liquidation_allowed(&wallet));
+        
+        // Non-frozen wallets are unaffected
+        assert!(client.liquidation_allowed(&Address::generate(&env)));
+    }
+}
@@ -0,0 +1,282 @@
+//! Merkle-root batch attestation.
+//!
+//! For very large scoring runs the Oracle signs a single Merkle root over
+//! `(wallet, score, timestamp)` leaves instead of one payload per wallet.
+//! Anyone holding a leaf and its inclusion proof can then materialize the
+//! score with `submit_with_proof`, or have a decision computed from it
+//! without storing anything via `check_with_proof`. Submission cost drops
+//! from O(wallets) to O(1) per epoch; each proof costs O(log wallets).
+//!
+//! Leaves hash as `sha256(0x00 || json)` and inner nodes as
+//! `sha256(0x01 || min(a, b) || max(a, b))` (see `crypto.rs`).
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, panic_with_error, BytesN, Env, Map, Vec};
+
+use crate::crypto::{merkle_leaf_hash, merkle_node_hash, merkle_root_message};
+use crate::errors::SentinelError;
+use crate::events;
+use crate::schema;
+use crate::types::{DataKey, MerkleLeaf, MerkleRoot, RiskDecision, RiskPayload, RiskState, Signature, PAYLOAD_VERSION_V2};
+use crate::{pipeline, SentinelSDK, SentinelSDKClient};
+
+/// Longest accepted inclusion proof (trees of up to 2^32 leaves)
+pub const MAX_PROOF_DEPTH: u32 = 32;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Publish an Oracle-signed Merkle root for a scoring run
+    /// 
+    /// The Oracle signs `{"merkle_root":"<hex>","timestamp":T}`. The new
+    /// root replaces the previous one; proofs are checked against the
+    /// latest root only.
+    /// 
+    /// # Panics
+    /// * `PayloadTooOld` / `PayloadFromFuture` if the timestamp is outside
+    ///   the replay window or clock-skew tolerance
+    /// * If the timestamp is not newer than the stored root
+    /// * If the signature is invalid
+    pub fn submit_merkle_root(env: Env, root: BytesN<32>, timestamp: u64, signature: Signature) {
+        let now = env.ledger().timestamp();
+        if now > timestamp && now - timestamp > Self::get_replay_window(env.clone()) {
+            panic_with_error!(&env, SentinelError::PayloadTooOld);
+        }
+        if timestamp > now.saturating_add(Self::get_max_clock_skew(env.clone())) {
+            panic_with_error!(&env, SentinelError::PayloadFromFuture);
+        }
+        if let Some(current) = Self::get_merkle_root(env.clone()) {
+            if timestamp <= current.timestamp {
+                panic!("Merkle root is not newer than the stored root");
+            }
+        }
+        
+        let message = merkle_root_message(&env, &root, timestamp);
+        Self::authenticate_message(&env, &message, &signature);
+        
+        let attestation = MerkleRoot { root, timestamp };
+        env.storage().instance().set(&DataKey::MerkleRoot, &attestation);
+        
+        env.events().publish((topic::MRK_ROOT,), attestation);
+    }
+    
+    /// Get the latest Oracle-signed Merkle root, if any (read-only)
+    pub fn get_merkle_root(env: Env) -> Option<MerkleRoot> {
+        env.storage().instance().get(&DataKey::MerkleRoot)
+    }
+    
+    /// Store a leaf of the latest Merkle root as the wallet's risk state
+    /// 
+    /// Handled like `submit_risk` once the proof checks out: invalid
+    /// contents emit `REJECTED` and leave the state unchanged, and a leaf
+    /// matching the stored score is a silent no-op, and guarded jumps to
+    /// Freeze are held (see `set_jump_guard`). The replay window is
+    /// enforced on the root, not on the leaf, so proofs may be submitted
+    /// for as long as their root is the latest.
+    /// 
+    /// # Arguments
+    /// * `leaf` - Wallet, score and timestamp committed to by the root
+    /// * `proof` - Sibling hashes from the leaf up to the root
+    /// 
+    /// # Panics
+    /// * If no Merkle root has been submitted
+    /// * If the proof is too long or does not lead to the latest root
+    /// 
+    /// # Rejects
+    /// * `PayloadFromFuture` - leaf is dated after its root
+    /// * `OutOfOrderUpdate` - leaf is older than the stored score
+    /// * `RateLimited` - wallet exceeded the submission rate limit
+    /// * `RiskScoreOutOfRange` - score is out of range (0-100)
+    pub fn submit_with_proof(env: Env, leaf: MerkleLeaf, proof: Vec<BytesN<32>>) {
+        let payload = leaf_payload(&env, &leaf);
+        let root = Self::require_merkle_proof(&env, &leaf, &proof);
+        if Self::is_duplicate(&env, &payload) {
+            return;
+        }
+        
+        if let Err(error) = Self::validate_leaf(&env, &payload, &root) {
+            events::publish_rejected(&env, &payload, error);
+            return;
+        }
+        
+        Self::apply_risk(&env, &payload);
+    }
+    
+    /// Decide on a wallet from a leaf of the latest Merkle root (read-only)
+    /// 
+    /// The leaf is evaluated through the decision pipeline as if it were
+    /// stored, so overrides and freezes still win. If the stored score is
+    /// at least as recent as the leaf, the stored score is used instead.
+    /// 
+    /// # Panics
+    /// * If no Merkle root has been submitted
+    /// * If the proof is too long or does not lead to the latest root
+    /// * `RiskScoreOutOfRange` if the leaf score is above 100
+    pub fn check_with_proof(env: Env, leaf: MerkleLeaf, proof: Vec<BytesN<32>>) -> RiskDecision {
+        Self::require_merkle_proof(&env, &leaf, &proof);
+        if leaf.risk_score > 100 {
+            panic_with_error!(&env, SentinelError::RiskScoreOutOfRange);
+        }
+        
+        match schema::load_risk_state(&env, &leaf.wallet) {
+            Some(stored) if stored.last_updated >= leaf.timestamp => {
+                pipeline::evaluate(&env, &leaf.wallet)
+            }
+            _ => {
+                let state = RiskState::from_payload(&leaf_payload(&env, &leaf), &pipeline::score_bands(&env));
+                pipeline::evaluate_state(&env, &leaf.wallet, &state)
+            }
+        }
+    }
+}
+
+impl SentinelSDK {
+    /// Verify a leaf's inclusion in the latest root and return that root
+    fn require_merkle_proof(env: &Env, leaf: &MerkleLeaf, proof: &Vec<BytesN<32>>) -> MerkleRoot {
+        let root = Self::get_merkle_root(env.clone()).expect("No Merkle root submitted");
+        if proof.len() > MAX_PROOF_DEPTH {
+            panic!("Merkle proof too long: max 32 hashes");
+        }
+        
+        let mut hash = merkle_leaf_hash(env, leaf);
+        for sibling in proof.iter() {
+            hash = merkle_node_hash(env, &hash, &sibling);
+        }
+        if hash != root.root {
+            panic!("Invalid Merkle proof");
+        }
+        
+        root
+    }
+    
+    /// Validate a proven leaf before it is stored
+    fn validate_leaf(env: &Env, payload: &RiskPayload, root: &MerkleRoot) -> Result<(), SentinelError> {
+        if payload.timestamp > root.timestamp {
+            return Err(SentinelError::PayloadFromFuture);
+        }
+        
+        if let Some(stored) = schema::load_risk_state(env, &payload.wallet) {
+            if payload.timestamp < stored.last_updated {
+                return Err(SentinelError::OutOfOrderUpdate);
+            }
+        }
+        
+        if Self::rate_limited(env, &payload.wallet) {
+            return Err(SentinelError::RateLimited);
+        }
+        
+        if payload.risk_score > 100 {
+            return Err(SentinelError::RiskScoreOutOfRange);
+        }
+        
+        Ok(())
+    }
+}
+
+/// Payload equivalent of a leaf (no reason codes, categories or quarantine)
+fn leaf_payload(env: &Env, leaf: &MerkleLeaf) -> RiskPayload {
+    RiskPayload {
+        version: PAYLOAD_VERSION_V2,
+        wallet: leaf.wallet.clone(),
+        risk_score: leaf.risk_score,
+        timestamp: leaf.timestamp,
+        reason_codes: Vec::new(env),
+        freeze_duration: 0,
+        category_scores: Map::new(env),
+        model_version: 0,
+        confidence: 0,
+        evidence_hash: BytesN::from_array(env, &[0; 32]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::TimeTravel;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{testutils::Address as _, vec, Address};
+    
+    /// Four leaves, their level-1 nodes and the root
+    struct Tree {
+        leaves: std::vec::Vec<MerkleLeaf>,
+        hashes: std::vec::Vec<BytesN<32>>,
+        nodes: [BytesN<32>; 2],
+        root: BytesN<32>,
+    }
+    
+    impl Tree {
+        /// Leaves hash to the deployment at `contract`
+        fn new(env: &Env, contract: &Address, scores: [u32; 4], timestamp: u64) -> Self {
+            let leaves: std::vec::Vec<MerkleLeaf> = scores
+                .iter()
+                .map(|score| MerkleLeaf {
+                    wallet: Address::generate(env),
+                    risk_score: *score,
+                    timestamp,
+                })
+                .collect();
+            let hashes: std::vec::Vec<BytesN<32>> =
+                env.as_contract(contract, || leaves.iter().map(|leaf| merkle_leaf_hash(env, leaf)).collect());
+            let nodes = [
+                merkle_node_hash(env, &hashes[0], &hashes[1]),
+                merkle_node_hash(env, &hashes[2], &hashes[3]),
+            ];
+            let root = merkle_node_hash(env, &nodes[0], &nodes[1]);
+            Tree { leaves, hashes, nodes, root }
+        }
+        
+        /// Inclusion proof for leaf `i`
+        fn proof(&self, env: &Env, i: usize) -> Vec<BytesN<32>> {
+            vec![env, self.hashes[i ^ 1].clone(), self.nodes[1 - i / 2].clone()]
+        }
+    }
+    
+    fn setup(env: &Env, contract_id: &Address, tree: &Tree, timestamp: u64) -> SentinelSDKClient<'static> {
+        let client = SentinelSDKClient::new(env, contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(env),
+            &BytesN::from_array(env, &key.verifying_key().to_bytes()),
+        );
+        
+        let message = merkle_root_message(env, &tree.root, timestamp);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        let signature = BytesN::from_array(env, &key.sign(&message).to_bytes());
+        client.submit_merkle_root(&tree.root, &timestamp, &signature);
+        client
+    }
+    
+    #[test]
+    fn test_proven_leaves_check_and_store() {
+        let env = Env::default();
+        env.set_time(1_000);
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let tree = Tree::new(&env, &contract_id, [10, 60, 90, 20], 1_000);
+        let client = setup(&env, &contract_id, &tree, 1_000);
+        
+        let frozen = &tree.leaves[2];
+        assert_eq!(client.check_with_proof(frozen, &tree.proof(&env, 2)), RiskDecision::Freeze);
+        assert_eq!(client.get_risk(&frozen.wallet), None);
+        
+        let limited = &tree.leaves[1];
+        client.submit_with_proof(limited, &tree.proof(&env, 1));
+        let state = client.get_risk(&limited.wallet).unwrap();
+        assert_eq!((state.risk_score, state.last_updated), (60, 1_000));
+        assert_eq!(client.check_permission(&limited.wallet), RiskDecision::Limit(5000));
+    }
+    
+    #[test]
+    #[should_panic(expected = "Invalid Merkle proof")]
+    fn test_tampered_leaf_is_rejected() {
+        let env = Env::default();
+        env.set_time(1_000);
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let tree = Tree::new(&env, &contract_id, [10, 60, 90, 20], 1_000);
+        let client = setup(&env, &contract_id, &tree, 1_000);
+        
+        let mut leaf = tree.leaves[2].clone();
+        leaf.risk_score = 0;
+        client.submit_with_proof(&leaf, &tree.proof(&env, 2));
+    }
+}
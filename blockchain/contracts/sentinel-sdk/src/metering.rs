@@ -0,0 +1,183 @@
+//! Cross-protocol usage metering.
+//!
+//! `check_permission` reports `Limit(5000)` per call, so a wallet can move
+//! 5000 through every integrator, or 5000 per transaction. With
+//! `check_and_consume` registered integrators draw from one shared
+//! allowance per window instead: `Limit(5000)` means 5000 in total until
+//! the window resets. The wallet authorizes every draw, so an integrator
+//! cannot exhaust a wallet's allowance on its own.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, FillResult, RiskDecision, Usage, UsageWindow};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Current metering window (daily by default)
+pub(crate) fn usage_window(env: &Env) -> UsageWindow {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::UsageWindow))
+        .unwrap_or(UsageWindow::Daily)
+}
+
+/// Amount consumed by `wallet` in the window containing `now`
+fn consumed_in_window(env: &Env, wallet: &Address, window_start: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get::<_, Usage>(&DataKey::Consumed(wallet.clone()))
+        .filter(|usage| usage.window_start == window_start)
+        .map(|usage| usage.consumed)
+        .unwrap_or(0)
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Check a wallet and consume from its allowance in one step
+    /// 
+    /// The decision comes from `check_permission_for`. `Allow` fills in
+    /// full and `Freeze` rejects, neither touching the allowance. For
+    /// `Limit(cap)` the fill is capped by what remains of `cap` in the
+    /// current window, and the filled amount is recorded against the
+    /// wallet for every integrator.
+    /// 
+    /// # Arguments
+    /// * `integrator` - Registered protocol; must authorize the call
+    /// * `wallet` - Address performing the operation; must authorize the call
+    /// * `amount` - Amount requested
+    /// 
+    /// # Panics
+    /// * If `integrator` or `wallet` has not authorized the call
+    /// * If `integrator` is not registered (see `register_integrator`)
+    pub fn check_and_consume(env: Env, integrator: Address, wallet: Address, amount: i128) -> FillResult {
+        integrator.require_auth();
+        wallet.require_auth();
+        if Self::get_integrator_policy(env.clone(), integrator.clone()).is_none() {
+            panic!("Unregistered integrator");
+        }
+        
+        if amount <= 0 {
+            return FillResult::Rejected;
+        }
+        
+        let cap = match Self::check_permission_for(env.clone(), integrator.clone(), wallet.clone()) {
+            RiskDecision::Allow => return FillResult::Full,
+            RiskDecision::Review | RiskDecision::Freeze => return FillResult::Rejected,
+            RiskDecision::Limit(cap) => cap,
+        };
+        
+        let window_start = usage_window(&env).start(env.ledger().timestamp());
+        let consumed = consumed_in_window(&env, &wallet, window_start);
+        let remaining = (cap as i128 - consumed).max(0);
+        let filled = amount.min(remaining);
+        if filled == 0 {
+            return FillResult::Rejected;
+        }
+        
+        let usage = Usage { window_start, consumed: consumed + filled };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Consumed(wallet.clone()), &usage);
+        env.events().publish((topic::CONSUMED, wallet), (integrator, filled));
+        
+        if filled == amount {
+            FillResult::Full
+        } else {
+            FillResult::Partial(filled)
+        }
+    }
+    
+    /// Amount a wallet has consumed in the current window (read-only)
+    pub fn get_consumed(env: Env, wallet: Address) -> i128 {
+        let window_start = usage_window(&env).start(env.ledger().timestamp());
+        consumed_in_window(&env, &wallet, window_start)
+    }
+    
+    /// Get the metering window (read-only)
+    pub fn get_usage_window(env: Env) -> UsageWindow {
+        usage_window(&env)
+    }
+    
+    /// Set the metering window (admin only)
+    /// 
+    /// Existing usage is kept but only counts while its window matches.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    pub fn set_usage_window(env: Env, window: UsageWindow) {
+        let admin = Self::require_admin(&env);
+        
+        let old = usage_window(&env);
+        env.storage().instance().set(&DataKey::Config(ConfigKey::UsageWindow), &window);
+        audit::record(&env, &admin, "set_usage_window", old, window);
+        env.events().publish((topic::USE_WIN,), window);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Policy, ScoreBands};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, BytesN};
+    
+    fn setup(env: &Env) -> (SentinelSDKClient<'_>, Address) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        
+        client.set_policy(
+            &1,
+            &Policy::from_bands(ScoreBands { allow_max: 30, limit_max: 70, review_max: 70, limit_amount: 5000 }),
+        );
+        let integrator = Address::generate(env);
+        client.register_integrator(&client.get_admin(), &integrator, &1);
+        (client, integrator)
+    }
+    
+    #[test]
+    fn test_limit_is_shared_across_integrators() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 86_400 * 10 + 100);
+        let (client, amm) = setup(&env);
+        let lender = Address::generate(&env);
+        client.register_integrator(&client.get_admin(), &lender, &1);
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Limit(5000), &0);
+        
+        assert_eq!(client.check_and_consume(&amm, &wallet, &3000), FillResult::Full);
+        assert!(env.auths().iter().any(|(address, _)| *address == wallet));
+        assert_eq!(client.check_and_consume(&lender, &wallet, &3000), FillResult::Partial(2000));
+        assert_eq!(client.check_and_consume(&amm, &wallet, &1), FillResult::Rejected);
+        assert_eq!(client.get_consumed(&wallet), 5000);
+        
+        // Next day the allowance resets
+        env.ledger().with_mut(|li| li.timestamp = 86_400 * 11);
+        assert_eq!(client.get_consumed(&wallet), 0);
+        assert_eq!(client.check_and_consume(&amm, &wallet, &4000), FillResult::Full);
+    }
+    
+    #[test]
+    fn test_allow_and_freeze_do_not_meter() {
+        let env = Env::default();
+        let (client, integrator) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        assert_eq!(client.check_and_consume(&integrator, &wallet, &1_000_000), FillResult::Full);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        assert_eq!(client.check_and_consume(&integrator, &wallet, &1), FillResult::Rejected);
+        assert_eq!(client.get_consumed(&wallet), 0);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Unregistered integrator")]
+    fn test_unregistered_integrator_rejected() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        client.check_and_consume(&Address::generate(&env), &Address::generate(&env), &1);
+    }
+}
@@ -0,0 +1,93 @@
+//! Operation-aware decisions.
+//!
+//! One global decision cannot express "may deposit or repay, may not
+//! withdraw". `check_operation` applies the wallet's decision per
+//! `OperationKind`: kinds the admin marks `Exempt` are always filled, the
+//! rest are enforced with partial-fill semantics.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, FillResult, OperationKind, OperationRule};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Rule for an operation kind (every kind is enforced by default)
+pub(crate) fn operation_rule(env: &Env, kind: OperationKind) -> OperationRule {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::OperationRule(kind)))
+        .unwrap_or(OperationRule::Enforce)
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Check whether a wallet may perform an operation
+    /// 
+    /// # Arguments
+    /// * `wallet` - Address performing the operation
+    /// * `kind` - Operation kind
+    /// * `amount` - Amount involved
+    /// 
+    /// # Returns
+    /// * `Full` for exempt kinds (positive amounts); otherwise the wallet's
+    ///   decision applied to `amount` (see `apply_limit`)
+    pub fn check_operation(env: Env, wallet: Address, kind: OperationKind, amount: i128) -> FillResult {
+        if amount <= 0 {
+            return FillResult::Rejected;
+        }
+        
+        match operation_rule(&env, kind) {
+            OperationRule::Exempt => FillResult::Full,
+            OperationRule::Enforce => Self::check_permission(env, wallet).apply_limit(amount),
+        }
+    }
+    
+    /// Get the rule for an operation kind (read-only)
+    pub fn get_operation_rule(env: Env, kind: OperationKind) -> OperationRule {
+        operation_rule(&env, kind)
+    }
+    
+    /// Set the rule for an operation kind (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    pub fn set_operation_rule(env: Env, kind: OperationKind, rule: OperationRule) {
+        let admin = Self::require_admin(&env);
+        
+        let old = operation_rule(&env, kind);
+        env.storage().instance().set(&DataKey::Config(ConfigKey::OperationRule(kind)), &rule);
+        audit::record(&env, &admin, "set_operation_rule", (kind, old), (kind, rule));
+        env.events().publish((topic::OPRULE,), (kind, rule));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RiskDecision;
+    use soroban_sdk::{testutils::Address as _, BytesN};
+    
+    #[test]
+    fn test_frozen_wallet_may_deposit_but_not_withdraw() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.set_operation_rule(&OperationKind::Deposit, &OperationRule::Exempt);
+        
+        assert_eq!(client.check_operation(&wallet, &OperationKind::Deposit, &100), FillResult::Full);
+        assert_eq!(client.check_operation(&wallet, &OperationKind::Withdraw, &100), FillResult::Rejected);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Limit(40), &0);
+        assert_eq!(
+            client.check_operation(&wallet, &OperationKind::Swap, &100),
+            FillResult::Partial(40)
+        );
+    }
+}
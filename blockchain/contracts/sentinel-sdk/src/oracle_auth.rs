@@ -0,0 +1,157 @@
+//! Oracle trust models.
+//!
+//! By default the Oracle is a public key and every Oracle message carries
+//! a signature the contract verifies itself (see `crypto.rs`). A
+//! deployment initialized with `initialize_with_oracle_address` instead
+//! registers the Oracle as a Soroban `Address`: Oracle entrypoints call
+//! `oracle.require_auth()` and ignore their signature argument. That lets
+//! multisig accounts, smart-contract oracles and fee-bump flows act as the
+//! Oracle without custom crypto. The trust model is fixed at initialization.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, panic_with_error, Address, Bytes, Env};
+
+use crate::crypto::{signed_message, verify_message, verify_signature};
+use crate::errors::SentinelError;
+use crate::types::{feature, ConfigKey, DataKey, PublicKey, RiskPayload, Signature};
+use crate::{invariants, SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Initialize the SDK with an admin and the Oracle as a Soroban address
+    /// 
+    /// Alternative to `initialize`: Oracle entrypoints then require the
+    /// address's authorization instead of an Ed25519 signature. Key trials
+    /// are not available in this mode.
+    /// 
+    /// # Arguments
+    /// * `admin` - Address allowed to perform compliance actions (overrides)
+    /// * `oracle` - Account or contract that authorizes Oracle submissions
+    /// 
+    /// # Panics
+    /// * If already initialized
+    pub fn initialize_with_oracle_address(env: Env, admin: Address, oracle: Address) {
+        let storage = env.storage().instance();
+        
+        if Self::is_initialized(env.clone()) {
+            panic!("SDK already initialized");
+        }
+        
+        storage.set(&DataKey::Admin, &admin);
+        storage.set(&DataKey::OracleAddress, &oracle);
+        storage.set(&DataKey::Config(ConfigKey::Features), &feature::DEFAULT);
+        
+        env.events().publish((topic::SDK_INIT,), (admin, oracle));
+        
+        invariants::check_contract(&env);
+    }
+    
+    /// Get the Oracle address in Soroban-auth mode (read-only)
+    /// 
+    /// # Returns
+    /// * `Some(address)` if initialized with `initialize_with_oracle_address`
+    /// * `None` if the Oracle is a public key
+    pub fn get_oracle_address(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::OracleAddress)
+    }
+}
+
+impl SentinelSDK {
+    /// Live Oracle public key
+    /// 
+    /// # Panics
+    /// * If the SDK is not initialized or runs in Soroban-auth mode
+    pub(crate) fn oracle_pubkey(env: &Env) -> PublicKey {
+        Self::get_oracle_pubkey(env.clone()).expect("SDK not initialized - call initialize() first")
+    }
+    
+    /// Authenticate a payload as coming from the live Oracle
+    /// 
+    /// # Errors
+    /// * `InvalidSignature` if the signature does not verify (signature mode)
+    /// * `UnsupportedPayloadVersion` for unknown payload versions
+    /// 
+    /// # Panics
+    /// * If the Oracle address has not authorized the call (Soroban-auth mode)
+    pub(crate) fn authenticate_payload(
+        env: &Env,
+        payload: &RiskPayload,
+        signature: &Signature,
+    ) -> Result<(), SentinelError> {
+        match Self::get_oracle_address(env.clone()) {
+            Some(oracle) => {
+                oracle.require_auth();
+                signed_message(env, payload).map(|_| ())
+            }
+            None => verify_signature(env, payload, signature, &Self::oracle_pubkey(env)),
+        }
+    }
+    
+    /// Authenticate a non-payload Oracle message (labels, roots, plans)
+    /// 
+    /// # Panics
+    /// * If the Oracle address has not authorized the call (Soroban-auth
+    ///   mode)
+    /// * `InvalidSignature` if the signature does not verify (signature mode)
+    pub(crate) fn authenticate_message(env: &Env, message: &Bytes, signature: &Signature) {
+        match Self::get_oracle_address(env.clone()) {
+            Some(oracle) => oracle.require_auth(),
+            None => {
+                if let Err(error) = verify_message(env, message, signature, &Self::oracle_pubkey(env)) {
+                    panic_with_error!(env, error);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutils::TimeTravel;
+    use crate::types::{RiskDecision, PAYLOAD_VERSION_V2};
+    use soroban_sdk::{testutils::Address as _, BytesN, Map, Vec};
+    
+    #[test]
+    fn test_address_oracle_submits_with_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.set_time(1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let oracle = Address::generate(&env);
+        client.initialize_with_oracle_address(&Address::generate(&env), &oracle);
+        assert_eq!(client.get_oracle_address(), Some(oracle.clone()));
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 90,
+            timestamp: 1_000,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        client.submit_risk(&payload, &BytesN::from_array(&env, &[0u8; 64]));
+        
+        assert_eq!(env.auths()[0].0, oracle);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.last_oracle_activity(), Some(1_000));
+    }
+    
+    #[test]
+    #[should_panic(expected = "SDK already initialized")]
+    fn test_trust_model_is_fixed() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        
+        client.initialize_with_oracle_address(&Address::generate(&env), &Address::generate(&env));
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+    }
+}
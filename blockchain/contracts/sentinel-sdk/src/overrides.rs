@@ -0,0 +1,205 @@
+//! Admin override registry.
+//!
+//! Compliance officers can pin a wallet to a manual decision (allow or
+//! freeze) that takes precedence over the Oracle score until it expires or
+//! is cleared.
+
+use sentinel_events::{OverrideEvent, RiskEvent};
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::invariants;
+use crate::types::{DataKey, Override, RiskDecision, Role, TransitionCause};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Seconds a ruling pinned by `pin_decision` overrides the Oracle (30 days)
+pub const PIN_DURATION: u64 = 30 * 86_400;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Set a manual decision for a wallet (`Overrider` role)
+    /// 
+    /// # Arguments
+    /// * `caller` - Role holder setting the override (must authorize)
+    /// * `wallet` - Address to override
+    /// * `decision` - Decision returned by `check_permission` while active
+    /// * `expiry` - Unix timestamp when the override lapses (0 = never)
+    /// 
+    /// # Panics
+    /// * If caller does not hold the `Overrider` role
+    /// * If `expiry` is non-zero and not in the future
+    pub fn set_override(env: Env, caller: Address, wallet: Address, decision: RiskDecision, expiry: u64) {
+        Self::require_role(&env, &caller, Role::Overrider);
+        
+        let old = Self::get_override(env.clone(), wallet.clone());
+        let old_decision = Self::check_permission(env.clone(), wallet.clone());
+        let now = env.ledger().timestamp();
+        if expiry != 0 && expiry <= now {
+            panic!("Override expiry must be in the future");
+        }
+        
+        let record = Override {
+            decision: decision.clone(),
+            expires_at: expiry,
+            set_by: caller.clone(),
+            set_at: now,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Override(wallet.clone()), &record);
+        audit::record(&env, &caller, "set_override", old, record);
+        
+        Self::record_transition(
+            &env,
+            &wallet,
+            decision.clone(),
+            TransitionCause::Override,
+            Some(caller),
+        );
+        
+        invariants::check_wallet(&env, &wallet);
+        
+        let data = OverrideEvent {
+            old_decision: old_decision.into(),
+            new_decision: decision.into(),
+            expiry,
+        };
+        RiskEvent::OverrideSet { wallet, data }.publish(&env);
+    }
+    
+    /// Remove a wallet's manual decision (`Overrider` role)
+    /// 
+    /// # Panics
+    /// * If caller does not hold the `Overrider` role
+    pub fn clear_override(env: Env, caller: Address, wallet: Address) {
+        Self::require_role(&env, &caller, Role::Overrider);
+        
+        let old = Self::get_override(env.clone(), wallet.clone());
+        let old_decision = Self::check_permission(env.clone(), wallet.clone());
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Override(wallet.clone()));
+        audit::record(&env, &caller, "clear_override", old, wallet.clone());
+        
+        let decision = Self::check_permission(env.clone(), wallet.clone());
+        Self::record_transition(&env, &wallet, decision.clone(), TransitionCause::Override, Some(caller));
+        invariants::check_wallet(&env, &wallet);
+        
+        let data = OverrideEvent {
+            old_decision: old_decision.into(),
+            new_decision: decision.into(),
+            expiry: 0,
+        };
+        RiskEvent::OverrideCleared { wallet, data }.publish(&env);
+    }
+    
+    /// Get the stored override for a wallet (read-only)
+    /// 
+    /// Returns the record even after it has expired so auditors can see
+    /// what was set, by whom and until when. Only active overrides affect
+    /// `check_permission`.
+    pub fn get_override(env: Env, wallet: Address) -> Option<Override> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Override(wallet))
+    }
+}
+
+impl SentinelSDK {
+    /// Pin a wallet to a decision for `PIN_DURATION` and log it
+    /// 
+    /// Used by flows that change a wallet's treatment on their own
+    /// authority (appeals, remediation). The override lapses on
+    /// its own so the Oracle score takes over again once the Oracle has
+    /// had time to rescore the wallet.
+    pub(crate) fn pin_decision(
+        env: &Env,
+        wallet: &Address,
+        decision: RiskDecision,
+        set_by: Address,
+        cause: TransitionCause,
+    ) {
+        let now = env.ledger().timestamp();
+        let record = Override {
+            decision: decision.clone(),
+            expires_at: now.saturating_add(PIN_DURATION),
+            set_by: set_by.clone(),
+            set_at: now,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Override(wallet.clone()), &record);
+        Self::record_transition(env, wallet, decision, cause, Some(set_by));
+        invariants::check_wallet(env, wallet);
+    }
+    
+    /// Decision from an active (non-expired) override, if any
+    pub(crate) fn active_override(env: &Env, wallet: &Address) -> Option<RiskDecision> {
+        let record = Self::get_override(env.clone(), wallet.clone())?;
+        
+        if record.expires_at != 0 && env.ledger().timestamp() >= record.expires_at {
+            return None;
+        }
+        
+        Some(record.decision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        BytesN,
+    };
+    
+    fn setup(env: &Env) -> SentinelSDKClient<'_> {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        client
+    }
+    
+    #[test]
+    fn test_override_takes_precedence_until_expiry() {
+        let env = Env::default();
+        let client = setup(&env);
+        let wallet = Address::generate(&env);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &1_000);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        
+        // Expiry boundary: override lapses at exactly `expiry`
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        
+        // Expired record stays visible for audit
+        assert_eq!(client.get_override(&wallet).unwrap().expires_at, 1_000);
+    }
+    
+    #[test]
+    fn test_clear_override_restores_oracle_decision() {
+        let env = Env::default();
+        let client = setup(&env);
+        let wallet = Address::generate(&env);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.clear_override(&client.get_admin(), &wallet);
+        
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(client.get_override(&wallet), None);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn test_override_requires_admin_auth() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        client.set_override(&client.get_admin(), &Address::generate(&env), &RiskDecision::Allow, &0);
+    }
+}
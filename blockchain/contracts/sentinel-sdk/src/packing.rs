@@ -0,0 +1,113 @@
+//! Packed encoding of the fixed-size part of a risk state.
+//!
+//! Score, last-update timestamp and decision are the fields every wallet
+//! has; stored as separate map entries they dominate the per-wallet
+//! ledger entry size. They are packed into one `u128` instead:
+//!
+//! | bits    | field                                               |
+//! |---------|-----------------------------------------------------|
+//! | 0-63    | `last_updated`                                      |
+//! | 64-95   | Limit amount (0 unless the decision is Limit)       |
+//! | 96-97   | decision tag (0 Allow, 1 Limit, 2 Freeze, 3 Review) |
+//! | 98-105  | `risk_score`                                        |
+//!
+//! Only storage sees the packed form; the public API keeps returning the
+//! rich `RiskState`.
+
+use crate::types::{PackedRiskState, RiskDecision, RiskState};
+
+const LIMIT_SHIFT: u32 = 64;
+const TAG_SHIFT: u32 = 96;
+const SCORE_SHIFT: u32 = 98;
+
+const TAG_ALLOW: u128 = 0;
+const TAG_LIMIT: u128 = 1;
+const TAG_FREEZE: u128 = 2;
+const TAG_REVIEW: u128 = 3;
+
+/// Pack score, timestamp and decision into one word
+/// 
+/// # Panics
+/// * If `risk_score` does not fit in 8 bits
+pub fn pack(risk_score: u32, last_updated: u64, decision: &RiskDecision) -> u128 {
+    if risk_score > u8::MAX as u32 {
+        panic!("Invalid risk score: must be 0-100");
+    }
+    
+    let (tag, limit) = match decision {
+        RiskDecision::Allow => (TAG_ALLOW, 0),
+        RiskDecision::Limit(limit) => (TAG_LIMIT, *limit),
+        RiskDecision::Review => (TAG_REVIEW, 0),
+        RiskDecision::Freeze => (TAG_FREEZE, 0),
+    };
+    
+    (last_updated as u128)
+        | ((limit as u128) << LIMIT_SHIFT)
+        | (tag << TAG_SHIFT)
+        | ((risk_score as u128) << SCORE_SHIFT)
+}
+
+/// Unpack score, timestamp and decision from a packed word
+pub fn unpack(packed: u128) -> (u32, u64, RiskDecision) {
+    let last_updated = packed as u64;
+    let limit = (packed >> LIMIT_SHIFT) as u32;
+    let risk_score = ((packed >> SCORE_SHIFT) & 0xff) as u32;
+    
+    let decision = match (packed >> TAG_SHIFT) & 0b11 {
+        TAG_ALLOW => RiskDecision::Allow,
+        TAG_LIMIT => RiskDecision::Limit(limit),
+        TAG_REVIEW => RiskDecision::Review,
+        TAG_FREEZE => RiskDecision::Freeze,
+        _ => unreachable!("decision tag is two bits"),
+    };
+    (risk_score, last_updated, decision)
+}
+
+impl RiskState {
+    /// Storage form of this state
+    pub fn to_packed(&self) -> PackedRiskState {
+        PackedRiskState {
+            packed: pack(self.risk_score, self.last_updated, &self.decision),
+            reason_codes: self.reason_codes.clone(),
+            freeze_until: self.freeze_until,
+            category_scores: self.category_scores.clone(),
+            model_version: self.model_version,
+            confidence: self.confidence,
+            evidence_hash: self.evidence_hash.clone(),
+        }
+    }
+    
+    /// Rich form of a stored state
+    pub fn from_packed(stored: &PackedRiskState) -> RiskState {
+        let (risk_score, last_updated, decision) = unpack(stored.packed);
+        RiskState {
+            risk_score,
+            last_updated,
+            decision,
+            reason_codes: stored.reason_codes.clone(),
+            freeze_until: stored.freeze_until,
+            category_scores: stored.category_scores.clone(),
+            model_version: stored.model_version,
+            confidence: stored.confidence,
+            evidence_hash: stored.evidence_hash.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_round_trip_extremes() {
+        for (score, timestamp, decision) in [
+            (0, 0, RiskDecision::Allow),
+            (100, u64::MAX, RiskDecision::Freeze),
+            (85, 1_737_718_800, RiskDecision::Review),
+            (65, 1_737_718_800, RiskDecision::Limit(5000)),
+            (50, 1, RiskDecision::Limit(u32::MAX)),
+        ] {
+            assert_eq!(unpack(pack(score, timestamp, &decision)), (score, timestamp, decision));
+        }
+    }
+}
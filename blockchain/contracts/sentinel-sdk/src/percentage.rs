@@ -0,0 +1,134 @@
+//! Percentage-based limits.
+//!
+//! An absolute `Limit` is a hard cap. For progressive de-risking the admin
+//! can also express limits in basis points of each attempted transaction:
+//! with 2500 bps configured a limited wallet may move 25% of whatever it
+//! requests, still bounded by the absolute Limit amount. Protocols get the
+//! concrete figure from `check_transaction` instead of applying the
+//! percentage themselves.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, RiskDecision};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Configured share of each transaction a limited wallet may move
+pub(crate) fn limit_bps(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::LimitBps))
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Amount of a transaction a wallet may move (read-only)
+    /// 
+    /// `Allow` permits the full amount; `Review` and `Freeze` permit
+    /// nothing. `Limit(cap)` permits `amount * bps / 10000` (rounded down)
+    /// when basis-point limits are configured, and never more than `cap`.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Address performing the transaction
+    /// * `amount` - Amount requested
+    /// 
+    /// # Returns
+    /// * Permitted amount, between 0 and `amount` (0 for non-positive amounts)
+    pub fn check_transaction(env: Env, wallet: Address, amount: i128) -> i128 {
+        if amount <= 0 {
+            return 0;
+        }
+        
+        match Self::check_permission(env.clone(), wallet) {
+            RiskDecision::Allow => amount,
+            RiskDecision::Limit(cap) => {
+                let share = match limit_bps(&env) {
+                    Some(bps) => amount.saturating_mul(bps as i128) / BPS_DENOMINATOR as i128,
+                    None => amount,
+                };
+                share.min(cap as i128)
+            }
+            RiskDecision::Review | RiskDecision::Freeze => 0,
+        }
+    }
+    
+    /// Get the basis-point limit, if configured (read-only)
+    pub fn get_limit_bps(env: Env) -> Option<u32> {
+        limit_bps(&env)
+    }
+    
+    /// Set the share of each transaction limited wallets may move (admin only)
+    /// 
+    /// # Arguments
+    /// * `bps` - Basis points of the requested amount (1-10000), or `None`
+    ///   for absolute limits only
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If `bps` is 0 or above 10000
+    pub fn set_limit_bps(env: Env, bps: Option<u32>) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = limit_bps(&env);
+        Self::apply_limit_bps(&env, bps);
+        audit::record(&env, &admin, "set_limit_bps", old, bps);
+    }
+}
+
+/// Panic unless the basis points are 1-10000
+pub(crate) fn check_limit_bps(bps: u32) {
+    if bps == 0 || bps > BPS_DENOMINATOR {
+        panic!("Limit basis points must be 1-10000");
+    }
+}
+
+impl SentinelSDK {
+    /// Store or clear the basis-point limit (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If `bps` is 0 or above 10000
+    pub(crate) fn apply_limit_bps(env: &Env, bps: Option<u32>) {
+        let storage = env.storage().instance();
+        match bps {
+            Some(bps) => {
+                check_limit_bps(bps);
+                storage.set(&DataKey::Config(ConfigKey::LimitBps), &bps);
+            }
+            None => storage.remove(&DataKey::Config(ConfigKey::LimitBps)),
+        }
+        env.events().publish((topic::LIMIT_BPS,), bps);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, BytesN};
+    
+    #[test]
+    fn test_limit_scales_with_transaction() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Limit(5000), &0);
+        assert_eq!(client.check_transaction(&wallet, &8000), 5000);
+        
+        client.set_limit_bps(&Some(2500));
+        assert_eq!(client.check_transaction(&wallet, &8000), 2000);
+        assert_eq!(client.check_transaction(&wallet, &1_000_000), 5000);
+        assert_eq!(client.check_transaction(&wallet, &-1), 0);
+        assert_eq!(client.check_transaction(&Address::generate(&env), &8000), 8000);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        assert_eq!(client.check_transaction(&wallet, &8000), 0);
+    }
+}
@@ -0,0 +1,534 @@
+//! Decision pipeline.
+//!
+//! `check_permission` runs an ordered list of policy stages. Each stage
+//! can adjust the evaluation (e.g. the working score) or settle the
+//! decision outright; the first stage that settles wins. The stage order
+//! and the score bands are admin-configurable, and each stage is a plain
+//! function that can be tested on its own. A wallet's self-lock and an
+//! engaged emergency global freeze are checked before any stage, so no
+//! pipeline configuration can switch them off.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, panic_with_error, vec, Address, Env, Vec};
+
+use crate::audit;
+use crate::bands::MAX_RISK_SCORE;
+use crate::breaker::breaker_decision;
+use crate::decay::effective_score;
+use crate::emergency::globally_frozen;
+use crate::errors::SentinelError;
+use crate::grace::grace_stage;
+use crate::heartbeat::degraded_stage;
+use crate::types::{ConfigKey, DataKey, PipelineStage, Policy, RiskCategory, RiskDecision, RiskState, ScoreBands};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Working state threaded through the stages for one wallet
+pub(crate) struct Evaluation {
+    pub wallet: Address,
+    /// Policy supplying the bands and the unknown/stale fallbacks
+    pub policy: Policy,
+    /// Unix timestamp of the last Oracle update (0 if never scored)
+    pub last_updated: u64,
+    /// Quarantine expiry of the stored Freeze (0 = none)
+    pub freeze_until: u64,
+    /// Score the score-based stages operate on
+    pub score: Option<u32>,
+    /// Decision reached so far (`None` until a stage sets one)
+    pub decision: Option<RiskDecision>,
+}
+
+/// What a stage did with the evaluation
+pub(crate) enum StageResult {
+    /// Keep going with the next stage
+    Continue,
+    /// Decision is final; skip remaining stages
+    Settled(RiskDecision),
+}
+
+/// Stage order used until the admin configures one
+pub(crate) fn default_pipeline(env: &Env) -> Vec<PipelineStage> {
+    vec![
+        env,
+        PipelineStage::Override,
+        PipelineStage::OracleScore,
+        PipelineStage::Decay,
+        PipelineStage::Quarantine,
+        PipelineStage::Grace,
+    ]
+}
+
+/// Current stage order
+pub(crate) fn stages(env: &Env) -> Vec<PipelineStage> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::Pipeline))
+        .unwrap_or_else(|| default_pipeline(env))
+}
+
+/// Current score bands
+pub(crate) fn score_bands(env: &Env) -> ScoreBands {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::ScoreBands))
+        .unwrap_or_else(ScoreBands::default_bands)
+}
+
+/// Decision a quarantine freeze is released to once it lapses
+pub(crate) fn quarantine_release(env: &Env) -> RiskDecision {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::QuarantineRelease))
+        .unwrap_or_else(|| RiskDecision::Limit(score_bands(env).limit_amount))
+}
+
+/// Decision for never-scored wallets (defaults to Allow)
+pub(crate) fn unknown_default(env: &Env) -> RiskDecision {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::UnknownDefault))
+        .unwrap_or(RiskDecision::Allow)
+}
+
+/// Policy built from the global configuration
+pub(crate) fn global_policy(env: &Env) -> Policy {
+    let mut policy = Policy::from_bands(score_bands(env));
+    policy.unknown_default = unknown_default(env);
+    policy
+}
+
+/// Map a score through bands, rejecting out-of-range scores
+pub(crate) fn preview(env: &Env, bands: &ScoreBands, score: u32) -> RiskDecision {
+    if score > MAX_RISK_SCORE {
+        panic_with_error!(env, SentinelError::RiskScoreOutOfRange);
+    }
+    bands.decide(score)
+}
+
+/// Run the pipeline for a wallet and return its decision
+pub(crate) fn evaluate(env: &Env, wallet: &Address) -> RiskDecision {
+    evaluate_with_policy(env, wallet, global_policy(env))
+}
+
+/// Run the pipeline for a wallet under a specific policy
+/// 
+/// Stages that settle (overrides) win over the policy's fallbacks; stale
+/// and unknown wallets otherwise get `stale_fallback`/`unknown_default`.
+pub(crate) fn evaluate_with_policy(env: &Env, wallet: &Address, policy: Policy) -> RiskDecision {
+    let state = SentinelSDK::get_risk(env.clone(), wallet.clone());
+    let score = state.as_ref().map(|s| s.worst_score());
+    run_pipeline(env, wallet, policy, state.as_ref(), score)
+}
+
+/// Run the pipeline for a wallet as if `state` were its stored state
+pub(crate) fn evaluate_state(env: &Env, wallet: &Address, state: &RiskState) -> RiskDecision {
+    run_pipeline(env, wallet, global_policy(env), Some(state), Some(state.worst_score()))
+}
+
+/// Run the pipeline for a wallet on its state in a risk domain
+/// 
+/// Wallets the domain never scored get the unknown default.
+pub(crate) fn evaluate_domain(env: &Env, wallet: &Address, state: Option<&RiskState>) -> RiskDecision {
+    run_pipeline(env, wallet, global_policy(env), state, state.map(RiskState::worst_score))
+}
+
+/// Run the pipeline for a wallet on a single category's score
+/// 
+/// Scored wallets without a score in this category are treated as 0.
+pub(crate) fn evaluate_category(env: &Env, wallet: &Address, category: RiskCategory) -> RiskDecision {
+    let state = SentinelSDK::get_risk(env.clone(), wallet.clone());
+    let score = state
+        .as_ref()
+        .map(|s| s.category_scores.get(category).unwrap_or(0));
+    run_pipeline(env, wallet, global_policy(env), state.as_ref(), score)
+}
+
+/// Run the stages over a wallet's state with the given working score
+fn run_pipeline(
+    env: &Env,
+    wallet: &Address,
+    policy: Policy,
+    state: Option<&RiskState>,
+    score: Option<u32>,
+) -> RiskDecision {
+    let mut eval = Evaluation {
+        wallet: wallet.clone(),
+        policy,
+        last_updated: state.map_or(0, |s| s.last_updated),
+        freeze_until: state.map_or(0, |s| s.freeze_until),
+        score,
+        decision: None,
+    };
+    
+    if SentinelSDK::self_locked_at(env.clone(), wallet.clone()).is_some()
+        || globally_frozen(env, score)
+    {
+        return RiskDecision::Freeze;
+    }
+    for stage in stages(env).iter() {
+        if let StageResult::Settled(decision) = run_stage(env, stage, &mut eval) {
+            return decision;
+        }
+    }
+    
+    if eval.score.is_none() {
+        return eval.policy.unknown_default;
+    }
+    
+    let stale_after = eval.policy.stale_after;
+    if stale_after != 0 && env.ledger().timestamp().saturating_sub(eval.last_updated) > stale_after {
+        return eval.policy.stale_fallback;
+    }
+    
+    eval.decision.unwrap_or(eval.policy.unknown_default)
+}
+
+/// Dispatch a single stage
+fn run_stage(env: &Env, stage: PipelineStage, eval: &mut Evaluation) -> StageResult {
+    match stage {
+        PipelineStage::Override => override_stage(env, eval),
+        PipelineStage::OracleScore => oracle_score_stage(env, eval),
+        PipelineStage::Quarantine => quarantine_stage(env, eval),
+        PipelineStage::Decay => decay_stage(env, eval),
+        PipelineStage::Grace => grace_stage(env, eval),
+    }
+}
+
+/// Active admin overrides settle the decision
+fn override_stage(env: &Env, eval: &mut Evaluation) -> StageResult {
+    match SentinelSDK::active_override(env, &eval.wallet) {
+        Some(decision) => StageResult::Settled(decision),
+        None => StageResult::Continue,
+    }
+}
+
+/// Map the working score to a decision using the evaluation's bands
+/// 
+/// While the Oracle is silent past the degraded-mode threshold, or the
+/// freeze-rate breaker has tripped, that fallback decision settles instead.
+fn oracle_score_stage(env: &Env, eval: &mut Evaluation) -> StageResult {
+    if let StageResult::Settled(decision) = degraded_stage(env, eval) {
+        return StageResult::Settled(decision);
+    }
+    if let Some(decision) = breaker_decision(env) {
+        return StageResult::Settled(decision);
+    }
+    
+    if let Some(score) = eval.score {
+        eval.decision = Some(eval.policy.bands.decide(score));
+    }
+    StageResult::Continue
+}
+
+/// Decay the working score and re-map it through the bands
+fn decay_stage(env: &Env, eval: &mut Evaluation) -> StageResult {
+    if let Some(score) = eval.score {
+        let decayed = effective_score(env, score, eval.last_updated);
+        if decayed != score {
+            eval.score = Some(decayed);
+            eval.decision = Some(eval.policy.bands.decide(decayed));
+        }
+    }
+    StageResult::Continue
+}
+
+/// Downgrade a lapsed quarantine freeze to the release decision
+/// 
+/// The freeze holds while `now < freeze_until` and is released from
+/// `freeze_until` onwards.
+fn quarantine_stage(env: &Env, eval: &mut Evaluation) -> StageResult {
+    if eval.freeze_until != 0
+        && eval.decision == Some(RiskDecision::Freeze)
+        && env.ledger().timestamp() >= eval.freeze_until
+    {
+        eval.decision = Some(quarantine_release(env));
+    }
+    StageResult::Continue
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the decision pipeline stage order (read-only)
+    pub fn get_pipeline(env: Env) -> Vec<PipelineStage> {
+        stages(&env)
+    }
+    
+    /// Set the decision pipeline stage order (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If a stage appears twice or `OracleScore` is missing
+    pub fn set_pipeline(env: Env, stages: Vec<PipelineStage>) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_pipeline(env.clone());
+        Self::apply_pipeline(&env, stages.clone());
+        audit::record(&env, &admin, "set_pipeline", old, stages);
+    }
+    
+    /// Get the decision a lapsed quarantine is released to (read-only)
+    /// 
+    /// Defaults to `Limit` with the current bands' limit amount.
+    pub fn get_quarantine_release(env: Env) -> RiskDecision {
+        quarantine_release(&env)
+    }
+    
+    /// Set the decision a lapsed quarantine is released to (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If `decision` is `Review` or `Freeze`
+    pub fn set_quarantine_release(env: Env, decision: RiskDecision) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = quarantine_release(&env);
+        Self::apply_quarantine_release(&env, decision.clone());
+        audit::record(&env, &admin, "set_quarantine_release", old, decision);
+    }
+    
+    /// Get the decision for never-scored wallets (read-only)
+    pub fn get_unknown_default(env: Env) -> RiskDecision {
+        unknown_default(&env)
+    }
+    
+    /// Set the decision for never-scored wallets (admin only)
+    /// 
+    /// Regulated venues can deny by default (`Freeze`) or cap unknown
+    /// wallets (`Limit(x)`); `Allow` restores the original behavior.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    pub fn set_unknown_default(env: Env, decision: RiskDecision) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = unknown_default(&env);
+        Self::apply_unknown_default(&env, decision.clone());
+        audit::record(&env, &admin, "set_unknown_default", old, decision);
+    }
+    
+    /// Get the score bands used by the OracleScore stage (read-only)
+    pub fn get_score_bands(env: Env) -> ScoreBands {
+        score_bands(&env)
+    }
+    
+    /// Decision a hypothetical score maps to under the current bands
+    /// (read-only)
+    /// 
+    /// Pure band math: overrides, decay, quarantine and staleness are not
+    /// applied, so UIs and the Oracle can see where a score lands without
+    /// submitting a signed payload.
+    /// 
+    /// # Panics
+    /// * `RiskScoreOutOfRange` if `score` is above 100
+    pub fn preview_decision(env: Env, score: u32) -> RiskDecision {
+        preview(&env, &score_bands(&env), score)
+    }
+    
+    /// Set the score bands (admin only)
+    /// 
+    /// New bands apply to reads immediately; stored decisions are
+    /// recomputed on the wallet's next Oracle update.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If thresholds are not ordered within 0-100
+    pub fn set_score_bands(env: Env, bands: ScoreBands) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = score_bands(&env);
+        Self::apply_score_bands(&env, bands.clone());
+        audit::record(&env, &admin, "set_score_bands", old, bands);
+    }
+}
+
+/// Panic unless `stages` includes `OracleScore` and has no duplicates
+pub(crate) fn check_pipeline(stages: &Vec<PipelineStage>) {
+    if !stages.contains(PipelineStage::OracleScore) {
+        panic!("Pipeline must include OracleScore");
+    }
+    for (i, stage) in stages.iter().enumerate() {
+        if stages.first_index_of(stage) != Some(i as u32) {
+            panic!("Duplicate pipeline stage");
+        }
+    }
+}
+
+/// Panic unless a lapsed quarantine may release to `decision`
+pub(crate) fn check_quarantine_release(decision: &RiskDecision) {
+    if matches!(decision, RiskDecision::Review | RiskDecision::Freeze) {
+        panic!("Quarantine must release to Limit or Allow");
+    }
+}
+
+impl SentinelSDK {
+    /// Store the pipeline stage order (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If a stage appears twice or `OracleScore` is missing
+    pub(crate) fn apply_pipeline(env: &Env, stages: Vec<PipelineStage>) {
+        check_pipeline(&stages);
+        env.storage().instance().set(&DataKey::Config(ConfigKey::Pipeline), &stages);
+        env.events().publish((topic::PIPE_SET,), stages);
+    }
+    
+    /// Store the quarantine release decision (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If `decision` is `Review` or `Freeze`
+    pub(crate) fn apply_quarantine_release(env: &Env, decision: RiskDecision) {
+        check_quarantine_release(&decision);
+        env.storage().instance().set(&DataKey::Config(ConfigKey::QuarantineRelease), &decision);
+        env.events().publish((topic::QREL_SET,), decision);
+    }
+    
+    /// Store the unknown-wallet default (authorization checked by caller)
+    pub(crate) fn apply_unknown_default(env: &Env, decision: RiskDecision) {
+        env.storage().instance().set(&DataKey::Config(ConfigKey::UnknownDefault), &decision);
+        env.events().publish((topic::UNK_SET,), decision);
+    }
+    
+    /// Store new score bands (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If thresholds are not ordered within 0-100
+    pub(crate) fn apply_score_bands(env: &Env, bands: ScoreBands) {
+        if !bands.is_valid() {
+            panic!("Invalid score bands");
+        }
+        
+        env.storage().instance().set(&DataKey::Config(ConfigKey::ScoreBands), &bands);
+        env.events().publish((topic::BANDS_SET,), bands);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, BytesN};
+    
+    fn setup(env: &Env) -> (Address, SentinelSDKClient<'_>) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        (contract_id, client)
+    }
+    
+    #[test]
+    fn test_oracle_score_stage_uses_configured_bands() {
+        let env = Env::default();
+        let (contract_id, client) = setup(&env);
+        
+        let mut eval = Evaluation {
+            wallet: Address::generate(&env),
+            policy: Policy::from_bands(ScoreBands::default_bands()),
+            last_updated: 0,
+            freeze_until: 0,
+            score: Some(60),
+            decision: None,
+        };
+        
+        env.as_contract(&contract_id, || {
+            oracle_score_stage(&env, &mut eval);
+        });
+        assert_eq!(eval.decision, Some(RiskDecision::Limit(5000)));
+        
+        client.set_score_bands(&ScoreBands { allow_max: 20, limit_max: 40, review_max: 40, limit_amount: 100 });
+        env.as_contract(&contract_id, || {
+            eval.policy.bands = score_bands(&env);
+            oracle_score_stage(&env, &mut eval);
+        });
+        assert_eq!(eval.decision, Some(RiskDecision::Freeze));
+    }
+    
+    #[test]
+    fn test_preview_follows_configured_bands() {
+        let env = Env::default();
+        let (_, client) = setup(&env);
+        
+        assert_eq!(client.preview_decision(&49), RiskDecision::Allow);
+        assert_eq!(client.preview_decision(&50), RiskDecision::Limit(5000));
+        assert_eq!(client.preview_decision(&100), RiskDecision::Freeze);
+        
+        client.set_score_bands(&ScoreBands { allow_max: 20, limit_max: 40, review_max: 40, limit_amount: 100 });
+        assert_eq!(client.preview_decision(&30), RiskDecision::Limit(100));
+        assert!(client.try_preview_decision(&101).is_err());
+    }
+    
+    #[test]
+    fn test_review_band_sits_between_limit_and_freeze() {
+        let env = Env::default();
+        let (_, client) = setup(&env);
+        
+        client.set_score_bands(&ScoreBands { allow_max: 49, limit_max: 79, review_max: 89, limit_amount: 5000 });
+        assert_eq!(client.preview_decision(&79), RiskDecision::Limit(5000));
+        assert_eq!(client.preview_decision(&85), RiskDecision::Review);
+        assert_eq!(client.preview_decision(&90), RiskDecision::Freeze);
+        assert_eq!(RiskDecision::Review.strictest(RiskDecision::Limit(1)), RiskDecision::Review);
+    }
+    
+    #[test]
+    fn test_override_stage_can_be_disabled() {
+        let env = Env::default();
+        let (_, client) = setup(&env);
+        let wallet = Address::generate(&env);
+        
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        
+        client.set_pipeline(&vec![&env, PipelineStage::OracleScore]);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+    }
+    
+    #[test]
+    fn test_self_lock_holds_without_override_stage() {
+        let env = Env::default();
+        let (_, client) = setup(&env);
+        let wallet = Address::generate(&env);
+        
+        client.self_freeze(&wallet);
+        client.set_pipeline(&vec![&env, PipelineStage::OracleScore]);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+    }
+    
+    #[test]
+    fn test_global_freeze_holds_without_override_stage() {
+        let env = Env::default();
+        let (_, client) = setup(&env);
+        let wallet = Address::generate(&env);
+        
+        client.set_pipeline(&vec![&env, PipelineStage::OracleScore]);
+        client.global_freeze(&client.get_admin(), &true);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Duplicate pipeline stage")]
+    fn test_duplicate_stage_rejected() {
+        let env = Env::default();
+        let (_, client) = setup(&env);
+        
+        client.set_pipeline(&vec![
+            &env,
+            PipelineStage::OracleScore,
+            PipelineStage::OracleScore,
+        ]);
+    }
+    
+    #[test]
+    fn test_unknown_default_is_configurable() {
+        let env = Env::default();
+        let (_, client) = setup(&env);
+        let wallet = Address::generate(&env);
+        
+        assert_eq!(client.get_unknown_default(), RiskDecision::Allow);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        
+        client.set_unknown_default(&RiskDecision::Freeze);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert!(client.is_frozen(&wallet));
+        
+        client.set_unknown_default(&RiskDecision::Limit(250));
+        assert_eq!(client.get_assessment(&wallet).decision, RiskDecision::Limit(250));
+    }
+}
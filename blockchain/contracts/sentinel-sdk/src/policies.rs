@@ -0,0 +1,165 @@
+//! Policy registry.
+//!
+//! Several risk appetites can coexist on one deployment: each `Policy`
+//! carries its own score bands plus fallbacks for stale and never-scored
+//! wallets. Policies are defined by the admin and selected per call
+//! (`check_permission_with_policy`) or per integrator.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::pipeline;
+use crate::types::{DataKey, Policy, RiskDecision};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Define or replace a policy (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If the policy's bands are invalid
+    pub fn set_policy(env: Env, policy_id: u32, policy: Policy) {
+        let admin = Self::require_untimelocked_admin(&env);
+        let old = Self::get_policy(env.clone(), policy_id);
+        Self::apply_policy(&env, policy_id, policy.clone());
+        audit::record(&env, &admin, "set_policy", (policy_id, old), (policy_id, policy));
+    }
+    
+    /// Get a policy (read-only)
+    pub fn get_policy(env: Env, policy_id: u32) -> Option<Policy> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+    }
+    
+    /// Check permission for a wallet under a named policy
+    /// 
+    /// Runs the configured pipeline with the policy's bands; an active
+    /// override still wins. Otherwise never-scored wallets get
+    /// `unknown_default` and stale scores get `stale_fallback`.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Address to check
+    /// * `policy_id` - Policy to apply
+    /// 
+    /// # Panics
+    /// * If the policy does not exist
+    pub fn check_permission_with_policy(env: Env, wallet: Address, policy_id: u32) -> RiskDecision {
+        let policy = Self::get_policy(env.clone(), policy_id)
+            .unwrap_or_else(|| panic!("Unknown policy"));
+        pipeline::evaluate_with_policy(&env, &wallet, policy)
+    }
+    
+    /// Decision a hypothetical score maps to under a named policy's bands
+    /// (read-only; see `preview_decision`)
+    /// 
+    /// # Panics
+    /// * If the policy does not exist
+    /// * `RiskScoreOutOfRange` if `score` is above 100
+    pub fn preview_decision_with_policy(env: Env, score: u32, policy_id: u32) -> RiskDecision {
+        let policy = Self::get_policy(env.clone(), policy_id)
+            .unwrap_or_else(|| panic!("Unknown policy"));
+        pipeline::preview(&env, &policy.bands, score)
+    }
+}
+
+impl SentinelSDK {
+    /// Store a policy and bump its version (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If the policy's bands are invalid
+    pub(crate) fn apply_policy(env: &Env, policy_id: u32, policy: Policy) {
+        if !policy.bands.is_valid() {
+            panic!("Invalid score bands");
+        }
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::Policy(policy_id), &policy);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PolicyVersion(policy_id), &(Self::policy_version(env, policy_id) + 1));
+        env.events().publish((topic::POL_SET,), (policy_id, policy));
+    }
+    
+    /// Number of times a policy has been set (0 if never)
+    pub(crate) fn policy_version(env: &Env, policy_id: u32) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PolicyVersion(policy_id))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Map;
+    use crate::types::{RiskState, ScoreBands};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        BytesN, Vec,
+    };
+    
+    #[test]
+    fn test_policies_coexist() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let strict = Policy {
+            bands: ScoreBands { allow_max: 19, limit_max: 39, review_max: 39, limit_amount: 100 },
+            stale_after: 3_600,
+            stale_fallback: RiskDecision::Freeze,
+            unknown_default: RiskDecision::Limit(100),
+        };
+        client.set_policy(&1, &strict);
+        client.set_policy(&2, &Policy::from_bands(ScoreBands::default_bands()));
+        
+        let wallet = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            let state = RiskState {
+                risk_score: 30,
+                last_updated: 0,
+                decision: RiskDecision::Allow,
+                reason_codes: Vec::new(&env),
+                freeze_until: 0,
+                category_scores: Map::new(&env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(&env, &[0; 32]),
+            };
+            env.storage().persistent().set(&DataKey::Risk(wallet.clone()), &state);
+        });
+        
+        assert_eq!(client.check_permission_with_policy(&wallet, &1), RiskDecision::Limit(100));
+        assert_eq!(client.check_permission_with_policy(&wallet, &2), RiskDecision::Allow);
+        assert_eq!(client.preview_decision_with_policy(&30, &1), RiskDecision::Limit(100));
+        assert_eq!(client.preview_decision_with_policy(&30, &2), RiskDecision::Allow);
+        
+        // Unknown wallet
+        let unknown = Address::generate(&env);
+        assert_eq!(client.check_permission_with_policy(&unknown, &1), RiskDecision::Limit(100));
+        assert_eq!(client.check_permission_with_policy(&unknown, &2), RiskDecision::Allow);
+        
+        // Stale score
+        env.ledger().with_mut(|li| li.timestamp = 3_601);
+        assert_eq!(client.check_permission_with_policy(&wallet, &1), RiskDecision::Freeze);
+        assert_eq!(client.check_permission_with_policy(&wallet, &2), RiskDecision::Allow);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Unknown policy")]
+    fn test_unknown_policy_panics() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.check_permission_with_policy(&Address::generate(&env), &9);
+    }
+}
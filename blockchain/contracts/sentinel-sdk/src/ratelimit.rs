@@ -0,0 +1,204 @@
+//! Per-wallet submission rate limiting.
+//!
+//! Bounds storage churn and event spam from a misbehaving Oracle: with a
+//! `RateLimit` configured, at most `max_updates` scores are accepted per
+//! wallet in each fixed window and the rest are rejected with
+//! `RateLimited`. During an emergency the admin can open a bypass so
+//! urgent rescoring is not throttled.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, RateLimit, SubmissionCount};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Longest bypass the admin can open at once (24 hours)
+pub const MAX_RATE_LIMIT_BYPASS: u64 = 86_400;
+
+/// Submissions counted for the wallet in the current window
+fn current_count(env: &Env, limit: &RateLimit, wallet: &Address) -> SubmissionCount {
+    let now = env.ledger().timestamp();
+    let window_start = now - now % limit.window;
+    
+    env.storage()
+        .persistent()
+        .get::<_, SubmissionCount>(&DataKey::Submissions(wallet.clone()))
+        .filter(|count| count.window_start == window_start)
+        .unwrap_or(SubmissionCount { window_start, count: 0 })
+}
+
+/// Whether the admin bypass is open
+fn bypass_open(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, u64>(&DataKey::RateLimitBypass)
+        .is_some_and(|until| env.ledger().timestamp() < until)
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the submission rate limit, if any (read-only)
+    pub fn get_rate_limit(env: Env) -> Option<RateLimit> {
+        env.storage().instance().get(&DataKey::Config(ConfigKey::RateLimit))
+    }
+    
+    /// Set or remove the per-wallet submission rate limit (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `max_updates` or `window` is 0
+    pub fn set_rate_limit(env: Env, limit: Option<RateLimit>) {
+        let admin = Self::require_admin(&env);
+        
+        let old = Self::get_rate_limit(env.clone());
+        match &limit {
+            Some(limit) => {
+                if limit.max_updates == 0 || limit.window == 0 {
+                    panic!("Rate limit must allow at least one update per window");
+                }
+                env.storage().instance().set(&DataKey::Config(ConfigKey::RateLimit), limit);
+            }
+            None => env.storage().instance().remove(&DataKey::Config(ConfigKey::RateLimit)),
+        }
+        
+        audit::record(&env, &admin, "set_rate_limit", old, limit.clone());
+        env.events().publish((topic::RATE_SET,), limit);
+    }
+    
+    /// Suspend the rate limit for `duration` seconds (admin only)
+    /// 
+    /// A `duration` of 0 closes an open bypass.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `duration` exceeds `MAX_RATE_LIMIT_BYPASS`
+    pub fn bypass_rate_limit(env: Env, duration: u64) {
+        let admin = Self::require_admin(&env);
+        
+        if duration > MAX_RATE_LIMIT_BYPASS {
+            panic!("Bypass too long: max 86400 seconds");
+        }
+        
+        let old: Option<u64> = env.storage().instance().get(&DataKey::RateLimitBypass);
+        let until = env.ledger().timestamp().saturating_add(duration);
+        env.storage().instance().set(&DataKey::RateLimitBypass, &until);
+        audit::record(&env, &admin, "bypass_rate_limit", old, until);
+        env.events().publish((topic::RATE_BYP,), (admin, until));
+    }
+}
+
+impl SentinelSDK {
+    /// Whether another submission for the wallet would exceed the limit
+    pub(crate) fn rate_limited(env: &Env, wallet: &Address) -> bool {
+        match Self::get_rate_limit(env.clone()) {
+            Some(limit) if !bypass_open(env) => current_count(env, &limit, wallet).count >= limit.max_updates,
+            _ => false,
+        }
+    }
+    
+    /// Count an accepted submission against the wallet's window
+    pub(crate) fn record_submission(env: &Env, wallet: &Address) {
+        if let Some(limit) = Self::get_rate_limit(env.clone()) {
+            let mut count = current_count(env, &limit, wallet);
+            count.count += 1;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Submissions(wallet.clone()), &count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::errors::SentinelError;
+    use crate::testutils::TimeTravel;
+    use crate::types::{RiskPayload, PAYLOAD_VERSION_V2};
+    use sentinel_events::RejectionEvent;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        BytesN, IntoVal, Map, Vec,
+    };
+    
+    fn setup(env: &Env) -> (SentinelSDKClient<'_>, SigningKey) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(env),
+            &BytesN::from_array(env, &key.verifying_key().to_bytes()),
+        );
+        (client, key)
+    }
+    
+    fn submit(env: &Env, client: &SentinelSDKClient, key: &SigningKey, wallet: &Address, score: u32) {
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: score,
+            timestamp: env.ledger().timestamp(),
+            reason_codes: Vec::new(env),
+            freeze_duration: 0,
+            category_scores: Map::new(env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(env, &[0; 32]),
+        };
+        let message = crate::crypto::serialize_canonical_json(env, &client.address, &payload);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        client.submit_risk(&payload, &BytesN::from_array(env, &key.sign(&message).to_bytes()));
+    }
+    
+    #[test]
+    fn test_excess_submissions_rejected_until_next_window() {
+        let env = Env::default();
+        let (client, key) = setup(&env);
+        env.set_time(6_000);
+        client.set_rate_limit(&Some(RateLimit { max_updates: 1, window: 60 }));
+        
+        let wallet = Address::generate(&env);
+        submit(&env, &client, &key, &wallet, 10);
+        env.advance_secs(10);
+        submit(&env, &client, &key, &wallet, 20);
+        
+        assert_eq!(client.get_risk(&wallet).unwrap().risk_score, 10);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let data: RejectionEvent = data.into_val(&env);
+        assert_eq!(data.error_code, SentinelError::RateLimited as u32);
+        
+        // Other wallets have their own budget
+        let other = Address::generate(&env);
+        submit(&env, &client, &key, &other, 30);
+        assert!(client.get_risk(&other).is_some());
+        
+        env.advance_secs(50);
+        submit(&env, &client, &key, &wallet, 20);
+        assert_eq!(client.get_risk(&wallet).unwrap().risk_score, 20);
+    }
+    
+    #[test]
+    fn test_admin_bypass_lifts_limit_temporarily() {
+        let env = Env::default();
+        let (client, key) = setup(&env);
+        env.set_time(6_000);
+        client.set_rate_limit(&Some(RateLimit { max_updates: 1, window: 3_600 }));
+        
+        let wallet = Address::generate(&env);
+        submit(&env, &client, &key, &wallet, 10);
+        
+        client.bypass_rate_limit(&120);
+        env.advance_secs(1);
+        submit(&env, &client, &key, &wallet, 95);
+        assert_eq!(client.get_risk(&wallet).unwrap().risk_score, 95);
+        
+        env.advance_secs(120);
+        submit(&env, &client, &key, &wallet, 50);
+        assert_eq!(client.get_risk(&wallet).unwrap().risk_score, 95);
+    }
+}
@@ -0,0 +1,170 @@
+//! Sponsored wallet remediation.
+//!
+//! A freeze with no way out is an indefinite lockout. The Oracle can
+//! instead offer a frozen wallet a signed plan, for example returning
+//! stolen funds to a given address. When the wallet completes the plan
+//! on-chain through `remediate`, its freeze is downgraded to a Limit.
+//!
+//! The Oracle signs the 32-byte SHA-256 of the plan's XDR encoding (the
+//! plan hash); the wallet accepts by that hash.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, token, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::types::{DataKey, RemediationPlan, RiskDecision, Signature, TransitionCause};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Hash identifying a remediation plan (what the Oracle signs)
+pub(crate) fn plan_hash(env: &Env, plan: &RemediationPlan) -> BytesN<32> {
+    env.crypto().sha256(&plan.clone().to_xdr(env)).to_bytes()
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Publish an Oracle-signed remediation plan for a wallet
+    /// 
+    /// Anyone may relay the plan; the signature authorizes it. A newer
+    /// plan replaces any outstanding one.
+    /// 
+    /// # Returns
+    /// * The plan hash the wallet accepts with `remediate`
+    /// 
+    /// # Panics
+    /// * If the plan has already expired or its amount is not positive
+    /// * If the signature is invalid
+    pub fn offer_remediation(env: Env, plan: RemediationPlan, signature: Signature) -> BytesN<32> {
+        if plan.expires_at <= env.ledger().timestamp() {
+            panic!("Remediation plan has expired");
+        }
+        if plan.amount <= 0 {
+            panic!("Remediation amount must be positive");
+        }
+        
+        let hash = plan_hash(&env, &plan);
+        Self::authenticate_message(&env, &Bytes::from(hash.clone()), &signature);
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::Remediation(plan.wallet.clone()), &plan);
+        env.events().publish((topic::REM_OFFER,), (plan.wallet, hash.clone()));
+        
+        hash
+    }
+    
+    /// Complete a remediation plan and downgrade the wallet's freeze
+    /// 
+    /// Transfers the plan's funds from the wallet to its recipient, then
+    /// pins the wallet to `Limit(limit_amount)` as an override for
+    /// `PIN_DURATION`.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Frozen wallet; must authorize the call (and the transfer)
+    /// * `plan_hash` - Hash of the plan being accepted
+    /// 
+    /// # Panics
+    /// * If there is no outstanding plan with this hash, or it has expired
+    /// * If the wallet is not currently frozen
+    pub fn remediate(env: Env, wallet: Address, plan_hash: BytesN<32>) {
+        wallet.require_auth();
+        
+        let plan = Self::get_remediation_plan(env.clone(), wallet.clone())
+            .filter(|plan| self::plan_hash(&env, plan) == plan_hash)
+            .expect("No matching remediation plan");
+        let now = env.ledger().timestamp();
+        if plan.expires_at <= now {
+            panic!("Remediation plan has expired");
+        }
+        if Self::check_permission(env.clone(), wallet.clone()) != RiskDecision::Freeze {
+            panic!("Wallet is not frozen");
+        }
+        
+        token::Client::new(&env, &plan.token).transfer(&wallet, &plan.recipient, &plan.amount);
+        
+        let decision = RiskDecision::Limit(plan.limit_amount);
+        Self::pin_decision(&env, &wallet, decision.clone(), wallet.clone(), TransitionCause::Remediation);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Remediation(wallet.clone()));
+        
+        env.events().publish((topic::REMEDIED,), (wallet, plan_hash, decision));
+    }
+    
+    /// Get a wallet's outstanding remediation plan (read-only)
+    pub fn get_remediation_plan(env: Env, wallet: Address) -> Option<RemediationPlan> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Remediation(wallet))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{testutils::Address as _, token::StellarAssetClient};
+    
+    fn setup(env: &Env) -> (SentinelSDKClient<'_>, SigningKey) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(env),
+            &BytesN::from_array(env, &key.verifying_key().to_bytes()),
+        );
+        (client, key)
+    }
+    
+    fn sign(env: &Env, key: &SigningKey, plan: &RemediationPlan) -> Signature {
+        let hash = plan_hash(env, plan).to_array();
+        BytesN::from_array(env, &key.sign(&hash).to_bytes())
+    }
+    
+    #[test]
+    fn test_completed_plan_downgrades_freeze_to_limit() {
+        let env = Env::default();
+        let (client, key) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        
+        let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+        StellarAssetClient::new(&env, &token).mint(&wallet, &1_000);
+        let victim = Address::generate(&env);
+        
+        let plan = RemediationPlan {
+            wallet: wallet.clone(),
+            token: token.clone(),
+            recipient: victim.clone(),
+            amount: 800,
+            limit_amount: 100,
+            expires_at: 1_000,
+        };
+        let hash = client.offer_remediation(&plan, &sign(&env, &key, &plan));
+        assert_eq!(client.get_remediation_plan(&wallet), Some(plan));
+        
+        client.remediate(&wallet, &hash);
+        
+        assert_eq!(token::Client::new(&env, &token).balance(&victim), 800);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Limit(100));
+        assert_eq!(client.get_remediation_plan(&wallet), None);
+        assert_eq!(
+            client.transition_log(&wallet).last().unwrap().cause,
+            TransitionCause::Remediation
+        );
+    }
+    
+    #[test]
+    #[should_panic(expected = "No matching remediation plan")]
+    fn test_remediate_requires_offered_plan() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        client.remediate(&wallet, &BytesN::from_array(&env, &[0u8; 32]));
+    }
+}
@@ -0,0 +1,268 @@
+//! On-demand scoring (Oracle pull model).
+//!
+//! Besides its push schedule, the Oracle watches `SCORE_REQ` events and
+//! scores the requested wallets. A request stays outstanding until a
+//! payload for the wallet is stored or `SCORE_REQUEST_TTL` passes, so an
+//! Oracle that missed an event can still check `get_score_request`.
+//!
+//! Requests come from registered integrators and cost a fee in a
+//! configured token, so nobody can flood the Oracle for free. Fees
+//! accumulate in the contract until withdrawn with `withdraw_fees`, which
+//! lets Oracle operators fund the service on-chain.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, token, Address, Env};
+
+use crate::audit;
+use crate::ttl::LEDGERS_PER_DAY;
+use crate::types::{ConfigKey, DataKey, ScoreFeeConfig, WalletKey};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Seconds an unanswered request stays outstanding (1 day)
+pub const SCORE_REQUEST_TTL: u64 = 86_400;
+
+/// Fees collected and not yet withdrawn (in the configured fee token)
+fn accrued_fees(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::AccruedFees).unwrap_or(0)
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Ask the Oracle to score a wallet now
+    /// 
+    /// Requesting a wallet that is already outstanding is a no-op and
+    /// charges nothing.
+    /// 
+    /// # Arguments
+    /// * `integrator` - Registered protocol paying the fee; must authorize
+    /// * `wallet` - Address to score
+    /// 
+    /// # Panics
+    /// * If `integrator` has not authorized the call
+    /// * If `integrator` is not registered (see `register_integrator`)
+    /// * If no request fee is configured
+    pub fn request_score(env: Env, integrator: Address, wallet: Address) {
+        integrator.require_auth();
+        if Self::get_integrator_policy(env.clone(), integrator.clone()).is_none() {
+            panic!("Unregistered integrator");
+        }
+        let fee = Self::get_score_fee(env.clone()).expect("Score request fee not configured");
+        
+        if Self::get_score_request(env.clone(), wallet.clone()).is_some() {
+            return;
+        }
+        
+        token::Client::new(&env, &fee.token).transfer(
+            &integrator,
+            &env.current_contract_address(),
+            &fee.amount,
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::AccruedFees, &(accrued_fees(&env) + fee.amount));
+        
+        let now = env.ledger().timestamp();
+        let key = WalletKey::ScoreRequest(wallet.clone());
+        env.storage().persistent().set(&key, &now);
+        env.storage().persistent().extend_ttl(&key, LEDGERS_PER_DAY, LEDGERS_PER_DAY);
+        
+        env.events().publish((topic::SCORE_REQ,), (wallet, now));
+    }
+    
+    /// When a wallet's outstanding score request was made (read-only)
+    /// 
+    /// # Returns
+    /// * `None` if there is no request, or it was answered or expired
+    pub fn get_score_request(env: Env, wallet: Address) -> Option<u64> {
+        let requested_at: u64 = env.storage().persistent().get(&WalletKey::ScoreRequest(wallet))?;
+        let expires_at = requested_at.saturating_add(SCORE_REQUEST_TTL);
+        (env.ledger().timestamp() < expires_at).then_some(requested_at)
+    }
+    
+    /// Configure the score request fee (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `amount` is not positive
+    /// * If the token changes while fees in the old token are unwithdrawn
+    pub fn set_score_fee(env: Env, config: ScoreFeeConfig) {
+        let admin = Self::require_admin(&env);
+        
+        if config.amount <= 0 {
+            panic!("Score request fee must be positive");
+        }
+        let token_changed = Self::get_score_fee(env.clone())
+            .map(|current| current.token != config.token)
+            .unwrap_or(false);
+        if token_changed && accrued_fees(&env) > 0 {
+            panic!("Withdraw accrued fees before changing the fee token");
+        }
+        
+        let old = Self::get_score_fee(env.clone());
+        env.storage().instance().set(&DataKey::Config(ConfigKey::ScoreFee), &config);
+        audit::record(&env, &admin, "set_score_fee", old, config.clone());
+        env.events().publish((topic::FEE_SET,), config);
+    }
+    
+    /// Get the score request fee configuration (read-only)
+    pub fn get_score_fee(env: Env) -> Option<ScoreFeeConfig> {
+        env.storage().instance().get(&DataKey::Config(ConfigKey::ScoreFee))
+    }
+    
+    /// Fees collected and not yet withdrawn (read-only)
+    pub fn get_accrued_fees(env: Env) -> i128 {
+        accrued_fees(&env)
+    }
+    
+    /// Send all accrued fees to `to` (admin only)
+    /// 
+    /// # Returns
+    /// * Amount withdrawn
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    pub fn withdraw_fees(env: Env, to: Address) -> i128 {
+        let admin = Self::require_admin(&env);
+        
+        let amount = accrued_fees(&env);
+        if amount > 0 {
+            let fee = Self::get_score_fee(env.clone()).expect("Score request fee not configured");
+            token::Client::new(&env, &fee.token).transfer(&env.current_contract_address(), &to, &amount);
+            env.storage().instance().set(&DataKey::AccruedFees, &0i128);
+        }
+        
+        audit::record(&env, &admin, "withdraw_fees", amount, (to.clone(), amount));
+        env.events().publish((topic::FEE_WDRAW,), (admin, to, amount));
+        amount
+    }
+}
+
+impl SentinelSDK {
+    /// Mark a wallet's request as served (called when its score is stored)
+    pub(crate) fn clear_score_request(env: &Env, wallet: &Address) {
+        let key = WalletKey::ScoreRequest(wallet.clone());
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Map;
+    use crate::types::{Policy, RiskPayload, ScoreBands, PAYLOAD_VERSION_V2};
+    use crate::testutils::{TimeTravel, DAY};
+    use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, BytesN, Vec};
+    
+    /// Client with a 25-unit request fee and a registered integrator holding 100
+    fn setup(env: &Env) -> (SentinelSDKClient<'_>, Address, Address) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        
+        let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+        client.set_score_fee(&ScoreFeeConfig { token: token.clone(), amount: 25 });
+        
+        client.set_policy(
+            &1,
+            &Policy::from_bands(ScoreBands { allow_max: 30, limit_max: 70, review_max: 70, limit_amount: 5000 }),
+        );
+        let integrator = Address::generate(env);
+        client.register_integrator(&client.get_admin(), &integrator, &1);
+        StellarAssetClient::new(env, &token).mint(&integrator, &100);
+        (client, integrator, token)
+    }
+    
+    #[test]
+    fn test_request_pending_until_scored() {
+        let env = Env::default();
+        env.set_time(1_000);
+        let (client, integrator, _) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        client.request_score(&integrator, &wallet);
+        assert_eq!(client.get_score_request(&wallet), Some(1_000));
+        
+        env.as_contract(&client.address, || {
+            let payload = RiskPayload {
+                version: PAYLOAD_VERSION_V2,
+                wallet: wallet.clone(),
+                risk_score: 10,
+                timestamp: 1_000,
+                reason_codes: Vec::new(&env),
+                freeze_duration: 0,
+                category_scores: Map::new(&env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(&env, &[0; 32]),
+            };
+            SentinelSDK::store_risk(&env, &payload);
+        });
+        assert_eq!(client.get_score_request(&wallet), None);
+    }
+    
+    #[test]
+    fn test_requests_expire() {
+        let env = Env::default();
+        env.set_time(1_000);
+        let (client, integrator, _) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        client.request_score(&integrator, &wallet);
+        env.set_time(1_000 + DAY);
+        assert_eq!(client.get_score_request(&wallet), None);
+        
+        // An expired request can be made (and is charged) again
+        client.request_score(&integrator, &wallet);
+        assert_eq!(client.get_score_request(&wallet), Some(1_000 + DAY));
+        assert_eq!(client.get_accrued_fees(), 50);
+    }
+    
+    #[test]
+    fn test_fees_accrue_and_withdraw() {
+        let env = Env::default();
+        let (client, integrator, token) = setup(&env);
+        
+        let wallet = Address::generate(&env);
+        client.request_score(&integrator, &wallet);
+        // Already outstanding: not charged again
+        client.request_score(&integrator, &wallet);
+        client.request_score(&integrator, &Address::generate(&env));
+        assert_eq!(client.get_accrued_fees(), 50);
+        
+        let treasury = Address::generate(&env);
+        assert_eq!(client.withdraw_fees(&treasury), 50);
+        assert_eq!(token::Client::new(&env, &token).balance(&treasury), 50);
+        assert_eq!(token::Client::new(&env, &token).balance(&integrator), 50);
+        assert_eq!(client.get_accrued_fees(), 0);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Unregistered integrator")]
+    fn test_unregistered_caller_cannot_request() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+        client.request_score(&Address::generate(&env), &Address::generate(&env));
+    }
+    
+    #[test]
+    #[should_panic(expected = "Score request fee not configured")]
+    fn test_requests_need_a_fee() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_policy(
+            &1,
+            &Policy::from_bands(ScoreBands { allow_max: 30, limit_max: 70, review_max: 70, limit_amount: 5000 }),
+        );
+        let integrator = Address::generate(&env);
+        client.register_integrator(&client.get_admin(), &integrator, &1);
+        client.request_score(&integrator, &Address::generate(&env));
+    }
+}
@@ -0,0 +1,159 @@
+//! Role-based access control.
+//!
+//! Besides the admin set at initialization, addresses can be granted
+//! narrower roles so that powers are split between keys: a
+//! security-council multisig can hold `Pauser` (global freeze) while a
+//! different key holds `OracleManager` (key trials and promotion) and
+//! compliance officers hold `Overrider`. Role-gated entrypoints take the
+//! acting `caller`, which must authorize. The admin address, and holders
+//! of the `Admin` role, pass every role check.
+//!
+//! Grants are persistent entries extended per the risk-state TTL config
+//! (see `ttl.rs`) when granted and whenever they are checked, so a role
+//! in use is not archived out from under its holder.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::types::{DataKey, Role};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Extend a role grant per the TTL config (entry must exist)
+fn extend_role_ttl(env: &Env, key: &DataKey) {
+    let config = SentinelSDK::get_ttl_config(env.clone());
+    env.storage()
+        .persistent()
+        .extend_ttl(key, config.threshold, config.extend_to);
+}
+
+/// Whether `account` was granted `role`, extending the grant if so
+fn granted(env: &Env, role: Role, account: &Address) -> bool {
+    let key = DataKey::Role(role, account.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    extend_role_ttl(env, &key);
+    true
+}
+
+impl SentinelSDK {
+    /// Require `caller`'s authorization and that it holds `role`
+    /// 
+    /// # Panics
+    /// * If `caller` does not hold the role
+    pub(crate) fn require_role(env: &Env, caller: &Address, role: Role) {
+        caller.require_auth();
+        
+        if !Self::has_role(env.clone(), role, caller.clone()) {
+            panic!("Caller lacks the required role");
+        }
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Check whether an address holds a role (read-only)
+    /// 
+    /// True for the admin address and for `Admin` role holders, whatever
+    /// the role.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        account == Self::get_admin(env.clone())
+            || granted(&env, Role::Admin, &account)
+            || granted(&env, role, &account)
+    }
+    
+    /// Grant a role to an address (`Admin` role)
+    /// 
+    /// # Panics
+    /// * If caller does not hold the `Admin` role
+    pub fn grant_role(env: Env, caller: Address, role: Role, account: Address) {
+        Self::require_role(&env, &caller, Role::Admin);
+        
+        let key = DataKey::Role(role, account.clone());
+        env.storage().persistent().set(&key, &true);
+        extend_role_ttl(&env, &key);
+        audit::record(&env, &caller, "grant_role", (), (role, account.clone()));
+        env.events().publish((topic::ROLE_GRNT, role), (account, caller));
+    }
+    
+    /// Revoke a role from an address (`Admin` role)
+    /// 
+    /// The admin address keeps every role regardless.
+    /// 
+    /// # Panics
+    /// * If caller does not hold the `Admin` role
+    pub fn revoke_role(env: Env, caller: Address, role: Role, account: Address) {
+        Self::require_role(&env, &caller, Role::Admin);
+        
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Role(role, account.clone()));
+        audit::record(&env, &caller, "revoke_role", (role, account.clone()), ());
+        env.events().publish((topic::ROLE_RVK, role), (account, caller));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GlobalFreezeConfig, RiskDecision, TtlConfig};
+    use soroban_sdk::{
+        testutils::{storage::Persistent as _, Address as _, Ledger},
+        BytesN,
+    };
+    
+    #[test]
+    fn test_roles_split_powers() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_global_freeze_config(&GlobalFreezeConfig { score_floor: 0, duration: 3_600 });
+        
+        let council = Address::generate(&env);
+        assert!(!client.has_role(&Role::Pauser, &council));
+        assert!(client.try_global_freeze(&council, &true).is_err());
+        
+        client.grant_role(&admin, &Role::Pauser, &council);
+        assert!(client.has_role(&Role::Pauser, &council));
+        client.global_freeze(&council, &true);
+        assert!(client.get_global_freeze().is_some());
+        
+        // Pause power does not extend to overrides
+        let wallet = Address::generate(&env);
+        assert!(client.try_set_override(&council, &wallet, &RiskDecision::Allow, &0).is_err());
+        
+        client.revoke_role(&admin, &Role::Pauser, &council);
+        assert!(client.try_global_freeze(&council, &false).is_err());
+        assert!(client.has_role(&Role::Overrider, &admin));
+    }
+    
+    #[test]
+    fn test_grants_are_extended_on_grant_and_check() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_ttl_config(&TtlConfig { threshold: 4_500, extend_to: 5_000 });
+        
+        let council = Address::generate(&env);
+        let ttl = || {
+            env.as_contract(&contract_id, || {
+                env.storage().persistent().get_ttl(&DataKey::Role(Role::Pauser, council.clone()))
+            })
+        };
+        client.grant_role(&admin, &Role::Pauser, &council);
+        assert_eq!(ttl(), 5_000);
+        
+        env.ledger().with_mut(|li| li.sequence_number += 1_000);
+        assert!(client.has_role(&Role::Pauser, &council));
+        assert_eq!(ttl(), 5_000);
+    }
+}
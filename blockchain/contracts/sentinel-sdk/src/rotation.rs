@@ -0,0 +1,258 @@
+//! Blue/green Oracle key rotation.
+//!
+//! The admin starts a trial for a candidate key. During the trial the old
+//! key stays live while payloads signed by the candidate are verified and
+//! stored in a shadow slot that never affects decisions. Each shadow
+//! submission is compared with the live decision, building a report the
+//! operator reviews before promoting the candidate.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::crypto::require_signature;
+use crate::events;
+use crate::heartbeat::record_oracle_activity;
+use crate::types::{DataKey, KeyTrial, PublicKey, RiskPayload, RiskState, Role, Signature};
+use crate::{pipeline, SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Start a trial for a candidate Oracle key (`OracleManager` role)
+    /// 
+    /// # Arguments
+    /// * `caller` - Role holder starting the trial (must authorize)
+    /// * `candidate` - Ed25519 public key to trial
+    /// * `duration` - Minimum trial length in seconds before promotion
+    /// 
+    /// # Panics
+    /// * If caller does not hold the `OracleManager` role
+    /// * In Soroban-auth mode (see `initialize_with_oracle_address`)
+    /// * If a trial is already running
+    pub fn start_key_trial(env: Env, caller: Address, candidate: PublicKey, duration: u64) {
+        Self::require_role(&env, &caller, Role::OracleManager);
+        
+        if Self::get_oracle_address(env.clone()).is_some() {
+            panic!("Key trials are not available in Soroban-auth mode");
+        }
+        if Self::get_key_trial(env.clone()).is_some() {
+            panic!("Key trial already running");
+        }
+        
+        let now = env.ledger().timestamp();
+        let trial = KeyTrial {
+            candidate: candidate.clone(),
+            started_at: now,
+            ends_at: now.saturating_add(duration),
+            shadow_submissions: 0,
+            matches: 0,
+            mismatches: 0,
+        };
+        env.storage().instance().set(&DataKey::KeyTrial, &trial);
+        audit::record(&env, &caller, "start_key_trial", (), trial.clone());
+        
+        env.events().publish(
+            (topic::TRIAL_ON,),
+            (candidate, trial.ends_at)
+        );
+    }
+    
+    /// Get the running key trial and its comparison report (read-only)
+    pub fn get_key_trial(env: Env) -> Option<KeyTrial> {
+        env.storage().instance().get(&DataKey::KeyTrial)
+    }
+    
+    /// Submit a payload signed by the candidate key (shadow only)
+    /// 
+    /// The payload is verified and validated like `submit_risk`, stored in
+    /// the wallet's shadow slot and compared against the live decision.
+    /// Live state and decisions are never touched.
+    /// 
+    /// # Panics
+    /// * If no trial is running
+    /// * `InvalidSignature` if the signature does not verify against the
+    ///   candidate key
+    pub fn submit_shadow_risk(env: Env, payload: RiskPayload, signature: Signature) {
+        let mut trial = Self::get_key_trial(env.clone()).expect("No key trial running");
+        
+        require_signature(&env, &payload, &signature, &trial.candidate);
+        
+        if let Err(error) = Self::validate_payload(&env, &payload) {
+            events::publish_rejected(&env, &payload, error);
+            return;
+        }
+        
+        let shadow = RiskState::from_payload(&payload, &pipeline::score_bands(&env));
+        let live = Self::get_risk(env.clone(), payload.wallet.clone());
+        
+        trial.shadow_submissions += 1;
+        let matches = match &live {
+            Some(live) if live.decision == shadow.decision => {
+                trial.matches += 1;
+                true
+            }
+            Some(_) => {
+                trial.mismatches += 1;
+                false
+            }
+            None => false,
+        };
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::ShadowRisk(payload.wallet.clone()), &shadow);
+        env.storage().instance().set(&DataKey::KeyTrial, &trial);
+        record_oracle_activity(&env, &trial.candidate);
+        
+        env.events().publish(
+            (topic::SHADOW,),
+            (payload.wallet, shadow.decision, live.map(|s| s.decision), matches)
+        );
+    }
+    
+    /// Get the latest shadow state recorded for a wallet (read-only)
+    pub fn get_shadow_risk(env: Env, wallet: Address) -> Option<RiskState> {
+        env.storage().persistent().get(&DataKey::ShadowRisk(wallet))
+    }
+    
+    /// Promote the candidate key to live Oracle key (`OracleManager` role)
+    /// 
+    /// # Panics
+    /// * If caller does not hold the `OracleManager` role
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If no trial is running or the trial window has not elapsed
+    pub fn promote_trial_key(env: Env, caller: Address) {
+        Self::require_role(&env, &caller, Role::OracleManager);
+        Self::require_no_timelock(&env);
+        let old = Self::oracle_pubkey(&env);
+        let trial = Self::get_key_trial(env.clone()).expect("No key trial running");
+        Self::apply_trial_key(&env, trial.candidate);
+        audit::record(&env, &caller, "promote_trial_key", old, Self::oracle_pubkey(&env));
+    }
+    
+    /// Abandon the running key trial (`OracleManager` role)
+    /// 
+    /// # Panics
+    /// * If caller does not hold the `OracleManager` role
+    pub fn cancel_key_trial(env: Env, caller: Address) {
+        Self::require_role(&env, &caller, Role::OracleManager);
+        
+        let old = Self::get_key_trial(env.clone());
+        env.storage().instance().remove(&DataKey::KeyTrial);
+        audit::record(&env, &caller, "cancel_key_trial", old, ());
+        env.events().publish((topic::TRIAL_OFF,), ());
+    }
+}
+
+impl SentinelSDK {
+    /// Make the trial candidate the live key (authorization checked by caller)
+    /// 
+    /// # Arguments
+    /// * `candidate` - Key the promotion was approved for
+    /// 
+    /// # Panics
+    /// * If no trial is running or the trial window has not elapsed
+    /// * If the running trial is for a different key than `candidate`
+    pub(crate) fn apply_trial_key(env: &Env, candidate: PublicKey) {
+        let trial = Self::get_key_trial(env.clone()).expect("No key trial running");
+        if trial.candidate != candidate {
+            panic!("Key trial candidate changed since the promotion was queued");
+        }
+        if env.ledger().timestamp() < trial.ends_at {
+            panic!("Key trial still running");
+        }
+        
+        let storage = env.storage().instance();
+        let old_key = Self::oracle_pubkey(env);
+        storage.set(&DataKey::Oracle, &trial.candidate);
+        storage.remove(&DataKey::KeyTrial);
+        
+        env.events().publish(
+            (topic::KEY_ROT,),
+            (old_key, trial.candidate, trial.matches, trial.mismatches)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::sign_for;
+    use soroban_sdk::Map;
+    use crate::types::{RiskDecision, PAYLOAD_VERSION_V2};
+    use ed25519_dalek::SigningKey;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        BytesN, Vec,
+    };
+    
+    fn payload(env: &Env, wallet: &Address, risk_score: u32) -> RiskPayload {
+        RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score,
+            timestamp: 0,
+            reason_codes: Vec::new(env),
+            freeze_duration: 0,
+            category_scores: Map::new(env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(env, &[0; 32]),
+        }
+    }
+    
+    #[test]
+    fn test_shadow_submissions_are_compared_but_not_live() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let old_key = SigningKey::from_bytes(&[7u8; 32]);
+        let new_key = SigningKey::from_bytes(&[8u8; 32]);
+        let old_pub = BytesN::from_array(&env, &old_key.verifying_key().to_bytes());
+        let new_pub = BytesN::from_array(&env, &new_key.verifying_key().to_bytes());
+        client.initialize(&Address::generate(&env), &old_pub);
+        
+        let wallet = Address::generate(&env);
+        let live = payload(&env, &wallet, 90);
+        client.submit_risk(&live, &sign_for(&client, &old_key, &live));
+        
+        client.start_key_trial(&client.get_admin(), &new_pub, &3_600);
+        
+        // Candidate agrees on Freeze
+        let shadow = payload(&env, &wallet, 85);
+        client.submit_shadow_risk(&shadow, &sign_for(&client, &new_key, &shadow));
+        // Candidate disagrees: would allow
+        let shadow = payload(&env, &wallet, 10);
+        client.submit_shadow_risk(&shadow, &sign_for(&client, &new_key, &shadow));
+        
+        let trial = client.get_key_trial().unwrap();
+        assert_eq!(trial.shadow_submissions, 2);
+        assert_eq!(trial.matches, 1);
+        assert_eq!(trial.mismatches, 1);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.get_shadow_risk(&wallet).unwrap().risk_score, 10);
+        
+        env.ledger().with_mut(|li| li.timestamp = 3_600);
+        client.promote_trial_key(&client.get_admin());
+        assert_eq!(client.get_oracle_pubkey(), Some(new_pub));
+        assert_eq!(client.get_key_trial(), None);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Key trial still running")]
+    fn test_promotion_waits_for_trial_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        client.start_key_trial(&client.get_admin(), &BytesN::from_array(&env, &[1u8; 32]), &3_600);
+        client.promote_trial_key(&client.get_admin());
+    }
+}
@@ -0,0 +1,313 @@
+//! Version-tolerant reads of stored risk states.
+//!
+//! `RiskState` has gained fields since the first deployment (reason codes
+//! and quarantine expiry, then category scores, then model provenance and
+//! evidence hashes) and is now stored packed (see `packing`). Entries written by older
+//! versions keep their original layout until rewritten, and decoding them
+//! as the current struct would trap. Every read therefore goes through
+//! `load_risk_state`, which identifies the layout from the fields present
+//! and fills missing ones with their defaults, so `check_permission` keeps
+//! answering correctly while `migrate_risk_states` rewrites entries in the
+//! background.
+//!
+//! The first deployment stored the Oracle key under the `oracle` symbol
+//! and risk states under the bare wallet address, before every key became
+//! a `DataKey`. `migrate_storage_keys` moves those entries to their
+//! `DataKey`; run it right after upgrading such a deployment, since a
+//! wallet reads as unknown until its entry has been moved.
+
+use sentinel_events::topic;
+use soroban_sdk::{
+    contractimpl, symbol_short, Address, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::audit;
+use crate::ttl::{read_risk_entry, write_risk_entry};
+use crate::types::{DataKey, RiskDecision, RiskState};
+use crate::{packing, SentinelSDK, SentinelSDKClient, MAX_BATCH_SIZE};
+
+/// `{decision, last_updated, risk_score}`
+pub const RISK_STATE_V1: u32 = 1;
+/// V1 + `reason_codes`, `freeze_until`
+pub const RISK_STATE_V2: u32 = 2;
+/// V2 + `category_scores`
+pub const RISK_STATE_V3: u32 = 3;
+/// V3 with score, timestamp and decision packed into `packed`
+pub const RISK_STATE_V4: u32 = 4;
+/// V4 + `model_version`, `confidence`
+pub const RISK_STATE_V5: u32 = 5;
+/// V5 + `evidence_hash` (current)
+pub const RISK_STATE_V6: u32 = 6;
+
+/// Move an instance entry to its `DataKey`, if present under `old`
+fn move_instance<K: IntoVal<Env, Val>>(env: &Env, old: &K, new: &DataKey) -> bool {
+    let storage = env.storage().instance();
+    match storage.get::<_, Val>(old) {
+        Some(value) => {
+            storage.set(new, &value);
+            storage.remove(old);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Move a persistent entry to its `DataKey`, if present under `old`
+fn move_persistent<K: IntoVal<Env, Val>>(env: &Env, old: &K, new: &DataKey) -> bool {
+    let storage = env.storage().persistent();
+    match storage.get::<_, Val>(old) {
+        Some(value) => {
+            storage.set(new, &value);
+            storage.remove(old);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Whether contract-wide entries still sit under pre-`DataKey` keys
+pub(crate) fn has_legacy_keys(env: &Env) -> bool {
+    env.storage().instance().has(&symbol_short!("oracle"))
+}
+
+/// Move the Oracle key off its pre-`DataKey` symbol
+fn migrate_oracle_key(env: &Env) -> u32 {
+    move_instance(env, &symbol_short!("oracle"), &DataKey::Oracle) as u32
+}
+
+/// Layout version of a stored entry, from the fields it carries
+fn layout_version(env: &Env, fields: &Map<Symbol, Val>) -> u32 {
+    if fields.contains_key(symbol_short!("packed")) {
+        if fields.contains_key(Symbol::new(env, "evidence_hash")) {
+            RISK_STATE_V6
+        } else if fields.contains_key(Symbol::new(env, "model_version")) {
+            RISK_STATE_V5
+        } else {
+            RISK_STATE_V4
+        }
+    } else if fields.contains_key(Symbol::new(env, "category_scores")) {
+        RISK_STATE_V3
+    } else if fields.contains_key(Symbol::new(env, "freeze_until")) {
+        RISK_STATE_V2
+    } else {
+        RISK_STATE_V1
+    }
+}
+
+/// Decode a field, falling back to `default` if the layout predates it
+fn field<T: TryFromVal<Env, Val>>(env: &Env, fields: &Map<Symbol, Val>, name: Symbol, default: T) -> T {
+    match fields.get(name) {
+        Some(val) => T::try_from_val(env, &val).unwrap_or_else(|_| panic!("Corrupt risk state")),
+        None => default,
+    }
+}
+
+/// Read a wallet's risk state in whichever layout it was stored
+/// 
+/// Returns the entry converted to the current `RiskState` and the layout
+/// version it was stored in.
+pub(crate) fn load_risk_state_versioned(env: &Env, wallet: &Address) -> Option<(RiskState, u32)> {
+    let fields: Map<Symbol, Val> = read_risk_entry(env, wallet)?;
+    let version = layout_version(env, &fields);
+    
+    let (risk_score, last_updated, decision) = if version >= RISK_STATE_V4 {
+        packing::unpack(field(env, &fields, symbol_short!("packed"), 0u128))
+    } else {
+        (
+            field(env, &fields, Symbol::new(env, "risk_score"), 0u32),
+            field(env, &fields, Symbol::new(env, "last_updated"), 0u64),
+            field(env, &fields, symbol_short!("decision"), RiskDecision::Allow),
+        )
+    };
+    let state = RiskState {
+        risk_score,
+        last_updated,
+        decision,
+        reason_codes: field(env, &fields, Symbol::new(env, "reason_codes"), Vec::new(env)),
+        freeze_until: field(env, &fields, Symbol::new(env, "freeze_until"), 0u64),
+        category_scores: field(env, &fields, Symbol::new(env, "category_scores"), Map::new(env)),
+        model_version: field(env, &fields, Symbol::new(env, "model_version"), 0u32),
+        confidence: field(env, &fields, Symbol::new(env, "confidence"), 0u32),
+        evidence_hash: field(env, &fields, Symbol::new(env, "evidence_hash"), BytesN::from_array(env, &[0; 32])),
+    };
+    Some((state, version))
+}
+
+/// Write a wallet's risk state in the current layout
+pub(crate) fn store_risk_state(env: &Env, wallet: &Address, state: &RiskState) {
+    write_risk_entry(env, wallet, &state.to_packed());
+}
+
+/// Read a wallet's risk state in whichever layout it was stored
+pub(crate) fn load_risk_state(env: &Env, wallet: &Address) -> Option<RiskState> {
+    load_risk_state_versioned(env, wallet).map(|(state, _)| state)
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Layout version a wallet's risk state is stored in (read-only)
+    /// 
+    /// `None` if the wallet has never been scored.
+    pub fn risk_state_version(env: Env, wallet: Address) -> Option<u32> {
+        load_risk_state_versioned(&env, &wallet).map(|(_, version)| version)
+    }
+    
+    /// Rewrite wallets' risk states in the current layout (admin only)
+    /// 
+    /// Entries already current and unscored wallets are skipped. Safe to
+    /// run while the contract is live: reads understand every layout.
+    /// 
+    /// # Returns
+    /// * Number of entries rewritten
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If more than `MAX_BATCH_SIZE` wallets are given
+    pub fn migrate_risk_states(env: Env, wallets: Vec<Address>) -> u32 {
+        let admin = Self::require_admin(&env);
+        if wallets.len() > MAX_BATCH_SIZE {
+            panic!("Batch too large: max 50 wallets");
+        }
+        
+        let mut migrated = 0;
+        for wallet in wallets.iter() {
+            if let Some((state, version)) = load_risk_state_versioned(&env, &wallet) {
+                if version < RISK_STATE_V6 {
+                    store_risk_state(&env, &wallet, &state);
+                    migrated += 1;
+                }
+            }
+        }
+        
+        audit::record(&env, &admin, "migrate_risk_states", (), (wallets, migrated));
+        env.events().publish((topic::MIGRATED,), migrated);
+        migrated
+    }
+    
+    /// Move entries stored under pre-`DataKey` keys to their `DataKey`
+    /// 
+    /// The Oracle key is moved on the first call, and the risk state of
+    /// each of `wallets` stored under its bare address. Entries already
+    /// moved are skipped, so the call can be repeated in batches. Anyone
+    /// may call this: entries are moved unchanged, and deployments this
+    /// old have no admin to authorize it.
+    /// 
+    /// # Returns
+    /// * Number of entries moved
+    /// 
+    /// # Panics
+    /// * If more than `MAX_BATCH_SIZE` wallets are given
+    pub fn migrate_storage_keys(env: Env, wallets: Vec<Address>) -> u32 {
+        if wallets.len() > MAX_BATCH_SIZE {
+            panic!("Batch too large: max 50 wallets");
+        }
+        
+        let mut moved = migrate_oracle_key(&env);
+        for wallet in wallets.iter() {
+            moved += move_persistent(&env, &wallet, &DataKey::Risk(wallet.clone())) as u32;
+        }
+        
+        env.events().publish((topic::KEYS_MIG,), moved);
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PackedRiskState;
+    use soroban_sdk::{testutils::Address as _, BytesN, IntoVal};
+    
+    #[test]
+    fn test_legacy_entry_readable_and_migrated() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        // Entry as written by the first deployment
+        let wallet = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            let mut legacy: Map<Symbol, Val> = Map::new(&env);
+            legacy.set(symbol_short!("decision"), RiskDecision::Freeze.into_val(&env));
+            legacy.set(Symbol::new(&env, "last_updated"), 7u64.into_val(&env));
+            legacy.set(Symbol::new(&env, "risk_score"), 95u32.into_val(&env));
+            env.storage().persistent().set(&DataKey::Risk(wallet.clone()), &legacy);
+        });
+        
+        assert_eq!(client.risk_state_version(&wallet), Some(RISK_STATE_V1));
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.get_risk(&wallet).unwrap().risk_score, 95);
+        
+        assert_eq!(client.migrate_risk_states(&soroban_sdk::vec![&env, wallet.clone()]), 1);
+        assert_eq!(client.risk_state_version(&wallet), Some(RISK_STATE_V6));
+        let stored: PackedRiskState = env.as_contract(&contract_id, || {
+            env.storage().persistent().get(&DataKey::Risk(wallet.clone())).unwrap()
+        });
+        assert_eq!(RiskState::from_packed(&stored).last_updated, 7);
+        
+        // Already current: nothing to do
+        assert_eq!(client.migrate_risk_states(&soroban_sdk::vec![&env, wallet]), 0);
+    }
+    
+    #[test]
+    fn test_pre_datakey_entries_moved() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let wallet = Address::generate(&env);
+        let state = RiskState {
+            risk_score: 90,
+            last_updated: 0,
+            decision: RiskDecision::Freeze,
+            reason_codes: Vec::new(&env),
+            freeze_until: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        
+        // Storage as written before `DataKey`
+        let oracle = BytesN::from_array(&env, &[0u8; 32]);
+        env.as_contract(&contract_id, || {
+            env.storage().instance().set(&symbol_short!("oracle"), &oracle);
+            env.storage().persistent().set(&wallet, &state);
+        });
+        
+        assert_eq!(client.get_risk(&wallet), None);
+        
+        let moved = client.migrate_storage_keys(&soroban_sdk::vec![&env, wallet.clone()]);
+        assert_eq!(moved, 2);
+        assert_eq!(client.get_oracle_pubkey(), Some(oracle));
+        assert_eq!(client.get_risk(&wallet), Some(state));
+        
+        // Nothing left under old keys
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().persistent().has(&wallet));
+            assert!(!env.storage().instance().has(&symbol_short!("oracle")));
+        });
+        assert_eq!(client.migrate_storage_keys(&soroban_sdk::vec![&env, wallet]), 0);
+    }
+    
+    #[test]
+    #[should_panic(expected = "SDK already initialized")]
+    fn test_unmigrated_deployment_cannot_be_reinitialized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("oracle"), &BytesN::from_array(&env, &[0u8; 32]));
+        });
+        
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+    }
+}
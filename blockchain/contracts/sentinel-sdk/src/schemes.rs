@@ -0,0 +1,143 @@
+//! Oracle signature schemes.
+//!
+//! Oracle keys are addressed by a 32-byte `PublicKey` everywhere (live
+//! key, key trials, activity). An Ed25519 key is its own id. Some HSMs and
+//! passkey-based signers only produce secp256r1 (P-256) signatures: the
+//! admin registers such a key once, it is addressed by the SHA-256 of its
+//! SEC1 encoding from then on, and `crypto.rs` verifies signatures for
+//! that id with secp256r1 instead of Ed25519.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Bytes, Env};
+
+use crate::audit;
+use crate::types::{DataKey, P256PublicKey, PublicKey, SignatureScheme};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Register a secp256r1 Oracle key (admin only)
+    /// 
+    /// Registering only makes the key known; it becomes the live Oracle
+    /// key through `initialize` or a key trial, using the returned id.
+    /// 
+    /// # Arguments
+    /// * `key` - SEC1 uncompressed public key (`0x04 || x || y`)
+    /// 
+    /// # Returns
+    /// * Key id (SHA-256 of `key`)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock is configured (use `queue_action`)
+    /// * If the key is not SEC1 uncompressed
+    pub fn register_p256_key(env: Env, key: P256PublicKey) -> PublicKey {
+        let admin = Self::require_untimelocked_admin(&env);
+        let id = Self::apply_p256_key(&env, key.clone());
+        audit::record(&env, &admin, "register_p256_key", (), (id.clone(), key));
+        id
+    }
+    
+    /// Get the scheme an Oracle key id verifies with (read-only)
+    pub fn get_signature_scheme(env: Env, key: PublicKey) -> SignatureScheme {
+        match Self::p256_key(&env, &key) {
+            Some(_) => SignatureScheme::Secp256r1,
+            None => SignatureScheme::Ed25519,
+        }
+    }
+}
+
+impl SentinelSDK {
+    /// Store a P-256 key under its id (authorization checked by caller)
+    /// 
+    /// # Panics
+    /// * If the key is not SEC1 uncompressed
+    pub(crate) fn apply_p256_key(env: &Env, key: P256PublicKey) -> PublicKey {
+        check_p256_key(&key);
+        let id = p256_key_id(env, &key);
+        env.storage()
+            .persistent()
+            .set(&DataKey::P256Key(id.clone()), &key);
+        env.events().publish((topic::P256_REG,), (id.clone(), key));
+        id
+    }
+    
+    /// Registered P-256 key behind an id (`None` for Ed25519 keys)
+    pub(crate) fn p256_key(env: &Env, key: &PublicKey) -> Option<P256PublicKey> {
+        env.storage().persistent().get(&DataKey::P256Key(key.clone()))
+    }
+}
+
+/// Panic unless the key is SEC1 uncompressed
+pub(crate) fn check_p256_key(key: &P256PublicKey) {
+    if key.get(0) != Some(0x04) {
+        panic!("P-256 key must be SEC1 uncompressed");
+    }
+}
+
+/// Id a P-256 key is addressed by
+pub(crate) fn p256_key_id(env: &Env, key: &P256PublicKey) -> PublicKey {
+    env.crypto().sha256(&Bytes::from(key.clone())).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::errors::SentinelError;
+    use crate::types::{RiskDecision, RiskPayload, PAYLOAD_VERSION_V2};
+    use p256::ecdsa::{signature::Signer, Signature as P256Signature, SigningKey};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Map, Vec};
+    
+    #[test]
+    fn test_p256_oracle_submits_risk() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        
+        let signing_key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let point = signing_key.verifying_key().to_encoded_point(false);
+        let key = BytesN::from_array(&env, point.as_bytes().try_into().unwrap());
+        let id = p256_key_id(&env, &key);
+        
+        client.initialize(&Address::generate(&env), &id);
+        assert_eq!(client.get_signature_scheme(&id), SignatureScheme::Ed25519);
+        assert_eq!(client.register_p256_key(&key), id);
+        assert_eq!(client.get_signature_scheme(&id), SignatureScheme::Secp256r1);
+        
+        let wallet = Address::generate(&env);
+        let payload = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score: 90,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let message = crate::crypto::serialize_canonical_json(&env, &client.address, &payload);
+        let message: std::vec::Vec<u8> = message.iter().collect();
+        let signature: P256Signature = signing_key.sign(&message);
+        let signature = signature.normalize_s().unwrap_or(signature);
+        let signature = BytesN::from_array(&env, &signature.to_bytes().into());
+        assert!(client.verify_payload(&payload, &signature));
+        
+        // Bad signatures are a typed error, not a trap
+        let mut tampered = payload.clone();
+        tampered.risk_score = 10;
+        assert!(!client.verify_payload(&tampered, &signature));
+        assert_eq!(
+            client.try_submit_risk(&tampered, &signature),
+            Err(Ok(SentinelError::InvalidSignature))
+        );
+        
+        client.submit_risk(&payload, &signature);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+    }
+}
@@ -0,0 +1,154 @@
+//! Self-service wallet lock.
+//!
+//! A user who suspects their key is compromised can freeze their own
+//! wallet immediately with `self_freeze`. The lock is a separate flag, so
+//! Oracle updates and admin overrides do not clear it, and it wins over
+//! every other decision. `self_unfreeze` lifts it once the admin-configured
+//! cooldown has passed, which stops an attacker holding the key from
+//! undoing the lock straight away.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::pipeline;
+use crate::types::{ConfigKey, DataKey, RiskDecision, TransitionCause};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Seconds a self-lock must hold before it can be lifted (default 0)
+pub(crate) fn self_lock_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::SelfLockCooldown))
+        .unwrap_or(0)
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Freeze the caller's own wallet regardless of its score
+    /// 
+    /// Locking an already locked wallet keeps the original lock time.
+    /// 
+    /// # Panics
+    /// * If `wallet` has not authorized the call
+    pub fn self_freeze(env: Env, wallet: Address) {
+        wallet.require_auth();
+        
+        if Self::self_locked_at(env.clone(), wallet.clone()).is_some() {
+            return;
+        }
+        
+        let now = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::SelfLock(wallet.clone()), &now);
+        Self::record_transition(
+            &env,
+            &wallet,
+            RiskDecision::Freeze,
+            TransitionCause::SelfLock,
+            Some(wallet.clone()),
+        );
+        
+        env.events().publish((topic::SELF_FRZ,), wallet);
+    }
+    
+    /// Lift the caller's self-lock
+    /// 
+    /// The wallet returns to its regular decision (score, overrides).
+    /// 
+    /// # Panics
+    /// * If `wallet` has not authorized the call
+    /// * If the wallet is not self-locked
+    /// * If the cooldown since the lock has not passed
+    pub fn self_unfreeze(env: Env, wallet: Address) {
+        wallet.require_auth();
+        
+        let locked_at = Self::self_locked_at(env.clone(), wallet.clone())
+            .expect("Wallet is not self-locked");
+        if env.ledger().timestamp() < locked_at.saturating_add(self_lock_cooldown(&env)) {
+            panic!("Self-lock cooldown has not passed");
+        }
+        
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SelfLock(wallet.clone()));
+        let decision = pipeline::evaluate(&env, &wallet);
+        Self::record_transition(&env, &wallet, decision, TransitionCause::SelfLock, Some(wallet.clone()));
+        
+        env.events().publish((topic::SELF_UNFZ,), wallet);
+    }
+    
+    /// When the wallet locked itself, if it is self-locked (read-only)
+    pub fn self_locked_at(env: Env, wallet: Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SelfLock(wallet))
+    }
+    
+    /// Set the self-lock cooldown in seconds (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    pub fn set_self_lock_cooldown(env: Env, seconds: u64) {
+        let admin = Self::require_admin(&env);
+        
+        let old = self_lock_cooldown(&env);
+        env.storage().instance().set(&DataKey::Config(ConfigKey::SelfLockCooldown), &seconds);
+        audit::record(&env, &admin, "set_self_lock_cooldown", old, seconds);
+        env.events().publish((topic::SELFCD_ST,), seconds);
+    }
+    
+    /// Get the self-lock cooldown in seconds (read-only)
+    pub fn get_self_lock_cooldown(env: Env) -> u64 {
+        self_lock_cooldown(&env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutils::{TimeTravel, HOUR};
+    use soroban_sdk::{testutils::Address as _, BytesN};
+    
+    #[test]
+    fn test_self_lock_wins_and_respects_cooldown() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.set_time(1_000);
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_self_lock_cooldown(&HOUR);
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Allow, &0);
+        client.self_freeze(&wallet);
+        
+        // Even an admin Allow override does not lift the lock
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        assert_eq!(client.self_locked_at(&wallet), Some(1_000));
+        
+        env.advance_hours(1);
+        client.self_unfreeze(&wallet);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(client.self_locked_at(&wallet), None);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Self-lock cooldown has not passed")]
+    fn test_unlock_before_cooldown_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_self_lock_cooldown(&3_600);
+        
+        let wallet = Address::generate(&env);
+        client.self_freeze(&wallet);
+        client.self_unfreeze(&wallet);
+    }
+}
@@ -0,0 +1,231 @@
+//! Oracle bond withdrawal and slashing.
+//!
+//! The bond posted with `post_oracle_bond` also backs the Oracle's
+//! honesty. The operator can only take it back through a timelocked
+//! withdrawal, and while the bond is held the admin can slash it for
+//! provably bad attestations: two payloads for the same wallet and
+//! timestamp with different contents, both signed by the live Oracle key.
+//! Slashed amounts go to a recipient chosen by the admin (e.g. a treasury
+//! or the affected users' compensation fund).
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, token, Address, Env};
+
+use crate::audit;
+use crate::crypto::{require_signature, serialize_canonical_json};
+use crate::types::{BondWithdrawal, ConfigKey, DataKey, RiskPayload, Signature};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Delay between requesting and completing a bond withdrawal (7 days)
+pub const BOND_WITHDRAWAL_DELAY: u64 = 604_800;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Start withdrawing part of the Oracle bond (operator only)
+    /// 
+    /// The amount stays bonded, and slashable, until the withdrawal
+    /// completes. A new request replaces the pending one and restarts the
+    /// timelock.
+    /// 
+    /// # Returns
+    /// * Timestamp from which `withdraw_oracle_bond` succeeds
+    /// 
+    /// # Panics
+    /// * If no bond has been posted
+    /// * If amount is not positive or exceeds the bond
+    pub fn request_bond_withdrawal(env: Env, amount: i128) -> u64 {
+        let bond = Self::get_oracle_bond(env.clone()).expect("No Oracle bond posted");
+        bond.operator.require_auth();
+        
+        if amount <= 0 || amount > bond.amount {
+            panic!("Withdrawal amount must be positive and within the bond");
+        }
+        
+        let available_at = env.ledger().timestamp().saturating_add(BOND_WITHDRAWAL_DELAY);
+        env.storage()
+            .instance()
+            .set(&DataKey::BondWithdrawal, &BondWithdrawal { amount, available_at });
+        
+        env.events().publish(
+            (topic::UNBOND,),
+            (bond.operator, amount, available_at)
+        );
+        
+        available_at
+    }
+    
+    /// Get the pending bond withdrawal, if any (read-only)
+    pub fn get_bond_withdrawal(env: Env) -> Option<BondWithdrawal> {
+        env.storage().instance().get(&DataKey::BondWithdrawal)
+    }
+    
+    /// Complete a pending bond withdrawal once its timelock has passed
+    /// 
+    /// Pays the requested amount, or what remains of the bond if it was
+    /// slashed in the meantime, to the operator.
+    /// 
+    /// # Returns
+    /// * Amount paid out
+    /// 
+    /// # Panics
+    /// * If no withdrawal is pending or its timelock has not passed
+    pub fn withdraw_oracle_bond(env: Env) -> i128 {
+        let withdrawal = Self::get_bond_withdrawal(env.clone()).expect("No bond withdrawal pending");
+        if env.ledger().timestamp() < withdrawal.available_at {
+            panic!("Bond withdrawal is still timelocked");
+        }
+        
+        let mut bond = Self::get_oracle_bond(env.clone()).expect("No Oracle bond posted");
+        bond.operator.require_auth();
+        
+        let paid = withdrawal.amount.min(bond.amount);
+        token::Client::new(&env, &bond.token).transfer(
+            &env.current_contract_address(),
+            &bond.operator,
+            &paid,
+        );
+        
+        bond.amount -= paid;
+        env.storage().instance().set(&DataKey::Config(ConfigKey::ChallengeBond), &bond);
+        env.storage().instance().remove(&DataKey::BondWithdrawal);
+        
+        env.events().publish(
+            (topic::BOND_WD,),
+            (bond.operator, paid, bond.amount)
+        );
+        
+        paid
+    }
+    
+    /// Slash the Oracle bond for contradictory attestations (admin only)
+    /// 
+    /// `first` and `second` must be different payloads for the same
+    /// wallet and timestamp, each with a valid live Oracle signature.
+    /// 
+    /// # Arguments
+    /// * `recipient` - Receives the slashed amount
+    /// * `amount` - Amount to slash (capped at the remaining bond)
+    /// 
+    /// # Returns
+    /// * Amount slashed
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If no bond has been posted or amount is not positive
+    /// * If the payloads do not contradict each other
+    /// * `InvalidSignature` if either signature does not verify
+    pub fn slash_oracle_bond(
+        env: Env,
+        first: RiskPayload,
+        first_signature: Signature,
+        second: RiskPayload,
+        second_signature: Signature,
+        recipient: Address,
+        amount: i128,
+    ) -> i128 {
+        let admin = Self::require_admin(&env);
+        
+        if amount <= 0 {
+            panic!("Slash amount must be positive");
+        }
+        let mut bond = Self::get_oracle_bond(env.clone()).expect("No Oracle bond posted");
+        
+        let contract = env.current_contract_address();
+        if first.wallet != second.wallet
+            || first.timestamp != second.timestamp
+            || serialize_canonical_json(&env, &contract, &first) == serialize_canonical_json(&env, &contract, &second)
+        {
+            panic!("Attestations do not contradict each other");
+        }
+        let key = Self::oracle_pubkey(&env);
+        require_signature(&env, &first, &first_signature, &key);
+        require_signature(&env, &second, &second_signature, &key);
+        
+        let slashed = amount.min(bond.amount);
+        token::Client::new(&env, &bond.token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &slashed,
+        );
+        
+        bond.amount -= slashed;
+        env.storage().instance().set(&DataKey::Config(ConfigKey::ChallengeBond), &bond);
+        audit::record(&env, &admin, "slash_oracle_bond", bond.amount + slashed, (recipient.clone(), slashed));
+        
+        env.events().publish(
+            (topic::SLASHED,),
+            (admin, first.wallet, first.timestamp, recipient, slashed, bond.amount)
+        );
+        
+        slashed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use crate::testutils::sign_for;
+    use crate::testutils::TimeTravel;
+    use crate::types::{OracleBond, PAYLOAD_VERSION_V2};
+    use ed25519_dalek::SigningKey;
+    use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, BytesN, Map, Vec};
+    
+    #[test]
+    fn test_contradictory_attestations_slash_bond_before_withdrawal() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        client.initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+        );
+        
+        let operator = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+        StellarAssetClient::new(&env, &token).mint(&operator, &100_000);
+        client.post_oracle_bond(&operator, &token, &100_000);
+        assert_eq!(client.request_bond_withdrawal(&100_000), BOND_WITHDRAWAL_DELAY);
+        
+        let wallet = Address::generate(&env);
+        let first = RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet,
+            risk_score: 10,
+            timestamp: 0,
+            reason_codes: Vec::new(&env),
+            freeze_duration: 0,
+            category_scores: Map::new(&env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        let mut second = first.clone();
+        second.risk_score = 95;
+        
+        let treasury = Address::generate(&env);
+        let slashed = client.slash_oracle_bond(
+            &first,
+            &sign_for(&client, &key, &first),
+            &second,
+            &sign_for(&client, &key, &second),
+            &treasury,
+            &40_000,
+        );
+        assert_eq!(slashed, 40_000);
+        assert_eq!(token::Client::new(&env, &token).balance(&treasury), 40_000);
+        
+        assert!(client.try_withdraw_oracle_bond().is_err());
+        env.set_time(BOND_WITHDRAWAL_DELAY);
+        assert_eq!(client.withdraw_oracle_bond(), 60_000);
+        assert_eq!(
+            client.get_oracle_bond(),
+            Some(OracleBond { operator: operator.clone(), token: token.clone(), amount: 0 })
+        );
+        assert_eq!(token::Client::new(&env, &token).balance(&operator), 60_000);
+    }
+}
@@ -0,0 +1,104 @@
+//! Aggregate submission statistics.
+//!
+//! Counters maintained as Oracle scores are stored, so dashboards and
+//! monitoring can read the contract's health in one call. Frozen,
+//! in-review and limited counts follow the stored Oracle decisions: overrides,
+//! quarantine lapses and expired temporary entries are not reflected.
+
+use soroban_sdk::{contractimpl, Env};
+
+use crate::types::{DataKey, RiskDecision, Stats};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Adjust frozen/in-review/limited counts for one decision entering (`+1`) or
+/// leaving (`-1`) the stored set
+fn count_decision(stats: &mut Stats, decision: &RiskDecision, delta: i32) {
+    let counter = match decision {
+        RiskDecision::Freeze => &mut stats.frozen,
+        RiskDecision::Review => &mut stats.in_review,
+        RiskDecision::Limit(_) => &mut stats.limited,
+        RiskDecision::Allow => return,
+    };
+    *counter = counter.saturating_add_signed(delta);
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Aggregate submission statistics (read-only)
+    pub fn get_stats(env: Env) -> Stats {
+        env.storage().instance().get(&DataKey::Stats).unwrap_or(Stats {
+            total_submissions: 0,
+            frozen: 0,
+            limited: 0,
+            in_review: 0,
+            last_submission_ledger: 0,
+        })
+    }
+}
+
+impl SentinelSDK {
+    /// Count a stored Oracle score that replaced `previous`
+    pub(crate) fn record_stats(env: &Env, previous: Option<&RiskDecision>, current: &RiskDecision) {
+        let mut stats = Self::get_stats(env.clone());
+        stats.total_submissions += 1;
+        stats.last_submission_ledger = env.ledger().sequence();
+        if let Some(previous) = previous {
+            count_decision(&mut stats, previous, -1);
+        }
+        count_decision(&mut stats, current, 1);
+        env.storage().instance().set(&DataKey::Stats, &stats);
+    }
+    
+    /// Uncount an erased risk state
+    pub(crate) fn forget_stats(env: &Env, erased: &RiskDecision) {
+        let mut stats = Self::get_stats(env.clone());
+        count_decision(&mut stats, erased, -1);
+        env.storage().instance().set(&DataKey::Stats, &stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RiskPayload, PAYLOAD_VERSION_V2};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Map, Vec};
+    
+    #[test]
+    fn test_counters_track_decision_changes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        env.ledger().with_mut(|li| li.sequence_number = 42);
+        env.as_contract(&contract_id, || {
+            for (wallet, risk_score) in [(&a, 95u32), (&b, 60), (&a, 60), (&b, 10)] {
+                let payload = RiskPayload {
+                    version: PAYLOAD_VERSION_V2,
+                    wallet: wallet.clone(),
+                    risk_score,
+                    timestamp: 0,
+                    reason_codes: Vec::new(&env),
+                    freeze_duration: 0,
+                    category_scores: Map::new(&env),
+                    model_version: 0,
+                    confidence: 0,
+                    evidence_hash: BytesN::from_array(&env, &[0; 32]),
+                };
+                SentinelSDK::store_risk(&env, &payload);
+            }
+        });
+        
+        let stats = client.get_stats();
+        assert_eq!(stats.total_submissions, 4);
+        assert_eq!((stats.frozen, stats.limited), (0, 1));
+        assert_eq!(stats.last_submission_ledger, 42);
+        
+        client.clear_risk(&a, &false);
+        assert_eq!(client.get_stats().limited, 0);
+    }
+}
@@ -0,0 +1,293 @@
+//! Risk-change subscriptions.
+//!
+//! Protocols that must react to decision changes (a lending market pausing
+//! an account's borrows) subscribe instead of polling. Only registered
+//! integrators may subscribe. When a stored score changes a wallet's
+//! decision, the change is queued for every subscriber for that wallet and
+//! every global subscriber; `deliver_notifications` then calls
+//! `on_risk_change(wallet, old, new)` on the subscriber for each queued
+//! change.
+//!
+//! Delivery runs in its own transaction, never inside `submit_risk`: a
+//! callback that traps or exhausts the budget can only fail its own
+//! delivery, not the Oracle's submission. Subscriber counts and queues are
+//! bounded so queueing stays cheap; a full queue drops its oldest change,
+//! which subscribers can recover from the `DEC_CHG` events.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, vec, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::types::{DataKey, RiskChange, RiskDecision, WalletKey};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Maximum subscribers per wallet, and separately for global subscriptions
+pub const MAX_SUBSCRIBERS: u32 = 5;
+
+/// Maximum undelivered changes queued per subscriber
+pub const MAX_PENDING_NOTIFICATIONS: u32 = 16;
+
+fn subscribers(env: &Env, wallet: &Option<Address>) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Subscribers(wallet.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn pending(env: &Env, subscriber: &Address) -> Vec<RiskChange> {
+    env.storage()
+        .persistent()
+        .get(&WalletKey::Notifications(subscriber.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Subscribe a registered integrator to decision changes
+    /// 
+    /// The subscriber must implement
+    /// `on_risk_change(wallet: Address, old: RiskDecision, new: RiskDecision)`.
+    /// Subscribing twice is a no-op.
+    /// 
+    /// # Arguments
+    /// * `subscriber` - Contract to notify; must authorize the call
+    /// * `wallet` - Wallet to watch, or `None` for every wallet
+    /// 
+    /// # Panics
+    /// * If `subscriber` has not authorized the call
+    /// * If `subscriber` is not registered (see `register_integrator`)
+    /// * If `MAX_SUBSCRIBERS` is already reached for this scope
+    pub fn subscribe(env: Env, subscriber: Address, wallet: Option<Address>) {
+        subscriber.require_auth();
+        if Self::get_integrator_policy(env.clone(), subscriber.clone()).is_none() {
+            panic!("Unregistered integrator");
+        }
+        
+        let mut list = subscribers(&env, &wallet);
+        if list.contains(&subscriber) {
+            return;
+        }
+        if list.len() >= MAX_SUBSCRIBERS {
+            panic!("Too many subscribers: max 5");
+        }
+        list.push_back(subscriber.clone());
+        
+        env.storage().persistent().set(&DataKey::Subscribers(wallet.clone()), &list);
+        env.events().publish((topic::SUBSCRIBE,), (subscriber, wallet));
+    }
+    
+    /// Remove a subscription
+    /// 
+    /// # Panics
+    /// * If `subscriber` has not authorized the call
+    pub fn unsubscribe(env: Env, subscriber: Address, wallet: Option<Address>) {
+        subscriber.require_auth();
+        
+        let mut list = subscribers(&env, &wallet);
+        if let Some(index) = list.first_index_of(&subscriber) {
+            list.remove(index);
+            env.storage().persistent().set(&DataKey::Subscribers(wallet.clone()), &list);
+            env.events().publish((topic::UNSUB,), (subscriber, wallet));
+        }
+    }
+    
+    /// Get the subscribers for a wallet, or the global ones for `None` (read-only)
+    pub fn get_subscribers(env: Env, wallet: Option<Address>) -> Vec<Address> {
+        subscribers(&env, &wallet)
+    }
+    
+    /// Get the changes queued for a subscriber, oldest first (read-only)
+    pub fn get_pending_notifications(env: Env, subscriber: Address) -> Vec<RiskChange> {
+        pending(&env, &subscriber)
+    }
+    
+    /// Deliver a subscriber's queued changes (callable by anyone)
+    /// 
+    /// Calls `on_risk_change` once per queued change, oldest first, and
+    /// empties the queue. A failing callback emits `NTFY_ERR` and its
+    /// change is dropped.
+    /// 
+    /// # Returns
+    /// * Number of changes delivered successfully
+    pub fn deliver_notifications(env: Env, subscriber: Address) -> u32 {
+        let queue = pending(&env, &subscriber);
+        if queue.is_empty() {
+            return 0;
+        }
+        env.storage()
+            .persistent()
+            .remove(&WalletKey::Notifications(subscriber.clone()));
+        
+        let func = Symbol::new(&env, "on_risk_change");
+        let mut delivered = 0;
+        for change in queue.iter() {
+            let args: Vec<Val> = vec![
+                &env,
+                change.wallet.into_val(&env),
+                change.old.into_val(&env),
+                change.new.into_val(&env),
+            ];
+            let result = env.try_invoke_contract::<(), soroban_sdk::Error>(&subscriber, &func, args);
+            if matches!(result, Ok(Ok(()))) {
+                delivered += 1;
+            } else {
+                env.events().publish((topic::NTFY_ERR,), (subscriber.clone(), change.wallet));
+            }
+        }
+        delivered
+    }
+}
+
+impl SentinelSDK {
+    /// Queue a decision change for the wallet's and the global subscribers
+    /// 
+    /// Does nothing if the decision did not change. Subscribers that are
+    /// no longer registered integrators are skipped.
+    pub(crate) fn notify_subscribers(env: &Env, wallet: &Address, old: RiskDecision, new: RiskDecision) {
+        if old == new {
+            return;
+        }
+        
+        let mut targets = subscribers(env, &Some(wallet.clone()));
+        for subscriber in subscribers(env, &None).iter() {
+            if !targets.contains(&subscriber) {
+                targets.push_back(subscriber);
+            }
+        }
+        
+        let change = RiskChange { wallet: wallet.clone(), old, new };
+        for subscriber in targets.iter() {
+            if Self::get_integrator_policy(env.clone(), subscriber.clone()).is_none() {
+                continue;
+            }
+            
+            let mut queue = pending(env, &subscriber);
+            if queue.len() >= MAX_PENDING_NOTIFICATIONS {
+                queue.pop_front();
+            }
+            queue.push_back(change.clone());
+            env.storage()
+                .persistent()
+                .set(&WalletKey::Notifications(subscriber), &queue);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Policy, ScoreBands};
+    use soroban_sdk::{contract, symbol_short, testutils::Address as _, BytesN};
+    
+    /// Lending market stub that records the last change it was told about
+    #[contract]
+    struct Market;
+    
+    #[contractimpl]
+    impl Market {
+        pub fn on_risk_change(env: Env, wallet: Address, old: RiskDecision, new: RiskDecision) {
+            env.storage().instance().set(&symbol_short!("last"), &(wallet, old, new));
+        }
+    }
+    
+    fn last_change(env: &Env, market: &Address) -> Option<(Address, RiskDecision, RiskDecision)> {
+        env.as_contract(market, || env.storage().instance().get(&symbol_short!("last")))
+    }
+    
+    fn setup(env: &Env) -> (SentinelSDKClient<'_>, Address) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        client.set_policy(
+            &1,
+            &Policy::from_bands(ScoreBands { allow_max: 30, limit_max: 70, review_max: 70, limit_amount: 5000 }),
+        );
+        (client, contract_id)
+    }
+    
+    fn register(client: &SentinelSDKClient, integrator: &Address) {
+        client.register_integrator(&client.get_admin(), integrator, &1);
+    }
+    
+    #[test]
+    fn test_subscribers_notified_on_decision_change() {
+        let env = Env::default();
+        let (client, contract_id) = setup(&env);
+        
+        let watched = Address::generate(&env);
+        let other = Address::generate(&env);
+        let wallet_market = env.register_contract(None, Market);
+        let global_market = env.register_contract(None, Market);
+        register(&client, &wallet_market);
+        register(&client, &global_market);
+        client.subscribe(&wallet_market, &Some(watched.clone()));
+        client.subscribe(&global_market, &None);
+        
+        env.as_contract(&contract_id, || {
+            SentinelSDK::notify_subscribers(&env, &other, RiskDecision::Allow, RiskDecision::Freeze);
+        });
+        assert_eq!(client.get_pending_notifications(&wallet_market).len(), 0);
+        assert_eq!(client.get_pending_notifications(&global_market).len(), 1);
+        assert_eq!(last_change(&env, &global_market), None);
+        
+        assert_eq!(client.deliver_notifications(&global_market), 1);
+        assert_eq!(
+            last_change(&env, &global_market),
+            Some((other, RiskDecision::Allow, RiskDecision::Freeze))
+        );
+        assert_eq!(client.get_pending_notifications(&global_market).len(), 0);
+        
+        env.as_contract(&contract_id, || {
+            SentinelSDK::notify_subscribers(&env, &watched, RiskDecision::Allow, RiskDecision::Limit(10));
+        });
+        assert_eq!(client.deliver_notifications(&wallet_market), 1);
+        assert_eq!(
+            last_change(&env, &wallet_market),
+            Some((watched, RiskDecision::Allow, RiskDecision::Limit(10)))
+        );
+    }
+    
+    #[test]
+    fn test_failing_subscriber_does_not_block() {
+        let env = Env::default();
+        let (client, contract_id) = setup(&env);
+        
+        // The SDK itself has no on_risk_change
+        register(&client, &contract_id);
+        client.subscribe(&contract_id, &None);
+        env.as_contract(&contract_id, || {
+            for _ in 0..=MAX_PENDING_NOTIFICATIONS {
+                SentinelSDK::notify_subscribers(
+                    &env,
+                    &Address::generate(&env),
+                    RiskDecision::Allow,
+                    RiskDecision::Freeze,
+                );
+            }
+        });
+        assert_eq!(client.get_pending_notifications(&contract_id).len(), MAX_PENDING_NOTIFICATIONS);
+        assert_eq!(client.deliver_notifications(&contract_id), 0);
+        assert_eq!(client.get_pending_notifications(&contract_id).len(), 0);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Unregistered integrator")]
+    fn test_unregistered_contract_cannot_subscribe() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        client.subscribe(&Address::generate(&env), &None);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Too many subscribers")]
+    fn test_subscriber_count_is_bounded() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        for _ in 0..=MAX_SUBSCRIBERS {
+            let subscriber = Address::generate(&env);
+            register(&client, &subscriber);
+            client.subscribe(&subscriber, &None);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Test-only time-travel and signing helpers.
+//!
+//! Staleness, decay, grace periods and cooldowns all depend on ledger
+//! time. These helpers move the ledger clock in named units and assert the
+//! time-driven outcomes, so tests read as "a day later the score is stale"
+//! instead of repeating ledger arithmetic.
+
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Ledger, Address, BytesN, Env};
+
+use crate::crypto::serialize_canonical_json;
+use crate::types::{RiskPayload, Signature};
+use crate::{SentinelSDKClient, STALE_AFTER_SECONDS};
+
+/// Seconds per hour
+pub(crate) const HOUR: u64 = 3_600;
+
+/// Seconds per day
+pub(crate) const DAY: u64 = 24 * HOUR;
+
+/// Semantic ledger clock controls for `Env`
+pub(crate) trait TimeTravel {
+    /// Set the ledger timestamp
+    fn set_time(&self, timestamp: u64);
+    
+    /// Move the ledger clock forward by `seconds`
+    fn advance_secs(&self, seconds: u64);
+    
+    /// Move the ledger clock forward by `hours`
+    fn advance_hours(&self, hours: u64) {
+        self.advance_secs(hours * HOUR);
+    }
+    
+    /// Move the ledger clock forward by `days`
+    fn advance_days(&self, days: u64) {
+        self.advance_secs(days * DAY);
+    }
+}
+
+impl TimeTravel for Env {
+    fn set_time(&self, timestamp: u64) {
+        self.ledger().with_mut(|li| li.timestamp = timestamp);
+    }
+    
+    fn advance_secs(&self, seconds: u64) {
+        self.ledger().with_mut(|li| li.timestamp += seconds);
+    }
+}
+
+/// Move the clock to the first second at which the wallet's score is stale
+/// 
+/// # Panics
+/// * If the wallet has never been scored
+pub(crate) fn expire_entry(env: &Env, client: &SentinelSDKClient, wallet: &Address) {
+    let state = client.get_risk(wallet).expect("expire_entry: wallet was never scored");
+    env.set_time(state.last_updated + STALE_AFTER_SECONDS + 1);
+}
+
+/// Assert that the wallet's score is reported stale
+pub(crate) fn assert_stale(client: &SentinelSDKClient, wallet: &Address) {
+    let assessment = client.get_assessment(wallet);
+    assert!(assessment.is_known, "wallet was never scored");
+    assert!(assessment.is_stale, "expected stale score, age {}s", assessment.age_seconds);
+}
+
+/// Assert that the wallet's score is reported fresh
+pub(crate) fn assert_fresh(client: &SentinelSDKClient, wallet: &Address) {
+    let assessment = client.get_assessment(wallet);
+    assert!(assessment.is_known, "wallet was never scored");
+    assert!(!assessment.is_stale, "expected fresh score, age {}s", assessment.age_seconds);
+}
+
+/// Sign a payload the way the Oracle does, for the contract behind `client`
+pub(crate) fn sign_for(client: &SentinelSDKClient, key: &SigningKey, payload: &RiskPayload) -> Signature {
+    let message = serialize_canonical_json(&client.env, &client.address, payload);
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    BytesN::from_array(&client.env, &key.sign(&message).to_bytes())
+}
@@ -0,0 +1,319 @@
+//! Timelocked admin actions.
+//!
+//! Score-band, policy, pipeline, decay, jump-guard and quarantine changes,
+//! degraded-mode, breaker, limit, liquidation, asset-limit and
+//! freeze-grace changes, unknown-default flips, domain and P-256 key
+//! registration and Oracle key promotion change how every integrator's
+//! users are treated. Once the admin sets a timelock delay, these actions
+//! can no longer be called directly: they are queued with `queue_action`,
+//! become executable after the delay and can be cancelled until then.
+//! Integrators watching `ACT_QUEUE` get the whole delay to react before the
+//! change hits them. Lowering or removing the delay is itself timelocked.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::audit;
+use crate::breaker::check_freeze_breaker;
+use crate::decay::check_decay;
+use crate::heartbeat::check_degraded_mode;
+use crate::invariants;
+use crate::jump_guard::check_jump_guard;
+use crate::percentage::check_limit_bps;
+use crate::pipeline::{check_pipeline, check_quarantine_release};
+use crate::schemes::check_p256_key;
+use crate::types::{ConfigKey, DataKey, Policy, QueuedAction, TimelockAction};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+impl SentinelSDK {
+    /// Require the admin for an action that is timelockable
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock delay is configured
+    pub(crate) fn require_untimelocked_admin(env: &Env) -> Address {
+        let admin = Self::require_admin(env);
+        Self::require_no_timelock(env);
+        admin
+    }
+    
+    /// Refuse direct calls to a timelockable action while a delay is set
+    pub(crate) fn require_no_timelock(env: &Env) {
+        if Self::get_timelock_delay(env.clone()) > 0 {
+            panic!("Action is timelocked - use queue_action");
+        }
+    }
+    
+    /// Reject an action that would fail when executed as queued
+    /// 
+    /// Checks that depend on state at execution time are repeated then.
+    fn check_action(env: &Env, action: &TimelockAction) {
+        match action {
+            TimelockAction::ScoreBands(bands) | TimelockAction::Policy(_, Policy { bands, .. })
+                if !bands.is_valid() =>
+            {
+                panic!("Invalid score bands");
+            }
+            TimelockAction::PromoteTrialKey(candidate) => {
+                let trial = Self::get_key_trial(env.clone()).expect("No key trial running");
+                if trial.candidate != *candidate {
+                    panic!("No key trial for this candidate");
+                }
+            }
+            TimelockAction::Pipeline(stages) => check_pipeline(stages),
+            TimelockAction::Decay(config) => check_decay(config),
+            TimelockAction::JumpGuard(config) => check_jump_guard(env, config),
+            TimelockAction::QuarantineRelease(decision) => check_quarantine_release(decision),
+            TimelockAction::DegradedMode(mode) => check_degraded_mode(mode),
+            TimelockAction::FreezeBreaker(breaker) => check_freeze_breaker(breaker),
+            TimelockAction::LimitBps(bps) => check_limit_bps(*bps),
+            TimelockAction::RegisterP256Key(key) => check_p256_key(key),
+            _ => {}
+        }
+    }
+    
+    /// Store the timelock delay (authorization checked by caller)
+    fn apply_timelock_delay(env: &Env, delay: u64) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Config(ConfigKey::TimelockDelay), &delay);
+        env.events().publish((topic::TLOCK_SET,), delay);
+    }
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the timelock delay in seconds (read-only; 0 = disabled)
+    pub fn get_timelock_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::TimelockDelay))
+            .unwrap_or(0)
+    }
+    
+    /// Enable the timelock (admin only)
+    /// 
+    /// Only callable directly while no timelock is configured; afterwards
+    /// queue `TimelockAction::TimelockDelay` instead.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a timelock delay is already configured
+    pub fn set_timelock_delay(env: Env, delay: u64) {
+        let admin = Self::require_untimelocked_admin(&env);
+        Self::apply_timelock_delay(&env, delay);
+        audit::record(&env, &admin, "set_timelock_delay", 0u64, delay);
+    }
+    
+    /// Queue a sensitive admin action (admin only)
+    /// 
+    /// # Returns
+    /// * Id to execute or cancel the action with
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If the action carries an invalid value (as its direct setter)
+    /// * If `PromoteTrialKey` names a key that is not on trial
+    pub fn queue_action(env: Env, action: TimelockAction) -> u32 {
+        let admin = Self::require_admin(&env);
+        Self::check_action(&env, &action);
+        
+        let storage = env.storage().instance();
+        let id: u32 = storage.get(&DataKey::NextActionId).unwrap_or(0);
+        let eta = env
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::get_timelock_delay(env.clone()));
+        storage.set(&DataKey::NextActionId, &(id + 1));
+        env.storage()
+            .persistent()
+            .set(&DataKey::QueuedAction(id), &QueuedAction { action: action.clone(), eta });
+        audit::record(&env, &admin, "queue_action", (), (id, action.clone(), eta));
+        
+        env.events().publish((topic::ACT_QUEUE,), (id, action, eta));
+        id
+    }
+    
+    /// Get a queued action (read-only)
+    pub fn get_queued_action(env: Env, id: u32) -> Option<QueuedAction> {
+        env.storage().persistent().get(&DataKey::QueuedAction(id))
+    }
+    
+    /// Apply a queued action once its delay has passed (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If no action is queued under `id` or its delay has not passed
+    /// * As the underlying action (e.g. key trial still running)
+    pub fn execute_action(env: Env, id: u32) {
+        let admin = Self::require_admin(&env);
+        
+        let queued = Self::get_queued_action(env.clone(), id).expect("No queued action with this id");
+        if env.ledger().timestamp() < queued.eta {
+            panic!("Action is still timelocked");
+        }
+        env.storage().persistent().remove(&DataKey::QueuedAction(id));
+        audit::record(&env, &admin, "execute_action", (), (id, queued.action.clone()));
+        
+        match queued.action {
+            TimelockAction::ScoreBands(bands) => Self::apply_score_bands(&env, bands),
+            TimelockAction::UnknownDefault(decision) => Self::apply_unknown_default(&env, decision),
+            TimelockAction::PromoteTrialKey(candidate) => Self::apply_trial_key(&env, candidate),
+            TimelockAction::TimelockDelay(delay) => Self::apply_timelock_delay(&env, delay),
+            TimelockAction::GuardianCouncil(guardians, threshold) => {
+                Self::apply_guardian_council(&env, guardians, threshold)
+            }
+            TimelockAction::Pipeline(stages) => Self::apply_pipeline(&env, stages),
+            TimelockAction::Policy(policy_id, policy) => Self::apply_policy(&env, policy_id, policy),
+            TimelockAction::Decay(config) => Self::apply_decay(&env, config),
+            TimelockAction::JumpGuard(config) => Self::apply_jump_guard(&env, Some(config)),
+            TimelockAction::DisableJumpGuard => Self::apply_jump_guard(&env, None),
+            TimelockAction::QuarantineRelease(decision) => Self::apply_quarantine_release(&env, decision),
+            TimelockAction::DegradedMode(mode) => Self::apply_degraded_mode(&env, Some(mode)),
+            TimelockAction::DisableDegradedMode => Self::apply_degraded_mode(&env, None),
+            TimelockAction::FreezeBreaker(breaker) => Self::apply_freeze_breaker(&env, Some(breaker)),
+            TimelockAction::DisableFreezeBreaker => Self::apply_freeze_breaker(&env, None),
+            TimelockAction::LimitBps(bps) => Self::apply_limit_bps(&env, Some(bps)),
+            TimelockAction::DisableLimitBps => Self::apply_limit_bps(&env, None),
+            TimelockAction::LiquidationRule(rule) => Self::apply_liquidation_rule(&env, rule),
+            TimelockAction::AssetLimits(limits) => Self::apply_asset_limits(&env, limits),
+            TimelockAction::RegisterDomain(domain, key) => Self::apply_domain(&env, domain, key),
+            TimelockAction::RegisterP256Key(key) => {
+                Self::apply_p256_key(&env, key);
+            }
+            TimelockAction::FreezeGrace(seconds) => Self::apply_freeze_grace(&env, seconds),
+        }
+        invariants::check_contract(&env);
+        
+        env.events().publish((topic::ACT_EXEC,), id);
+    }
+    
+    /// Drop a queued action (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If no action is queued under `id`
+    pub fn cancel_action(env: Env, id: u32) {
+        let admin = Self::require_admin(&env);
+        
+        let queued = Self::get_queued_action(env.clone(), id).expect("No queued action with this id");
+        env.storage().persistent().remove(&DataKey::QueuedAction(id));
+        audit::record(&env, &admin, "cancel_action", (id, queued.action), ());
+        
+        env.events().publish((topic::ACT_CNCL,), id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutils::{TimeTravel, HOUR};
+    use crate::types::{DegradedMode, LiquidationRule, PipelineStage, RiskDecision};
+    use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Map, Symbol};
+    
+    fn setup(env: &Env) -> SentinelSDKClient<'_> {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        client.set_timelock_delay(&HOUR);
+        client
+    }
+    
+    #[test]
+    fn test_timelocked_change_applies_after_delay() {
+        let env = Env::default();
+        let client = setup(&env);
+        
+        assert!(client.try_set_unknown_default(&RiskDecision::Freeze).is_err());
+        
+        let id = client.queue_action(&TimelockAction::UnknownDefault(RiskDecision::Freeze));
+        assert!(client.try_execute_action(&id).is_err());
+        
+        env.advance_secs(HOUR);
+        client.execute_action(&id);
+        assert_eq!(client.get_unknown_default(), RiskDecision::Freeze);
+        assert_eq!(client.get_queued_action(&id), None);
+        
+        let id = client.queue_action(&TimelockAction::TimelockDelay(0));
+        client.cancel_action(&id);
+        assert!(client.try_execute_action(&id).is_err());
+        assert_eq!(client.get_timelock_delay(), HOUR);
+    }
+    
+    #[test]
+    fn test_pipeline_goes_through_the_queue() {
+        let env = Env::default();
+        let client = setup(&env);
+        
+        let stages = vec![&env, PipelineStage::OracleScore, PipelineStage::Override];
+        assert!(client.try_set_pipeline(&stages).is_err());
+        assert!(client
+            .try_queue_action(&TimelockAction::Pipeline(vec![&env, PipelineStage::Override]))
+            .is_err());
+        
+        let id = client.queue_action(&TimelockAction::Pipeline(stages.clone()));
+        env.advance_secs(HOUR);
+        client.execute_action(&id);
+        assert_eq!(client.get_pipeline(), stages);
+    }
+    
+    #[test]
+    fn test_operational_settings_go_through_the_queue() {
+        let env = Env::default();
+        let client = setup(&env);
+        
+        let mode = DegradedMode { silence_threshold: HOUR, decision: RiskDecision::Review };
+        let domain = Symbol::new(&env, "aml");
+        let key = BytesN::from_array(&env, &[3u8; 32]);
+        assert!(client.try_set_degraded_mode(&Some(mode.clone())).is_err());
+        assert!(client.try_set_freeze_breaker(&None).is_err());
+        assert!(client.try_set_limit_bps(&Some(2500)).is_err());
+        assert!(client.try_set_liquidation_rule(&LiquidationRule::Delayed(HOUR)).is_err());
+        assert!(client.try_set_asset_limits(&Map::new(&env)).is_err());
+        assert!(client.try_register_domain(&domain, &key).is_err());
+        assert!(client.try_register_p256_key(&BytesN::from_array(&env, &[4u8; 65])).is_err());
+        assert!(client.try_set_freeze_grace(&0).is_err());
+        
+        // Values the direct setters reject are refused at queue time
+        assert!(client.try_queue_action(&TimelockAction::LimitBps(0)).is_err());
+        assert!(client
+            .try_queue_action(&TimelockAction::RegisterP256Key(BytesN::from_array(&env, &[2u8; 65])))
+            .is_err());
+        
+        let ids = [
+            client.queue_action(&TimelockAction::DegradedMode(mode.clone())),
+            client.queue_action(&TimelockAction::LimitBps(2500)),
+            client.queue_action(&TimelockAction::RegisterDomain(domain.clone(), key.clone())),
+            client.queue_action(&TimelockAction::FreezeGrace(600)),
+        ];
+        env.advance_secs(HOUR);
+        for id in ids {
+            client.execute_action(&id);
+        }
+        assert_eq!(client.get_degraded_mode(), Some(mode));
+        assert_eq!(client.get_limit_bps(), Some(2500));
+        assert_eq!(client.get_domain_oracle(&domain), Some(key));
+        assert_eq!(client.get_freeze_grace(), 600);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Key trial candidate changed since the promotion was queued")]
+    fn test_promotion_is_bound_to_the_queued_candidate() {
+        let env = Env::default();
+        let client = setup(&env);
+        let admin = client.get_admin();
+        
+        let queued = BytesN::from_array(&env, &[1u8; 32]);
+        client.start_key_trial(&admin, &queued, &HOUR);
+        let id = client.queue_action(&TimelockAction::PromoteTrialKey(queued));
+        
+        // Swapped for another key while the promotion waits out the delay
+        client.cancel_key_trial(&admin);
+        client.start_key_trial(&admin, &BytesN::from_array(&env, &[2u8; 32]), &0);
+        
+        env.advance_secs(HOUR);
+        client.execute_action(&id);
+    }
+}
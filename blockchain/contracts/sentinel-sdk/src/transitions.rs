@@ -0,0 +1,98 @@
+//! Per-wallet state-transition log.
+//!
+//! Every change in a wallet's treatment is recorded with its cause
+//! (Oracle update, admin override, ...), the acting address where there is
+//! one, and the ledger it happened in. The last `MAX_TRANSITIONS` entries
+//! are kept in full; older ones are compacted to one per month (see
+//! `compaction`).
+
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::compaction::compact_log;
+use crate::types::{DataKey, RiskDecision, Transition, TransitionCause};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Number of recent transitions kept in full per wallet
+pub const MAX_TRANSITIONS: u32 = 20;
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get a wallet's transition log, oldest first (read-only)
+    pub fn transition_log(env: Env, wallet: Address) -> Vec<Transition> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Transitions(wallet))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
+
+impl SentinelSDK {
+    /// Append a transition if the decision differs from the last one logged
+    pub(crate) fn record_transition(
+        env: &Env,
+        wallet: &Address,
+        decision: RiskDecision,
+        cause: TransitionCause,
+        actor: Option<Address>,
+    ) {
+        let mut log = Self::transition_log(env.clone(), wallet.clone());
+        
+        if let Some(last) = log.last() {
+            if last.decision == decision {
+                return;
+            }
+        }
+        
+        log.push_back(Transition {
+            decision,
+            cause,
+            actor,
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+        });
+        let log = compact_log(env, &log, MAX_TRANSITIONS, |t: &Transition| t.timestamp);
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transitions(wallet.clone()), &log);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, BytesN};
+    
+    #[test]
+    fn test_log_records_changes_with_cause_and_caps() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+        
+        let wallet = Address::generate(&env);
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        // Same decision again is not a transition
+        client.set_override(&client.get_admin(), &wallet, &RiskDecision::Freeze, &0);
+        
+        let log = client.transition_log(&wallet);
+        assert_eq!(log.len(), 1);
+        let entry = log.get(0).unwrap();
+        assert_eq!(entry.decision, RiskDecision::Freeze);
+        assert_eq!(entry.cause, TransitionCause::Override);
+        assert_eq!(entry.actor, Some(admin));
+        
+        // Alternate decisions past the cap
+        env.as_contract(&contract_id, || {
+            for i in 0..MAX_TRANSITIONS + 5 {
+                let decision = if i % 2 == 0 { RiskDecision::Allow } else { RiskDecision::Freeze };
+                SentinelSDK::record_transition(&env, &wallet, decision, TransitionCause::Oracle, None);
+            }
+        });
+        // Recent transitions plus one for the (single) archived month
+        assert_eq!(client.transition_log(&wallet).len(), MAX_TRANSITIONS + 1);
+    }
+}
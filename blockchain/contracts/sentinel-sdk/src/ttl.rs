@@ -0,0 +1,268 @@
+//! Storage tier and time-to-live of risk states.
+//!
+//! Persistent entries are archived once their TTL lapses, which would
+//! silently turn a risky wallet back into "unknown". Risk states are
+//! therefore extended whenever they are written or read, and anyone can
+//! top up a wallet's entry with `bump_risk_ttl`.
+//!
+//! Deployments scoring at high frequency, where only the last few hours
+//! matter, can keep risk states in temporary storage instead: cheaper,
+//! never extended on read, and gone after the configured lifetime, at
+//! which point the wallet reads as unknown. Entries written under the
+//! other mode stay readable until rewritten. Values are in ledgers.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, IntoVal, TryFromVal, Val};
+
+use crate::audit;
+use crate::types::{ConfigKey, DataKey, RiskStorage, TtlConfig};
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Ledgers per day at ~5 second ledger close
+pub const LEDGERS_PER_DAY: u32 = 17_280;
+
+/// Default: extend once fewer than 30 days remain
+pub const DEFAULT_TTL_THRESHOLD: u32 = 30 * LEDGERS_PER_DAY;
+
+/// Default: extend to 90 days
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 90 * LEDGERS_PER_DAY;
+
+/// Extend a wallet's risk state per the TTL config (entry must exist)
+fn extend_risk_ttl(env: &Env, wallet: &Address) {
+    let config = SentinelSDK::get_ttl_config(env.clone());
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::Risk(wallet.clone()), config.threshold, config.extend_to);
+}
+
+/// Tier new risk states are written to
+pub(crate) fn risk_storage(env: &Env) -> RiskStorage {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config(ConfigKey::RiskStorage))
+        .unwrap_or(RiskStorage::Persistent)
+}
+
+/// Read a wallet's raw risk entry from whichever tier holds it
+/// 
+/// Persistent entries are extended per the TTL config; temporary ones are
+/// left to expire.
+pub(crate) fn read_risk_entry<T: TryFromVal<Env, Val>>(env: &Env, wallet: &Address) -> Option<T> {
+    let key = DataKey::Risk(wallet.clone());
+    let temporary = || env.storage().temporary().get(&key);
+    let persistent = || {
+        let entry = env.storage().persistent().get(&key)?;
+        extend_risk_ttl(env, wallet);
+        Some(entry)
+    };
+    
+    match risk_storage(env) {
+        RiskStorage::Persistent => persistent().or_else(temporary),
+        RiskStorage::Temporary(_) => temporary().or_else(persistent),
+    }
+}
+
+/// Write a wallet's risk entry to the configured tier
+pub(crate) fn write_risk_entry<V: IntoVal<Env, Val>>(env: &Env, wallet: &Address, entry: &V) {
+    let key = DataKey::Risk(wallet.clone());
+    match risk_storage(env) {
+        RiskStorage::Persistent => {
+            env.storage().temporary().remove(&key);
+            env.storage().persistent().set(&key, entry);
+            extend_risk_ttl(env, wallet);
+        }
+        RiskStorage::Temporary(lifetime) => {
+            env.storage().persistent().remove(&key);
+            env.storage().temporary().set(&key, entry);
+            env.storage().temporary().extend_ttl(&key, lifetime, lifetime);
+        }
+    }
+}
+
+/// Remove a wallet's risk entry from both tiers
+pub(crate) fn remove_risk_entry(env: &Env, wallet: &Address) {
+    let key = DataKey::Risk(wallet.clone());
+    env.storage().persistent().remove(&key);
+    env.storage().temporary().remove(&key);
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Get the tier risk states are written to (read-only)
+    pub fn get_risk_storage(env: Env) -> RiskStorage {
+        risk_storage(&env)
+    }
+    
+    /// Choose the tier risk states are written to (admin only)
+    /// 
+    /// Existing entries stay where they are, and readable, until the
+    /// wallet is scored again.
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If a temporary lifetime is 0 or exceeds the network maximum
+    pub fn set_risk_storage(env: Env, storage: RiskStorage) {
+        let admin = Self::require_admin(&env);
+        
+        if let RiskStorage::Temporary(lifetime) = storage {
+            if lifetime == 0 || lifetime > env.storage().max_ttl() {
+                panic!("Temporary lifetime must be within the network maximum");
+            }
+        }
+        
+        let old = Self::get_risk_storage(env.clone());
+        env.storage().instance().set(&DataKey::Config(ConfigKey::RiskStorage), &storage);
+        audit::record(&env, &admin, "set_risk_storage", old, storage);
+        env.events().publish((topic::STORE_SET,), storage);
+    }
+    
+    /// Get the risk-state TTL configuration (read-only)
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config(ConfigKey::RiskTtl))
+            .unwrap_or(TtlConfig {
+                threshold: DEFAULT_TTL_THRESHOLD,
+                extend_to: DEFAULT_TTL_EXTEND_TO,
+            })
+    }
+    
+    /// Set the risk-state TTL configuration (admin only)
+    /// 
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If `threshold` exceeds `extend_to`, or `extend_to` exceeds the
+    ///   network's maximum entry TTL
+    pub fn set_ttl_config(env: Env, config: TtlConfig) {
+        let admin = Self::require_admin(&env);
+        
+        if config.threshold > config.extend_to {
+            panic!("TTL threshold must not exceed extend_to");
+        }
+        if config.extend_to > env.storage().max_ttl() {
+            panic!("TTL exceeds the network maximum");
+        }
+        
+        let old = Self::get_ttl_config(env.clone());
+        env.storage().instance().set(&DataKey::Config(ConfigKey::RiskTtl), &config);
+        audit::record(&env, &admin, "set_ttl_config", old, config.clone());
+        env.events().publish((topic::TTL_SET,), config);
+    }
+    
+    /// Extend a wallet's risk state to live at least `ledgers` more ledgers
+    /// 
+    /// Applies to whichever tier holds the entry. Callable by anyone (the caller pays the rent), e.g. by an integrator
+    /// keeping the wallets it depends on from being archived.
+    /// 
+    /// # Panics
+    /// * If the wallet has never been scored
+    /// * If `ledgers` exceeds the network's maximum entry TTL
+    pub fn bump_risk_ttl(env: Env, wallet: Address, ledgers: u32) {
+        if ledgers > env.storage().max_ttl() {
+            panic!("TTL exceeds the network maximum");
+        }
+        
+        let key = DataKey::Risk(wallet);
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().extend_ttl(&key, ledgers, ledgers);
+        } else if env.storage().temporary().has(&key) {
+            env.storage().temporary().extend_ttl(&key, ledgers, ledgers);
+        } else {
+            panic!("Wallet has no risk state");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RiskDecision, RiskPayload, PAYLOAD_VERSION_V2};
+    use soroban_sdk::{
+        testutils::{storage::Persistent as _, Address as _, Ledger},
+        BytesN, Map, Vec,
+    };
+    
+    fn seed(env: &Env, contract_id: &Address, wallet: &Address) {
+        env.as_contract(contract_id, || {
+            let payload = RiskPayload {
+                version: PAYLOAD_VERSION_V2,
+                wallet: wallet.clone(),
+                risk_score: 90,
+                timestamp: 0,
+                reason_codes: Vec::new(env),
+                freeze_duration: 0,
+                category_scores: Map::new(env),
+                model_version: 0,
+                confidence: 0,
+                evidence_hash: BytesN::from_array(env, &[0; 32]),
+            };
+            SentinelSDK::store_risk(env, &payload);
+        });
+    }
+    
+    fn ttl(env: &Env, contract_id: &Address, wallet: &Address) -> u32 {
+        env.as_contract(contract_id, || {
+            env.storage().persistent().get_ttl(&DataKey::Risk(wallet.clone()))
+        })
+    }
+    
+    #[test]
+    fn test_write_read_and_bump_extend_ttl() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_ttl_config(&TtlConfig { threshold: 4_500, extend_to: 5_000 });
+        
+        let wallet = Address::generate(&env);
+        seed(&env, &contract_id, &wallet);
+        assert_eq!(ttl(&env, &contract_id, &wallet), 5_000);
+        
+        // Reads top the entry up once it drops below the threshold
+        env.ledger().with_mut(|li| li.sequence_number += 1_000);
+        client.check_permission(&wallet);
+        assert_eq!(ttl(&env, &contract_id, &wallet), 5_000);
+        
+        client.bump_risk_ttl(&wallet, &20_000);
+        assert_eq!(ttl(&env, &contract_id, &wallet), 20_000);
+    }
+    
+    #[test]
+    fn test_temporary_scores_expire_to_unknown() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &BytesN::from_array(&env, &[0u8; 32]));
+        
+        // Scored while persistent, then switched: still readable
+        let earlier = Address::generate(&env);
+        seed(&env, &contract_id, &earlier);
+        client.set_risk_storage(&RiskStorage::Temporary(100));
+        
+        let wallet = Address::generate(&env);
+        seed(&env, &contract_id, &wallet);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Freeze);
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().persistent().has(&DataKey::Risk(wallet.clone())));
+        });
+        
+        env.ledger().with_mut(|li| li.sequence_number += 101);
+        assert_eq!(client.get_risk(&wallet), None);
+        assert_eq!(client.check_permission(&wallet), RiskDecision::Allow);
+        assert_eq!(client.check_permission(&earlier), RiskDecision::Freeze);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Wallet has no risk state")]
+    fn test_bump_requires_scored_wallet() {
+        let env = Env::default();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(&env, &contract_id);
+        client.bump_risk_ttl(&Address::generate(&env), &1_000);
+    }
+}
@@ -3,10 +3,20 @@
 //! This module defines the core data structures used by the SDK:
 //! - RiskState: Wallet risk information stored on-chain
 //! - RiskDecision: The decision returned to integrating protocols
+//! - FillResult: How much of a requested amount a decision permits
+//! - PairDecision: Combined decision for a two-party flow
 //! - RiskPayload: Oracle-signed risk data
+//! - RiskCategory: Categories the Oracle can score separately
+//! - Assessment: Combined score, decision and freshness view
 
 
-use soroban_sdk::{contracttype, Address, BytesN};
+use sentinel_events::topic;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, Symbol, Vec};
+
+use crate::bands::{
+    band_for_score, Band, ALLOW_MAX_SCORE, DEFAULT_LIMIT_AMOUNT, LIMIT_MAX_SCORE, MAX_RISK_SCORE,
+    REVIEW_MAX_SCORE,
+};
 
 /// Decision returned to protocols about what action to take
 #[contracttype]
@@ -16,10 +26,111 @@ pub enum RiskDecision {
     Allow,
     /// Wallet has moderate risk - limit to specified amount
     Limit(u32),
+    /// Wallet is held for manual review - block all operations until a
+    /// reviewer clears it (unlike Freeze, not meant to be permanent)
+    Review,
     /// Wallet is high risk - freeze all operations
     Freeze,
 }
 
+/// Outcome of enforcing a decision against a requested amount
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FillResult {
+    /// Execute the full requested amount
+    Full,
+    /// Execute only this amount (the Limit cap)
+    Partial(i128),
+    /// Execute nothing
+    Rejected,
+}
+
+/// Kind of operation an integrator is about to perform
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OperationKind {
+    Swap,
+    Withdraw,
+    Deposit,
+    Borrow,
+    Transfer,
+}
+
+/// How the wallet's decision applies to one operation kind
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OperationRule {
+    /// The wallet's decision applies in full
+    Enforce,
+    /// Allowed regardless of the decision (risk-reducing, e.g. deposit/repay)
+    Exempt,
+}
+
+/// Decisions for both sides of a two-party flow
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairDecision {
+    /// Decision for the first wallet
+    pub a: RiskDecision,
+    /// Decision for the second wallet
+    pub b: RiskDecision,
+    /// Strictest of the two (what the flow should enforce)
+    pub combined: RiskDecision,
+}
+
+impl RiskDecision {
+    /// The stricter of two decisions
+    ///
+    /// `Freeze` beats `Review`, which beats `Limit`, which beats `Allow`;
+    /// two limits combine to the lower cap.
+    pub fn strictest(self, other: RiskDecision) -> RiskDecision {
+        match (self, other) {
+            (RiskDecision::Freeze, _) | (_, RiskDecision::Freeze) => RiskDecision::Freeze,
+            (RiskDecision::Review, _) | (_, RiskDecision::Review) => RiskDecision::Review,
+            (RiskDecision::Limit(x), RiskDecision::Limit(y)) => RiskDecision::Limit(x.min(y)),
+            (RiskDecision::Limit(x), _) | (_, RiskDecision::Limit(x)) => RiskDecision::Limit(x),
+            (RiskDecision::Allow, RiskDecision::Allow) => RiskDecision::Allow,
+        }
+    }
+    
+    /// Apply this decision to a requested amount
+    ///
+    /// Lets protocols that can partially fill (AMMs, order books) execute
+    /// up to a `Limit` cap instead of rejecting the whole request.
+    /// Non-positive amounts are always `Rejected`.
+    pub fn apply_limit(&self, amount: i128) -> FillResult {
+        if amount <= 0 {
+            return FillResult::Rejected;
+        }
+        
+        match self {
+            RiskDecision::Allow => FillResult::Full,
+            RiskDecision::Limit(cap) => {
+                let cap = *cap as i128;
+                if amount <= cap {
+                    FillResult::Full
+                } else if cap > 0 {
+                    FillResult::Partial(cap)
+                } else {
+                    FillResult::Rejected
+                }
+            }
+            RiskDecision::Review | RiskDecision::Freeze => FillResult::Rejected,
+        }
+    }
+}
+
+impl From<RiskDecision> for sentinel_events::RiskDecision {
+    fn from(decision: RiskDecision) -> Self {
+        match decision {
+            RiskDecision::Allow => sentinel_events::RiskDecision::Allow,
+            RiskDecision::Limit(cap) => sentinel_events::RiskDecision::Limit(cap),
+            RiskDecision::Review => sentinel_events::RiskDecision::Review,
+            RiskDecision::Freeze => sentinel_events::RiskDecision::Freeze,
+        }
+    }
+}
+
 /// Complete risk state for a wallet stored on-chain
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -30,45 +141,1083 @@ pub struct RiskState {
     pub last_updated: u64,
     /// Computed decision based on risk score
     pub decision: RiskDecision,
+    /// Oracle reason codes explaining the score
+    pub reason_codes: Vec<u32>,
+    /// Unix timestamp when a quarantine freeze lapses (0 = indefinite)
+    pub freeze_until: u64,
+    /// Per-category scores from 0-100 (empty if the Oracle sent none)
+    pub category_scores: Map<RiskCategory, u32>,
+    /// Release of the ML model that produced the score (0 = not reported)
+    pub model_version: u32,
+    /// Model confidence in basis points, 0-10000 (0 = not reported)
+    pub confidence: u32,
+    /// 32-byte digest of the off-chain evidence report (IPFS/S3) behind
+    /// the score; all zeros if the Oracle attached none
+    pub evidence_hash: BytesN<32>,
 }
 
+/// Storage form of `RiskState` (see `packing`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackedRiskState {
+    /// Risk score, last update and decision in one word
+    pub packed: u128,
+    /// Oracle reason codes explaining the score
+    pub reason_codes: Vec<u32>,
+    /// Unix timestamp when a quarantine freeze lapses (0 = indefinite)
+    pub freeze_until: u64,
+    /// Per-category scores from 0-100 (empty if the Oracle sent none)
+    pub category_scores: Map<RiskCategory, u32>,
+    /// Release of the ML model that produced the score (0 = not reported)
+    pub model_version: u32,
+    /// Model confidence in basis points, 0-10000 (0 = not reported)
+    pub confidence: u32,
+    /// 32-byte digest of the off-chain evidence report (IPFS/S3) behind
+    /// the score; all zeros if the Oracle attached none
+    pub evidence_hash: BytesN<32>,
+}
+
+/// Canonical JSON carrying the signed `version` and deployment `domain`
+/// (v1, without either, is no longer accepted)
+pub const PAYLOAD_VERSION_V2: u32 = 2;
+
+/// Payload schema version produced by current Oracle deployments
+pub const CURRENT_PAYLOAD_VERSION: u32 = PAYLOAD_VERSION_V2;
+
 /// Payload signed by Oracle (what gets verified)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RiskPayload {
+    /// Payload schema version (selects the signing format)
+    pub version: u32,
+    /// Wallet address being scored
+    pub wallet: Address,
+    /// Risk score from 0-100
+    pub risk_score: u32,
+    /// Unix timestamp when Oracle signed this
+    pub timestamp: u64,
+    /// Reason codes behind the score; empty if none
+    pub reason_codes: Vec<u32>,
+    /// Seconds a Freeze lasts before it is released (0 = indefinite)
+    pub freeze_duration: u64,
+    /// Per-category scores from 0-100; empty if none
+    pub category_scores: Map<RiskCategory, u32>,
+    /// Release of the ML model that produced the score (0 = not reported)
+    pub model_version: u32,
+    /// Model confidence in basis points, 0-10000 (0 = not reported)
+    pub confidence: u32,
+    /// 32-byte digest of the off-chain evidence report (IPFS/S3) behind
+    /// the score; all zeros if the Oracle attached none
+    pub evidence_hash: BytesN<32>,
+}
+
+/// Risk category the Oracle can score separately
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RiskCategory {
+    /// Anti-money-laundering exposure
+    Aml,
+    /// Automated / bot activity
+    BotActivity,
+    /// Fraud and scam signals
+    Fraud,
+    /// Sanctions exposure
+    Sanctions,
+}
+
+impl RiskCategory {
+    /// Every category, in canonical JSON key order
+    pub const ALL: [RiskCategory; 4] = [
+        RiskCategory::Aml,
+        RiskCategory::BotActivity,
+        RiskCategory::Fraud,
+        RiskCategory::Sanctions,
+    ];
+    
+    /// Key used in the signed canonical JSON
+    pub fn json_key(&self) -> &'static [u8] {
+        match self {
+            RiskCategory::Aml => b"aml",
+            RiskCategory::BotActivity => b"bot_activity",
+            RiskCategory::Fraud => b"fraud",
+            RiskCategory::Sanctions => b"sanctions",
+        }
+    }
+    
+    /// Topic of the category's transition events
+    pub fn event_topic(&self) -> Symbol {
+        match self {
+            RiskCategory::Aml => topic::CAT_AML,
+            RiskCategory::BotActivity => topic::CAT_BOT,
+            RiskCategory::Fraud => topic::CAT_FRAUD,
+            RiskCategory::Sanctions => topic::CAT_SANC,
+        }
+    }
+}
+
+/// Explainability view of a wallet's risk state
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskDetails {
+    /// Decision currently returned by `check_permission`
+    pub decision: RiskDecision,
+    /// Risk score from 0-100
+    pub risk_score: u32,
+    /// Unix timestamp of last update
+    pub last_updated: u64,
+    /// Oracle reason codes explaining the score
+    pub reason_codes: Vec<u32>,
+    /// Digest of the off-chain evidence report (all zeros if none)
+    pub evidence_hash: BytesN<32>,
+}
+
+/// Oracle-assigned labels for a wallet
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LabelSet {
+    /// Labels such as `mixer`, `exchange`, `sanctioned`
+    pub labels: Vec<Symbol>,
+    /// Oracle timestamp of the assignment (replay protection)
+    pub timestamp: u64,
+}
+
+/// Resume point of a `submit_risk_batch` that ran out of write budget
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchCursor {
+    /// SHA-256 of the XDR-encoded payload list the cursor belongs to
+    pub batch_hash: BytesN<32>,
+    /// Index of the first payload not yet processed
+    pub next: u32,
+    /// Number of payloads in the batch
+    pub total: u32,
+}
+
+/// Leaf of an Oracle Merkle-root attestation (see `submit_merkle_root`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleLeaf {
     /// Wallet address being scored
     pub wallet: Address,
     /// Risk score from 0-100
     pub risk_score: u32,
+    /// Unix timestamp of the score
+    pub timestamp: u64,
+}
+
+/// Oracle-signed Merkle root of a scoring run
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleRoot {
+    /// Root over the run's leaf hashes
+    pub root: BytesN<32>,
     /// Unix timestamp when Oracle signed this
     pub timestamp: u64,
 }
 
-/// Ed25519 signature type (64 bytes)
+/// Optional subsystem bits reported by `enabled_features`
+pub mod feature {
+    /// Multi-wallet queries and submissions
+    pub const BATCHING: u32 = 1 << 0;
+    /// Wallet appeals
+    pub const APPEALS: u32 = 1 << 1;
+    /// Signed permits
+    pub const PERMITS: u32 = 1 << 2;
+    /// Per-category risk scores
+    pub const CATEGORIES: u32 = 1 << 3;
+    
+    /// Subsystems active on a fresh deployment
+    pub const DEFAULT: u32 = BATCHING;
+    /// Every bit this version understands
+    pub const ALL: u32 = BATCHING | APPEALS | PERMITS | CATEGORIES;
+}
+
+/// Rolling aggregates of Oracle-to-ledger latency, in seconds
+/// 
+/// Latency is the ledger timestamp at which a payload was stored minus the
+/// payload timestamp (when the Oracle signed it).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LatencyStats {
+    /// Payloads measured
+    pub count: u64,
+    /// Sum of all latencies (mean = total / count)
+    pub total: u64,
+    /// Smallest latency seen
+    pub min: u64,
+    /// Largest latency seen
+    pub max: u64,
+    /// Most recent latency
+    pub last: u64,
+    /// Exponential moving average (weight 1/8 per sample)
+    pub recent_avg: u64,
+}
+
+/// Fallback served while the Oracle is silent (see `set_degraded_mode`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DegradedMode {
+    /// Seconds without an accepted submission before the fallback applies
+    pub silence_threshold: u64,
+    /// Decision returned for every wallet meanwhile
+    pub decision: RiskDecision,
+}
+
+/// Permission sets that can be granted to addresses (see `grant_role`)
+/// 
+/// The admin address holds every role implicitly; `Admin` holders hold
+/// every other role and may grant and revoke roles.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Manage roles; implies every other role
+    Admin,
+    /// Run Oracle key trials and promotions
+    OracleManager,
+    /// Engage and lift the emergency global freeze
+    Pauser,
+    /// Set and clear manual overrides
+    Overrider,
+    /// Approve and remove integrators
+    IntegratorManager,
+}
+
+/// Sensitive admin action that goes through the timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockAction {
+    /// `set_score_bands`
+    ScoreBands(ScoreBands),
+    /// `set_unknown_default`
+    UnknownDefault(RiskDecision),
+    /// `promote_trial_key` for this candidate key
+    PromoteTrialKey(PublicKey),
+    /// `set_timelock_delay`
+    TimelockDelay(u64),
+    /// `set_guardian_council` (guardians, threshold)
+    GuardianCouncil(Vec<Address>, u32),
+    /// `set_pipeline`
+    Pipeline(Vec<PipelineStage>),
+    /// `set_policy` (policy id, policy)
+    Policy(u32, Policy),
+    /// `set_decay`
+    Decay(DecayConfig),
+    /// `set_jump_guard` with a configuration
+    JumpGuard(JumpGuardConfig),
+    /// `set_jump_guard` with `None`
+    DisableJumpGuard,
+    /// `set_quarantine_release`
+    QuarantineRelease(RiskDecision),
+    /// `set_degraded_mode` with a configuration
+    DegradedMode(DegradedMode),
+    /// `set_degraded_mode` with `None`
+    DisableDegradedMode,
+    /// `set_freeze_breaker` with a configuration
+    FreezeBreaker(FreezeBreaker),
+    /// `set_freeze_breaker` with `None`
+    DisableFreezeBreaker,
+    /// `set_limit_bps` with basis points
+    LimitBps(u32),
+    /// `set_limit_bps` with `None`
+    DisableLimitBps,
+    /// `set_liquidation_rule`
+    LiquidationRule(LiquidationRule),
+    /// `set_asset_limits`
+    AssetLimits(Map<Address, u32>),
+    /// `register_domain` (domain, Oracle key)
+    RegisterDomain(Symbol, PublicKey),
+    /// `register_p256_key`
+    RegisterP256Key(P256PublicKey),
+    /// `set_freeze_grace`
+    FreezeGrace(u64),
+}
+
+/// Admin action waiting out the timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAction {
+    /// Change to apply
+    pub action: TimelockAction,
+    /// Unix timestamp from which the action can be executed
+    pub eta: u64,
+}
+
+/// M-of-N guardian council for emergency Oracle key replacement
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianCouncil {
+    /// Addresses that may approve a replacement key
+    pub guardians: Vec<Address>,
+    /// Approvals needed to replace the Oracle key
+    pub threshold: u32,
+    /// Replacement key each guardian currently approves
+    pub approvals: Map<Address, BytesN<32>>,
+}
+
+/// Freeze-rate circuit breaker (see `set_freeze_breaker`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreezeBreaker {
+    /// New freezes tolerated per window before the breaker trips
+    pub max_freezes: u32,
+    /// Length of the rolling window in seconds
+    pub window: u64,
+    /// Decision returned for every wallet while tripped
+    pub decision: RiskDecision,
+}
+
+/// New freezes counted in the current and previous breaker windows
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreezeWindow {
+    /// Unix timestamp the current window started
+    pub started_at: u64,
+    /// New freezes in the current window
+    pub current: u32,
+    /// New freezes in the window before it
+    pub previous: u32,
+}
+
+/// Aggregate submission statistics (see `get_stats`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stats {
+    /// Oracle scores stored since deployment
+    pub total_submissions: u64,
+    /// Wallets whose stored Oracle decision is Freeze
+    pub frozen: u32,
+    /// Wallets whose stored Oracle decision is Limit
+    pub limited: u32,
+    /// Wallets whose stored Oracle decision is Review
+    pub in_review: u32,
+    /// Ledger of the last stored score (0 = none yet)
+    pub last_submission_ledger: u32,
+}
+
+/// One page of an enumeration view (see `list_scored`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WalletPage {
+    /// Wallets found on this page
+    pub wallets: Vec<Address>,
+    /// Cursor of the next page (`None` when done)
+    pub next: Option<u32>,
+}
+
+/// Privileged action recorded in the audit log (see `get_audit_log`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+    /// Position in the log (0 for the first action ever recorded)
+    pub seq: u32,
+    /// Admin, role holder or guardian that acted
+    pub actor: Address,
+    /// Entrypoint invoked
+    pub action: Symbol,
+    /// XDR-encoded `ScVal` of the value before the action (void if none)
+    pub old_value: Bytes,
+    /// XDR-encoded `ScVal` of the value after the action, or its arguments
+    pub new_value: Bytes,
+    /// Ledger sequence the action happened in
+    pub ledger: u32,
+    /// Ledger timestamp the action happened at
+    pub timestamp: u64,
+}
+
+/// One page of the audit log (see `get_audit_log`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditPage {
+    /// Entries on this page, oldest first
+    pub entries: Vec<AuditEntry>,
+    /// Cursor of the next page (`None` when done)
+    pub next: Option<u32>,
+}
+
+/// Storage key of every entry the contract writes
+///
+/// Instance entries are contract-wide state; entries carrying an address
+/// or id are persistent. Keeping them in one enum means per-wallet data of
+/// different kinds can never collide.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Admin address
+    Admin,
+    /// Live Oracle public key
+    Oracle,
+    /// Oracle address (Soroban-auth mode)
+    OracleAddress,
+    /// Admin-settable configuration
+    Config(ConfigKey),
+    /// Running blue/green key trial
+    KeyTrial,
+    /// Resume point of an unfinished batch
+    BatchCursor,
+    /// Engaged emergency freeze
+    GlobalFreeze,
+    /// Expiry of the rate-limit bypass
+    RateLimitBypass,
+    /// Score-request fees not yet withdrawn
+    AccruedFees,
+    /// Oracle latency statistics
+    Latency,
+    /// Ledger timestamp of the last accepted Oracle update
+    LastOracleUpdate,
+    /// Latest Oracle-signed Merkle root
+    MerkleRoot,
+    /// Wallet's risk state
+    Risk(Address),
+    /// Wallet's risk state under a candidate Oracle key
+    ShadowRisk(Address),
+    /// Wallet's score history
+    History(Address),
+    /// Wallet's transition log
+    Transitions(Address),
+    /// Admin override on a wallet
+    Override(Address),
+    /// Wallet's appeal
+    Appeal(Address),
+    /// Remediation plan offered to a wallet
+    Remediation(Address),
+    /// Timestamp a wallet self-locked
+    SelfLock(Address),
+    /// Wallet's guardian
+    Guardian(Address),
+    /// Wallet's Oracle labels
+    Labels(Address),
+    /// Limit amount consumed by a wallet in the current window
+    Consumed(Address),
+    /// Wallet's hourly usage buckets
+    Velocity(Address),
+    /// Submissions accepted for a wallet in the rate-limit window
+    Submissions(Address),
+    /// Subscribers to a wallet's changes (`None` = every wallet)
+    Subscribers(Option<Address>),
+    /// Policy assigned to an integrator
+    Integrator(Address),
+    /// Policy by id
+    Policy(u32),
+    /// Change counter of a policy
+    PolicyVersion(u32),
+    /// Number of wallets in the scored-wallet index
+    IndexedCount,
+    /// Bucket of the scored-wallet index by id
+    IndexBucket(u32),
+    /// Index position of a scored wallet
+    Indexed(Address),
+    /// Aggregate submission statistics
+    Stats,
+    /// Ledger timestamp of an Oracle key's last accepted submission
+    OracleActivity(PublicKey),
+    /// Registered P-256 key behind an Oracle key id
+    P256Key(PublicKey),
+    /// Registered risk domains
+    Domains,
+    /// Oracle key owning a risk domain
+    DomainOracle(Symbol),
+    /// Wallet's risk state in a risk domain
+    DomainRisk(Symbol, Address),
+    /// Oracle keys whose scores are aggregated
+    AggregatedOracles,
+    /// Latest score an aggregated Oracle submitted for a wallet
+    OracleScore(PublicKey, Address),
+    /// Pending Oracle bond withdrawal
+    BondWithdrawal,
+    /// Score jump to Freeze awaiting confirmation
+    HeldScore(Address),
+    /// Freeze counts of the circuit breaker
+    FreezeWindow,
+    /// Unix timestamp the circuit breaker tripped
+    BreakerTripped,
+    /// Admin action waiting out the timelock, by id
+    QueuedAction(u32),
+    /// Id the next queued action gets
+    NextActionId,
+    /// Role granted to an address
+    Role(Role, Address),
+}
+
+/// Audit-log storage keys
+///
+/// Kept apart from `DataKey`, which is at the 50-variant limit of a
+/// contract union type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditKey {
+    /// Number of actions ever recorded
+    AuditCount,
+    /// Bucket of `AUDIT_BUCKET_SIZE` consecutive entries
+    AuditBucket(u32),
+}
+
+/// Per-address storage keys added once `DataKey` filled up
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WalletKey {
+    /// Digest of the last payload stored for a wallet
+    PayloadDigest(Address),
+    /// Domain score jump to Freeze awaiting confirmation
+    HeldDomainScore(Symbol, Address),
+    /// Decision changes queued for a subscriber
+    Notifications(Address),
+    /// Outstanding on-demand score request for a wallet
+    ScoreRequest(Address),
+}
+
+/// Admin-settable configuration entries (see `DataKey::Config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigKey {
+    Features,
+    ScoreBands,
+    Pipeline,
+    UnknownDefault,
+    QuarantineRelease,
+    Decay,
+    FreezeGrace,
+    SelfLockCooldown,
+    ReplayWindow,
+    ClockSkew,
+    RateLimit,
+    GlobalFreeze,
+    LiquidationRule,
+    OperationRule(OperationKind),
+    AppealBond,
+    ChallengeBond,
+    ChallengeStaleness,
+    UsageWindow,
+    ScoreFee,
+    RiskTtl,
+    RiskStorage,
+    DegradedMode,
+    AssetLimits,
+    LimitBps,
+    AggregationQuantile,
+    JumpGuard,
+    FreezeBreaker,
+    TimelockDelay,
+    GuardianCouncil,
+}
+
+/// Storage tier risk states are written to (see `set_risk_storage`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RiskStorage {
+    /// Kept until erased; TTL extended on read and write
+    Persistent,
+    /// Expires this many ledgers after the wallet was last scored
+    Temporary(u32),
+}
+
+/// When and how far risk-state TTLs are extended, in ledgers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    /// Extend once the remaining TTL drops below this
+    pub threshold: u32,
+    /// TTL the entry is extended to
+    pub extend_to: u32,
+}
+
+/// Cap on accepted submissions per wallet (see `set_rate_limit`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimit {
+    /// Submissions accepted per wallet in each window
+    pub max_updates: u32,
+    /// Window length in seconds
+    pub window: u64,
+}
+
+/// Submissions accepted for a wallet in the current rate-limit window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionCount {
+    /// Start of the window this count belongs to
+    pub window_start: u64,
+    /// Submissions accepted in the window
+    pub count: u32,
+}
+
+/// Tunable deployment configuration (see `get_config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SdkConfig {
+    /// Admin address
+    pub admin: Address,
+    /// Enabled feature bitmap
+    pub features: u32,
+    /// Score thresholds in effect
+    pub score_bands: ScoreBands,
+    /// Maximum payload age in seconds
+    pub replay_window: u64,
+    /// Seconds a payload may be dated ahead of the ledger
+    pub max_clock_skew: u64,
+}
+
+/// Parameters applied when the admin engages `global_freeze`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalFreezeConfig {
+    /// Freeze only wallets scored at or above this (0 = every wallet)
+    pub score_floor: u32,
+    /// Seconds until the freeze lifts by itself
+    pub duration: u64,
+}
+
+/// Score-jump guard parameters (see `set_jump_guard`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JumpGuardConfig {
+    /// Largest score change that may freeze a wallet without confirmation
+    pub max_delta: u32,
+    /// Second Oracle key whose signature confirms a held jump
+    pub confirmer: PublicKey,
+}
+
+/// An engaged emergency freeze
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalFreeze {
+    /// Freeze only wallets scored at or above this (0 = every wallet)
+    pub score_floor: u32,
+    /// Ledger timestamp it was engaged
+    pub started_at: u64,
+    /// Ledger timestamp it lifts by itself
+    pub expires_at: u64,
+}
+
+/// Configuration check for an integrating contract (see `healthcheck`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthReport {
+    /// Whether the SDK has been initialized
+    pub initialized: bool,
+    /// Whether the consumer is a registered integrator
+    pub registered: bool,
+    /// Policy the consumer registered with
+    pub policy_id: Option<u32>,
+    /// Number of times that policy has been set (0 if none)
+    pub policy_version: u32,
+    /// Ledger timestamp of the last stored Oracle update (0 = never)
+    pub last_oracle_update: u64,
+    /// Whether the last Oracle update is older than `STALE_AFTER_SECONDS`
+    pub oracle_stale: bool,
+    /// Enabled feature bitmap
+    pub features: u32,
+    /// Whether an emergency global freeze is in effect
+    pub global_freeze: bool,
+}
+
+/// Combined view of a wallet's score, decision and data freshness
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assessment {
+    /// Whether the wallet has ever been scored
+    pub is_known: bool,
+    /// Current decision (Allow for unknown wallets)
+    pub decision: RiskDecision,
+    /// Raw risk score from 0-100 (0 for unknown wallets)
+    pub risk_score: u32,
+    /// Unix timestamp of last update (0 for unknown wallets)
+    pub last_updated: u64,
+    /// Seconds between last update and the current ledger timestamp
+    pub age_seconds: u64,
+    /// Whether the score is older than the staleness threshold
+    pub is_stale: bool,
+}
+
+/// Manual decision set by the admin that takes precedence over the Oracle
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Override {
+    /// Decision returned while the override is active
+    pub decision: RiskDecision,
+    /// Unix timestamp when the override lapses (0 = never)
+    pub expires_at: u64,
+    /// Admin that set the override (the wallet itself after remediation)
+    pub set_by: Address,
+    /// Unix timestamp when the override was set
+    pub set_at: u64,
+}
+
+/// What caused a change in a wallet's treatment
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransitionCause {
+    /// Oracle-signed score update
+    Oracle,
+    /// Admin override set or cleared
+    Override,
+    /// Score decay over time
+    Decay,
+    /// Outcome of an appeal
+    Appeal,
+    /// Wallet completed an Oracle-signed remediation plan
+    Remediation,
+    /// Wallet locked or unlocked itself
+    SelfLock,
+}
+
+/// Entry in a wallet's transition log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transition {
+    /// Decision after the transition
+    pub decision: RiskDecision,
+    /// What caused it
+    pub cause: TransitionCause,
+    /// Address that acted, if any (the Oracle is identified by key, not address)
+    pub actor: Option<Address>,
+    /// Ledger sequence the transition happened in
+    pub ledger: u32,
+    /// Ledger timestamp the transition happened at
+    pub timestamp: u64,
+}
+
+/// Running trial of a candidate Oracle key, with its comparison report
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyTrial {
+    /// Candidate Ed25519 public key
+    pub candidate: BytesN<32>,
+    /// Unix timestamp the trial started
+    pub started_at: u64,
+    /// Earliest Unix timestamp the candidate can be promoted
+    pub ends_at: u64,
+    /// Shadow payloads accepted from the candidate
+    pub shadow_submissions: u32,
+    /// Shadow decisions that matched the live decision
+    pub matches: u32,
+    /// Shadow decisions that differed from the live decision
+    pub mismatches: u32,
+}
+
+/// Oracle operator bond that funds freshness-challenge rewards
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleBond {
+    /// Operator that posted (and may top up) the bond
+    pub operator: Address,
+    /// Token the bond is denominated in
+    pub token: Address,
+    /// Remaining bonded amount held by the contract
+    pub amount: i128,
+}
+
+/// Bond withdrawal waiting out its timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondWithdrawal {
+    /// Amount requested (paid up to what remains after slashing)
+    pub amount: i128,
+    /// Unix timestamp from which the withdrawal can be completed
+    pub available_at: u64,
+}
+
+/// Fee charged by `request_score`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreFeeConfig {
+    /// Token the fee is paid in
+    pub token: Address,
+    /// Fee per request (positive)
+    pub amount: i128,
+}
+
+/// Bond an appellant posts when filing an appeal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppealBondConfig {
+    /// Token the bond is paid in
+    pub token: Address,
+    /// Bond amount per appeal (0 = free to file)
+    pub amount: i128,
+    /// Insurance pool that receives forfeited bonds
+    pub pool: Address,
+}
+
+/// Admin or Oracle ruling on an appeal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AppealOutcome {
+    /// Treatment lifted entirely (pinned to Allow); bond refunded
+    Upheld,
+    /// Treatment reduced to a Limit with this cap; bond refunded
+    Downgrade(u32),
+    /// Appeal found frivolous; bond forfeited to the insurance pool
+    Rejected,
+}
+
+/// Lifecycle of an appeal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AppealStatus {
+    /// Awaiting manual review
+    Pending,
+    /// Ruled on
+    Resolved(AppealOutcome),
+}
+
+/// Oracle-signed recovery path for a frozen wallet
+/// 
+/// Completing it (transferring `amount` of `token` to `recipient`)
+/// downgrades the freeze to `Limit(limit_amount)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemediationPlan {
+    /// Frozen wallet the plan is for
+    pub wallet: Address,
+    /// Token to return
+    pub token: Address,
+    /// Where the funds go (e.g. the exploited protocol)
+    pub recipient: Address,
+    /// Amount to return
+    pub amount: i128,
+    /// Limit cap once the plan is completed
+    pub limit_amount: u32,
+    /// Unix timestamp after which the plan can no longer be completed
+    pub expires_at: u64,
+}
+
+/// Appeal filed by a wallet against its treatment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Appeal {
+    /// Token the bond was paid in (`None` if no bond was staked)
+    pub token: Option<Address>,
+    /// Bond held by the contract for this appeal
+    pub bond: i128,
+    /// Unix timestamp when the appeal was filed
+    pub filed_at: u64,
+    /// Whether the wallet's guardian co-signed the appeal
+    pub guardian_cosigned: bool,
+    /// Current status
+    pub status: AppealStatus,
+}
+
+/// Oracle signature (64 bytes: Ed25519, or secp256r1 `r || s`)
 pub type Signature = BytesN<64>;
 
-/// Ed25519 public key type (32 bytes)
+/// Oracle key id (32 bytes): an Ed25519 public key, or the SHA-256 of a
+/// registered P-256 key (see `register_p256_key`)
 pub type PublicKey = BytesN<32>;
 
+/// SEC1 uncompressed secp256r1 public key (65 bytes, `0x04 || x || y`)
+pub type P256PublicKey = BytesN<65>;
+
+/// Signature scheme an Oracle key verifies with
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SignatureScheme {
+    Ed25519,
+    Secp256r1,
+}
+
+/// Stage of the decision pipeline, evaluated in configured order
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PipelineStage {
+    /// Wallet self-locks, then admin overrides (sanctions pins, manual exemptions)
+    Override,
+    /// Map the Oracle score to a decision using the score bands
+    OracleScore,
+    /// Release quarantine freezes whose `freeze_until` has passed
+    Quarantine,
+    /// Lower the score over time since the last update and re-map it
+    Decay,
+    /// Hold new Oracle freezes as pending for the configured grace period
+    Grace,
+}
+
+/// Where a wallet stands with respect to a freeze (see `get_freeze_status`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FreezeStatus {
+    /// Not frozen
+    NotFrozen,
+    /// Frozen by the Oracle but in its grace period until this timestamp;
+    /// `check_permission` still returns the pre-freeze decision
+    PendingFreeze(u64),
+    /// Freeze in effect
+    Frozen,
+}
+
+/// Score decay rate: `points` removed per full `period` seconds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayConfig {
+    /// Score points removed per period (0 = decay disabled)
+    pub points: u32,
+    /// Period length in seconds
+    pub period: u64,
+}
+
+/// Score thresholds used to turn a risk score into a decision
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreBands {
+    /// Highest score that is allowed
+    pub allow_max: u32,
+    /// Highest score that is limited
+    pub limit_max: u32,
+    /// Highest score held for review (above this wallets are frozen);
+    /// equal to `limit_max` for no Review band
+    pub review_max: u32,
+    /// Limit amount applied to the Limit band
+    pub limit_amount: u32,
+}
+
+impl ScoreBands {
+    /// Bands used until the admin configures others (0-49 / 50-79 / 80-100,
+    /// no Review band)
+    pub fn default_bands() -> Self {
+        ScoreBands {
+            allow_max: ALLOW_MAX_SCORE,
+            limit_max: LIMIT_MAX_SCORE,
+            review_max: REVIEW_MAX_SCORE,
+            limit_amount: DEFAULT_LIMIT_AMOUNT,
+        }
+    }
+    
+    /// Whether the thresholds are ordered and within 0-100
+    pub fn is_valid(&self) -> bool {
+        self.allow_max < self.limit_max
+            && self.limit_max <= self.review_max
+            && self.review_max <= MAX_RISK_SCORE
+    }
+    
+    /// Calculate decision from risk score (deterministic)
+    pub fn decide(&self, risk_score: u32) -> RiskDecision {
+        match band_for_score(risk_score, self.allow_max, self.limit_max, self.review_max) {
+            Some(Band::Allow) => RiskDecision::Allow,
+            Some(Band::Limit) => RiskDecision::Limit(self.limit_amount),
+            Some(Band::Review) => RiskDecision::Review,
+            Some(Band::Freeze) => RiskDecision::Freeze,
+            None => panic!("Invalid risk score: must be 0-100"),
+        }
+    }
+}
+
+/// How liquidations of a frozen borrower are treated
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LiquidationRule {
+    /// Freezes do not affect liquidations
+    Allowed,
+    /// Liquidations wait this many seconds after the freeze began
+    Delayed(u64),
+}
+
+/// Period after which `check_and_consume` allowances reset
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UsageWindow {
+    /// Resets at every UTC day boundary
+    Daily,
+    /// Resets every 7 days (aligned to the Unix epoch)
+    Weekly,
+}
+
+impl UsageWindow {
+    /// Window length in seconds
+    pub fn period(&self) -> u64 {
+        match self {
+            UsageWindow::Daily => 86_400,
+            UsageWindow::Weekly => 7 * 86_400,
+        }
+    }
+    
+    /// Start of the window containing `now`
+    pub fn start(&self, now: u64) -> u64 {
+        now - now % self.period()
+    }
+}
+
+/// Decision change queued for a subscriber (see `deliver_notifications`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskChange {
+    pub wallet: Address,
+    pub old: RiskDecision,
+    pub new: RiskDecision,
+}
+
+/// Amount a Limit wallet has consumed in the current window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Usage {
+    /// Start of the window this usage belongs to
+    pub window_start: u64,
+    /// Total filled across all integrators in the window
+    pub consumed: i128,
+}
+
+/// Named risk appetite applied by `check_permission_with_policy`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Policy {
+    /// Score thresholds and limit amount
+    pub bands: ScoreBands,
+    /// Age in seconds after which a score is stale (0 = never)
+    pub stale_after: u64,
+    /// Decision for wallets whose score is stale
+    pub stale_fallback: RiskDecision,
+    /// Decision for wallets that were never scored
+    pub unknown_default: RiskDecision,
+}
+
+impl Policy {
+    /// Policy equivalent to the global configuration with the given bands
+    pub fn from_bands(bands: ScoreBands) -> Self {
+        Policy {
+            bands,
+            stale_after: 0,
+            stale_fallback: RiskDecision::Allow,
+            unknown_default: RiskDecision::Allow,
+        }
+    }
+}
+
 impl RiskState {
-    /// Create new RiskState from payload
-    pub fn from_payload(payload: &RiskPayload) -> Self {
-        let decision = Self::calculate_decision(payload.risk_score);
+    /// Create new RiskState from payload using the given score bands
+    /// 
+    /// A Freeze with a `freeze_duration` becomes a quarantine that lapses
+    /// `freeze_duration` seconds after the Oracle signed the payload.
+    /// 
+    /// The decision is the worst case over the overall score and every
+    /// category score.
+    pub fn from_payload(payload: &RiskPayload, bands: &ScoreBands) -> Self {
+        let decision = bands.decide(worst_score(payload.risk_score, &payload.category_scores));
+        
+        let freeze_until = match decision {
+            RiskDecision::Freeze if payload.freeze_duration > 0 => {
+                payload.timestamp.saturating_add(payload.freeze_duration)
+            }
+            _ => 0,
+        };
         
         RiskState {
             risk_score: payload.risk_score,
             last_updated: payload.timestamp,
             decision,
+            reason_codes: payload.reason_codes.clone(),
+            freeze_until,
+            category_scores: payload.category_scores.clone(),
+            model_version: payload.model_version,
+            confidence: payload.confidence,
+            evidence_hash: payload.evidence_hash.clone(),
         }
     }
     
-    /// Calculate decision from risk score (deterministic)
-    fn calculate_decision(risk_score: u32) -> RiskDecision {
-        match risk_score {
-            0..=49 => RiskDecision::Allow,
-            50..=79 => RiskDecision::Limit(5000), // 5000 stroops limit
-            80..=100 => RiskDecision::Freeze,
-            _ => panic!("Invalid risk score: must be 0-100"),
-        }
+    /// Highest of the overall score and all category scores
+    pub fn worst_score(&self) -> u32 {
+        worst_score(self.risk_score, &self.category_scores)
     }
 }
+
+/// Highest of an overall score and a set of category scores
+pub fn worst_score(risk_score: u32, category_scores: &Map<RiskCategory, u32>) -> u32 {
+    category_scores.values().iter().fold(risk_score, u32::max)
+}
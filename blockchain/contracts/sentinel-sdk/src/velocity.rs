@@ -0,0 +1,164 @@
+//! Sliding-window velocity tracking.
+//!
+//! Registered integrators report the volume each wallet moves through
+//! them with `record_usage`; `get_usage` sums it over a trailing window so
+//! rules like "max 10k / 24h for Limit-tier wallets" can be enforced. The
+//! wallet authorizes every report, so an integrator cannot inflate the
+//! volume of a wallet that never used it.
+//! Volume is kept in hourly buckets per wallet, and buckets older than
+//! `MAX_USAGE_WINDOW` are pruned on every write.
+
+use sentinel_events::topic;
+use soroban_sdk::{contractimpl, Address, Env, Map};
+
+use crate::types::DataKey;
+use crate::{SentinelSDK, SentinelSDKClient};
+
+/// Bucket granularity in seconds
+pub const USAGE_BUCKET: u64 = 3_600;
+
+/// Longest window `get_usage` can answer for (7 days)
+pub const MAX_USAGE_WINDOW: u64 = 7 * 86_400;
+
+fn buckets(env: &Env, wallet: &Address) -> Map<u64, i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Velocity(wallet.clone()))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+#[contractimpl]
+impl SentinelSDK {
+    /// Report volume a wallet moved through an integrator
+    /// 
+    /// # Arguments
+    /// * `integrator` - Registered protocol; must authorize the call
+    /// * `wallet` - Address that moved the volume; must authorize the call
+    /// * `amount` - Volume moved (positive)
+    /// 
+    /// # Panics
+    /// * If `integrator` or `wallet` has not authorized the call
+    /// * If `integrator` is not registered (see `register_integrator`)
+    /// * If `amount` is not positive
+    pub fn record_usage(env: Env, integrator: Address, wallet: Address, amount: i128) {
+        integrator.require_auth();
+        wallet.require_auth();
+        if Self::get_integrator_policy(env.clone(), integrator.clone()).is_none() {
+            panic!("Unregistered integrator");
+        }
+        if amount <= 0 {
+            panic!("Usage amount must be positive");
+        }
+        
+        let now = env.ledger().timestamp();
+        let current = now / USAGE_BUCKET;
+        let oldest = now.saturating_sub(MAX_USAGE_WINDOW) / USAGE_BUCKET;
+        
+        let mut usage = buckets(&env, &wallet);
+        for bucket in usage.keys().iter() {
+            if bucket < oldest {
+                usage.remove(bucket);
+            }
+        }
+        let total = usage.get(current).unwrap_or(0).saturating_add(amount);
+        usage.set(current, total);
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::Velocity(wallet.clone()), &usage);
+        env.events().publish((topic::USAGE, wallet), (integrator, amount));
+    }
+    
+    /// Total volume reported for a wallet over the trailing window (read-only)
+    /// 
+    /// Resolution is one bucket: the hour containing `now - window` is
+    /// counted in full.
+    /// 
+    /// # Arguments
+    /// * `wallet` - Address to query
+    /// * `window` - Window length in seconds (at most `MAX_USAGE_WINDOW`)
+    /// 
+    /// # Panics
+    /// * If `window` exceeds `MAX_USAGE_WINDOW`
+    pub fn get_usage(env: Env, wallet: Address, window: u64) -> i128 {
+        if window > MAX_USAGE_WINDOW {
+            panic!("Usage window too long: max 7 days");
+        }
+        
+        let oldest = env.ledger().timestamp().saturating_sub(window) / USAGE_BUCKET;
+        buckets(&env, &wallet)
+            .iter()
+            .filter(|(bucket, _)| *bucket >= oldest)
+            .fold(0i128, |sum, (_, amount)| sum.saturating_add(amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Policy, ScoreBands};
+    use crate::testutils::{TimeTravel, DAY};
+    use soroban_sdk::{testutils::Address as _, BytesN};
+    
+    fn setup(env: &Env) -> (SentinelSDKClient<'_>, Address) {
+        env.mock_all_auths();
+        
+        let contract_id = env.register_contract(None, SentinelSDK);
+        let client = SentinelSDKClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &BytesN::from_array(env, &[0u8; 32]));
+        
+        client.set_policy(
+            &1,
+            &Policy::from_bands(ScoreBands { allow_max: 30, limit_max: 70, review_max: 70, limit_amount: 5000 }),
+        );
+        let integrator = Address::generate(env);
+        client.register_integrator(&client.get_admin(), &integrator, &1);
+        (client, integrator)
+    }
+    
+    #[test]
+    fn test_usage_sums_trailing_window() {
+        let env = Env::default();
+        let (client, integrator) = setup(&env);
+        let wallet = Address::generate(&env);
+        
+        env.set_time(10 * DAY);
+        client.record_usage(&integrator, &wallet, &4000);
+        assert!(env.auths().iter().any(|(address, _)| *address == wallet));
+        
+        env.advance_hours(12);
+        client.record_usage(&integrator, &wallet, &3000);
+        
+        assert_eq!(client.get_usage(&wallet, &DAY), 7000);
+        assert_eq!(client.get_usage(&wallet, &USAGE_BUCKET), 3000);
+        
+        // A day later the first report has left the 24h window
+        env.advance_hours(18);
+        assert_eq!(client.get_usage(&wallet, &DAY), 3000);
+        assert_eq!(client.get_usage(&wallet, &MAX_USAGE_WINDOW), 7000);
+    }
+    
+    #[test]
+    fn test_old_buckets_are_pruned() {
+        let env = Env::default();
+        let (client, integrator) = setup(&env);
+        let wallet = Address::generate(&env);
+        
+        env.set_time(10 * DAY);
+        client.record_usage(&integrator, &wallet, &100);
+        
+        env.advance_days(10);
+        client.record_usage(&integrator, &wallet, &1);
+        
+        let stored = env.as_contract(&client.address, || buckets(&env, &wallet));
+        assert_eq!(stored.len(), 1);
+    }
+    
+    #[test]
+    #[should_panic(expected = "Unregistered integrator")]
+    fn test_unregistered_integrator_cannot_report() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        client.record_usage(&Address::generate(&env), &Address::generate(&env), &1);
+    }
+}
@@ -0,0 +1,413 @@
+//! Canonical JSON layout for Oracle payloads.
+//!
+//! The one definition of the bytes an Oracle signs, shared by everything
+//! that builds or checks them: the contract drives it through a `Bytes`
+//! sink in `crypto.rs`, the in-contract verifier (`sentinel-verify`)
+//! through its own `Bytes` sink, and the browser build
+//! (`sentinel-verify-wasm`) and off-chain Rust tools through `Vec<u8>`.
+//!
+//! `no_std` with no Soroban dependency. The default `std` feature adds the
+//! `Vec<u8>` sink; contracts depend on the crate with
+//! `default-features = false`.
+//!
+//! `vectors/canonical.json` holds golden vectors: JSON objects paired with
+//! the exact message bytes they canonicalize to. This crate's tests and the
+//! Python Oracle's (`oracle/test_canonical_vectors.py`) both check them, so
+//! a layout change on either side fails CI instead of signatures.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Destination for serialized bytes
+pub trait ByteSink {
+    /// Append raw bytes
+    fn put(&mut self, bytes: &[u8]);
+}
+
+#[cfg(feature = "std")]
+impl ByteSink for std::vec::Vec<u8> {
+    fn put(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+/// Maximum length of a strkey wallet address (muxed `M...` accounts)
+pub const MAX_WALLET_LEN: usize = 69;
+
+/// Message family the canonical payload format belongs to
+pub const DOMAIN_TAG: &[u8] = b"sentinel-risk";
+
+/// Unit of payload timestamps and durations (seconds)
+pub const TIMESTAMP_UNITS: &[u8] = b"s";
+
+/// Write the serialization parameters a signer must agree on
+///
+/// Format: `sentinel-conformance|format=2|domain=sentinel-risk|timestamp=s`
+///
+/// Its SHA-256 is the deployment's conformance hash.
+pub fn write_conformance_descriptor<S: ByteSink>(out: &mut S, format_version: u32) {
+    out.put(b"sentinel-conformance|format=");
+    write_u32(out, format_version);
+    out.put(b"|domain=");
+    out.put(DOMAIN_TAG);
+    out.put(b"|timestamp=");
+    out.put(TIMESTAMP_UNITS);
+}
+
+/// Fixed-size payload fields that are omitted from the JSON when 0 or `None`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct OptionalFields {
+    /// Model confidence in basis points
+    pub confidence: u32,
+    /// Digest of the off-chain evidence report
+    pub evidence_hash: Option<[u8; 32]>,
+    /// Seconds a Freeze lasts before it is released
+    pub freeze_duration: u64,
+    /// Release of the ML model that produced the score
+    pub model_version: u32,
+}
+
+/// Write a payload as canonical JSON
+///
+/// Format: {"category_scores":{"aml":30,"fraud":90},"confidence":9500,"domain":"sentinel-risk:CXXX...","evidence_hash":"<64 hex chars>","freeze_duration":3600,"model_version":7,"reason_codes":[1,3],"risk_score":87,"timestamp":1737718800,"version":2,"wallet":"GXXX..."}
+///
+/// - Sorted keys, no whitespace, compact separators
+/// - `domain` is `DOMAIN_TAG:<contract strkey>` of the deployment the
+///   payload is meant for, so a signature cannot be replayed against
+///   another deployment or network
+/// - `version` is the payload format version, so the format a verifier
+///   picks is itself signed
+/// - `category_scores` must be yielded in ascending key order
+/// - `category_scores` and `reason_codes` are omitted when empty and the
+///   `optional` fields when 0 or `None`
+/// - `evidence_hash` is written as lowercase hex
+///
+/// Matches Python's `json.dumps(data, sort_keys=True, separators=(',', ':'))`.
+#[allow(clippy::too_many_arguments)]
+pub fn write_payload_json<'a, S, C, I>(
+    out: &mut S,
+    category_scores: C,
+    contract: &[u8],
+    optional: OptionalFields,
+    reason_codes: I,
+    risk_score: u32,
+    timestamp: u64,
+    version: u32,
+    wallet: &[u8],
+) where
+    S: ByteSink,
+    C: Iterator<Item = (&'a [u8], u32)>,
+    I: Iterator<Item = u32>,
+{
+    out.put(b"{");
+    
+    // Optional field: "category_scores":{"aml":30,"fraud":90},
+    let mut first = true;
+    for (key, score) in category_scores {
+        if first {
+            out.put(b"\"category_scores\":{");
+            first = false;
+        } else {
+            out.put(b",");
+        }
+        out.put(b"\"");
+        out.put(key);
+        out.put(b"\":");
+        write_u32(out, score);
+    }
+    if !first {
+        out.put(b"},");
+    }
+    
+    // Optional field: "confidence":9500,
+    if optional.confidence != 0 {
+        out.put(b"\"confidence\":");
+        write_u32(out, optional.confidence);
+        out.put(b",");
+    }
+    
+    // "domain":"sentinel-risk:CXXX...",
+    out.put(b"\"domain\":\"");
+    out.put(DOMAIN_TAG);
+    out.put(b":");
+    out.put(contract);
+    out.put(b"\",");
+    
+    // Optional field: "evidence_hash":"<hex>",
+    if let Some(hash) = &optional.evidence_hash {
+        out.put(b"\"evidence_hash\":\"");
+        write_hex(out, hash);
+        out.put(b"\",");
+    }
+    
+    // Optional field: "freeze_duration":3600,
+    if optional.freeze_duration != 0 {
+        out.put(b"\"freeze_duration\":");
+        write_u64(out, optional.freeze_duration);
+        out.put(b",");
+    }
+    
+    // Optional field: "model_version":7,
+    if optional.model_version != 0 {
+        out.put(b"\"model_version\":");
+        write_u32(out, optional.model_version);
+        out.put(b",");
+    }
+    
+    // Optional field: "reason_codes":[1,3],
+    let mut first = true;
+    for code in reason_codes {
+        if first {
+            out.put(b"\"reason_codes\":[");
+            first = false;
+        } else {
+            out.put(b",");
+        }
+        write_u32(out, code);
+    }
+    if !first {
+        out.put(b"],");
+    }
+    
+    // "risk_score":87,
+    out.put(b"\"risk_score\":");
+    write_u32(out, risk_score);
+    out.put(b",");
+    
+    // "timestamp":1737718800,
+    out.put(b"\"timestamp\":");
+    write_u64(out, timestamp);
+    out.put(b",");
+    
+    // "version":2,
+    out.put(b"\"version\":");
+    write_u32(out, version);
+    out.put(b",");
+    
+    // "wallet":"GBXXX..."
+    out.put(b"\"wallet\":\"");
+    out.put(wallet);
+    out.put(b"\"}");
+}
+
+/// Write an Oracle label assignment as canonical JSON
+///
+/// Format: {"labels":["exchange","mixer"],"timestamp":1737718800,"wallet":"GXXX..."}
+///
+/// Labels keep the order given (an empty list clears all labels).
+pub fn write_labels_json<S, L, I>(out: &mut S, labels: I, timestamp: u64, wallet: &[u8])
+where
+    S: ByteSink,
+    L: AsRef<[u8]>,
+    I: Iterator<Item = L>,
+{
+    out.put(b"{\"labels\":[");
+    for (i, label) in labels.enumerate() {
+        if i > 0 {
+            out.put(b",");
+        }
+        out.put(b"\"");
+        out.put(label.as_ref());
+        out.put(b"\"");
+    }
+    out.put(b"],\"timestamp\":");
+    write_u64(out, timestamp);
+    out.put(b",\"wallet\":\"");
+    out.put(wallet);
+    out.put(b"\"}");
+}
+
+/// Write an Oracle Merkle-root attestation as canonical JSON
+///
+/// Format: {"merkle_root":"<64 lowercase hex chars>","timestamp":1737718800}
+pub fn write_merkle_root_json<S: ByteSink>(out: &mut S, root: &[u8; 32], timestamp: u64) {
+    out.put(b"{\"merkle_root\":\"");
+    write_hex(out, root);
+    out.put(b"\",\"timestamp\":");
+    write_u64(out, timestamp);
+    out.put(b"}");
+}
+
+/// Write an Oracle ruling on a wallet's appeal as canonical JSON
+///
+/// Format: {"filed_at":1737718800,"limit":5000,"outcome":"downgrade","wallet":"GXXX..."}
+///
+/// `filed_at` identifies the appeal being ruled on, so a ruling cannot be
+/// replayed against a later appeal. `limit` is present only for
+/// `downgrade`.
+pub fn write_appeal_ruling_json<S: ByteSink>(
+    out: &mut S,
+    filed_at: u64,
+    limit: Option<u32>,
+    outcome: &[u8],
+    wallet: &[u8],
+) {
+    out.put(b"{\"filed_at\":");
+    write_u64(out, filed_at);
+    if let Some(limit) = limit {
+        out.put(b",\"limit\":");
+        write_u32(out, limit);
+    }
+    out.put(b",\"outcome\":\"");
+    out.put(outcome);
+    out.put(b"\",\"wallet\":\"");
+    out.put(wallet);
+    out.put(b"\"}");
+}
+
+/// Write bytes as lowercase hex ASCII
+pub fn write_hex<S: ByteSink>(out: &mut S, bytes: &[u8]) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    for byte in bytes {
+        out.put(&[DIGITS[(byte >> 4) as usize], DIGITS[(byte & 0x0f) as usize]]);
+    }
+}
+
+/// Write u32 as decimal ASCII
+pub fn write_u32<S: ByteSink>(out: &mut S, value: u32) {
+    write_u64(out, value as u64);
+}
+
+/// Write u64 as decimal ASCII
+pub fn write_u64<S: ByteSink>(out: &mut S, mut value: u64) {
+    if value == 0 {
+        out.put(b"0");
+        return;
+    }
+    
+    // Build digits in reverse
+    let mut digits = [0u8; 20]; // u64 max is 20 digits
+    let mut i = digits.len();
+    
+    while value > 0 {
+        i -= 1;
+        digits[i] = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+    
+    out.put(&digits[i..]);
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_u32_to_bytes() {
+        let mut out = Vec::new();
+        write_u32(&mut out, 87);
+        assert_eq!(out, b"87");
+    }
+    
+    #[test]
+    fn test_u64_to_bytes() {
+        let mut out = Vec::new();
+        write_u64(&mut out, 1737718800);
+        assert_eq!(out, b"1737718800");
+        
+        let mut out = Vec::new();
+        write_u64(&mut out, u64::MAX);
+        assert_eq!(out, b"18446744073709551615");
+    }
+    
+    #[test]
+    fn test_payload_json_matches_python_layout() {
+        let mut out = Vec::new();
+        write_payload_json(&mut out, [].into_iter(), b"CDEF", OptionalFields::default(), [].into_iter(), 87, 1737718800, 2, b"GABC");
+        assert_eq!(
+            out,
+            b"{\"domain\":\"sentinel-risk:CDEF\",\"risk_score\":87,\"timestamp\":1737718800,\"version\":2,\"wallet\":\"GABC\"}"
+        );
+        
+        let mut out = Vec::new();
+        write_payload_json(&mut out, [].into_iter(), b"CDEF", OptionalFields::default(), [1, 3].into_iter(), 87, 1737718800, 2, b"GABC");
+        assert_eq!(
+            out,
+            b"{\"domain\":\"sentinel-risk:CDEF\",\"reason_codes\":[1,3],\"risk_score\":87,\"timestamp\":1737718800,\"version\":2,\"wallet\":\"GABC\"}"
+        );
+    }
+    
+    #[test]
+    fn test_freeze_duration_sorts_first() {
+        let mut out = Vec::new();
+        let optional = OptionalFields { freeze_duration: 3600, ..Default::default() };
+        write_payload_json(&mut out, [].into_iter(), b"CDEF", optional, [2].into_iter(), 90, 5, 2, b"GABC");
+        assert_eq!(
+            out,
+            b"{\"domain\":\"sentinel-risk:CDEF\",\"freeze_duration\":3600,\"reason_codes\":[2],\"risk_score\":90,\"timestamp\":5,\"version\":2,\"wallet\":\"GABC\"}"
+        );
+    }
+    
+    #[test]
+    fn test_provenance_sorts_around_freeze_duration() {
+        let mut out = Vec::new();
+        let optional = OptionalFields {
+            confidence: 9500,
+            freeze_duration: 3600,
+            model_version: 7,
+            ..Default::default()
+        };
+        write_payload_json(&mut out, [].into_iter(), b"CDEF", optional, [].into_iter(), 90, 5, 2, b"GABC");
+        assert_eq!(
+            out,
+            b"{\"confidence\":9500,\"domain\":\"sentinel-risk:CDEF\",\"freeze_duration\":3600,\"model_version\":7,\"risk_score\":90,\"timestamp\":5,\"version\":2,\"wallet\":\"GABC\"}"
+        );
+    }
+    
+    #[test]
+    fn test_evidence_hash_is_hex() {
+        let mut out = Vec::new();
+        let optional = OptionalFields { evidence_hash: Some([0xab; 32]), ..Default::default() };
+        write_payload_json(&mut out, [].into_iter(), b"CDEF", optional, [].into_iter(), 90, 5, 2, b"GABC");
+        
+        let mut expected = b"{\"domain\":\"sentinel-risk:CDEF\",\"evidence_hash\":\"".to_vec();
+        expected.extend_from_slice(&b"ab".repeat(32));
+        expected.extend_from_slice(b"\",\"risk_score\":90,\"timestamp\":5,\"version\":2,\"wallet\":\"GABC\"}");
+        assert_eq!(out, expected);
+    }
+    
+    #[test]
+    fn test_category_scores_sort_first() {
+        let mut out = Vec::new();
+        let categories = [(&b"aml"[..], 30), (&b"fraud"[..], 90)];
+        write_payload_json(&mut out, categories.into_iter(), b"CDEF", OptionalFields::default(), [].into_iter(), 90, 5, 2, b"GABC");
+        assert_eq!(
+            out,
+            b"{\"category_scores\":{\"aml\":30,\"fraud\":90},\"domain\":\"sentinel-risk:CDEF\",\"risk_score\":90,\"timestamp\":5,\"version\":2,\"wallet\":\"GABC\"}"
+        );
+    }
+    
+    #[test]
+    fn test_labels_json_layout() {
+        let mut out = Vec::new();
+        write_labels_json(&mut out, [&b"exchange"[..], &b"mixer"[..]].into_iter(), 5, b"GABC");
+        assert_eq!(
+            out,
+            b"{\"labels\":[\"exchange\",\"mixer\"],\"timestamp\":5,\"wallet\":\"GABC\"}"
+        );
+        
+        let mut out = Vec::new();
+        write_labels_json(&mut out, core::iter::empty::<&[u8]>(), 5, b"GABC");
+        assert_eq!(out, b"{\"labels\":[],\"timestamp\":5,\"wallet\":\"GABC\"}");
+    }
+    
+    #[test]
+    fn test_merkle_root_json_layout() {
+        let mut root = [0u8; 32];
+        root[0] = 0xab;
+        root[31] = 0x01;
+        
+        let mut out = Vec::new();
+        write_merkle_root_json(&mut out, &root, 5);
+        assert_eq!(
+            out,
+            b"{\"merkle_root\":\"ab00000000000000000000000000000000000000000000000000000000000001\",\"timestamp\":5}"
+        );
+    }
+    
+    #[test]
+    fn test_conformance_descriptor() {
+        let mut out = Vec::new();
+        write_conformance_descriptor(&mut out, 2);
+        assert_eq!(out, b"sentinel-conformance|format=2|domain=sentinel-risk|timestamp=s");
+    }
+}
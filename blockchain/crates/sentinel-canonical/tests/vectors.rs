@@ -0,0 +1,130 @@
+//! Golden vectors shared with the Python Oracle (`vectors/canonical.json`).
+
+use sentinel_canonical::{
+    write_appeal_ruling_json, write_labels_json, write_merkle_root_json, write_payload_json, OptionalFields,
+    DOMAIN_TAG,
+};
+use serde_json::Value;
+
+const VECTORS: &str = include_str!("../vectors/canonical.json");
+
+fn u64_field(object: &Value, key: &str) -> u64 {
+    object.get(key).map_or(0, |value| value.as_u64().expect(key))
+}
+
+fn u32_field(object: &Value, key: &str) -> u32 {
+    u32::try_from(u64_field(object, key)).expect(key)
+}
+
+fn str_field<'a>(object: &'a Value, key: &str) -> &'a str {
+    object[key].as_str().expect(key)
+}
+
+fn hash_field(object: &Value, key: &str) -> [u8; 32] {
+    let hex = str_field(object, key);
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).expect(key);
+    }
+    hash
+}
+
+fn payload_message(object: &Value) -> Vec<u8> {
+    let mut categories: Vec<(&[u8], u32)> = object
+        .get("category_scores")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .map(|(key, score)| (key.as_bytes(), u32::try_from(score.as_u64().unwrap()).unwrap()))
+        .collect();
+    categories.sort();
+    
+    let reason_codes: Vec<u32> = object
+        .get("reason_codes")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|code| u32::try_from(code.as_u64().unwrap()).unwrap())
+        .collect();
+    
+    let optional = OptionalFields {
+        confidence: u32_field(object, "confidence"),
+        evidence_hash: object.get("evidence_hash").map(|_| hash_field(object, "evidence_hash")),
+        freeze_duration: u64_field(object, "freeze_duration"),
+        model_version: u32_field(object, "model_version"),
+    };
+    
+    let contract = str_field(object, "domain")
+        .as_bytes()
+        .strip_prefix(DOMAIN_TAG)
+        .and_then(|rest| rest.strip_prefix(b":"))
+        .expect("domain");
+    
+    let mut out = Vec::new();
+    write_payload_json(
+        &mut out,
+        categories.into_iter(),
+        contract,
+        optional,
+        reason_codes.into_iter(),
+        u32_field(object, "risk_score"),
+        u64_field(object, "timestamp"),
+        u32_field(object, "version"),
+        str_field(object, "wallet").as_bytes(),
+    );
+    out
+}
+
+fn labels_message(object: &Value) -> Vec<u8> {
+    let labels = object["labels"].as_array().expect("labels");
+    let mut out = Vec::new();
+    write_labels_json(
+        &mut out,
+        labels.iter().map(|label| label.as_str().unwrap().as_bytes()),
+        u64_field(object, "timestamp"),
+        str_field(object, "wallet").as_bytes(),
+    );
+    out
+}
+
+fn merkle_root_message(object: &Value) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_merkle_root_json(&mut out, &hash_field(object, "merkle_root"), u64_field(object, "timestamp"));
+    out
+}
+
+fn appeal_ruling_message(object: &Value) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_appeal_ruling_json(
+        &mut out,
+        u64_field(object, "filed_at"),
+        object.get("limit").map(|_| u32_field(object, "limit")),
+        str_field(object, "outcome").as_bytes(),
+        str_field(object, "wallet").as_bytes(),
+    );
+    out
+}
+
+#[test]
+fn test_golden_vectors() {
+    let vectors: Value = serde_json::from_str(VECTORS).unwrap();
+    let vectors = vectors["vectors"].as_array().unwrap();
+    assert!(!vectors.is_empty());
+    
+    for vector in vectors {
+        let object = &vector["object"];
+        let message = match str_field(vector, "kind") {
+            "payload" => payload_message(object),
+            "labels" => labels_message(object),
+            "merkle_root" => merkle_root_message(object),
+            "appeal_ruling" => appeal_ruling_message(object),
+            kind => panic!("unknown vector kind {kind}"),
+        };
+        assert_eq!(
+            String::from_utf8(message).unwrap(),
+            str_field(vector, "message"),
+            "vector {}",
+            str_field(vector, "name")
+        );
+    }
+}
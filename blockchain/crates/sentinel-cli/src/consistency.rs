@@ -0,0 +1,89 @@
+//! Cross-deployment consistency checker.
+//!
+//! Compares configuration reads and per-wallet decisions between two
+//! deployments (testnet vs mainnet, old vs upgraded) and reports every
+//! divergence, catching misconfigured thresholds or serialization mismatches
+//! before cutover.
+
+use serde_json::Value;
+
+use crate::stellar::{CliError, Stellar};
+
+/// Read-only configuration getters compared between deployments
+pub const CONFIG_READS: &[&str] = &[
+    "conformance_hash",
+    "enabled_features",
+    "get_pipeline",
+    "get_score_bands",
+    "get_unknown_default",
+    "get_quarantine_release",
+    "get_decay",
+    "get_liquidation_rule",
+];
+
+/// One difference between the deployments
+#[derive(Debug, PartialEq)]
+pub struct Divergence {
+    /// Getter or `function(wallet)` that differed
+    pub what: String,
+    pub left: Value,
+    pub right: Value,
+}
+
+/// Compare two deployments over the config getters and a wallet sample
+pub fn compare(left: &Stellar, right: &Stellar, wallets: &[String]) -> Result<Vec<Divergence>, CliError> {
+    let mut divergences = Vec::new();
+    
+    for function in CONFIG_READS {
+        let l = left.read(function, &[])?;
+        let r = right.read(function, &[])?;
+        push_if_different(&mut divergences, function.to_string(), l, r);
+    }
+    
+    for wallet in wallets {
+        for function in ["check_permission", "get_risk"] {
+            let args = [("wallet", wallet.clone())];
+            let l = left.read(function, &args)?;
+            let r = right.read(function, &args)?;
+            push_if_different(&mut divergences, format!("{function}({wallet})"), l, r);
+        }
+    }
+    
+    Ok(divergences)
+}
+
+/// Parse a wallet sample: one address per line, `#` comments allowed
+pub fn parse_wallets(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn push_if_different(out: &mut Vec<Divergence>, what: String, left: Value, right: Value) {
+    if left != right {
+        out.push(Divergence { what, left, right });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn test_parse_wallets_skips_comments_and_blanks() {
+        let text = "# sample\nGAAA\n\n  GBBB  # exchange hot wallet\n";
+        assert_eq!(parse_wallets(text), vec!["GAAA".to_string(), "GBBB".to_string()]);
+    }
+    
+    #[test]
+    fn test_only_differences_are_reported() {
+        let mut out = Vec::new();
+        push_if_different(&mut out, "a".into(), json!(1), json!(1));
+        push_if_different(&mut out, "b".into(), json!("Allow"), json!({"Limit": 5000}));
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].what, "b");
+    }
+}
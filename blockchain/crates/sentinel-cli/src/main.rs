@@ -0,0 +1,169 @@
+//! Sentinel operator CLI.
+//!
+//! Runbook commands chain the contract calls, verifications and event
+//! index checks an incident needs, with confirmation prompts in between:
+//!
+//! ```text
+//! sentinel-cli incident freeze-and-notify <wallet>
+//! sentinel-cli rotate-oracle --candidate <hex> --dry-run
+//! sentinel-cli compare --other-contract-id <id> --wallets sample.txt
+//! ```
+
+mod consistency;
+mod runbook;
+mod stellar;
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use runbook::Runbook;
+use stellar::Stellar;
+
+#[derive(Parser)]
+#[command(name = "sentinel-cli", version, about = "Sentinel operator runbooks")]
+struct Cli {
+    /// Sentinel contract ID
+    #[arg(long, env = "SENTINEL_CONTRACT_ID")]
+    contract_id: String,
+    
+    /// Network name configured in the `stellar` CLI
+    #[arg(long, env = "SENTINEL_NETWORK", default_value = "testnet")]
+    network: String,
+    
+    /// Admin identity configured in the `stellar` CLI
+    #[arg(long, env = "SENTINEL_SOURCE")]
+    source: String,
+    
+    /// Shell command run after a successful action ($WALLET, $ACTION set)
+    #[arg(long, env = "SENTINEL_NOTIFY_CMD")]
+    notify_cmd: Option<String>,
+    
+    /// Answer yes to every confirmation prompt
+    #[arg(long, short = 'y', global = true)]
+    yes: bool,
+    
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Incident response
+    Incident {
+        #[command(subcommand)]
+        action: IncidentAction,
+    },
+    /// Rotate the Oracle key through a blue/green key trial
+    ///
+    /// Without --promote, starts a trial for the candidate; with --promote,
+    /// promotes a finished trial after checking its shadow comparison.
+    RotateOracle {
+        /// Candidate Ed25519 public key (hex)
+        #[arg(long)]
+        candidate: String,
+        /// Trial length in seconds
+        #[arg(long, default_value_t = 86_400)]
+        duration: u64,
+        /// Promote the running trial instead of starting one
+        #[arg(long)]
+        promote: bool,
+        /// Print the plan without submitting anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Compare configuration and decisions with another deployment
+    ///
+    /// Exits non-zero if any getter or sampled wallet decision differs.
+    Compare {
+        /// Contract ID of the other deployment
+        #[arg(long)]
+        other_contract_id: String,
+        /// Network of the other deployment (defaults to --network)
+        #[arg(long)]
+        other_network: Option<String>,
+        /// File with one wallet address per line
+        #[arg(long)]
+        wallets: Option<std::path::PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum IncidentAction {
+    /// Freeze a wallet by admin override, verify it and notify
+    FreezeAndNotify {
+        /// Wallet address (G... or C...)
+        wallet: String,
+        /// Ledger time the freeze lapses (0 = until cleared)
+        #[arg(long, default_value_t = 0)]
+        expiry: u64,
+    },
+}
+
+fn run_compare(
+    left: &Stellar,
+    right: &Stellar,
+    wallets: Option<std::path::PathBuf>,
+) -> Result<(), stellar::CliError> {
+    let wallets = match wallets {
+        Some(path) => consistency::parse_wallets(
+            &std::fs::read_to_string(path).map_err(stellar::CliError::Io)?,
+        ),
+        None => Vec::new(),
+    };
+    
+    println!(
+        "Comparing {} ({}) with {} ({}) over {} wallets",
+        left.contract_id, left.network, right.contract_id, right.network, wallets.len()
+    );
+    let divergences = consistency::compare(left, right, &wallets)?;
+    for d in &divergences {
+        println!("  DIFF {}\n    left:  {}\n    right: {}", d.what, d.left, d.right);
+    }
+    
+    if divergences.is_empty() {
+        println!("No divergences.");
+        Ok(())
+    } else {
+        Err(stellar::CliError::Check(format!("{} divergences", divergences.len())))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let stellar = Stellar {
+        contract_id: cli.contract_id,
+        network: cli.network,
+        source: cli.source,
+    };
+    let runbook = Runbook {
+        stellar: &stellar,
+        assume_yes: cli.yes,
+        notify_cmd: cli.notify_cmd,
+    };
+    
+    let result = match cli.command {
+        Command::Incident { action: IncidentAction::FreezeAndNotify { wallet, expiry } } => {
+            runbook.freeze_and_notify(&wallet, expiry)
+        }
+        Command::RotateOracle { candidate, duration, promote, dry_run } => {
+            runbook.rotate_oracle(&candidate, duration, promote, dry_run)
+        }
+        Command::Compare { other_contract_id, other_network, wallets } => {
+            let other = Stellar {
+                contract_id: other_contract_id,
+                network: other_network.unwrap_or_else(|| stellar.network.clone()),
+                source: stellar.source.clone(),
+            };
+            run_compare(&stellar, &other, wallets)
+        }
+    };
+    
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Incident-response runbooks.
+//!
+//! Each runbook prints numbered steps, asks for confirmation before any
+//! state-changing call, and verifies the result on-chain and in the RPC
+//! event index afterwards, so an operator under pressure cannot skip a
+//! check by accident.
+
+use std::io::{self, BufRead, Write};
+use std::process::Command;
+
+use serde_json::Value;
+
+use crate::stellar::{variant, CliError, Stellar};
+
+/// Shared runbook options
+pub struct Runbook<'a> {
+    pub stellar: &'a Stellar,
+    /// Skip confirmation prompts
+    pub assume_yes: bool,
+    /// Shell command run after a successful action (`$WALLET`/`$ACTION` set)
+    pub notify_cmd: Option<String>,
+}
+
+impl Runbook<'_> {
+    /// `incident freeze-and-notify <wallet>`
+    pub fn freeze_and_notify(&self, wallet: &str, expiry: u64) -> Result<(), CliError> {
+        step(1, "Read current decision");
+        let before = self.stellar.read("check_permission", &[("wallet", wallet.to_string())])?;
+        println!("    {wallet}: {before}");
+        if before == Value::String("Freeze".into()) {
+            println!("    already frozen; the override pins it until cleared");
+        }
+        
+        step(2, "Confirm");
+        let until = if expiry == 0 { "cleared".to_string() } else { format!("ledger time {expiry}") };
+        self.confirm(&format!("Freeze {wallet} by admin override until {until}?"))?;
+        
+        step(3, "Submit set_override");
+        let start_ledger = self.stellar.latest_ledger()?;
+        self.stellar.invoke(
+            "set_override",
+            &[
+                ("caller", self.stellar.source.clone()),
+                ("wallet", wallet.to_string()),
+                ("decision", variant("Freeze")),
+                ("expiry", expiry.to_string()),
+            ],
+        )?;
+        
+        step(4, "Verify on-chain decision");
+        let after = self.stellar.read("check_permission", &[("wallet", wallet.to_string())])?;
+        if after != Value::String("Freeze".into()) {
+            return Err(CliError::Check(format!("decision is {after}, expected \"Freeze\"")));
+        }
+        println!("    check_permission = \"Freeze\"");
+        
+        step(5, "Verify event index");
+        if !self.stellar.has_event("OVR_SET", start_ledger)? {
+            return Err(CliError::Check("OVR_SET not yet visible in the event index".into()));
+        }
+        println!("    OVR_SET indexed since ledger {start_ledger}");
+        
+        step(6, "Notify");
+        self.notify(wallet, "freeze")
+    }
+    
+    /// `rotate-oracle --candidate <hex> [--duration <s>] [--promote] [--dry-run]`
+    pub fn rotate_oracle(
+        &self,
+        candidate: &str,
+        duration: u64,
+        promote: bool,
+        dry_run: bool,
+    ) -> Result<(), CliError> {
+        step(1, "Validate candidate key");
+        if !is_hex_key(candidate) {
+            return Err(CliError::Check("candidate must be 32 bytes of hex".into()));
+        }
+        
+        step(2, "Read current Oracle key and trial");
+        let current = self.stellar.read("get_oracle_pubkey", &[])?;
+        let trial = self.stellar.read("get_key_trial", &[])?;
+        println!("    live key:  {current}");
+        println!("    trial:     {trial}");
+        if current == Value::String(candidate.to_lowercase()) {
+            return Err(CliError::Check("candidate is already the live key".into()));
+        }
+        
+        let plan = rotation_plan(&trial, candidate, promote)?;
+        
+        step(3, "Plan");
+        match plan {
+            RotationStep::StartTrial => println!("    start_key_trial({candidate}, {duration}s)"),
+            RotationStep::Promote => println!("    promote_trial_key() -> {candidate}"),
+        }
+        if dry_run {
+            println!("\nDry run: no transaction submitted.");
+            return Ok(());
+        }
+        
+        step(4, "Confirm");
+        self.confirm("Submit this rotation step?")?;
+        
+        let start_ledger = self.stellar.latest_ledger()?;
+        match plan {
+            RotationStep::StartTrial => {
+                step(5, "Submit start_key_trial");
+                self.stellar.invoke(
+                    "start_key_trial",
+                    &[
+                        ("caller", self.stellar.source.clone()),
+                        ("candidate", candidate.to_string()),
+                        ("duration", duration.to_string()),
+                    ],
+                )?;
+                
+                step(6, "Verify trial and event index");
+                let trial = self.stellar.read("get_key_trial", &[])?;
+                if trial.get("candidate") != Some(&Value::String(candidate.to_lowercase())) {
+                    return Err(CliError::Check(format!("trial is {trial}")));
+                }
+                self.require_event("TRIAL_ON", start_ledger)?;
+                self.notify(candidate, "key-trial")
+            }
+            RotationStep::Promote => {
+                step(5, "Submit promote_trial_key");
+                self.stellar.invoke("promote_trial_key", &[("caller", self.stellar.source.clone())])?;
+                
+                step(6, "Verify live key and event index");
+                let live = self.stellar.read("get_oracle_pubkey", &[])?;
+                if live != Value::String(candidate.to_lowercase()) {
+                    return Err(CliError::Check(format!("live key is {live}")));
+                }
+                self.require_event("KEY_ROT", start_ledger)?;
+                self.notify(candidate, "key-rotated")
+            }
+        }
+    }
+    
+    fn require_event(&self, topic: &str, start_ledger: u64) -> Result<(), CliError> {
+        if !self.stellar.has_event(topic, start_ledger)? {
+            return Err(CliError::Check(format!("{topic} not yet visible in the event index")));
+        }
+        println!("    {topic} indexed since ledger {start_ledger}");
+        Ok(())
+    }
+    
+    fn confirm(&self, question: &str) -> Result<(), CliError> {
+        if self.assume_yes {
+            println!("    {question} yes (--yes)");
+            return Ok(());
+        }
+        
+        print!("    {question} [y/N] ");
+        io::stdout().flush().ok();
+        let mut answer = String::new();
+        io::stdin().lock().read_line(&mut answer).map_err(CliError::Io)?;
+        
+        match answer.trim() {
+            "y" | "Y" | "yes" => Ok(()),
+            _ => Err(CliError::Aborted),
+        }
+    }
+    
+    fn notify(&self, wallet: &str, action: &str) -> Result<(), CliError> {
+        let Some(cmd) = &self.notify_cmd else {
+            println!("    no notify command configured (SENTINEL_NOTIFY_CMD)");
+            return Ok(());
+        };
+        
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(cmd)
+            .env("WALLET", wallet)
+            .env("ACTION", action)
+            .status()
+            .map_err(CliError::Io)?;
+        if !status.success() {
+            return Err(CliError::Check(format!("notify command exited with {status}")));
+        }
+        println!("    notified ({action})");
+        Ok(())
+    }
+}
+
+/// Next state-changing step of a key rotation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RotationStep {
+    StartTrial,
+    Promote,
+}
+
+/// Decide the rotation step from the current trial (JSON from `get_key_trial`)
+pub fn rotation_plan(trial: &Value, candidate: &str, promote: bool) -> Result<RotationStep, CliError> {
+    match (trial, promote) {
+        (Value::Null, false) => Ok(RotationStep::StartTrial),
+        (Value::Null, true) => Err(CliError::Check("no key trial to promote".into())),
+        (trial, false) => Err(CliError::Check(format!(
+            "a key trial is already running ({trial}); use --promote or cancel it first"
+        ))),
+        (trial, true) => {
+            if trial.get("candidate") != Some(&Value::String(candidate.to_lowercase())) {
+                return Err(CliError::Check("running trial is for a different key".into()));
+            }
+            if trial.get("mismatches").and_then(Value::as_u64).unwrap_or(0) > 0 {
+                return Err(CliError::Check(
+                    "shadow comparison has mismatches; investigate before promoting".into(),
+                ));
+            }
+            Ok(RotationStep::Promote)
+        }
+    }
+}
+
+/// Whether `key` is a 32-byte hex string
+pub fn is_hex_key(key: &str) -> bool {
+    key.len() == 64 && key.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn step(n: u32, title: &str) {
+    println!("[{n}] {title}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    const KEY: &str = "aa00000000000000000000000000000000000000000000000000000000000000";
+    
+    #[test]
+    fn test_hex_key_validation() {
+        assert!(is_hex_key(KEY));
+        assert!(!is_hex_key("aa"));
+        assert!(!is_hex_key(&KEY.replace('a', "z")));
+    }
+    
+    #[test]
+    fn test_rotation_plan() {
+        assert_eq!(rotation_plan(&Value::Null, KEY, false).unwrap(), RotationStep::StartTrial);
+        assert!(rotation_plan(&Value::Null, KEY, true).is_err());
+        
+        let clean = json!({ "candidate": KEY, "matches": 12, "mismatches": 0 });
+        assert_eq!(rotation_plan(&clean, KEY, true).unwrap(), RotationStep::Promote);
+        assert!(rotation_plan(&clean, KEY, false).is_err());
+        
+        let dirty = json!({ "candidate": KEY, "matches": 12, "mismatches": 1 });
+        assert!(rotation_plan(&dirty, KEY, true).is_err());
+    }
+}
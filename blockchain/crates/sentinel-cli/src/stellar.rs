@@ -0,0 +1,150 @@
+//! Thin wrapper over the `stellar` CLI.
+//!
+//! Every contract interaction goes through `stellar contract invoke`, so
+//! the operator's configured identities and networks are reused as-is.
+//! Reads are simulated (`--send=no`) and never submit a transaction.
+
+use std::fmt;
+use std::process::Command;
+
+use serde_json::Value;
+
+/// Error from a `stellar` invocation
+#[derive(Debug)]
+pub enum CliError {
+    /// The `stellar` binary could not be started
+    Spawn(std::io::Error),
+    /// Local I/O failed (prompt input, wallet sample file)
+    Io(std::io::Error),
+    /// The command exited non-zero (stderr attached)
+    Failed(String),
+    /// The command's output was not the expected JSON
+    Parse(String),
+    /// A runbook check did not hold
+    Check(String),
+    /// The operator declined a confirmation prompt
+    Aborted,
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Spawn(e) => write!(f, "failed to run `stellar`: {e}"),
+            CliError::Io(e) => write!(f, "i/o error: {e}"),
+            CliError::Failed(stderr) => write!(f, "`stellar` failed: {}", stderr.trim()),
+            CliError::Parse(output) => write!(f, "unexpected output: {}", output.trim()),
+            CliError::Check(msg) => write!(f, "check failed: {msg}"),
+            CliError::Aborted => write!(f, "aborted by operator"),
+        }
+    }
+}
+
+/// A deployed Sentinel contract reached through the `stellar` CLI
+pub struct Stellar {
+    pub contract_id: String,
+    pub network: String,
+    pub source: String,
+}
+
+impl Stellar {
+    /// Simulate a read-only call and parse its JSON result
+    pub fn read(&self, function: &str, args: &[(&str, String)]) -> Result<Value, CliError> {
+        let output = self.run_invoke(function, args, false)?;
+        parse_json(&output)
+    }
+    
+    /// Submit a state-changing call; returns the raw CLI output
+    pub fn invoke(&self, function: &str, args: &[(&str, String)]) -> Result<String, CliError> {
+        self.run_invoke(function, args, true)
+    }
+    
+    /// Latest ledger sequence known to the network's RPC
+    pub fn latest_ledger(&self) -> Result<u64, CliError> {
+        let output = self.run(&["ledger", "latest", "--network", &self.network, "--output", "json"])?;
+        parse_json(&output)?
+            .get("sequence")
+            .and_then(Value::as_u64)
+            .ok_or(CliError::Parse(output))
+    }
+    
+    /// Whether the RPC event index has an event with `topic` since `start_ledger`
+    pub fn has_event(&self, topic: &str, start_ledger: u64) -> Result<bool, CliError> {
+        let start = start_ledger.to_string();
+        let output = self.run(&[
+            "events",
+            "--id",
+            &self.contract_id,
+            "--network",
+            &self.network,
+            "--start-ledger",
+            &start,
+            "--output",
+            "pretty",
+        ])?;
+        Ok(output.contains(topic))
+    }
+    
+    fn run_invoke(&self, function: &str, args: &[(&str, String)], send: bool) -> Result<String, CliError> {
+        let mut argv = vec![
+            "contract".to_string(),
+            "invoke".to_string(),
+            "--id".to_string(),
+            self.contract_id.clone(),
+            "--network".to_string(),
+            self.network.clone(),
+            "--source".to_string(),
+            self.source.clone(),
+        ];
+        if !send {
+            argv.push("--send=no".to_string());
+        }
+        argv.push("--".to_string());
+        argv.push(function.to_string());
+        for (name, value) in args {
+            argv.push(format!("--{name}"));
+            argv.push(value.clone());
+        }
+        
+        let argv: Vec<&str> = argv.iter().map(String::as_str).collect();
+        self.run(&argv)
+    }
+    
+    fn run(&self, argv: &[&str]) -> Result<String, CliError> {
+        let output = Command::new("stellar")
+            .args(argv)
+            .output()
+            .map_err(CliError::Spawn)?;
+        
+        if !output.status.success() {
+            return Err(CliError::Failed(String::from_utf8_lossy(&output.stderr).into_owned()));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// Parse the JSON value a `stellar` command printed
+pub fn parse_json(output: &str) -> Result<Value, CliError> {
+    serde_json::from_str(output.trim()).map_err(|_| CliError::Parse(output.to_string()))
+}
+
+/// JSON argument for a unit enum variant (e.g. `"Freeze"`)
+pub fn variant(name: &str) -> String {
+    Value::String(name.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_parse_json_trims_cli_output() {
+        assert_eq!(parse_json("\"Freeze\"\n").unwrap(), Value::String("Freeze".into()));
+        assert_eq!(parse_json("null\n").unwrap(), Value::Null);
+        assert!(matches!(parse_json("Error: boom"), Err(CliError::Parse(_))));
+    }
+    
+    #[test]
+    fn test_variant_is_quoted() {
+        assert_eq!(variant("Freeze"), "\"Freeze\"");
+    }
+}
@@ -0,0 +1,373 @@
+//! Chain-agnostic address abstraction.
+//!
+//! Analytics providers report risk against whatever identifiers they index:
+//! Stellar accounts, muxed accounts, contracts, or addresses on other
+//! chains. [`AnyAddress`] parses and validates all of them into one
+//! normalized form so the same wallet is never scored twice under two
+//! spellings, and [`AddressBook`] maps foreign identifiers onto the Stellar
+//! wallet a payload should be signed for.
+//!
+//! Accepted input:
+//!
+//! - `G...` account and `C...` contract strkeys (checksum verified)
+//! - `M...` muxed strkeys (SEP-23); risk is tracked per base account, so
+//!   [`AnyAddress::stellar_wallet`] strips the mux id
+//! - CAIP-10 identifiers `namespace:reference:address` for other chains,
+//!   e.g. `eip155:1:0xAb5801a7D398351b8bE11C439e05C5B3259aeC9B`
+//! - bare `0x` + 40 hex EVM addresses (treated as Ethereum mainnet)
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// Strkey version byte of an ed25519 account (`G...`)
+const VERSION_ACCOUNT: u8 = 6 << 3;
+
+/// Strkey version byte of a contract (`C...`)
+const VERSION_CONTRACT: u8 = 2 << 3;
+
+/// Strkey version byte of a muxed account (`M...`)
+const VERSION_MUXED: u8 = 12 << 3;
+
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Why an identifier was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    /// Empty input
+    Empty,
+    /// Not a recognized address format
+    Unrecognized(String),
+    /// Looked like a strkey but failed to decode or checksum
+    InvalidStrkey(String),
+    /// Malformed CAIP-10 identifier or foreign address
+    InvalidForeign(String),
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressError::Empty => write!(f, "empty address"),
+            AddressError::Unrecognized(s) => write!(f, "unrecognized address: {s}"),
+            AddressError::InvalidStrkey(s) => write!(f, "invalid Stellar strkey: {s}"),
+            AddressError::InvalidForeign(s) => write!(f, "invalid foreign address: {s}"),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+/// A validated, normalized address on Stellar or another chain
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AnyAddress {
+    /// Ed25519 account (`G...`)
+    Account([u8; 32]),
+    /// Soroban contract (`C...`)
+    Contract([u8; 32]),
+    /// Muxed account (`M...`): base account key and mux id
+    Muxed { key: [u8; 32], id: u64 },
+    /// Address on another chain, as CAIP-10 parts
+    Foreign {
+        /// CAIP-2 namespace, lowercase (e.g. `eip155`, `bip122`)
+        namespace: String,
+        /// CAIP-2 chain reference (e.g. `1` for Ethereum mainnet)
+        reference: String,
+        /// Account address on that chain (lowercased for `eip155`)
+        address: String,
+    },
+}
+
+impl AnyAddress {
+    /// Parse and validate any supported identifier
+    ///
+    /// Surrounding whitespace is ignored; strkeys are accepted in either
+    /// case.
+    pub fn parse(input: &str) -> Result<Self, AddressError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(AddressError::Empty);
+        }
+        
+        if let Some(hex) = input.strip_prefix("0x").or_else(|| input.strip_prefix("0X")) {
+            return evm_address(hex)
+                .map(|address| AnyAddress::Foreign {
+                    namespace: "eip155".into(),
+                    reference: "1".into(),
+                    address,
+                })
+                .ok_or_else(|| AddressError::InvalidForeign(input.into()));
+        }
+        
+        if input.contains(':') {
+            return parse_caip10(input);
+        }
+        
+        let upper = input.to_ascii_uppercase();
+        match upper.as_bytes()[0] {
+            b'G' | b'C' | b'M' => parse_strkey(&upper),
+            _ => Err(AddressError::Unrecognized(input.into())),
+        }
+    }
+    
+    /// Whether this is a Stellar identifier (account, contract or muxed)
+    pub fn is_stellar(&self) -> bool {
+        !matches!(self, AnyAddress::Foreign { .. })
+    }
+    
+    /// The Stellar wallet risk is tracked against
+    ///
+    /// Muxed accounts resolve to their base `G...` account; foreign
+    /// addresses have none (use an [`AddressBook`]).
+    pub fn stellar_wallet(&self) -> Option<String> {
+        match self {
+            AnyAddress::Account(key) | AnyAddress::Muxed { key, .. } => {
+                Some(encode_strkey(VERSION_ACCOUNT, key))
+            }
+            AnyAddress::Contract(key) => Some(encode_strkey(VERSION_CONTRACT, key)),
+            AnyAddress::Foreign { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for AnyAddress {
+    /// Canonical form: uppercase strkey, or CAIP-10 for other chains
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyAddress::Account(key) => f.write_str(&encode_strkey(VERSION_ACCOUNT, key)),
+            AnyAddress::Contract(key) => f.write_str(&encode_strkey(VERSION_CONTRACT, key)),
+            AnyAddress::Muxed { key, id } => {
+                let mut payload = key.to_vec();
+                payload.extend_from_slice(&id.to_be_bytes());
+                f.write_str(&encode_strkey(VERSION_MUXED, &payload))
+            }
+            AnyAddress::Foreign { namespace, reference, address } => {
+                write!(f, "{namespace}:{reference}:{address}")
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for AnyAddress {
+    type Err = AddressError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AnyAddress::parse(s)
+    }
+}
+
+/// Maps foreign identifiers onto the Stellar wallets they belong to
+///
+/// Links come from whatever attribution the operator trusts (bridge
+/// deposits, exchange KYC). Stellar identifiers resolve without a link.
+#[derive(Debug, Default, Clone)]
+pub struct AddressBook {
+    links: HashMap<AnyAddress, AnyAddress>,
+}
+
+impl AddressBook {
+    /// Create an empty address book
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Link a foreign address to a Stellar wallet
+    ///
+    /// # Errors
+    /// * `Unrecognized` if `foreign` is a Stellar identifier or `stellar`
+    ///   is not one
+    pub fn link(&mut self, foreign: AnyAddress, stellar: AnyAddress) -> Result<(), AddressError> {
+        if foreign.is_stellar() {
+            return Err(AddressError::Unrecognized(format!("{foreign} is already a Stellar address")));
+        }
+        if !stellar.is_stellar() {
+            return Err(AddressError::Unrecognized(format!("{stellar} is not a Stellar address")));
+        }
+        self.links.insert(foreign, stellar);
+        Ok(())
+    }
+    
+    /// The Stellar wallet a payload for `address` should be signed for
+    pub fn resolve(&self, address: &AnyAddress) -> Option<String> {
+        match address {
+            AnyAddress::Foreign { .. } => self.links.get(address)?.stellar_wallet(),
+            _ => address.stellar_wallet(),
+        }
+    }
+}
+
+fn parse_strkey(input: &str) -> Result<AnyAddress, AddressError> {
+    let invalid = || AddressError::InvalidStrkey(input.into());
+    let data = decode_base32(input).ok_or_else(invalid)?;
+    if data.len() < 3 {
+        return Err(invalid());
+    }
+    
+    let (body, checksum) = data.split_at(data.len() - 2);
+    if crc16_xmodem(body).to_le_bytes() != checksum {
+        return Err(invalid());
+    }
+    
+    let (version, payload) = (body[0], &body[1..]);
+    match (version, payload.len()) {
+        (VERSION_ACCOUNT, 32) => Ok(AnyAddress::Account(payload.try_into().unwrap())),
+        (VERSION_CONTRACT, 32) => Ok(AnyAddress::Contract(payload.try_into().unwrap())),
+        (VERSION_MUXED, 40) => Ok(AnyAddress::Muxed {
+            key: payload[..32].try_into().unwrap(),
+            id: u64::from_be_bytes(payload[32..].try_into().unwrap()),
+        }),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_caip10(input: &str) -> Result<AnyAddress, AddressError> {
+    let invalid = || AddressError::InvalidForeign(input.into());
+    let mut parts = input.splitn(3, ':');
+    let (namespace, reference, address) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(n), Some(r), Some(a)) if !n.is_empty() && !r.is_empty() && !a.is_empty() => (n, r, a),
+        _ => return Err(invalid()),
+    };
+    
+    let namespace = namespace.to_ascii_lowercase();
+    if !namespace.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-') {
+        return Err(invalid());
+    }
+    
+    let address = match namespace.as_str() {
+        // Stellar identifiers must not hide behind a foreign namespace
+        "stellar" => return parse_strkey(&address.to_ascii_uppercase()),
+        "eip155" => {
+            let hex = address.strip_prefix("0x").ok_or_else(invalid)?;
+            evm_address(hex).ok_or_else(invalid)?
+        }
+        _ => address.to_string(),
+    };
+    
+    Ok(AnyAddress::Foreign { namespace, reference: reference.to_string(), address })
+}
+
+/// Lowercased `0x` form of a 20-byte hex address
+fn evm_address(hex: &str) -> Option<String> {
+    if hex.len() != 40 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(format!("0x{}", hex.to_ascii_lowercase()))
+}
+
+fn encode_strkey(version: u8, payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + 3);
+    data.push(version);
+    data.extend_from_slice(payload);
+    let checksum = crc16_xmodem(&data);
+    data.extend_from_slice(&checksum.to_le_bytes());
+    encode_base32(&data)
+}
+
+fn encode_base32(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() * 8).div_ceil(5));
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for &byte in data {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    out
+}
+
+fn decode_base32(input: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(input.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for c in input.bytes() {
+        let value = BASE32.iter().position(|&b| b == c)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    // Leftover bits must be zero padding
+    if buffer & ((1 << bits) - 1) != 0 {
+        return None;
+    }
+    Some(out)
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const ACCOUNT: &str = "GAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB7JZX";
+    const MUXED: &str = "MAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB6AAAAAAAAAAAA6X66";
+    const CONTRACT: &str = "CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526";
+    
+    #[test]
+    fn test_strkeys_round_trip() {
+        for input in [ACCOUNT, MUXED, CONTRACT] {
+            assert_eq!(AnyAddress::parse(input).unwrap().to_string(), input);
+        }
+        assert_eq!(
+            AnyAddress::parse("GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF").unwrap(),
+            AnyAddress::Account([0; 32])
+        );
+    }
+    
+    #[test]
+    fn test_muxed_resolves_to_base_account() {
+        let muxed = AnyAddress::parse(MUXED).unwrap();
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+        assert_eq!(muxed, AnyAddress::Muxed { key, id: 7 });
+        assert_eq!(muxed.stellar_wallet().as_deref(), Some(ACCOUNT));
+    }
+    
+    #[test]
+    fn test_strkey_normalization_and_checksum() {
+        let lower = format!("  {}  ", ACCOUNT.to_ascii_lowercase());
+        assert_eq!(AnyAddress::parse(&lower).unwrap().to_string(), ACCOUNT);
+        
+        let mut corrupted = ACCOUNT.to_string();
+        corrupted.replace_range(10..11, "B");
+        assert!(matches!(AnyAddress::parse(&corrupted), Err(AddressError::InvalidStrkey(_))));
+    }
+    
+    #[test]
+    fn test_foreign_addresses_normalize() {
+        let bare = AnyAddress::parse("0xAb5801a7D398351b8bE11C439e05C5B3259aeC9B").unwrap();
+        let caip = AnyAddress::parse("EIP155:1:0xab5801a7d398351b8be11c439e05c5b3259aec9b").unwrap();
+        assert_eq!(bare, caip);
+        assert_eq!(bare.to_string(), "eip155:1:0xab5801a7d398351b8be11c439e05c5b3259aec9b");
+        
+        assert!(AnyAddress::parse("eip155:1:0x1234").is_err());
+        assert!(AnyAddress::parse("bip122:000000000019d6689c085ae165831e93").is_err());
+        assert_eq!(AnyAddress::parse(&format!("stellar:pubnet:{ACCOUNT}")).unwrap().to_string(), ACCOUNT);
+    }
+    
+    #[test]
+    fn test_address_book_maps_foreign_to_stellar() {
+        let evm = AnyAddress::parse("0xab5801a7d398351b8be11c439e05c5b3259aec9b").unwrap();
+        let wallet = AnyAddress::parse(MUXED).unwrap();
+        
+        let mut book = AddressBook::new();
+        assert_eq!(book.resolve(&evm), None);
+        book.link(evm.clone(), wallet.clone()).unwrap();
+        
+        assert_eq!(book.resolve(&evm).as_deref(), Some(ACCOUNT));
+        assert_eq!(book.resolve(&wallet).as_deref(), Some(ACCOUNT));
+        assert!(book.link(wallet.clone(), wallet).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+//! Contract client for protocols that enforce Sentinel decisions.
+//!
+//! `SentinelClient` is the client `contractimport!` would generate for the
+//! integrator-facing part of the Sentinel SDK interface, without needing
+//! the contract WASM at build time. The types match the contract's
+//! storage and return types field for field; `RiskDecision` is the one
+//! the contract's events carry (see `sentinel-events`).
+//!
+//! `SentinelSpec` carries the spec of each `Sentinel` function. The
+//! contract's tests compare it with the contract's own spec, so a change
+//! to the contract interface fails there instead of drifting silently.
+//!
+//! ```ignore
+//! let sentinel = SentinelClient::new(&env, &sentinel_id);
+//! sentinel.enforce_limit(&user, amount);
+//! ```
+
+use soroban_sdk::{contractclient, contractspecfn, contracttype, panic_with_error, Address, BytesN, Env, Map, Vec};
+
+use crate::guard;
+
+pub use sentinel_events::RiskDecision;
+
+/// Risk category the Oracle can score separately
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RiskCategory {
+    /// Anti-money-laundering exposure
+    Aml,
+    /// Automated / bot activity
+    BotActivity,
+    /// Fraud and scam signals
+    Fraud,
+    /// Sanctions exposure
+    Sanctions,
+}
+
+/// Risk state of a wallet, as returned by `get_risk`
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskState {
+    /// Risk score from 0-100
+    pub risk_score: u32,
+    /// Unix timestamp of last update
+    pub last_updated: u64,
+    /// Computed decision based on risk score
+    pub decision: RiskDecision,
+    /// Oracle reason codes explaining the score
+    pub reason_codes: Vec<u32>,
+    /// Unix timestamp when a quarantine freeze lapses (0 = indefinite)
+    pub freeze_until: u64,
+    /// Per-category scores from 0-100 (empty if the Oracle sent none)
+    pub category_scores: Map<RiskCategory, u32>,
+    /// Release of the ML model that produced the score (0 = not reported)
+    pub model_version: u32,
+    /// Model confidence in basis points, 0-10000 (0 = not reported)
+    pub confidence: u32,
+    /// Digest of the off-chain evidence report (all zeros if none)
+    pub evidence_hash: BytesN<32>,
+}
+
+/// Kind of operation an integrator is about to perform
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OperationKind {
+    Swap,
+    Withdraw,
+    Deposit,
+    Borrow,
+    Transfer,
+}
+
+/// Outcome of enforcing a decision against a requested amount
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FillResult {
+    /// Execute the full requested amount
+    Full,
+    /// Execute only this amount (the Limit cap)
+    Partial(i128),
+    /// Execute nothing
+    Rejected,
+}
+
+/// Spec of the `Sentinel` functions, checked against the contract
+#[doc(hidden)]
+pub struct SentinelSpec;
+
+/// Integrator-facing interface of the Sentinel SDK contract
+#[contractspecfn(name = "SentinelSpec", export = false)]
+#[contractclient(name = "SentinelClient")]
+pub trait Sentinel {
+    /// Decision for a wallet (`Allow` if it was never scored)
+    fn check_permission(env: Env, wallet: Address) -> RiskDecision;
+    
+    /// Decision for a wallet moving a specific asset
+    fn check_permission_for_asset(env: Env, wallet: Address, asset: Address) -> RiskDecision;
+    
+    /// Decision applied to `amount` for one operation kind (kinds the
+    /// admin exempts are always `Full`)
+    fn check_operation(env: Env, wallet: Address, kind: OperationKind, amount: i128) -> FillResult;
+    
+    /// Stored risk state (`None` if the wallet was never scored)
+    fn get_risk(env: Env, wallet: Address) -> Option<RiskState>;
+    
+    /// Whether the wallet is frozen
+    fn is_frozen(env: Env, wallet: Address) -> bool;
+}
+
+impl SentinelClient<'_> {
+    /// Panic with a `GuardError` unless the wallet's decision is `Allow`
+    /// (see `SentinelGuard::require_allowed`)
+    pub fn require_allowed(&self, wallet: &Address) {
+        if let Err(error) = guard::allowed(&self.check_permission(wallet)) {
+            panic_with_error!(&self.env, error);
+        }
+    }
+    
+    /// Panic with a `GuardError` unless the wallet may move `amount`
+    /// (see `SentinelGuard::require_limit`)
+    ///
+    /// # Returns
+    /// * The decision the amount was checked against, e.g. to log a
+    ///   limited transfer
+    pub fn enforce_limit(&self, wallet: &Address, amount: i128) -> RiskDecision {
+        let decision = self.check_permission(wallet);
+        if let Err(error) = guard::within_limit(&decision, amount) {
+            panic_with_error!(&self.env, error);
+        }
+        decision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutils::mock_sentinel;
+    use soroban_sdk::testutils::Address as _;
+    
+    #[test]
+    fn test_enforce_limit() {
+        let env = Env::default();
+        let wallet = Address::generate(&env);
+        
+        let sentinel = SentinelClient::new(&env, &mock_sentinel(&env, RiskDecision::Allow));
+        sentinel.require_allowed(&wallet);
+        assert_eq!(sentinel.enforce_limit(&wallet, i128::MAX), RiskDecision::Allow);
+        
+        let sentinel = SentinelClient::new(&env, &mock_sentinel(&env, RiskDecision::Limit(500)));
+        assert_eq!(sentinel.enforce_limit(&wallet, 500), RiskDecision::Limit(500));
+        assert!(!sentinel.is_frozen(&wallet));
+    }
+    
+    #[test]
+    #[should_panic(expected = "Error(Contract, #903)")]
+    fn test_enforce_limit_blocks_amount_over_limit() {
+        let env = Env::default();
+        let sentinel = SentinelClient::new(&env, &mock_sentinel(&env, RiskDecision::Limit(500)));
+        
+        sentinel.enforce_limit(&Address::generate(&env), 501);
+    }
+}
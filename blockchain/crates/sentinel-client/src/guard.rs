@@ -0,0 +1,289 @@
+//! One-line Sentinel guards for protocol entrypoints.
+//!
+//! Every integrator used to write the same match over `check_permission`
+//! with its own panic strings. `SentinelGuard` makes the cross-contract
+//! call and either returns a `GuardError` (`check_*`) or panics with it
+//! (`require_*`), so a blocked user sees the same error code whichever
+//! protocol refused them. `#[sentinel_guard]` injects the same calls as
+//! a function attribute. `enforce` applies the same rules without
+//! trapping, for protocols that fill or queue what a `Limit` cuts.
+
+use soroban_sdk::{contracterror, panic_with_error, Address, Env};
+
+use crate::{FillResult, OperationKind, RiskDecision, SentinelClient};
+
+/// Why a guard refused an action
+///
+/// Codes start at 900 to stay clear of the calling contract's own error
+/// codes; a guard panic surfaces as `Error(Contract, #90x)`.
+#[contracterror(export = false)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GuardError {
+    /// Wallet is limited and the action has no amount to cap
+    Restricted = 900,
+    /// Wallet is held for manual review
+    UnderReview = 901,
+    /// Wallet is frozen
+    Frozen = 902,
+    /// Amount exceeds the wallet's `Limit` cap
+    OverLimit = 903,
+    /// No Sentinel contract configured (see `Guarded::set_sentinel_id`)
+    NotConfigured = 904,
+    /// Amount is zero or negative
+    InvalidAmount = 905,
+}
+
+/// Sentinel checks for protocol entrypoints
+pub struct SentinelGuard;
+
+impl SentinelGuard {
+    /// `Ok` if the wallet's decision is `Allow`
+    ///
+    /// For actions with no amount to cap, where `Limit` is a block too.
+    pub fn check_allowed(env: &Env, sentinel_id: &Address, user: &Address) -> Result<(), GuardError> {
+        allowed(&SentinelClient::new(env, sentinel_id).check_permission(user))
+    }
+    
+    /// Panic with a `GuardError` unless the wallet's decision is `Allow`
+    pub fn require_allowed(env: &Env, sentinel_id: &Address, user: &Address) {
+        if let Err(error) = Self::check_allowed(env, sentinel_id, user) {
+            panic_with_error!(env, error);
+        }
+    }
+    
+    /// `Ok` unless the wallet is frozen
+    ///
+    /// For actions a risky wallet must still be able to take, e.g.
+    /// repaying debt or closing a position.
+    pub fn check_not_frozen(env: &Env, sentinel_id: &Address, user: &Address) -> Result<(), GuardError> {
+        match SentinelClient::new(env, sentinel_id).is_frozen(user) {
+            true => Err(GuardError::Frozen),
+            false => Ok(()),
+        }
+    }
+    
+    /// Panic with `GuardError::Frozen` if the wallet is frozen
+    pub fn require_not_frozen(env: &Env, sentinel_id: &Address, user: &Address) {
+        if let Err(error) = Self::check_not_frozen(env, sentinel_id, user) {
+            panic_with_error!(env, error);
+        }
+    }
+    
+    /// Decision the wallet may move `amount` under
+    ///
+    /// `Allow` permits any amount and `Limit(max)` up to `max`; `Review` and
+    /// `Freeze` permit nothing.
+    pub fn check_limit(
+        env: &Env,
+        sentinel_id: &Address,
+        user: &Address,
+        amount: i128,
+    ) -> Result<RiskDecision, GuardError> {
+        let decision = SentinelClient::new(env, sentinel_id).check_permission(user);
+        within_limit(&decision, amount).map(|()| decision)
+    }
+    
+    /// Panic with a `GuardError` unless the wallet may move `amount`
+    ///
+    /// # Returns
+    /// * The decision the amount was checked against
+    pub fn require_limit(env: &Env, sentinel_id: &Address, user: &Address, amount: i128) -> RiskDecision {
+        Self::check_limit(env, sentinel_id, user, amount)
+            .unwrap_or_else(|error| panic_with_error!(env, error))
+    }
+    
+    /// `Ok` if the wallet may move all of `amount` in an operation of `kind`
+    ///
+    /// Uses the contract's `check_operation`, so kinds the admin exempts
+    /// (e.g. deposits) pass whatever the wallet's decision. A refusal is
+    /// reported with the error of the wallet's decision.
+    pub fn check_operation(
+        env: &Env,
+        sentinel_id: &Address,
+        user: &Address,
+        kind: OperationKind,
+        amount: i128,
+    ) -> Result<(), GuardError> {
+        let sentinel = SentinelClient::new(env, sentinel_id);
+        match sentinel.check_operation(user, &kind, &amount) {
+            FillResult::Full => Ok(()),
+            FillResult::Partial(_) => Err(GuardError::OverLimit),
+            FillResult::Rejected => match allowed(&sentinel.check_permission(user)) {
+                Ok(()) => Err(GuardError::InvalidAmount),
+                Err(error) => Err(error),
+            },
+        }
+    }
+    
+    /// Panic with a `GuardError` unless the wallet may move `amount` in an
+    /// operation of `kind`
+    pub fn require_operation(env: &Env, sentinel_id: &Address, user: &Address, kind: OperationKind, amount: i128) {
+        if let Err(error) = Self::check_operation(env, sentinel_id, user, kind, amount) {
+            panic_with_error!(env, error);
+        }
+    }
+}
+
+/// `Ok` for `Allow`
+pub(crate) fn allowed(decision: &RiskDecision) -> Result<(), GuardError> {
+    match decision {
+        RiskDecision::Allow => Ok(()),
+        RiskDecision::Limit(_) => Err(GuardError::Restricted),
+        RiskDecision::Review => Err(GuardError::UnderReview),
+        RiskDecision::Freeze => Err(GuardError::Frozen),
+    }
+}
+
+/// Amount a decision lets a wallet move, as returned by `enforce`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnforcedAmount {
+    /// Amount the wallet asked to move
+    pub requested: i128,
+    /// Amount it may move (`requested` capped at a `Limit`)
+    pub allowed: i128,
+    /// Decision the amount was checked against
+    pub decision: RiskDecision,
+}
+
+impl EnforcedAmount {
+    /// Whether the `Limit` cap cut the amount (fill `allowed`, queue or
+    /// refund the rest)
+    pub fn is_partial(&self) -> bool {
+        self.allowed < self.requested
+    }
+}
+
+/// Amount a decision lets a wallet move, without trapping
+///
+/// For protocols that degrade gracefully: `Allow` permits the whole
+/// amount, `Limit(max)` up to `max` (a partial fill if `amount` is over
+/// it), `Review` and `Freeze` nothing. The panicking helpers use the same
+/// rules but treat a partial amount as `OverLimit`.
+pub fn enforce(decision: &RiskDecision, amount: i128) -> Result<EnforcedAmount, GuardError> {
+    let allowed = match decision {
+        RiskDecision::Allow => amount,
+        RiskDecision::Limit(max) => amount.min(i128::from(*max)),
+        RiskDecision::Review => return Err(GuardError::UnderReview),
+        RiskDecision::Freeze => return Err(GuardError::Frozen),
+    };
+    Ok(EnforcedAmount { requested: amount, allowed, decision: decision.clone() })
+}
+
+/// `Ok` if the decision permits moving all of `amount`
+pub(crate) fn within_limit(decision: &RiskDecision, amount: i128) -> Result<(), GuardError> {
+    match enforce(decision, amount)? {
+        enforced if enforced.is_partial() => Err(GuardError::OverLimit),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sentinel_guard;
+    use crate::testutils::mock_sentinel;
+    use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _};
+    
+    #[test]
+    fn test_guards_map_decisions_to_errors() {
+        let env = Env::default();
+        let user = Address::generate(&env);
+        
+        let limited = mock_sentinel(&env, RiskDecision::Limit(500));
+        assert_eq!(SentinelGuard::check_allowed(&env, &limited, &user), Err(GuardError::Restricted));
+        assert_eq!(SentinelGuard::check_not_frozen(&env, &limited, &user), Ok(()));
+        assert_eq!(SentinelGuard::check_limit(&env, &limited, &user, 500), Ok(RiskDecision::Limit(500)));
+        assert_eq!(SentinelGuard::check_limit(&env, &limited, &user, 501), Err(GuardError::OverLimit));
+        
+        let review = mock_sentinel(&env, RiskDecision::Review);
+        assert_eq!(SentinelGuard::check_limit(&env, &review, &user, 1), Err(GuardError::UnderReview));
+        
+        let frozen = mock_sentinel(&env, RiskDecision::Freeze);
+        assert_eq!(SentinelGuard::check_not_frozen(&env, &frozen, &user), Err(GuardError::Frozen));
+        
+        SentinelGuard::require_allowed(&env, &mock_sentinel(&env, RiskDecision::Allow), &user);
+    }
+    
+    /// Protocol gating its endpoints with `#[sentinel_guard]`
+    #[contract]
+    struct Pool;
+    
+    #[contractimpl]
+    impl Pool {
+        pub fn init(env: Env, sentinel_id: Address) {
+            env.storage().instance().set(&symbol_short!("sentinel"), &sentinel_id);
+        }
+        
+        #[sentinel_guard(user = "user", amount = "amount")]
+        pub fn swap(env: Env, user: Address, amount: i128) -> i128 {
+            amount
+        }
+        
+        #[sentinel_guard(user = "to", env = "e", sentinel = "sentinel_id")]
+        pub fn claim(e: Env, sentinel_id: Address, to: Address) -> bool {
+            true
+        }
+    }
+    
+    impl Pool {
+        fn sentinel_id(env: &Env) -> Address {
+            env.storage().instance().get(&symbol_short!("sentinel")).unwrap()
+        }
+    }
+    
+    #[test]
+    fn test_sentinel_guard_attribute() {
+        let env = Env::default();
+        let sentinel_id = mock_sentinel(&env, RiskDecision::Limit(500));
+        let pool = PoolClient::new(&env, &env.register_contract(None, Pool));
+        pool.init(&sentinel_id);
+        let user = Address::generate(&env);
+        
+        assert_eq!(pool.swap(&user, &500), 500);
+        assert_eq!(pool.try_swap(&user, &501), Err(Ok(GuardError::OverLimit.into())));
+        assert_eq!(pool.try_claim(&sentinel_id, &user), Err(Ok(GuardError::Restricted.into())));
+        assert!(pool.claim(&mock_sentinel(&env, RiskDecision::Allow), &user));
+    }
+    
+    #[test]
+    fn test_sentinel_guard_attribute_allow_and_freeze() {
+        let env = Env::default();
+        let pool = PoolClient::new(&env, &env.register_contract(None, Pool));
+        let user = Address::generate(&env);
+        
+        // Both expansions (`require_limit` and `require_allowed`) let an
+        // allowed wallet through uncapped
+        let allowed = mock_sentinel(&env, RiskDecision::Allow);
+        pool.init(&allowed);
+        assert_eq!(pool.swap(&user, &i128::MAX), i128::MAX);
+        assert!(pool.claim(&allowed, &user));
+        
+        // ... and stop a frozen one before the body runs, whatever the amount
+        let frozen = mock_sentinel(&env, RiskDecision::Freeze);
+        pool.init(&frozen);
+        assert_eq!(pool.try_swap(&user, &0), Err(Ok(GuardError::Frozen.into())));
+        assert_eq!(pool.try_claim(&frozen, &user), Err(Ok(GuardError::Frozen.into())));
+    }
+    
+    #[test]
+    fn test_enforce_caps_limited_amounts() {
+        let enforced = enforce(&RiskDecision::Limit(500), 800).unwrap();
+        assert_eq!(enforced.allowed, 500);
+        assert!(enforced.is_partial());
+        
+        assert!(!enforce(&RiskDecision::Limit(500), 500).unwrap().is_partial());
+        assert_eq!(enforce(&RiskDecision::Allow, i128::MAX).unwrap().allowed, i128::MAX);
+        assert_eq!(enforce(&RiskDecision::Review, 1), Err(GuardError::UnderReview));
+        assert_eq!(enforce(&RiskDecision::Freeze, 0), Err(GuardError::Frozen));
+    }
+    
+    #[test]
+    #[should_panic(expected = "Error(Contract, #902)")]
+    fn test_require_not_frozen_panics_with_guard_error() {
+        let env = Env::default();
+        let frozen = mock_sentinel(&env, RiskDecision::Freeze);
+        
+        SentinelGuard::require_not_frozen(&env, &frozen, &Address::generate(&env));
+    }
+}
@@ -0,0 +1,115 @@
+//! Per-action enforcement shared across protocols.
+//!
+//! A protocol implements `Guarded` on its contract type and calls
+//! `Self::guard_swap` / `guard_withdraw` / `guard_transfer` from its
+//! entrypoints. The Sentinel contract id lives under one standard storage
+//! key, and every action gets the same semantics in every protocol: each
+//! is checked with the contract's `check_operation` for its
+//! `OperationKind`, so operation kinds the Sentinel admin exempts pass, and
+//! otherwise a `Limit(max)` caps the `i128` amount at `max` and `Review`
+//! and `Freeze` block with their stable `GuardError` codes.
+
+use soroban_sdk::{panic_with_error, symbol_short, Address, Env, Symbol};
+
+use crate::{GuardError, OperationKind, SentinelGuard};
+
+/// Instance storage key of the Sentinel contract id
+pub const SENTINEL_ID_KEY: Symbol = symbol_short!("SENTINEL");
+
+/// Sentinel enforcement for a protocol contract
+///
+/// Every method has a default; override an action to give it a different
+/// policy (e.g. let `Review` wallets withdraw) without touching the rest.
+pub trait Guarded {
+    /// Configured Sentinel contract id
+    ///
+    /// # Panics
+    /// * `GuardError::NotConfigured` if `set_sentinel_id` was never called
+    fn sentinel_id(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&SENTINEL_ID_KEY)
+            .unwrap_or_else(|| panic_with_error!(env, GuardError::NotConfigured))
+    }
+    
+    /// Store the Sentinel contract id (call from the protocol's own
+    /// initializer or admin entrypoint)
+    fn set_sentinel_id(env: &Env, sentinel_id: &Address) {
+        env.storage().instance().set(&SENTINEL_ID_KEY, sentinel_id);
+    }
+    
+    /// Guard a swap of `amount` by `user`
+    fn guard_swap(env: &Env, user: &Address, amount: i128) {
+        SentinelGuard::require_operation(env, &Self::sentinel_id(env), user, OperationKind::Swap, amount);
+    }
+    
+    /// Guard a withdrawal of `amount` by `user`
+    fn guard_withdraw(env: &Env, user: &Address, amount: i128) {
+        SentinelGuard::require_operation(env, &Self::sentinel_id(env), user, OperationKind::Withdraw, amount);
+    }
+    
+    /// Guard a transfer of `amount` from `from`
+    fn guard_transfer(env: &Env, from: &Address, amount: i128) {
+        SentinelGuard::require_operation(env, &Self::sentinel_id(env), from, OperationKind::Transfer, amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutils::{mock_sentinel, MockSentinelClient};
+    use crate::RiskDecision;
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _};
+    
+    #[contract]
+    struct Vault;
+    
+    #[contractimpl]
+    impl Vault {
+        pub fn init(env: Env, sentinel_id: Address) {
+            Self::set_sentinel_id(&env, &sentinel_id);
+        }
+        
+        pub fn withdraw(env: Env, user: Address, amount: i128) {
+            Self::guard_withdraw(&env, &user, amount);
+        }
+        
+        pub fn swap(env: Env, user: Address, amount: i128) {
+            Self::guard_swap(&env, &user, amount);
+        }
+    }
+    
+    impl Guarded for Vault {}
+    
+    #[test]
+    fn test_guarded_defaults() {
+        let env = Env::default();
+        let vault = VaultClient::new(&env, &env.register_contract(None, Vault));
+        let user = Address::generate(&env);
+        assert_eq!(vault.try_withdraw(&user, &1), Err(Ok(GuardError::NotConfigured.into())));
+        
+        vault.init(&mock_sentinel(&env, RiskDecision::Limit(500)));
+        vault.withdraw(&user, &500);
+        assert_eq!(vault.try_withdraw(&user, &501), Err(Ok(GuardError::OverLimit.into())));
+        
+        vault.init(&mock_sentinel(&env, RiskDecision::Allow));
+        assert_eq!(vault.try_withdraw(&user, &0), Err(Ok(GuardError::InvalidAmount.into())));
+        
+        vault.init(&mock_sentinel(&env, RiskDecision::Freeze));
+        assert_eq!(vault.try_withdraw(&user, &0), Err(Ok(GuardError::Frozen.into())));
+    }
+    
+    #[test]
+    fn test_guarded_actions_follow_operation_rules() {
+        let env = Env::default();
+        let vault = VaultClient::new(&env, &env.register_contract(None, Vault));
+        let user = Address::generate(&env);
+        
+        let sentinel_id = mock_sentinel(&env, RiskDecision::Freeze);
+        MockSentinelClient::new(&env, &sentinel_id).exempt(&OperationKind::Withdraw);
+        vault.init(&sentinel_id);
+        
+        vault.withdraw(&user, &1_000);
+        assert_eq!(vault.try_swap(&user, &1_000), Err(Ok(GuardError::Frozen.into())));
+    }
+}
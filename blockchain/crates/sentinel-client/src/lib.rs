@@ -0,0 +1,40 @@
+//! Client for integrating with the Sentinel SDK contract.
+//!
+//! - [`contract`]: `no_std` client for protocols that gate actions on
+//!   Sentinel decisions, with the contract's `RiskDecision` / `RiskState`
+//!   types and enforcement helpers (`require_allowed`, `enforce_limit`), so
+//!   integrators no longer copy enum definitions that drift from the
+//!   contract
+//! - [`guard`]: `SentinelGuard`, one-line `require_allowed` /
+//!   `require_not_frozen` / `require_limit` checks that fail with a typed
+//!   `GuardError`, the `#[sentinel_guard]` attribute that injects them
+//!   into contract functions, and `enforce`, which applies the same rules
+//!   without trapping
+//! - [`guarded`]: `Guarded`, per-action defaults (`guard_swap`,
+//!   `guard_withdraw`, `guard_transfer`) checked with the contract's
+//!   `check_operation`, reading the Sentinel id from a standard storage key
+//! - [`address`] (feature `std`): chain-agnostic addresses, so risk data
+//!   from multi-chain analytics providers maps onto Stellar wallets
+//!   consistently before the Oracle signs a payload
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// `#[sentinel_guard]` expands to `::sentinel_client::...` paths
+#[cfg(test)]
+extern crate self as sentinel_client;
+
+#[cfg(feature = "std")]
+pub mod address;
+pub mod contract;
+pub mod guard;
+pub mod guarded;
+
+#[cfg(test)]
+mod testutils;
+
+#[cfg(feature = "std")]
+pub use address::{AddressBook, AddressError, AnyAddress};
+pub use contract::{FillResult, OperationKind, RiskCategory, RiskDecision, RiskState, SentinelClient};
+pub use guard::{enforce, EnforcedAmount, GuardError, SentinelGuard};
+pub use guarded::{Guarded, SENTINEL_ID_KEY};
+pub use sentinel_guard::sentinel_guard;
@@ -0,0 +1,53 @@
+//! Test-only stand-in for the Sentinel SDK contract.
+
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+use crate::{FillResult, OperationKind, RiskDecision};
+
+/// Sentinel stand-in returning the decision set by the test for every
+/// wallet
+#[contract]
+pub(crate) struct MockSentinel;
+
+#[contractimpl]
+impl MockSentinel {
+    pub fn set(env: Env, decision: RiskDecision) {
+        env.storage().instance().set(&symbol_short!("decision"), &decision);
+    }
+    
+    /// Mark an operation kind exempt, as the contract's `set_operation_rule`
+    pub fn exempt(env: Env, kind: OperationKind) {
+        env.storage().instance().set(&kind, &true);
+    }
+    
+    pub fn check_permission(env: Env, _wallet: Address) -> RiskDecision {
+        env.storage().instance().get(&symbol_short!("decision")).unwrap()
+    }
+    
+    pub fn check_operation(env: Env, wallet: Address, kind: OperationKind, amount: i128) -> FillResult {
+        if amount <= 0 {
+            return FillResult::Rejected;
+        }
+        if env.storage().instance().has(&kind) {
+            return FillResult::Full;
+        }
+        
+        match Self::check_permission(env, wallet) {
+            RiskDecision::Allow => FillResult::Full,
+            RiskDecision::Limit(cap) if amount <= i128::from(cap) => FillResult::Full,
+            RiskDecision::Limit(cap) if cap > 0 => FillResult::Partial(i128::from(cap)),
+            _ => FillResult::Rejected,
+        }
+    }
+    
+    pub fn is_frozen(env: Env, wallet: Address) -> bool {
+        Self::check_permission(env, wallet) == RiskDecision::Freeze
+    }
+}
+
+/// Register a `MockSentinel` answering `decision`
+pub(crate) fn mock_sentinel(env: &Env, decision: RiskDecision) -> Address {
+    let sentinel_id = env.register_contract(None, MockSentinel);
+    MockSentinelClient::new(env, &sentinel_id).set(&decision);
+    sentinel_id
+}
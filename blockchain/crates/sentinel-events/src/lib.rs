@@ -0,0 +1,328 @@
+//! Event schema of the Sentinel SDK contract.
+//!
+//! Shared by the contract (which publishes with it), the Rust bindings and
+//! off-chain consumers (which decode with it), so an indexer no longer
+//! hand-parses tuples that drift between releases.
+//!
+//! - [`topic`]: the name (first topic) of every event
+//! - [`RiskEvent`]: the wallet risk stream, typed end to end: score
+//!   updates, decision changes, rejections, overrides and erasures, all
+//!   carrying the wallet as second topic
+//!
+//! The crate is `no_std`. The `std` feature adds `RiskEvent::from_xdr` for
+//! consumers holding raw `ScVal`s from RPC and [`rpc`], which decodes RPC
+//! event XDR into plain std types for indexers and alerting bots; it pulls
+//! in the Soroban host and must not be enabled in contracts.
+
+#![no_std]
+
+#[cfg(feature = "std")]
+pub mod rpc;
+pub mod topic;
+
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+/// Decision as carried in event data (same encoding as the contract's
+/// `RiskDecision`)
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RiskDecision {
+    Allow,
+    Limit(u32),
+    Review,
+    Freeze,
+}
+
+/// Data of `RISK_UPD` and the decision events (`FROZEN`, `REVIEW`,
+/// `LIMITED`, `ALLOWED`)
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskUpdateEvent {
+    /// Score stored (0-100)
+    pub risk_score: u32,
+    /// Oracle timestamp of the score
+    pub timestamp: u64,
+    /// Score-based decision before the update (`Allow` for a first
+    /// score, as for any unscored wallet)
+    pub old_decision: RiskDecision,
+    /// Score-based decision after the update
+    pub new_decision: RiskDecision,
+}
+
+/// Data of `DEC_CHG`, emitted only when a stored score flips the
+/// score-based decision
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecisionChangeEvent {
+    /// Decision before the score (`Allow` for a first score)
+    pub old_decision: RiskDecision,
+    /// Decision after the score
+    pub new_decision: RiskDecision,
+    /// Score that caused the change (0-100)
+    pub risk_score: u32,
+}
+
+/// Data of `REJECTED` (the stored state is unchanged)
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RejectionEvent {
+    /// `SentinelError` code the payload failed with
+    pub error_code: u32,
+    /// Score the payload carried
+    pub risk_score: u32,
+    /// Oracle timestamp of the payload
+    pub timestamp: u64,
+    /// Ledger timestamp at rejection
+    pub ledger_timestamp: u64,
+}
+
+/// Data of `OUT_ORDER`, published with `REJECTED` for payloads older than
+/// the stored score
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutOfOrderEvent {
+    /// Oracle timestamp of the payload
+    pub timestamp: u64,
+    /// Oracle timestamp of the stored score
+    pub stored_timestamp: u64,
+}
+
+/// Data of `OVR_SET` and `OVR_CLR`
+///
+/// Decisions are `check_permission` results before and after the change.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OverrideEvent {
+    /// Effective decision before the change
+    pub old_decision: RiskDecision,
+    /// Effective decision after the change
+    pub new_decision: RiskDecision,
+    /// Override expiry (0 = permanent, or cleared)
+    pub expiry: u64,
+}
+
+/// Data of `RISK_DEL`
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErasureEvent {
+    /// Score-based decision of the deleted state (`Allow` if unscored)
+    pub old_decision: RiskDecision,
+    /// Admin that deleted the state
+    pub admin: Address,
+    /// Whether a freeze override was bypassed
+    pub force: bool,
+}
+
+/// Event of the wallet risk stream
+///
+/// Topics are (name, wallet), plus the domain for scores submitted to a
+/// risk domain (`submit_risk_domain`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RiskEvent {
+    /// `RISK_UPD`: a score was stored
+    Updated { wallet: Address, domain: Option<Symbol>, data: RiskUpdateEvent },
+    /// `FROZEN` / `REVIEW` / `LIMITED` / `ALLOWED`: the decision of a
+    /// stored score (the name follows `data.new_decision`)
+    Decided { wallet: Address, domain: Option<Symbol>, data: RiskUpdateEvent },
+    /// `DEC_CHG`: a stored score flipped the decision
+    DecisionChanged { wallet: Address, domain: Option<Symbol>, data: DecisionChangeEvent },
+    /// `REJECTED`: a payload failed validation
+    Rejected { wallet: Address, data: RejectionEvent },
+    /// `OUT_ORDER`: a payload was older than the stored score
+    OutOfOrder { wallet: Address, data: OutOfOrderEvent },
+    /// `OVR_SET`: an override was set
+    OverrideSet { wallet: Address, data: OverrideEvent },
+    /// `OVR_CLR`: an override was cleared
+    OverrideCleared { wallet: Address, data: OverrideEvent },
+    /// `RISK_DEL`: the wallet's Oracle data was erased
+    Erased { wallet: Address, data: ErasureEvent },
+}
+
+/// Name of the decision event for a decision
+pub fn decision_topic(decision: &RiskDecision) -> Symbol {
+    match decision {
+        RiskDecision::Allow => topic::ALLOWED,
+        RiskDecision::Limit(_) => topic::LIMITED,
+        RiskDecision::Review => topic::REVIEW,
+        RiskDecision::Freeze => topic::FROZEN,
+    }
+}
+
+impl RiskEvent {
+    /// Event name (first topic)
+    pub fn name(&self) -> Symbol {
+        match self {
+            RiskEvent::Updated { .. } => topic::RISK_UPD,
+            RiskEvent::Decided { data, .. } => decision_topic(&data.new_decision),
+            RiskEvent::DecisionChanged { .. } => topic::DEC_CHG,
+            RiskEvent::Rejected { .. } => topic::REJECTED,
+            RiskEvent::OutOfOrder { .. } => topic::OUT_ORDER,
+            RiskEvent::OverrideSet { .. } => topic::OVR_SET,
+            RiskEvent::OverrideCleared { .. } => topic::OVR_CLR,
+            RiskEvent::Erased { .. } => topic::RISK_DEL,
+        }
+    }
+    
+    /// Wallet the event is about
+    pub fn wallet(&self) -> &Address {
+        match self {
+            RiskEvent::Updated { wallet, .. }
+            | RiskEvent::Decided { wallet, .. }
+            | RiskEvent::DecisionChanged { wallet, .. }
+            | RiskEvent::Rejected { wallet, .. }
+            | RiskEvent::OutOfOrder { wallet, .. }
+            | RiskEvent::OverrideSet { wallet, .. }
+            | RiskEvent::OverrideCleared { wallet, .. }
+            | RiskEvent::Erased { wallet, .. } => wallet,
+        }
+    }
+    
+    /// Encoded topics: (name, wallet[, domain])
+    pub fn topics(&self, env: &Env) -> Vec<Val> {
+        let mut topics = Vec::new(env);
+        topics.push_back(self.name().to_val());
+        topics.push_back(self.wallet().to_val());
+        match self {
+            RiskEvent::Updated { domain: Some(domain), .. }
+            | RiskEvent::Decided { domain: Some(domain), .. }
+            | RiskEvent::DecisionChanged { domain: Some(domain), .. } => {
+                topics.push_back(domain.to_val());
+            }
+            _ => {}
+        }
+        topics
+    }
+    
+    /// Encoded data
+    pub fn data(&self, env: &Env) -> Val {
+        match self {
+            RiskEvent::Updated { data, .. } | RiskEvent::Decided { data, .. } => data.into_val(env),
+            RiskEvent::DecisionChanged { data, .. } => data.into_val(env),
+            RiskEvent::Rejected { data, .. } => data.into_val(env),
+            RiskEvent::OutOfOrder { data, .. } => data.into_val(env),
+            RiskEvent::OverrideSet { data, .. } | RiskEvent::OverrideCleared { data, .. } => {
+                data.into_val(env)
+            }
+            RiskEvent::Erased { data, .. } => data.into_val(env),
+        }
+    }
+    
+    /// Publish the event from the current contract
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(self.topics(env), self.data(env));
+    }
+    
+    /// Decode an event of the risk stream
+    ///
+    /// # Returns
+    /// * `None` if the event is not part of the risk stream or its topics
+    ///   or data do not match the schema
+    pub fn decode(env: &Env, topics: &Vec<Val>, data: Val) -> Option<RiskEvent> {
+        let name = Symbol::try_from_val(env, &topics.get(0)?).ok()?;
+        let wallet = Address::try_from_val(env, &topics.get(1)?).ok()?;
+        let domain = match topics.len() {
+            2 => None,
+            3 => Some(Symbol::try_from_val(env, &topics.get(2)?).ok()?),
+            _ => return None,
+        };
+        let keyed_by_wallet = domain.is_none();
+        
+        let event = if name == topic::RISK_UPD {
+            let data = RiskUpdateEvent::try_from_val(env, &data).ok()?;
+            RiskEvent::Updated { wallet, domain, data }
+        } else if name == topic::DEC_CHG {
+            let data = DecisionChangeEvent::try_from_val(env, &data).ok()?;
+            RiskEvent::DecisionChanged { wallet, domain, data }
+        } else if [topic::ALLOWED, topic::LIMITED, topic::REVIEW, topic::FROZEN].contains(&name) {
+            let data = RiskUpdateEvent::try_from_val(env, &data).ok()?;
+            if decision_topic(&data.new_decision) != name {
+                return None;
+            }
+            RiskEvent::Decided { wallet, domain, data }
+        } else if !keyed_by_wallet {
+            return None;
+        } else if name == topic::REJECTED {
+            RiskEvent::Rejected { wallet, data: RejectionEvent::try_from_val(env, &data).ok()? }
+        } else if name == topic::OUT_ORDER {
+            RiskEvent::OutOfOrder { wallet, data: OutOfOrderEvent::try_from_val(env, &data).ok()? }
+        } else if name == topic::OVR_SET {
+            RiskEvent::OverrideSet { wallet, data: OverrideEvent::try_from_val(env, &data).ok()? }
+        } else if name == topic::OVR_CLR {
+            RiskEvent::OverrideCleared { wallet, data: OverrideEvent::try_from_val(env, &data).ok()? }
+        } else if name == topic::RISK_DEL {
+            RiskEvent::Erased { wallet, data: ErasureEvent::try_from_val(env, &data).ok()? }
+        } else {
+            return None;
+        };
+        Some(event)
+    }
+    
+    /// Decode an event of the risk stream from RPC `ScVal`s (feature `std`)
+    #[cfg(feature = "std")]
+    pub fn from_xdr(
+        env: &Env,
+        topics: &[soroban_sdk::xdr::ScVal],
+        data: &soroban_sdk::xdr::ScVal,
+    ) -> Option<RiskEvent> {
+        let mut vals = Vec::new(env);
+        for topic in topics {
+            vals.push_back(Val::try_from_val(env, topic).ok()?);
+        }
+        RiskEvent::decode(env, &vals, Val::try_from_val(env, data).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, symbol_short, testutils::{Address as _, Events}};
+    
+    #[contract]
+    struct Emitter;
+    
+    #[contractimpl]
+    impl Emitter {}
+    
+    #[test]
+    fn test_published_events_decode() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, Emitter);
+        let wallet = Address::generate(&env);
+        
+        let update = RiskUpdateEvent {
+            risk_score: 90,
+            timestamp: 1_000,
+            old_decision: RiskDecision::Limit(5000),
+            new_decision: RiskDecision::Freeze,
+        };
+        let sent = [
+            RiskEvent::Updated { wallet: wallet.clone(), domain: None, data: update.clone() },
+            RiskEvent::Decided { wallet: wallet.clone(), domain: Some(symbol_short!("bots")), data: update },
+            RiskEvent::Rejected {
+                wallet: wallet.clone(),
+                data: RejectionEvent { error_code: 1, risk_score: 90, timestamp: 1_000, ledger_timestamp: 2_000 },
+            },
+        ];
+        env.as_contract(&contract_id, || {
+            for event in sent.iter() {
+                event.publish(&env);
+            }
+        });
+        
+        let published = env.events().all();
+        assert_eq!(published.len(), 3);
+        for (i, (_, topics, data)) in published.iter().enumerate() {
+            assert_eq!(RiskEvent::decode(&env, &topics, data), Some(sent[i].clone()));
+        }
+        assert_eq!(sent[1].name(), topic::FROZEN);
+        
+        // Names outside the risk stream, and a decision event whose name
+        // contradicts its data, are not decoded
+        let (_, topics, data) = published.get(0).unwrap();
+        let mut renamed = topics.clone();
+        renamed.set(0, topic::SDK_INIT.to_val());
+        assert_eq!(RiskEvent::decode(&env, &renamed, data), None);
+        renamed.set(0, topic::ALLOWED.to_val());
+        assert_eq!(RiskEvent::decode(&env, &renamed, data), None);
+    }
+}
@@ -0,0 +1,835 @@
+//! Decoding of raw RPC events for off-chain indexers (feature `std`).
+//!
+//! Soroban RPC `getEvents` (and `stellar events --output json`) returns
+//! each topic and the value as a base64 XDR `ScVal`. `decode` turns them
+//! into a `SentinelEvent` with plain std types: addresses as strkeys
+//! (`G...` / `C...`), symbols as strings, decisions as `RiskDecision`.
+//!
+//! The `ScVal`s are read directly, without a Soroban host, so a
+//! long-running indexer does not accumulate host objects or budget.
+
+extern crate std;
+
+use std::fmt;
+use std::string::String;
+use std::vec::Vec;
+
+use soroban_sdk::xdr::{Limits, ReadXdr, ScMap, ScVal};
+
+use crate::RiskDecision;
+
+/// Sentinel event decoded from RPC XDR
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SentinelEvent {
+    /// `RISK_UPD`: a score was stored
+    RiskUpdated {
+        wallet: String,
+        /// Risk domain, for `submit_risk_domain` scores
+        domain: Option<String>,
+        risk_score: u32,
+        /// Oracle timestamp of the score
+        timestamp: u64,
+        old_decision: RiskDecision,
+        new_decision: RiskDecision,
+    },
+    /// `FROZEN`: a stored score freezes the wallet
+    Frozen {
+        wallet: String,
+        domain: Option<String>,
+        risk_score: u32,
+        timestamp: u64,
+    },
+    /// `REVIEW`: a stored score holds the wallet for review
+    Review {
+        wallet: String,
+        domain: Option<String>,
+        risk_score: u32,
+        timestamp: u64,
+    },
+    /// `LIMITED`: a stored score limits the wallet to `limit`
+    Limited {
+        wallet: String,
+        domain: Option<String>,
+        risk_score: u32,
+        timestamp: u64,
+        limit: u32,
+    },
+    /// `ALLOWED`: a stored score allows the wallet
+    Allowed {
+        wallet: String,
+        domain: Option<String>,
+        risk_score: u32,
+        timestamp: u64,
+    },
+    /// `DEC_CHG`: a stored score flipped the decision
+    DecisionChanged {
+        wallet: String,
+        domain: Option<String>,
+        old_decision: RiskDecision,
+        new_decision: RiskDecision,
+        risk_score: u32,
+    },
+    /// `REJECTED`: a payload failed validation
+    Rejected {
+        wallet: String,
+        error_code: u32,
+        risk_score: u32,
+        timestamp: u64,
+        ledger_timestamp: u64,
+    },
+    /// `OUT_ORDER`: a payload was older than the stored score
+    OutOfOrder {
+        wallet: String,
+        timestamp: u64,
+        stored_timestamp: u64,
+    },
+    /// `OVR_SET`: an override was set (`expiry` 0 = permanent)
+    OverrideSet {
+        wallet: String,
+        old_decision: RiskDecision,
+        new_decision: RiskDecision,
+        expiry: u64,
+    },
+    /// `OVR_CLR`: an override was cleared
+    OverrideCleared {
+        wallet: String,
+        old_decision: RiskDecision,
+        new_decision: RiskDecision,
+    },
+    /// `RISK_DEL`: the wallet's Oracle data was erased
+    Erased {
+        wallet: String,
+        admin: String,
+        force: bool,
+    },
+    /// `GLOB_FRZ` (`engaged`): global freeze engaged
+    Paused {
+        admin: String,
+        score_floor: u32,
+        expires_at: u64,
+    },
+    /// `GLOB_FRZ` (`lifted`): global freeze lifted
+    Unpaused { admin: String },
+}
+
+impl SentinelEvent {
+    /// Wallet the event is about (`None` for contract-wide events)
+    pub fn wallet(&self) -> Option<&str> {
+        match self {
+            SentinelEvent::RiskUpdated { wallet, .. }
+            | SentinelEvent::Frozen { wallet, .. }
+            | SentinelEvent::Review { wallet, .. }
+            | SentinelEvent::Limited { wallet, .. }
+            | SentinelEvent::Allowed { wallet, .. }
+            | SentinelEvent::DecisionChanged { wallet, .. }
+            | SentinelEvent::Rejected { wallet, .. }
+            | SentinelEvent::OutOfOrder { wallet, .. }
+            | SentinelEvent::OverrideSet { wallet, .. }
+            | SentinelEvent::OverrideCleared { wallet, .. }
+            | SentinelEvent::Erased { wallet, .. } => Some(wallet),
+            SentinelEvent::Paused { .. } | SentinelEvent::Unpaused { .. } => None,
+        }
+    }
+}
+
+/// Why an event could not be decoded
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// A topic or the value is not base64 XDR of an `ScVal`
+    Xdr,
+    /// The event name is known but topics or data do not match its schema
+    Schema(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Xdr => write!(f, "event is not valid ScVal XDR"),
+            DecodeError::Schema(name) => write!(f, "{name} event does not match its schema"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Decode an event from the base64 XDR topics and value RPC returns
+///
+/// # Returns
+/// * `Ok(None)` for events this decoder does not cover
+pub fn decode<T: AsRef<str>>(
+    topics: &[T],
+    value: &str,
+) -> Result<Option<SentinelEvent>, DecodeError> {
+    let topics = topics
+        .iter()
+        .map(|topic| parse_base64(topic.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    decode_xdr(&topics, &parse_base64(value)?)
+}
+
+/// Decode an event from already parsed `ScVal`s
+///
+/// # Returns
+/// * `Ok(None)` for events this decoder does not cover
+pub fn decode_xdr(topics: &[ScVal], value: &ScVal) -> Result<Option<SentinelEvent>, DecodeError> {
+    let Some(name) = topics.first().and_then(symbol) else {
+        return Ok(None);
+    };
+    let event = match name.as_str() {
+        "RISK_UPD" | "FROZEN" | "REVIEW" | "LIMITED" | "ALLOWED" | "DEC_CHG" => {
+            decode_score(&name, topics, value)
+        }
+        "REJECTED" | "OUT_ORDER" | "OVR_SET" | "OVR_CLR" | "RISK_DEL" => {
+            decode_wallet(&name, topics, value)
+        }
+        "GLOB_FRZ" => decode_pause(topics, value),
+        _ => return Ok(None),
+    };
+    event.map(Some).ok_or(DecodeError::Schema(name))
+}
+
+/// Score events: topics (name, wallet[, domain])
+fn decode_score(name: &str, topics: &[ScVal], value: &ScVal) -> Option<SentinelEvent> {
+    let wallet = address(topics.get(1)?)?;
+    let domain = match topics.len() {
+        2 => None,
+        3 => Some(symbol(&topics[2])?),
+        _ => return None,
+    };
+    let data = map(value)?;
+    let risk_score = u32_field(data, "risk_score")?;
+    if name == "DEC_CHG" {
+        return Some(SentinelEvent::DecisionChanged {
+            wallet,
+            domain,
+            old_decision: decision(field(data, "old_decision")?)?,
+            new_decision: decision(field(data, "new_decision")?)?,
+            risk_score,
+        });
+    }
+    
+    let timestamp = u64_field(data, "timestamp")?;
+    let old_decision = decision(field(data, "old_decision")?)?;
+    let new_decision = decision(field(data, "new_decision")?)?;
+    let event = match (name, &new_decision) {
+        ("RISK_UPD", _) => SentinelEvent::RiskUpdated {
+            wallet,
+            domain,
+            risk_score,
+            timestamp,
+            old_decision,
+            new_decision,
+        },
+        ("FROZEN", RiskDecision::Freeze) => SentinelEvent::Frozen {
+            wallet,
+            domain,
+            risk_score,
+            timestamp,
+        },
+        ("REVIEW", RiskDecision::Review) => SentinelEvent::Review {
+            wallet,
+            domain,
+            risk_score,
+            timestamp,
+        },
+        ("LIMITED", RiskDecision::Limit(limit)) => SentinelEvent::Limited {
+            wallet,
+            domain,
+            risk_score,
+            timestamp,
+            limit: *limit,
+        },
+        ("ALLOWED", RiskDecision::Allow) => SentinelEvent::Allowed {
+            wallet,
+            domain,
+            risk_score,
+            timestamp,
+        },
+        _ => return None,
+    };
+    Some(event)
+}
+
+/// Other wallet events: topics (name, wallet)
+fn decode_wallet(name: &str, topics: &[ScVal], value: &ScVal) -> Option<SentinelEvent> {
+    if topics.len() != 2 {
+        return None;
+    }
+    let wallet = address(&topics[1])?;
+    let data = map(value)?;
+    let event = match name {
+        "REJECTED" => SentinelEvent::Rejected {
+            wallet,
+            error_code: u32_field(data, "error_code")?,
+            risk_score: u32_field(data, "risk_score")?,
+            timestamp: u64_field(data, "timestamp")?,
+            ledger_timestamp: u64_field(data, "ledger_timestamp")?,
+        },
+        "OUT_ORDER" => SentinelEvent::OutOfOrder {
+            wallet,
+            timestamp: u64_field(data, "timestamp")?,
+            stored_timestamp: u64_field(data, "stored_timestamp")?,
+        },
+        "OVR_SET" => SentinelEvent::OverrideSet {
+            wallet,
+            old_decision: decision(field(data, "old_decision")?)?,
+            new_decision: decision(field(data, "new_decision")?)?,
+            expiry: u64_field(data, "expiry")?,
+        },
+        "OVR_CLR" => SentinelEvent::OverrideCleared {
+            wallet,
+            old_decision: decision(field(data, "old_decision")?)?,
+            new_decision: decision(field(data, "new_decision")?)?,
+        },
+        "RISK_DEL" => SentinelEvent::Erased {
+            wallet,
+            admin: address(field(data, "admin")?)?,
+            force: match field(data, "force")? {
+                ScVal::Bool(force) => *force,
+                _ => return None,
+            },
+        },
+        _ => return None,
+    };
+    Some(event)
+}
+
+/// `GLOB_FRZ`: topics (name, `engaged` | `lifted`)
+fn decode_pause(topics: &[ScVal], value: &ScVal) -> Option<SentinelEvent> {
+    if topics.len() != 2 {
+        return None;
+    }
+    match symbol(&topics[1])?.as_str() {
+        "engaged" => match items(value)? {
+            [admin, ScVal::U32(score_floor), ScVal::U64(expires_at)] => {
+                Some(SentinelEvent::Paused {
+                    admin: address(admin)?,
+                    score_floor: *score_floor,
+                    expires_at: *expires_at,
+                })
+            }
+            _ => None,
+        },
+        "lifted" => Some(SentinelEvent::Unpaused {
+            admin: address(value)?,
+        }),
+        _ => None,
+    }
+}
+
+fn parse_base64(xdr: &str) -> Result<ScVal, DecodeError> {
+    ScVal::from_xdr_base64(xdr, Limits::none()).map_err(|_| DecodeError::Xdr)
+}
+
+fn symbol(value: &ScVal) -> Option<String> {
+    match value {
+        ScVal::Symbol(symbol) => symbol.0.to_utf8_string().ok(),
+        _ => None,
+    }
+}
+
+/// Strkey of an address (`G...` for accounts, `C...` for contracts)
+fn address(value: &ScVal) -> Option<String> {
+    match value {
+        ScVal::Address(address) => Some(std::format!("{address}")),
+        _ => None,
+    }
+}
+
+fn items(value: &ScVal) -> Option<&[ScVal]> {
+    match value {
+        ScVal::Vec(Some(items)) => Some(items.as_slice()),
+        _ => None,
+    }
+}
+
+fn map(value: &ScVal) -> Option<&ScMap> {
+    match value {
+        ScVal::Map(Some(map)) => Some(map),
+        _ => None,
+    }
+}
+
+fn field<'a>(map: &'a ScMap, name: &str) -> Option<&'a ScVal> {
+    map.iter()
+        .find(|entry| symbol(&entry.key).as_deref() == Some(name))
+        .map(|entry| &entry.val)
+}
+
+fn u32_field(map: &ScMap, name: &str) -> Option<u32> {
+    match field(map, name)? {
+        ScVal::U32(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn u64_field(map: &ScMap, name: &str) -> Option<u64> {
+    match field(map, name)? {
+        ScVal::U64(value) => Some(*value),
+        _ => None,
+    }
+}
+
+/// `RiskDecision` as encoded by `contracttype`: a vector of the variant
+/// name and its payload
+fn decision(value: &ScVal) -> Option<RiskDecision> {
+    match items(value)? {
+        [tag] => match symbol(tag)?.as_str() {
+            "Allow" => Some(RiskDecision::Allow),
+            "Review" => Some(RiskDecision::Review),
+            "Freeze" => Some(RiskDecision::Freeze),
+            _ => None,
+        },
+        [tag, ScVal::U32(limit)] if symbol(tag)?.as_str() == "Limit" => {
+            Some(RiskDecision::Limit(*limit))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        DecisionChangeEvent, ErasureEvent, OutOfOrderEvent, OverrideEvent, RejectionEvent,
+        RiskEvent, RiskUpdateEvent,
+    };
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short,
+        testutils::{Address as _, Events},
+        xdr::WriteXdr,
+        Address, Env, IntoVal, TryFromVal,
+    };
+    use std::string::ToString;
+    
+    #[contract]
+    struct Emitter;
+    
+    #[contractimpl]
+    impl Emitter {}
+    
+    /// Base64 XDR of a value, as RPC returns it
+    fn base64(env: &Env, value: soroban_sdk::Val) -> String {
+        ScVal::try_from_val(env, &value)
+            .unwrap()
+            .to_xdr_base64(Limits::none())
+            .unwrap()
+    }
+    
+    #[test]
+    fn test_decodes_rpc_xdr_with_strkeys() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, Emitter);
+        let (wallet, admin) = (Address::generate(&env), Address::generate(&env));
+        
+        let data = RiskUpdateEvent {
+            risk_score: 60,
+            timestamp: 1_000,
+            old_decision: RiskDecision::Allow,
+            new_decision: RiskDecision::Limit(5000),
+        };
+        env.as_contract(&contract_id, || {
+            RiskEvent::Decided {
+                wallet: wallet.clone(),
+                domain: Some(symbol_short!("bots")),
+                data,
+            }
+            .publish(&env);
+            env.events().publish(
+                (crate::topic::GLOB_FRZ, symbol_short!("engaged")),
+                (admin.clone(), 80u32, 9_000u64),
+            );
+        });
+        
+        let strkey = |address: &Address| address.to_string().to_string();
+        
+        let decoded: Vec<_> = env
+            .events()
+            .all()
+            .iter()
+            .map(|(_, topics, value)| {
+                let topics: Vec<String> = topics.iter().map(|topic| base64(&env, topic)).collect();
+                decode(&topics, &base64(&env, value)).unwrap().unwrap()
+            })
+            .collect();
+        assert_eq!(
+            decoded[0],
+            SentinelEvent::Limited {
+                wallet: strkey(&wallet),
+                domain: Some("bots".into()),
+                risk_score: 60,
+                timestamp: 1_000,
+                limit: 5000,
+            }
+        );
+        assert!(decoded[0].wallet().unwrap().starts_with('C'));
+        assert_eq!(
+            decoded[1],
+            SentinelEvent::Paused {
+                admin: strkey(&admin),
+                score_floor: 80,
+                expires_at: 9_000
+            }
+        );
+        
+        // Unknown events are skipped; garbage is an error
+        let other = base64(&env, symbol_short!("SDK_INIT").to_val());
+        assert_eq!(decode(&[other.as_str()], &other), Ok(None));
+        assert_eq!(decode(&["not xdr"], &other), Err(DecodeError::Xdr));
+        let frozen = base64(&env, crate::topic::FROZEN.to_val());
+        assert_eq!(
+            decode(&[frozen.as_str()], &other),
+            Err(DecodeError::Schema("FROZEN".into()))
+        );
+    }
+    
+    /// Publish events from a fresh contract and decode them as an indexer
+    /// reading RPC would
+    fn decode_published(
+        env: &Env,
+        publish: impl FnOnce(),
+    ) -> Vec<Result<Option<SentinelEvent>, DecodeError>> {
+        let contract_id = env.register_contract(None, Emitter);
+        env.as_contract(&contract_id, publish);
+        env.events()
+            .all()
+            .iter()
+            .map(|(_, topics, value)| {
+                let topics: Vec<String> = topics.iter().map(|topic| base64(env, topic)).collect();
+                decode(&topics, &base64(env, value))
+            })
+            .collect()
+    }
+    
+    fn strkey(address: &Address) -> String {
+        address.to_string().to_string()
+    }
+    
+    #[test]
+    fn test_decodes_each_score_event() {
+        let env = Env::default();
+        let wallet = Address::generate(&env);
+        let update = |risk_score, new_decision| RiskUpdateEvent {
+            risk_score,
+            timestamp: 1_000,
+            old_decision: RiskDecision::Allow,
+            new_decision,
+        };
+        
+        let decoded = decode_published(&env, || {
+            let domain = Some(symbol_short!("bots"));
+            let events = [
+                RiskEvent::Updated {
+                    wallet: wallet.clone(),
+                    domain: None,
+                    data: update(40, RiskDecision::Review),
+                },
+                RiskEvent::Decided {
+                    wallet: wallet.clone(),
+                    domain: None,
+                    data: update(95, RiskDecision::Freeze),
+                },
+                RiskEvent::Decided {
+                    wallet: wallet.clone(),
+                    domain: None,
+                    data: update(40, RiskDecision::Review),
+                },
+                RiskEvent::Decided {
+                    wallet: wallet.clone(),
+                    domain: None,
+                    data: update(60, RiskDecision::Limit(5000)),
+                },
+                RiskEvent::Decided {
+                    wallet: wallet.clone(),
+                    domain: domain.clone(),
+                    data: update(10, RiskDecision::Allow),
+                },
+                RiskEvent::DecisionChanged {
+                    wallet: wallet.clone(),
+                    domain,
+                    data: DecisionChangeEvent {
+                        old_decision: RiskDecision::Allow,
+                        new_decision: RiskDecision::Freeze,
+                        risk_score: 95,
+                    },
+                },
+            ];
+            for event in events.iter() {
+                event.publish(&env);
+            }
+        });
+        
+        let wallet = strkey(&wallet);
+        let bots = Some(String::from("bots"));
+        let expected = [
+            SentinelEvent::RiskUpdated {
+                wallet: wallet.clone(),
+                domain: None,
+                risk_score: 40,
+                timestamp: 1_000,
+                old_decision: RiskDecision::Allow,
+                new_decision: RiskDecision::Review,
+            },
+            SentinelEvent::Frozen {
+                wallet: wallet.clone(),
+                domain: None,
+                risk_score: 95,
+                timestamp: 1_000,
+            },
+            SentinelEvent::Review {
+                wallet: wallet.clone(),
+                domain: None,
+                risk_score: 40,
+                timestamp: 1_000,
+            },
+            SentinelEvent::Limited {
+                wallet: wallet.clone(),
+                domain: None,
+                risk_score: 60,
+                timestamp: 1_000,
+                limit: 5000,
+            },
+            SentinelEvent::Allowed {
+                wallet: wallet.clone(),
+                domain: bots.clone(),
+                risk_score: 10,
+                timestamp: 1_000,
+            },
+            SentinelEvent::DecisionChanged {
+                wallet,
+                domain: bots,
+                old_decision: RiskDecision::Allow,
+                new_decision: RiskDecision::Freeze,
+                risk_score: 95,
+            },
+        ];
+        assert_eq!(decoded.len(), expected.len());
+        for (decoded, expected) in decoded.into_iter().zip(expected) {
+            assert_eq!(decoded, Ok(Some(expected)));
+        }
+    }
+    
+    #[test]
+    fn test_decodes_each_wallet_and_pause_event() {
+        let env = Env::default();
+        let (wallet, admin) = (Address::generate(&env), Address::generate(&env));
+        let change = |expiry| OverrideEvent {
+            old_decision: RiskDecision::Allow,
+            new_decision: RiskDecision::Freeze,
+            expiry,
+        };
+        
+        let decoded = decode_published(&env, || {
+            let events = [
+                RiskEvent::Rejected {
+                    wallet: wallet.clone(),
+                    data: RejectionEvent {
+                        error_code: 3,
+                        risk_score: 101,
+                        timestamp: 1_000,
+                        ledger_timestamp: 2_000,
+                    },
+                },
+                RiskEvent::OutOfOrder {
+                    wallet: wallet.clone(),
+                    data: OutOfOrderEvent {
+                        timestamp: 900,
+                        stored_timestamp: 1_000,
+                    },
+                },
+                RiskEvent::OverrideSet {
+                    wallet: wallet.clone(),
+                    data: change(5_000),
+                },
+                RiskEvent::OverrideCleared {
+                    wallet: wallet.clone(),
+                    data: change(0),
+                },
+                RiskEvent::Erased {
+                    wallet: wallet.clone(),
+                    data: ErasureEvent {
+                        old_decision: RiskDecision::Review,
+                        admin: admin.clone(),
+                        force: true,
+                    },
+                },
+            ];
+            for event in events.iter() {
+                event.publish(&env);
+            }
+            env.events().publish(
+                (crate::topic::GLOB_FRZ, symbol_short!("lifted")),
+                admin.clone(),
+            );
+        });
+        
+        let (wallet, admin) = (strkey(&wallet), strkey(&admin));
+        let expected = [
+            SentinelEvent::Rejected {
+                wallet: wallet.clone(),
+                error_code: 3,
+                risk_score: 101,
+                timestamp: 1_000,
+                ledger_timestamp: 2_000,
+            },
+            SentinelEvent::OutOfOrder {
+                wallet: wallet.clone(),
+                timestamp: 900,
+                stored_timestamp: 1_000,
+            },
+            SentinelEvent::OverrideSet {
+                wallet: wallet.clone(),
+                old_decision: RiskDecision::Allow,
+                new_decision: RiskDecision::Freeze,
+                expiry: 5_000,
+            },
+            SentinelEvent::OverrideCleared {
+                wallet: wallet.clone(),
+                old_decision: RiskDecision::Allow,
+                new_decision: RiskDecision::Freeze,
+            },
+            SentinelEvent::Erased {
+                wallet,
+                admin: admin.clone(),
+                force: true,
+            },
+            SentinelEvent::Unpaused { admin },
+        ];
+        assert_eq!(decoded.len(), expected.len());
+        for (decoded, expected) in decoded.into_iter().zip(expected) {
+            assert_eq!(decoded, Ok(Some(expected)));
+        }
+    }
+    
+    #[test]
+    fn test_malformed_events_are_schema_errors() {
+        let env = Env::default();
+        let (wallet, admin) = (Address::generate(&env), Address::generate(&env));
+        let update = RiskUpdateEvent {
+            risk_score: 95,
+            timestamp: 1_000,
+            old_decision: RiskDecision::Allow,
+            new_decision: RiskDecision::Freeze,
+        };
+        
+        let decoded = decode_published(&env, || {
+            let events = env.events();
+            // Decision event whose name contradicts its data
+            events.publish((crate::topic::ALLOWED, wallet.clone()), update.clone());
+            // Wallet topic that is not an address
+            events.publish(
+                (crate::topic::RISK_UPD, symbol_short!("wallet")),
+                update.clone(),
+            );
+            // Too many topics for a score event
+            events.publish(
+                (
+                    crate::topic::FROZEN,
+                    wallet.clone(),
+                    symbol_short!("bots"),
+                    1u32,
+                ),
+                update.clone(),
+            );
+            // Domain topic on an event keyed by wallet only
+            events.publish(
+                (
+                    crate::topic::OUT_ORDER,
+                    wallet.clone(),
+                    symbol_short!("bots"),
+                ),
+                OutOfOrderEvent {
+                    timestamp: 900,
+                    stored_timestamp: 1_000,
+                },
+            );
+            // Data of another event
+            events.publish((crate::topic::REJECTED, wallet.clone()), update.clone());
+            // Unknown pause transition, and engaged data of the wrong shape
+            events.publish(
+                (crate::topic::GLOB_FRZ, symbol_short!("paused")),
+                admin.clone(),
+            );
+            events.publish(
+                (crate::topic::GLOB_FRZ, symbol_short!("engaged")),
+                (admin.clone(), 80u64),
+            );
+        });
+        
+        let expected = [
+            "ALLOWED",
+            "RISK_UPD",
+            "FROZEN",
+            "OUT_ORDER",
+            "REJECTED",
+            "GLOB_FRZ",
+            "GLOB_FRZ",
+        ];
+        assert_eq!(decoded.len(), expected.len());
+        for (decoded, name) in decoded.into_iter().zip(expected) {
+            assert_eq!(decoded, Err(DecodeError::Schema(name.into())));
+        }
+        
+        // A field of the wrong type (`expiry` as u32)
+        let topics = [
+            ScVal::try_from_val(&env, &crate::topic::OVR_SET.to_val()).unwrap(),
+            ScVal::try_from_val(&env, &wallet.to_val()).unwrap(),
+        ];
+        let data: soroban_sdk::Val = OverrideEvent {
+            old_decision: RiskDecision::Allow,
+            new_decision: RiskDecision::Freeze,
+            expiry: 0,
+        }
+        .into_val(&env);
+        let ScVal::Map(Some(mut fields)) = ScVal::try_from_val(&env, &data).unwrap() else {
+            panic!("contracttype structs encode as maps");
+        };
+        let mut entries = fields.to_vec();
+        for entry in entries.iter_mut() {
+            if symbol(&entry.key).as_deref() == Some("expiry") {
+                entry.val = ScVal::U32(0);
+            }
+        }
+        fields = entries.try_into().unwrap();
+        assert_eq!(
+            decode_xdr(&topics, &ScVal::Map(Some(fields))),
+            Err(DecodeError::Schema("OVR_SET".into()))
+        );
+    }
+    
+    #[test]
+    fn test_unknown_events_are_skipped_and_garbage_is_an_error() {
+        let env = Env::default();
+        let wallet = base64(&env, Address::generate(&env).to_val());
+        let value = base64(&env, 1u32.into());
+        let unknown = base64(&env, symbol_short!("SWAP").to_val());
+        let frozen = base64(&env, crate::topic::FROZEN.to_val());
+        
+        // Names this decoder does not cover, no topics, or a first topic
+        // that is not a name
+        assert_eq!(
+            decode(&[unknown.as_str(), wallet.as_str()], &value),
+            Ok(None)
+        );
+        assert_eq!(decode::<&str>(&[], &value), Ok(None));
+        assert_eq!(
+            decode(&[wallet.as_str(), frozen.as_str()], &value),
+            Ok(None)
+        );
+        
+        // Topics or value that are not base64 XDR of an `ScVal`
+        assert_eq!(
+            decode(&[frozen.as_str(), "not xdr"], &value),
+            Err(DecodeError::Xdr)
+        );
+        assert_eq!(
+            decode(&[frozen.as_str(), wallet.as_str()], "not xdr"),
+            Err(DecodeError::Xdr)
+        );
+        let truncated = &wallet[..wallet.len() / 2];
+        assert_eq!(
+            decode(&[frozen.as_str(), truncated], &value),
+            Err(DecodeError::Xdr)
+        );
+    }
+}
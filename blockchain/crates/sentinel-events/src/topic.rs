@@ -0,0 +1,206 @@
+//! Event names (first topic of every event the contract publishes).
+//!
+//! The contract publishes with these constants, so an indexer matching on
+//! them cannot drift from the contract. Layouts beyond the name are in the
+//! contract README; the wallet risk stream is typed in `RiskEvent`.
+
+use soroban_sdk::{symbol_short, Symbol};
+
+/// Queued admin action cancelled
+pub const ACT_CNCL: Symbol = symbol_short!("ACT_CNCL");
+/// Queued admin action executed
+pub const ACT_EXEC: Symbol = symbol_short!("ACT_EXEC");
+/// Admin action queued
+pub const ACT_QUEUE: Symbol = symbol_short!("ACT_QUEUE");
+/// Aggregated Oracle added
+pub const AGG_ADD: Symbol = symbol_short!("AGG_ADD");
+/// Aggregated Oracle removed
+pub const AGG_DEL: Symbol = symbol_short!("AGG_DEL");
+/// Aggregation quantile changed
+pub const AGG_QUANT: Symbol = symbol_short!("AGG_QUANT");
+/// Stored score allows the wallet (see `RiskEvent`)
+pub const ALLOWED: Symbol = symbol_short!("ALLOWED");
+/// Appeal bond configured
+pub const APBND_SET: Symbol = symbol_short!("APBND_SET");
+/// Appeal filed
+pub const APPEAL: Symbol = symbol_short!("APPEAL");
+/// Appeal resolved
+pub const APPEAL_RS: Symbol = symbol_short!("APPEAL_RS");
+/// Per-asset limits changed
+pub const ASSET_LIM: Symbol = symbol_short!("ASSET_LIM");
+/// Score bands changed
+pub const BANDS_SET: Symbol = symbol_short!("BANDS_SET");
+/// Oracle bond posted
+pub const BONDED: Symbol = symbol_short!("BONDED");
+/// Bond withdrawal completed
+pub const BOND_WD: Symbol = symbol_short!("BOND_WD");
+/// Tripped freeze-rate breaker reset
+pub const BRK_RESET: Symbol = symbol_short!("BRK_RESET");
+/// Freeze-rate breaker changed
+pub const BRK_SET: Symbol = symbol_short!("BRK_SET");
+/// Freeze-rate breaker tripped
+pub const BRK_TRIP: Symbol = symbol_short!("BRK_TRIP");
+/// AML category decision changed
+pub const CAT_AML: Symbol = symbol_short!("CAT_AML");
+/// Bot-activity category decision changed
+pub const CAT_BOT: Symbol = symbol_short!("CAT_BOT");
+/// Fraud category decision changed
+pub const CAT_FRAUD: Symbol = symbol_short!("CAT_FRAUD");
+/// Sanctions category decision changed
+pub const CAT_SANC: Symbol = symbol_short!("CAT_SANC");
+/// Freshness challenge won
+pub const CHALLENGE: Symbol = symbol_short!("CHALLENGE");
+/// Challenge staleness threshold changed
+pub const CHAL_SET: Symbol = symbol_short!("CHAL_SET");
+/// Wallet logs compacted
+pub const COMPACT: Symbol = symbol_short!("COMPACT");
+/// Limit allowance consumed
+pub const CONSUMED: Symbol = symbol_short!("CONSUMED");
+/// Guardian council set or removed
+pub const COUNCIL: Symbol = symbol_short!("COUNCIL");
+/// Signed message verified (contract feature `debug-events`)
+pub const DBG_MSG: Symbol = symbol_short!("DBG_MSG");
+/// Score decay changed
+pub const DECAY_SET: Symbol = symbol_short!("DECAY_SET");
+/// Stored score flipped the decision (see `RiskEvent`)
+pub const DEC_CHG: Symbol = symbol_short!("DEC_CHG");
+/// Degraded-mode fallback changed
+pub const DEGR_SET: Symbol = symbol_short!("DEGR_SET");
+/// Risk domain registered
+pub const DOM_REG: Symbol = symbol_short!("DOM_REG");
+/// Feature bitmap changed
+pub const FEAT_SET: Symbol = symbol_short!("FEAT_SET");
+/// Score request fee configured
+pub const FEE_SET: Symbol = symbol_short!("FEE_SET");
+/// Accrued fees withdrawn
+pub const FEE_WDRAW: Symbol = symbol_short!("FEE_WDRAW");
+/// Stored score freezes the wallet (see `RiskEvent`)
+pub const FROZEN: Symbol = symbol_short!("FROZEN");
+/// Global freeze parameters changed
+pub const GFRZ_CFG: Symbol = symbol_short!("GFRZ_CFG");
+/// Global freeze engaged or lifted
+pub const GLOB_FRZ: Symbol = symbol_short!("GLOB_FRZ");
+/// Freeze grace period changed
+pub const GRACE_SET: Symbol = symbol_short!("GRACE_SET");
+/// Guardian co-signed an appeal
+pub const GRD_COSGN: Symbol = symbol_short!("GRD_COSGN");
+/// Guardian set or removed
+pub const GRD_SET: Symbol = symbol_short!("GRD_SET");
+/// Guardian lifted a self-lock
+pub const GRD_UNFZ: Symbol = symbol_short!("GRD_UNFZ");
+/// Wallet index at capacity
+pub const IDX_FULL: Symbol = symbol_short!("IDX_FULL");
+/// Integrator removed
+pub const INTEG_DEL: Symbol = symbol_short!("INTEG_DEL");
+/// Integrator registered
+pub const INTEG_REG: Symbol = symbol_short!("INTEG_REG");
+/// Held score jump discarded
+pub const JUMP_DROP: Symbol = symbol_short!("JUMP_DROP");
+/// Jump guard changed
+pub const JUMP_GRD: Symbol = symbol_short!("JUMP_GRD");
+/// Score jump to Freeze held
+pub const JUMP_HELD: Symbol = symbol_short!("JUMP_HELD");
+/// Held score jump applied
+pub const JUMP_OK: Symbol = symbol_short!("JUMP_OK");
+/// Entries moved to their current storage key
+pub const KEYS_MIG: Symbol = symbol_short!("KEYS_MIG");
+/// Trial key promoted
+pub const KEY_ROT: Symbol = symbol_short!("KEY_ROT");
+/// Wallet labels replaced
+pub const LABELS: Symbol = symbol_short!("LABELS");
+/// Stored score limits the wallet (see `RiskEvent`)
+pub const LIMITED: Symbol = symbol_short!("LIMITED");
+/// Basis-point limit changed
+pub const LIMIT_BPS: Symbol = symbol_short!("LIMIT_BPS");
+/// Liquidation rule changed
+pub const LIQ_SET: Symbol = symbol_short!("LIQ_SET");
+/// Risk states rewritten in the current layout
+pub const MIGRATED: Symbol = symbol_short!("MIGRATED");
+/// Oracle Merkle root accepted
+pub const MRK_ROOT: Symbol = symbol_short!("MRK_ROOT");
+/// Subscriber callback failed
+pub const NTFY_ERR: Symbol = symbol_short!("NTFY_ERR");
+/// Operation rule changed
+pub const OPRULE: Symbol = symbol_short!("OPRULE");
+/// Oracle key replaced by the guardian council
+pub const ORCL_RPL: Symbol = symbol_short!("ORCL_RPL");
+/// Aggregated Oracle score stored
+pub const ORCL_SCR: Symbol = symbol_short!("ORCL_SCR");
+/// Payload older than the stored score (see `RiskEvent`)
+pub const OUT_ORDER: Symbol = symbol_short!("OUT_ORDER");
+/// Override cleared (see `RiskEvent`)
+pub const OVR_CLR: Symbol = symbol_short!("OVR_CLR");
+/// Override set (see `RiskEvent`)
+pub const OVR_SET: Symbol = symbol_short!("OVR_SET");
+/// secp256r1 Oracle key registered
+pub const P256_REG: Symbol = symbol_short!("P256_REG");
+/// Batch stopped at the write budget
+pub const PARTIAL: Symbol = symbol_short!("PARTIAL");
+/// Decision pipeline changed
+pub const PIPE_SET: Symbol = symbol_short!("PIPE_SET");
+/// Policy defined
+pub const POL_SET: Symbol = symbol_short!("POL_SET");
+/// Quarantine release decision changed
+pub const QREL_SET: Symbol = symbol_short!("QREL_SET");
+/// Rate-limit bypass opened or closed
+pub const RATE_BYP: Symbol = symbol_short!("RATE_BYP");
+/// Submission rate limit changed
+pub const RATE_SET: Symbol = symbol_short!("RATE_SET");
+/// Payload failed validation (see `RiskEvent`)
+pub const REJECTED: Symbol = symbol_short!("REJECTED");
+/// Remediation completed
+pub const REMEDIED: Symbol = symbol_short!("REMEDIED");
+/// Remediation plan offered
+pub const REM_OFFER: Symbol = symbol_short!("REM_OFFER");
+/// Stored score holds the wallet for review (see `RiskEvent`)
+pub const REVIEW: Symbol = symbol_short!("REVIEW");
+/// Risk data erased (see `RiskEvent`)
+pub const RISK_DEL: Symbol = symbol_short!("RISK_DEL");
+/// Score stored (see `RiskEvent`)
+pub const RISK_UPD: Symbol = symbol_short!("RISK_UPD");
+/// Role granted
+pub const ROLE_GRNT: Symbol = symbol_short!("ROLE_GRNT");
+/// Role revoked
+pub const ROLE_RVK: Symbol = symbol_short!("ROLE_RVK");
+/// Replay window changed
+pub const RPLY_SET: Symbol = symbol_short!("RPLY_SET");
+/// Guardian approved a replacement Oracle key
+pub const RPL_APPRV: Symbol = symbol_short!("RPL_APPRV");
+/// Wallet scoring requested
+pub const SCORE_REQ: Symbol = symbol_short!("SCORE_REQ");
+/// Contract initialized
+pub const SDK_INIT: Symbol = symbol_short!("SDK_INIT");
+/// Self-lock cooldown changed
+pub const SELFCD_ST: Symbol = symbol_short!("SELFCD_ST");
+/// Wallet self-lock set
+pub const SELF_FRZ: Symbol = symbol_short!("SELF_FRZ");
+/// Wallet self-lock lifted
+pub const SELF_UNFZ: Symbol = symbol_short!("SELF_UNFZ");
+/// Trial key payload recorded
+pub const SHADOW: Symbol = symbol_short!("SHADOW");
+/// Forward clock-skew tolerance changed
+pub const SKEW_SET: Symbol = symbol_short!("SKEW_SET");
+/// Oracle bond slashed
+pub const SLASHED: Symbol = symbol_short!("SLASHED");
+/// Risk-state storage tier changed
+pub const STORE_SET: Symbol = symbol_short!("STORE_SET");
+/// Subscription added
+pub const SUBSCRIBE: Symbol = symbol_short!("SUBSCRIBE");
+/// Timelock delay changed
+pub const TLOCK_SET: Symbol = symbol_short!("TLOCK_SET");
+/// Key trial cancelled
+pub const TRIAL_OFF: Symbol = symbol_short!("TRIAL_OFF");
+/// Key trial started
+pub const TRIAL_ON: Symbol = symbol_short!("TRIAL_ON");
+/// Risk-state TTL config changed
+pub const TTL_SET: Symbol = symbol_short!("TTL_SET");
+/// Bond withdrawal requested
+pub const UNBOND: Symbol = symbol_short!("UNBOND");
+/// Unknown-wallet default changed
+pub const UNK_SET: Symbol = symbol_short!("UNK_SET");
+/// Subscription removed
+pub const UNSUB: Symbol = symbol_short!("UNSUB");
+/// Volume reported
+pub const USAGE: Symbol = symbol_short!("USAGE");
+/// Metering window changed
+pub const USE_WIN: Symbol = symbol_short!("USE_WIN");
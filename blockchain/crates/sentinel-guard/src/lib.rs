@@ -0,0 +1,128 @@
+//! `#[sentinel_guard]`: Sentinel permission checks as a function attribute.
+//!
+//! Annotating a contract function injects a `SentinelGuard` call (from
+//! `sentinel-client`) as its first statement, so gating an endpoint is one
+//! line instead of a match over `check_permission`:
+//!
+//! ```ignore
+//! #[contractimpl]
+//! impl Pool {
+//!     #[sentinel_guard(user = "user", amount = "amount")]
+//!     pub fn swap(env: Env, user: Address, amount: i128) { ... }
+//! }
+//! ```
+//!
+//! Arguments (all string literals):
+//!
+//! - `user` (required): parameter holding the wallet to check
+//! - `amount`: parameter holding the amount; with it the guard is
+//!   `require_limit`, without it `require_allowed`
+//! - `env`: the `Env` parameter (default `env`)
+//! - `sentinel`: expression for the Sentinel contract `Address` (default
+//!   `Self::sentinel_id(&env)`, a function the protocol provides)
+//!
+//! `#[contractimpl]` copies a function's attributes onto the items it
+//! generates for it (spec, client methods, invoke module). Those are not
+//! contract functions and pass through unchanged; every other use with a
+//! missing parameter is a compile error, so a typo never silently drops
+//! the check.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Error, Expr, FnArg, Ident, Item, ItemFn, LitStr, Pat};
+
+/// Check the caller-supplied wallet against Sentinel before the function
+/// body runs (see the crate docs for arguments)
+#[proc_macro_attribute]
+pub fn sentinel_guard(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut func = match syn::parse::<Item>(item.clone()) {
+        Ok(Item::Fn(func)) if !generated(&func) => func,
+        _ => return item,
+    };
+    
+    let mut user = None;
+    let mut amount = None;
+    let mut env = None;
+    let mut sentinel = None;
+    let parser = syn::meta::parser(|meta| {
+        let slot = if meta.path.is_ident("user") {
+            &mut user
+        } else if meta.path.is_ident("amount") {
+            &mut amount
+        } else if meta.path.is_ident("env") {
+            &mut env
+        } else if meta.path.is_ident("sentinel") {
+            &mut sentinel
+        } else {
+            return Err(meta.error("expected `user`, `amount`, `env` or `sentinel`"));
+        };
+        *slot = Some(meta.value()?.parse::<LitStr>()?);
+        Ok(())
+    });
+    parse_macro_input!(args with parser);
+    
+    match expand(&mut func, user, amount, env, sentinel) {
+        Ok(()) => quote!(#func).into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(
+    func: &mut ItemFn,
+    user: Option<LitStr>,
+    amount: Option<LitStr>,
+    env: Option<LitStr>,
+    sentinel: Option<LitStr>,
+) -> Result<(), Error> {
+    let user = user.ok_or_else(|| Error::new(Span::call_site(), "missing `user = \"<parameter>\"`"))?;
+    let user = parameter(func, &user)?;
+    let env = match env {
+        Some(env) => parameter(func, &env)?,
+        None => parameter(func, &LitStr::new("env", Span::call_site()))?,
+    };
+    let sentinel: Expr = match sentinel {
+        Some(sentinel) => sentinel.parse()?,
+        None => parse_quote!(Self::sentinel_id(&#env)),
+    };
+    
+    let guard = match amount {
+        Some(amount) => {
+            let amount = parameter(func, &amount)?;
+            quote! {
+                ::sentinel_client::SentinelGuard::require_limit(
+                    &#env,
+                    &#sentinel,
+                    &#user,
+                    ::core::convert::Into::<i128>::into(#amount),
+                );
+            }
+        }
+        None => quote! {
+            ::sentinel_client::SentinelGuard::require_allowed(&#env, &#sentinel, &#user);
+        },
+    };
+    func.block.stmts.insert(0, syn::parse2(guard)?);
+    Ok(())
+}
+
+/// Whether `func` is one of the copies `#[contractimpl]` generates: client
+/// methods take `&self`, spec functions are `const`
+fn generated(func: &ItemFn) -> bool {
+    func.sig.constness.is_some() || matches!(func.sig.inputs.first(), Some(FnArg::Receiver(_)))
+}
+
+/// Ident of the function parameter named by `name`
+fn parameter(func: &ItemFn, name: &LitStr) -> Result<Ident, Error> {
+    func.sig
+        .inputs
+        .iter()
+        .find_map(|input| match input {
+            FnArg::Typed(typed) => match &*typed.pat {
+                Pat::Ident(pat) if pat.ident == name.value() => Some(pat.ident.clone()),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .ok_or_else(|| Error::new(name.span(), format!("no parameter named `{}`", name.value())))
+}
@@ -0,0 +1,234 @@
+//! Rust Oracle signer for the Sentinel SDK contract.
+//!
+//! Builds the canonical message with `sentinel-canonical` (the code the
+//! contract verifies against), signs it with Ed25519 and returns the
+//! `RiskPayload` and `Signature` arguments of `submit_risk`, so an Oracle
+//! can run in Rust instead of Python without a second serialization.
+//!
+//! ```ignore
+//! let signer = OracleSigner::from_hex(&std::env::var("ORACLE_PRIVATE_KEY")?)?;
+//! let payload = RiskPayload::new("CSENTINEL...", "GABC...", 87, now)?.with_reason_codes([1, 3]);
+//! let signed = signer.sign(payload)?;
+//!
+//! // Contract arguments, e.g. for an `InvokeHostFunctionOp`
+//! let (payload_arg, signature_arg) = (signed.payload_scval()?, signed.signature_scval()?);
+//! // Or the Python Oracle's `/sign-risk` response shape
+//! let response = signed.to_json(&signer);
+//! ```
+//!
+//! Wallets are strkeys; muxed `M...` accounts are signed for their base
+//! account, the address the contract stores risk under. Scores and
+//! confidence are checked against the contract's ranges before signing,
+//! so the signer never produces a payload the contract would reject as
+//! out of range.
+
+mod payload;
+mod xdr;
+
+use std::fmt;
+
+use ed25519_dalek::{Signer, SigningKey};
+use sentinel_client::AddressError;
+use serde_json::{json, Value};
+use soroban_sdk::xdr::ScVal;
+
+pub use payload::{RiskPayload, PAYLOAD_VERSION_V2};
+pub use sentinel_client::RiskCategory;
+
+/// Why a payload could not be built or signed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignerError {
+    /// Wallet is not a valid address
+    Address(AddressError),
+    /// Wallet is valid but not on Stellar (resolve it with an `AddressBook`)
+    NotStellar(String),
+    /// Deployment address is valid but not a contract
+    NotContract(String),
+    /// Risk or category score is above 100
+    RiskScoreOutOfRange(u32),
+    /// Confidence is above 10000 bps
+    ConfidenceOutOfRange(u32),
+    /// Signing key is not 32 bytes of hex
+    InvalidKey,
+    /// A value does not fit its XDR type
+    Xdr(String),
+}
+
+impl fmt::Display for SignerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignerError::Address(error) => write!(f, "{error}"),
+            SignerError::NotStellar(wallet) => write!(f, "not a Stellar wallet: {wallet}"),
+            SignerError::NotContract(address) => write!(f, "not a contract address: {address}"),
+            SignerError::RiskScoreOutOfRange(score) => write!(f, "score {score} is above 100"),
+            SignerError::ConfidenceOutOfRange(bps) => write!(f, "confidence {bps} is above 10000 bps"),
+            SignerError::InvalidKey => write!(f, "signing key must be 32 bytes of hex"),
+            SignerError::Xdr(error) => write!(f, "XDR encoding failed: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SignerError {}
+
+impl From<AddressError> for SignerError {
+    fn from(error: AddressError) -> Self {
+        SignerError::Address(error)
+    }
+}
+
+/// Ed25519 Oracle key
+pub struct OracleSigner {
+    key: SigningKey,
+}
+
+impl OracleSigner {
+    /// Signer from a 32-byte Ed25519 seed
+    pub fn from_bytes(seed: &[u8; 32]) -> Self {
+        OracleSigner { key: SigningKey::from_bytes(seed) }
+    }
+    
+    /// Signer from a hex seed, the format of the Python Oracle's
+    /// `oracle_private.key` and `ORACLE_PRIVATE_KEY`
+    pub fn from_hex(seed: &str) -> Result<Self, SignerError> {
+        let seed = decode_hex::<32>(seed.trim()).ok_or(SignerError::InvalidKey)?;
+        Ok(OracleSigner::from_bytes(&seed))
+    }
+    
+    /// Public key the contract is initialized with
+    pub fn public_key(&self) -> [u8; 32] {
+        self.key.verifying_key().to_bytes()
+    }
+    
+    /// Public key as lowercase hex (`--oracle_pubkey`)
+    pub fn public_key_hex(&self) -> String {
+        encode_hex(&self.public_key())
+    }
+    
+    /// Sign a payload's canonical message
+    ///
+    /// # Errors
+    /// * `RiskScoreOutOfRange` / `ConfidenceOutOfRange` if the contract
+    ///   would reject the payload's values
+    pub fn sign(&self, payload: RiskPayload) -> Result<SignedPayload, SignerError> {
+        payload.validate()?;
+        let signature = self.key.sign(&payload.message()).to_bytes();
+        Ok(SignedPayload { payload, signature })
+    }
+}
+
+/// A payload and the Oracle signature over its canonical message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedPayload {
+    pub payload: RiskPayload,
+    pub signature: [u8; 64],
+}
+
+impl SignedPayload {
+    /// `payload` argument of `submit_risk`
+    pub fn payload_scval(&self) -> Result<ScVal, SignerError> {
+        xdr::payload(&self.payload)
+    }
+    
+    /// `signature` argument of `submit_risk`
+    pub fn signature_scval(&self) -> Result<ScVal, SignerError> {
+        xdr::bytes(&self.signature)
+    }
+    
+    /// Signature as lowercase hex
+    pub fn signature_hex(&self) -> String {
+        encode_hex(&self.signature)
+    }
+    
+    /// Response in the Python Oracle's `/sign-risk` shape:
+    /// `{"payload": {...}, "signature": "<hex>", "oracle_pubkey": "<hex>"}`
+    ///
+    /// The payload object holds exactly the signed fields, so
+    /// `canonical_json(response["payload"])` in Python rebuilds the message.
+    pub fn to_json(&self, signer: &OracleSigner) -> Value {
+        json!({
+            "payload": self.payload.to_json(),
+            "signature": self.signature_hex(),
+            "oracle_pubkey": signer.public_key_hex(),
+        })
+    }
+}
+
+/// Lowercase hex of `bytes`
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    let mut out = Vec::new();
+    sentinel_canonical::write_hex(&mut out, bytes);
+    String::from_utf8(out).expect("hex is ASCII")
+}
+
+/// Exactly `N` bytes from hex (either case)
+pub(crate) fn decode_hex<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    if hex.len() != 2 * N || !hex.is_ascii() {
+        return None;
+    }
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).ok()?;
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sentinel_verify::{verify, RiskDecision, DEFAULT_MAX_AGE};
+    use soroban_sdk::testutils::Ledger;
+    use soroban_sdk::{Address, BytesN, Env, String as SorobanString, TryFromVal, Val};
+    
+    const CONTRACT: &str = "CDG43TONZXG43TONZXG43TONZXG43TONZXG43TONZXG43TONZXG42EVB";
+    const WALLET: &str = "GAIH3ULLFQ4DGSECF2AR555KZ4KNDGEKN4AFI4SU2M7B43MGK3QJZNSR";
+    
+    #[test]
+    fn test_signed_payload_verifies_in_contract_code() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1737718800);
+        let signer = OracleSigner::from_hex(&"07".repeat(32)).unwrap();
+        
+        let payload = RiskPayload::new(CONTRACT, WALLET, 60, 1737718800)
+            .unwrap()
+            .with_reason_codes([1, 3])
+            .with_category_score(RiskCategory::Sanctions, 10)
+            .with_category_score(RiskCategory::Aml, 30)
+            .with_freeze_duration(3600)
+            .with_provenance(7, 9500)
+            .with_evidence_hash([0xab; 32]);
+        let signed = signer.sign(payload).unwrap();
+        
+        // Decode the arguments the way the host does for `submit_risk`
+        let val = Val::try_from_val(&env, &signed.payload_scval().unwrap()).unwrap();
+        let contract_payload = sentinel_verify::RiskPayload::try_from_val(&env, &val).unwrap();
+        let val = Val::try_from_val(&env, &signed.signature_scval().unwrap()).unwrap();
+        let signature = BytesN::<64>::try_from_val(&env, &val).unwrap();
+        
+        let oracle_key = BytesN::from_array(&env, &signer.public_key());
+        let sentinel = Address::from_string(&SorobanString::from_str(&env, CONTRACT));
+        let decision = verify(
+            &env,
+            &contract_payload.wallet,
+            &contract_payload,
+            &signature,
+            &oracle_key,
+            &sentinel,
+            DEFAULT_MAX_AGE,
+        );
+        assert_eq!(decision, Ok(RiskDecision::Limit(5000)));
+        assert_eq!(contract_payload.category_scores.len(), 2);
+    }
+    
+    #[test]
+    fn test_sign_rejects_out_of_range_values() {
+        let signer = OracleSigner::from_bytes(&[7; 32]);
+        
+        let payload = RiskPayload::new(CONTRACT, WALLET, 40, 5).unwrap().with_category_score(RiskCategory::Fraud, 101);
+        assert_eq!(signer.sign(payload), Err(SignerError::RiskScoreOutOfRange(101)));
+        
+        let payload = RiskPayload::new(CONTRACT, WALLET, 40, 5).unwrap().with_provenance(1, 10_001);
+        assert_eq!(signer.sign(payload), Err(SignerError::ConfidenceOutOfRange(10_001)));
+        
+        assert!(OracleSigner::from_hex("07").is_err());
+    }
+}
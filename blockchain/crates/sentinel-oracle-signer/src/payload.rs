@@ -0,0 +1,248 @@
+//! Risk payloads as the Oracle signs them.
+
+use sentinel_canonical::{write_payload_json, OptionalFields, DOMAIN_TAG};
+use sentinel_client::{AnyAddress, RiskCategory};
+use serde_json::{Map, Value};
+
+use crate::{encode_hex, SignerError};
+
+/// Payload schema the contract verifies: canonical JSON with the signed
+/// `version` and deployment `domain`
+pub const PAYLOAD_VERSION_V2: u32 = 2;
+
+/// Every category, in canonical JSON key order
+pub(crate) const CATEGORIES: [RiskCategory; 4] = [
+    RiskCategory::Aml,
+    RiskCategory::BotActivity,
+    RiskCategory::Fraud,
+    RiskCategory::Sanctions,
+];
+
+/// Risk payload, field for field the contract's `RiskPayload`, plus the
+/// deployment it is signed for
+///
+/// Optional fields default to "not reported" (0, empty or `None`) and are
+/// left out of the signed message, like in the Python Oracle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskPayload {
+    /// Sentinel contract (`C...`) the payload is signed for; signed as the
+    /// `domain`, not passed to the contract
+    pub contract: String,
+    /// Payload schema version (selects the signing format)
+    pub version: u32,
+    /// Wallet strkey (`G...` or `C...`) the contract stores the score under
+    pub wallet: String,
+    /// Risk score from 0-100
+    pub risk_score: u32,
+    /// Unix timestamp of the signature, in seconds
+    pub timestamp: u64,
+    /// Reason codes behind the score
+    pub reason_codes: Vec<u32>,
+    /// Seconds a Freeze lasts before it is released (0 = indefinite)
+    pub freeze_duration: u64,
+    /// Per-category scores from 0-100, at most one per category
+    pub category_scores: Vec<(RiskCategory, u32)>,
+    /// Release of the ML model that produced the score (0 = not reported)
+    pub model_version: u32,
+    /// Model confidence in basis points, 0-10000 (0 = not reported)
+    pub confidence: u32,
+    /// Digest of the off-chain evidence report
+    pub evidence_hash: Option<[u8; 32]>,
+}
+
+impl RiskPayload {
+    /// Payload for `wallet` with no optional fields, signed for the
+    /// Sentinel deployment at `contract`
+    ///
+    /// # Errors
+    /// * `Address` if `contract` or `wallet` is not a valid address
+    /// * `NotContract` if `contract` is not a `C...` contract
+    /// * `NotStellar` for wallets on other chains
+    pub fn new(contract: &str, wallet: &str, risk_score: u32, timestamp: u64) -> Result<Self, SignerError> {
+        let contract = match AnyAddress::parse(contract)? {
+            address @ AnyAddress::Contract(_) => address.to_string(),
+            address => return Err(SignerError::NotContract(address.to_string())),
+        };
+        let address = AnyAddress::parse(wallet)?;
+        let wallet = address.stellar_wallet().ok_or_else(|| SignerError::NotStellar(address.to_string()))?;
+        Ok(RiskPayload {
+            contract,
+            version: PAYLOAD_VERSION_V2,
+            wallet,
+            risk_score,
+            timestamp,
+            reason_codes: Vec::new(),
+            freeze_duration: 0,
+            category_scores: Vec::new(),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: None,
+        })
+    }
+    
+    /// Set the reason codes
+    pub fn with_reason_codes(mut self, codes: impl IntoIterator<Item = u32>) -> Self {
+        self.reason_codes = codes.into_iter().collect();
+        self
+    }
+    
+    /// Set how long a Freeze lasts (seconds)
+    pub fn with_freeze_duration(mut self, seconds: u64) -> Self {
+        self.freeze_duration = seconds;
+        self
+    }
+    
+    /// Set one category's score, replacing an earlier one
+    pub fn with_category_score(mut self, category: RiskCategory, score: u32) -> Self {
+        self.category_scores.retain(|(existing, _)| *existing != category);
+        self.category_scores.push((category, score));
+        self
+    }
+    
+    /// Set the model release and its confidence (basis points)
+    pub fn with_provenance(mut self, model_version: u32, confidence: u32) -> Self {
+        self.model_version = model_version;
+        self.confidence = confidence;
+        self
+    }
+    
+    /// Set the evidence report digest
+    pub fn with_evidence_hash(mut self, hash: [u8; 32]) -> Self {
+        self.evidence_hash = Some(hash);
+        self
+    }
+    
+    /// Canonical message the Oracle signs, byte for byte what the
+    /// contract rebuilds
+    pub fn message(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_payload_json(
+            &mut out,
+            self.categories().map(|(category, score)| (json_key(category).as_bytes(), score)),
+            self.contract.as_bytes(),
+            OptionalFields {
+                confidence: self.confidence,
+                evidence_hash: self.evidence_hash.filter(|hash| *hash != [0; 32]),
+                freeze_duration: self.freeze_duration,
+                model_version: self.model_version,
+            },
+            self.reason_codes.iter().copied(),
+            self.risk_score,
+            self.timestamp,
+            self.version,
+            self.wallet.as_bytes(),
+        );
+        out
+    }
+    
+    /// The signed fields as a JSON object (the Python Oracle's `payload`)
+    pub fn to_json(&self) -> Value {
+        let mut object = Map::new();
+        let categories: Map<String, Value> =
+            self.categories().map(|(category, score)| (json_key(category).into(), score.into())).collect();
+        if !categories.is_empty() {
+            object.insert("category_scores".into(), categories.into());
+        }
+        if self.confidence != 0 {
+            object.insert("confidence".into(), self.confidence.into());
+        }
+        object.insert("domain".into(), format!("{}:{}", std::str::from_utf8(DOMAIN_TAG).expect("ASCII"), self.contract).into());
+        if let Some(hash) = self.evidence_hash.filter(|hash| *hash != [0; 32]) {
+            object.insert("evidence_hash".into(), encode_hex(&hash).into());
+        }
+        if self.freeze_duration != 0 {
+            object.insert("freeze_duration".into(), self.freeze_duration.into());
+        }
+        if self.model_version != 0 {
+            object.insert("model_version".into(), self.model_version.into());
+        }
+        if !self.reason_codes.is_empty() {
+            object.insert("reason_codes".into(), self.reason_codes.clone().into());
+        }
+        object.insert("risk_score".into(), self.risk_score.into());
+        object.insert("timestamp".into(), self.timestamp.into());
+        object.insert("version".into(), self.version.into());
+        object.insert("wallet".into(), self.wallet.clone().into());
+        object.into()
+    }
+    
+    /// Category scores in canonical order
+    pub(crate) fn categories(&self) -> impl Iterator<Item = (RiskCategory, u32)> + '_ {
+        CATEGORIES.into_iter().filter_map(|category| {
+            self.category_scores
+                .iter()
+                .find(|(existing, _)| *existing == category)
+                .map(|(_, score)| (category, *score))
+        })
+    }
+    
+    /// Check the ranges the contract enforces
+    pub(crate) fn validate(&self) -> Result<(), SignerError> {
+        let worst = self.category_scores.iter().map(|(_, score)| *score).fold(self.risk_score, u32::max);
+        if worst > 100 {
+            return Err(SignerError::RiskScoreOutOfRange(worst));
+        }
+        if self.confidence > 10_000 {
+            return Err(SignerError::ConfidenceOutOfRange(self.confidence));
+        }
+        Ok(())
+    }
+}
+
+/// Key of a category in the canonical JSON
+pub(crate) fn json_key(category: RiskCategory) -> &'static str {
+    match category {
+        RiskCategory::Aml => "aml",
+        RiskCategory::BotActivity => "bot_activity",
+        RiskCategory::Fraud => "fraud",
+        RiskCategory::Sanctions => "sanctions",
+    }
+}
+
+/// Contract-side name of a category (`RiskCategory` variant)
+pub(crate) fn variant_name(category: RiskCategory) -> &'static str {
+    match category {
+        RiskCategory::Aml => "Aml",
+        RiskCategory::BotActivity => "BotActivity",
+        RiskCategory::Fraud => "Fraud",
+        RiskCategory::Sanctions => "Sanctions",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const CONTRACT: &str = "CDG43TONZXG43TONZXG43TONZXG43TONZXG43TONZXG43TONZXG42EVB";
+    const WALLET: &str = "GAIH3ULLFQ4DGSECF2AR555KZ4KNDGEKN4AFI4SU2M7B43MGK3QJZNSR";
+    
+    #[test]
+    fn test_message_matches_python_layout() {
+        let payload = RiskPayload::new(CONTRACT, WALLET, 90, 1737718800)
+            .unwrap()
+            .with_category_score(RiskCategory::Fraud, 90)
+            .with_category_score(RiskCategory::Aml, 30)
+            .with_reason_codes([1, 3]);
+        
+        let expected = format!(
+            "{{\"category_scores\":{{\"aml\":30,\"fraud\":90}},\"domain\":\"sentinel-risk:{CONTRACT}\",\"reason_codes\":[1,3],\"risk_score\":90,\"timestamp\":1737718800,\"version\":2,\"wallet\":\"{WALLET}\"}}"
+        );
+        assert_eq!(String::from_utf8(payload.message()).unwrap(), expected);
+        // serde_json sorts keys and prints compactly, like `canonical_json`
+        assert_eq!(payload.to_json().to_string(), expected);
+    }
+    
+    #[test]
+    fn test_wallet_normalization() {
+        let muxed = "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUAAAAAAAAAAAAGZFQ";
+        let payload = RiskPayload::new(CONTRACT, muxed, 10, 5).unwrap();
+        assert!(payload.wallet.starts_with('G'));
+        
+        assert!(matches!(
+            RiskPayload::new(CONTRACT, "0xAb5801a7D398351b8bE11C439e05C5B3259aeC9B", 10, 5),
+            Err(SignerError::NotStellar(_))
+        ));
+        assert!(matches!(RiskPayload::new(CONTRACT, "GABC", 10, 5), Err(SignerError::Address(_))));
+        assert!(matches!(RiskPayload::new(WALLET, WALLET, 10, 5), Err(SignerError::NotContract(_))));
+    }
+}
@@ -0,0 +1,71 @@
+//! `ScVal` encoding of the `submit_risk` arguments.
+//!
+//! Built directly from XDR types, without a Soroban host, in the layout
+//! `#[contracttype]` gives the contract's types: structs as maps keyed by
+//! field name in sorted order, unit enum variants as a one-symbol vector.
+
+use sentinel_client::AnyAddress;
+use soroban_sdk::xdr::{
+    AccountId, Hash, PublicKey, ScAddress, ScMap, ScMapEntry, ScSymbol, ScVal, ScVec, Uint256,
+};
+
+use crate::payload::{variant_name, RiskPayload};
+use crate::SignerError;
+
+/// `RiskPayload` as a contract argument
+pub(crate) fn payload(payload: &RiskPayload) -> Result<ScVal, SignerError> {
+    let reason_codes = payload.reason_codes.iter().map(|code| ScVal::U32(*code)).collect();
+    let category_scores = payload
+        .categories()
+        .map(|(category, score)| Ok((vec(vec![symbol(variant_name(category))?])?, ScVal::U32(score))))
+        .collect::<Result<_, SignerError>>()?;
+    
+    // Field names in sorted order, as the contract's map layout requires
+    map(vec![
+        (symbol("category_scores")?, map(category_scores)?),
+        (symbol("confidence")?, ScVal::U32(payload.confidence)),
+        (symbol("evidence_hash")?, bytes(&payload.evidence_hash.unwrap_or([0; 32]))?),
+        (symbol("freeze_duration")?, ScVal::U64(payload.freeze_duration)),
+        (symbol("model_version")?, ScVal::U32(payload.model_version)),
+        (symbol("reason_codes")?, vec(reason_codes)?),
+        (symbol("risk_score")?, ScVal::U32(payload.risk_score)),
+        (symbol("timestamp")?, ScVal::U64(payload.timestamp)),
+        (symbol("version")?, ScVal::U32(payload.version)),
+        (symbol("wallet")?, address(&payload.wallet)?),
+    ])
+}
+
+/// `Bytes` / `BytesN` value
+pub(crate) fn bytes(value: &[u8]) -> Result<ScVal, SignerError> {
+    Ok(ScVal::Bytes(value.to_vec().try_into().map_err(xdr_error)?))
+}
+
+/// `Address` value of a `G...` / `C...` strkey
+fn address(wallet: &str) -> Result<ScVal, SignerError> {
+    let address = match AnyAddress::parse(wallet)? {
+        AnyAddress::Account(key) => {
+            ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key))))
+        }
+        AnyAddress::Contract(key) => ScAddress::Contract(Hash(key)),
+        other => return Err(SignerError::NotStellar(other.to_string())),
+    };
+    Ok(ScVal::Address(address))
+}
+
+fn symbol(name: &str) -> Result<ScVal, SignerError> {
+    Ok(ScVal::Symbol(ScSymbol(name.try_into().map_err(xdr_error)?)))
+}
+
+fn vec(items: Vec<ScVal>) -> Result<ScVal, SignerError> {
+    Ok(ScVal::Vec(Some(ScVec(items.try_into().map_err(xdr_error)?))))
+}
+
+/// Map value; `entries` must be in ascending key order
+fn map(entries: Vec<(ScVal, ScVal)>) -> Result<ScVal, SignerError> {
+    let entries: Vec<ScMapEntry> = entries.into_iter().map(|(key, val)| ScMapEntry { key, val }).collect();
+    Ok(ScVal::Map(Some(ScMap(entries.try_into().map_err(xdr_error)?))))
+}
+
+fn xdr_error(error: soroban_sdk::xdr::Error) -> SignerError {
+    SignerError::Xdr(error.to_string())
+}
@@ -0,0 +1,178 @@
+//! Contract return values (`ScVal`) as plain Rust types.
+
+use sentinel_client::RiskCategory;
+use sentinel_events::RiskDecision;
+use soroban_sdk::xdr::{ScMap, ScVal};
+
+use crate::RpcError;
+
+/// Risk state of a wallet, as returned by `get_risk`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskState {
+    /// Risk score from 0-100
+    pub risk_score: u32,
+    /// Unix timestamp of last update
+    pub last_updated: u64,
+    /// Computed decision based on risk score
+    pub decision: RiskDecision,
+    /// Oracle reason codes explaining the score
+    pub reason_codes: Vec<u32>,
+    /// Unix timestamp when a quarantine freeze lapses (0 = indefinite)
+    pub freeze_until: u64,
+    /// Per-category scores from 0-100, in category order
+    pub category_scores: Vec<(RiskCategory, u32)>,
+    /// Release of the ML model that produced the score (0 = not reported)
+    pub model_version: u32,
+    /// Model confidence in basis points, 0-10000 (0 = not reported)
+    pub confidence: u32,
+    /// Digest of the off-chain evidence report (all zeros if none)
+    pub evidence_hash: [u8; 32],
+}
+
+/// `Option<RiskState>`
+pub(crate) fn risk_state(value: &ScVal) -> Result<Option<RiskState>, RpcError> {
+    let map = match value {
+        ScVal::Void => return Ok(None),
+        ScVal::Map(Some(map)) => map,
+        _ => return Err(schema("RiskState")),
+    };
+    
+    let reason_codes = items(field(map, "reason_codes")?)
+        .ok_or_else(|| schema("reason_codes"))?
+        .iter()
+        .map(|code| match code {
+            ScVal::U32(code) => Ok(*code),
+            _ => Err(schema("reason_codes")),
+        })
+        .collect::<Result<_, _>>()?;
+    
+    let category_scores = match field(map, "category_scores")? {
+        ScVal::Map(Some(scores)) => scores
+            .iter()
+            .map(|entry| match (category(&entry.key), &entry.val) {
+                (Some(category), ScVal::U32(score)) => Ok((category, *score)),
+                _ => Err(schema("category_scores")),
+            })
+            .collect::<Result<_, _>>()?,
+        _ => return Err(schema("category_scores")),
+    };
+    
+    let evidence_hash = match field(map, "evidence_hash")? {
+        ScVal::Bytes(bytes) => bytes.as_slice().try_into().map_err(|_| schema("evidence_hash"))?,
+        _ => return Err(schema("evidence_hash")),
+    };
+    
+    Ok(Some(RiskState {
+        risk_score: u32_field(map, "risk_score")?,
+        last_updated: u64_field(map, "last_updated")?,
+        decision: decision(field(map, "decision")?)?,
+        reason_codes,
+        freeze_until: u64_field(map, "freeze_until")?,
+        category_scores,
+        model_version: u32_field(map, "model_version")?,
+        confidence: u32_field(map, "confidence")?,
+        evidence_hash,
+    }))
+}
+
+/// `RiskDecision` as encoded by `contracttype`: a vector of the variant
+/// symbol and its fields
+pub(crate) fn decision(value: &ScVal) -> Result<RiskDecision, RpcError> {
+    match items(value).ok_or_else(|| schema("RiskDecision"))? {
+        [tag] => match symbol(tag) {
+            Some("Allow") => Ok(RiskDecision::Allow),
+            Some("Review") => Ok(RiskDecision::Review),
+            Some("Freeze") => Ok(RiskDecision::Freeze),
+            _ => Err(schema("RiskDecision")),
+        },
+        [tag, ScVal::U32(limit)] if symbol(tag) == Some("Limit") => Ok(RiskDecision::Limit(*limit)),
+        _ => Err(schema("RiskDecision")),
+    }
+}
+
+fn category(value: &ScVal) -> Option<RiskCategory> {
+    match items(value)? {
+        [tag] => match symbol(tag)? {
+            "Aml" => Some(RiskCategory::Aml),
+            "BotActivity" => Some(RiskCategory::BotActivity),
+            "Fraud" => Some(RiskCategory::Fraud),
+            "Sanctions" => Some(RiskCategory::Sanctions),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn symbol(value: &ScVal) -> Option<&str> {
+    match value {
+        ScVal::Symbol(symbol) => std::str::from_utf8(symbol.0.as_vec()).ok(),
+        _ => None,
+    }
+}
+
+fn items(value: &ScVal) -> Option<&[ScVal]> {
+    match value {
+        ScVal::Vec(Some(items)) => Some(items.as_slice()),
+        _ => None,
+    }
+}
+
+fn field<'a>(map: &'a ScMap, name: &str) -> Result<&'a ScVal, RpcError> {
+    map.iter()
+        .find(|entry| symbol(&entry.key) == Some(name))
+        .map(|entry| &entry.val)
+        .ok_or_else(|| schema(name))
+}
+
+fn u32_field(map: &ScMap, name: &str) -> Result<u32, RpcError> {
+    match field(map, name)? {
+        ScVal::U32(value) => Ok(*value),
+        _ => Err(schema(name)),
+    }
+}
+
+fn u64_field(map: &ScMap, name: &str) -> Result<u64, RpcError> {
+    match field(map, name)? {
+        ScVal::U64(value) => Ok(*value),
+        _ => Err(schema(name)),
+    }
+}
+
+fn schema(what: &str) -> RpcError {
+    RpcError::Response(format!("unexpected contract value for {what}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{map, vec, BytesN, Env, IntoVal, TryFromVal, Val};
+    
+    fn to_scval<T: IntoVal<Env, Val>>(env: &Env, value: T) -> ScVal {
+        ScVal::try_from_val(env, &value.into_val(env)).unwrap()
+    }
+    
+    #[test]
+    fn test_decodes_contract_risk_state() {
+        let env = Env::default();
+        let state = sentinel_client::RiskState {
+            risk_score: 65,
+            last_updated: 1737718800,
+            decision: RiskDecision::Limit(5000),
+            reason_codes: vec![&env, 1, 3],
+            freeze_until: 0,
+            category_scores: map![&env, (RiskCategory::Fraud, 65), (RiskCategory::Aml, 20)],
+            model_version: 7,
+            confidence: 9500,
+            evidence_hash: BytesN::from_array(&env, &[0xab; 32]),
+        };
+        
+        let decoded = risk_state(&to_scval(&env, state)).unwrap().unwrap();
+        assert_eq!(decoded.decision, RiskDecision::Limit(5000));
+        assert_eq!(decoded.reason_codes, [1, 3]);
+        assert_eq!(decoded.category_scores, [(RiskCategory::Aml, 20), (RiskCategory::Fraud, 65)]);
+        assert_eq!(decoded.evidence_hash, [0xab; 32]);
+        assert_eq!(risk_state(&ScVal::Void).unwrap(), None);
+        
+        assert_eq!(decision(&to_scval(&env, RiskDecision::Freeze)).unwrap(), RiskDecision::Freeze);
+    }
+}
@@ -0,0 +1,190 @@
+//! JSON-RPC transport to a Soroban RPC server, with retry and backoff.
+
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::RpcError;
+
+/// How transient failures are retried
+///
+/// Network errors, HTTP 429 / 5xx and `TRY_AGAIN_LATER` from
+/// `sendTransaction` are retried with exponential backoff; JSON-RPC errors
+/// and contract failures are not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts per request, including the first
+    pub max_attempts: u32,
+    /// Wait before the first retry; doubles on every further retry
+    pub initial_backoff: Duration,
+    /// Longest wait between two attempts
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait after failed attempt number `attempt` (0-based)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff.saturating_mul(1 << attempt.min(16)).min(self.max_backoff)
+    }
+}
+
+/// Result of `simulateTransaction`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SimulateResponse {
+    /// Why the invocation failed, e.g. `HostError: Error(Contract, #7)`
+    pub error: Option<String>,
+    /// Base64 `SorobanTransactionData` to attach to the transaction
+    pub transaction_data: Option<String>,
+    /// Resource fee the transaction needs, in stroops
+    pub min_resource_fee: Option<String>,
+    #[serde(default)]
+    pub results: Vec<SimulateResult>,
+    /// Present when archived entries must be restored first
+    pub restore_preamble: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SimulateResult {
+    /// Base64 `SorobanAuthorizationEntry`s the invocation requires
+    #[serde(default)]
+    pub auth: Vec<String>,
+    /// Base64 `ScVal` the function returned
+    pub xdr: String,
+}
+
+/// Result of `sendTransaction`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SendResponse {
+    /// `PENDING`, `DUPLICATE`, `TRY_AGAIN_LATER` or `ERROR`
+    pub status: String,
+    pub hash: String,
+    /// Base64 `TransactionResult` when `status` is `ERROR`
+    pub error_result_xdr: Option<String>,
+}
+
+/// Result of `getTransaction`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetTransactionResponse {
+    /// `SUCCESS`, `FAILED` or `NOT_FOUND`
+    pub status: String,
+    pub ledger: Option<u32>,
+    /// Base64 `TransactionResult`
+    pub result_xdr: Option<String>,
+}
+
+/// Result of `getLedgerEntries`
+#[derive(Debug, Deserialize)]
+pub(crate) struct LedgerEntriesResponse {
+    #[serde(default)]
+    pub entries: Option<Vec<LedgerEntryResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LedgerEntryResult {
+    /// Base64 `LedgerEntryData`
+    pub xdr: String,
+}
+
+/// Soroban RPC endpoint
+pub(crate) struct RpcClient {
+    http: reqwest::Client,
+    url: String,
+    pub retry: RetryPolicy,
+}
+
+impl RpcClient {
+    pub fn new(url: &str) -> Self {
+        RpcClient {
+            http: reqwest::Client::new(),
+            url: url.to_string(),
+            retry: RetryPolicy::default(),
+        }
+    }
+    
+    pub async fn simulate_transaction(&self, envelope: String) -> Result<SimulateResponse, RpcError> {
+        self.call("simulateTransaction", json!({ "transaction": envelope })).await
+    }
+    
+    /// Submit a signed envelope, retrying while the server asks to
+    pub async fn send_transaction(&self, envelope: String) -> Result<SendResponse, RpcError> {
+        let mut attempt = 0;
+        loop {
+            let response: SendResponse = self.call("sendTransaction", json!({ "transaction": envelope })).await?;
+            if response.status != "TRY_AGAIN_LATER" || attempt + 1 >= self.retry.max_attempts {
+                return Ok(response);
+            }
+            tokio::time::sleep(self.retry.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+    
+    pub async fn get_transaction(&self, hash: &str) -> Result<GetTransactionResponse, RpcError> {
+        self.call("getTransaction", json!({ "hash": hash })).await
+    }
+    
+    pub async fn get_ledger_entries(&self, keys: Vec<String>) -> Result<LedgerEntriesResponse, RpcError> {
+        self.call("getLedgerEntries", json!({ "keys": keys })).await
+    }
+    
+    /// One JSON-RPC call, retrying transient transport failures
+    async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, RpcError> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        
+        let mut attempt = 0;
+        let body: Value = loop {
+            let error = match self.http.post(&self.url).json(&request).send().await {
+                Ok(response) if response.status().is_success() => match response.json().await {
+                    Ok(body) => break body,
+                    Err(error) => RpcError::Http(error.to_string()),
+                },
+                Ok(response) if response.status().is_server_error() || response.status().as_u16() == 429 => {
+                    RpcError::Http(format!("{method}: HTTP {}", response.status()))
+                }
+                Ok(response) => return Err(RpcError::Http(format!("{method}: HTTP {}", response.status()))),
+                Err(error) => RpcError::Http(error.to_string()),
+            };
+            if attempt + 1 >= self.retry.max_attempts {
+                return Err(error);
+            }
+            tokio::time::sleep(self.retry.backoff(attempt)).await;
+            attempt += 1;
+        };
+        
+        if let Some(error) = body.get("error") {
+            return Err(RpcError::Rpc {
+                code: error["code"].as_i64().unwrap_or_default(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+        serde_json::from_value(body["result"].clone()).map_err(|error| RpcError::Response(format!("{method}: {error}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::from_millis(250));
+        assert_eq!(policy.backoff(2), Duration::from_secs(1));
+        assert_eq!(policy.backoff(10), Duration::from_secs(5));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(5));
+    }
+}
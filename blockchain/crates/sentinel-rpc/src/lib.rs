@@ -0,0 +1,322 @@
+//! Async Soroban RPC client for the Sentinel SDK contract.
+//!
+//! For backend services (exchanges, bots, indexers) that read Sentinel
+//! decisions or submit Oracle scores without writing XDR plumbing:
+//!
+//! ```ignore
+//! let sentinel = SentinelRpc::new(
+//!     "https://soroban-testnet.stellar.org",
+//!     "CDSENTINEL...",
+//!     "Test SDF Network ; September 2015",
+//! )?;
+//!
+//! // Reads are simulations: no account, no fee
+//! let decision = sentinel.check_permission("GABC...").await?;
+//! let state = sentinel.get_risk("GABC...").await?;
+//!
+//! // Writes build, simulate, sign, send and wait for the ledger
+//! let signed = oracle.sign(RiskPayload::new("CDSENTINEL...", "GABC...", 87, now)?)?;
+//! let tx = sentinel.submit_risk(&signed, &source_key).await?;
+//! ```
+//!
+//! Transient failures (network errors, HTTP 429 / 5xx, `TRY_AGAIN_LATER`)
+//! are retried with exponential backoff (see `RetryPolicy`). A submission
+//! that loses a sequence-number race is rebuilt once with a fresh
+//! sequence. Contract errors are never retried; they surface as
+//! `Simulation` with the host's message (e.g. `Error(Contract, #7)`).
+
+mod decode;
+mod jsonrpc;
+mod tx;
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use ed25519_dalek::SigningKey;
+use sentinel_client::AddressError;
+use soroban_sdk::xdr::{
+    Hash, LedgerEntryData, Limits, ReadXdr, ScVal, TransactionEnvelope, TransactionResult,
+    TransactionResultResult, WriteXdr,
+};
+
+use jsonrpc::RpcClient;
+
+pub use decode::RiskState;
+pub use jsonrpc::RetryPolicy;
+pub use sentinel_client::RiskCategory;
+pub use sentinel_events::RiskDecision;
+pub use sentinel_oracle_signer::SignedPayload;
+
+/// Inclusion fee bid on top of the resource fee (stroops), the network
+/// minimum
+pub const DEFAULT_INCLUSION_FEE: u32 = 100;
+
+/// How long `submit_risk` waits for a sent transaction to land
+pub const DEFAULT_CONFIRM_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Interval between `getTransaction` polls
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Why an RPC operation failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RpcError {
+    /// Transport failure or unexpected HTTP status (after retries)
+    Http(String),
+    /// JSON-RPC error returned by the server
+    Rpc { code: i64, message: String },
+    /// Response did not have the expected shape
+    Response(String),
+    /// XDR encoding or decoding failed
+    Xdr(String),
+    /// Wallet or contract id is not a valid address
+    Address(AddressError),
+    /// Wallet is valid but not on Stellar
+    NotStellar(String),
+    /// Contract id is not a `C...` strkey
+    NotAContract(String),
+    /// Source account does not exist on the network
+    AccountNotFound(String),
+    /// Invocation failed in simulation (contract errors land here)
+    Simulation(String),
+    /// The invocation needs authorization from an address other than the
+    /// source account (e.g. an Oracle in Soroban-auth mode)
+    AuthRequired,
+    /// Total fee is above the configured maximum
+    FeeTooHigh { required: u64, max: u32 },
+    /// Network refused the transaction (`sendTransaction` status `ERROR`)
+    Rejected { hash: String, result: String },
+    /// Transaction was included but failed
+    Failed { hash: String, result: String },
+    /// Transaction did not land within the confirm timeout; it may still
+    Timeout { hash: String },
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcError::Http(error) => write!(f, "RPC transport error: {error}"),
+            RpcError::Rpc { code, message } => write!(f, "RPC error {code}: {message}"),
+            RpcError::Response(error) => write!(f, "unexpected RPC response: {error}"),
+            RpcError::Xdr(error) => write!(f, "XDR error: {error}"),
+            RpcError::Address(error) => write!(f, "{error}"),
+            RpcError::NotStellar(wallet) => write!(f, "not a Stellar wallet: {wallet}"),
+            RpcError::NotAContract(id) => write!(f, "not a contract id: {id}"),
+            RpcError::AccountNotFound(account) => write!(f, "source account {account} does not exist"),
+            RpcError::Simulation(error) => write!(f, "simulation failed: {error}"),
+            RpcError::AuthRequired => write!(f, "invocation requires authorization beyond the source account"),
+            RpcError::FeeTooHigh { required, max } => write!(f, "fee {required} is above the maximum {max}"),
+            RpcError::Rejected { hash, result } => write!(f, "transaction {hash} rejected: {result}"),
+            RpcError::Failed { hash, result } => write!(f, "transaction {hash} failed: {result}"),
+            RpcError::Timeout { hash } => write!(f, "transaction {hash} not confirmed in time"),
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+impl From<AddressError> for RpcError {
+    fn from(error: AddressError) -> Self {
+        RpcError::Address(error)
+    }
+}
+
+/// A `submit_risk` transaction that landed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmittedTx {
+    /// Transaction hash (hex)
+    pub hash: String,
+    /// Ledger the transaction was included in
+    pub ledger: u32,
+    /// Fee charged, in stroops
+    pub fee_charged: i64,
+}
+
+/// Sentinel SDK contract on one network, through one RPC server
+pub struct SentinelRpc {
+    rpc: RpcClient,
+    contract: Hash,
+    network_id: [u8; 32],
+    inclusion_fee: u32,
+    max_fee: Option<u32>,
+    confirm_timeout: Duration,
+}
+
+impl SentinelRpc {
+    /// Client for the contract `contract_id` (`C...`) on the network with
+    /// `network_passphrase`
+    pub fn new(rpc_url: &str, contract_id: &str, network_passphrase: &str) -> Result<Self, RpcError> {
+        Ok(SentinelRpc {
+            rpc: RpcClient::new(rpc_url),
+            contract: tx::contract_hash(contract_id)?,
+            network_id: tx::network_id(network_passphrase),
+            inclusion_fee: DEFAULT_INCLUSION_FEE,
+            max_fee: None,
+            confirm_timeout: DEFAULT_CONFIRM_TIMEOUT,
+        })
+    }
+    
+    /// Set how transient failures are retried
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.rpc.retry = policy;
+        self
+    }
+    
+    /// Set the inclusion fee bid (stroops); raise it when the network is
+    /// congested
+    pub fn with_inclusion_fee(mut self, stroops: u32) -> Self {
+        self.inclusion_fee = stroops;
+        self
+    }
+    
+    /// Refuse to send transactions whose total fee is above `stroops`
+    pub fn with_max_fee(mut self, stroops: u32) -> Self {
+        self.max_fee = Some(stroops);
+        self
+    }
+    
+    /// Set how long `submit_risk` waits for inclusion
+    pub fn with_confirm_timeout(mut self, timeout: Duration) -> Self {
+        self.confirm_timeout = timeout;
+        self
+    }
+    
+    /// Stored risk state of a wallet (`None` if it was never scored)
+    pub async fn get_risk(&self, wallet: &str) -> Result<Option<RiskState>, RpcError> {
+        let value = self.read("get_risk", vec![tx::address(wallet)?]).await?;
+        decode::risk_state(&value)
+    }
+    
+    /// Decision for a wallet (`Allow` if it was never scored)
+    pub async fn check_permission(&self, wallet: &str) -> Result<RiskDecision, RpcError> {
+        let value = self.read("check_permission", vec![tx::address(wallet)?]).await?;
+        decode::decision(&value)
+    }
+    
+    /// Submit an Oracle-signed score and wait until it is in a ledger
+    ///
+    /// `source` pays the fee and its sequence number is used; it need not
+    /// be the Oracle key (the payload carries the Oracle signature).
+    ///
+    /// # Errors
+    /// * `Simulation` if the contract rejects the payload (bad signature,
+    ///   stale timestamp, ...)
+    /// * `FeeTooHigh` if the fee is above `with_max_fee`
+    /// * `Rejected` / `Failed` / `Timeout` for the transaction outcome
+    pub async fn submit_risk(&self, signed: &SignedPayload, source: &SigningKey) -> Result<SubmittedTx, RpcError> {
+        let payload = signed.payload_scval().map_err(|error| RpcError::Xdr(error.to_string()))?;
+        let signature = signed.signature_scval().map_err(|error| RpcError::Xdr(error.to_string()))?;
+        
+        let mut resequenced = false;
+        loop {
+            let envelope = self.prepare("submit_risk", vec![payload.clone(), signature.clone()], source).await?;
+            let sent = self.rpc.send_transaction(envelope.to_xdr_base64(Limits::none()).map_err(tx::xdr_error)?).await?;
+            let hash = sent.hash;
+            match sent.status.as_str() {
+                "PENDING" | "DUPLICATE" => return self.confirm(&hash).await,
+                "ERROR" => {
+                    let result = sent.error_result_xdr.as_deref().map(transaction_result).transpose()?;
+                    if !resequenced && matches!(result, Some(TransactionResultResult::TxBadSeq)) {
+                        resequenced = true;
+                        continue;
+                    }
+                    let result = result.map_or_else(|| "no result".to_string(), |result| result.name().to_string());
+                    return Err(RpcError::Rejected { hash, result });
+                }
+                status => return Err(RpcError::Rejected { hash, result: status.to_string() }),
+            }
+        }
+    }
+    
+    /// Simulate a read-only invocation and return its value
+    async fn read(&self, function: &str, args: Vec<ScVal>) -> Result<ScVal, RpcError> {
+        // Simulation does not load the source account; any key will do
+        let tx = tx::invoke([0; 32], 0, &self.contract, function, args)?;
+        let simulation = self
+            .rpc
+            .simulate_transaction(tx::unsigned(tx).to_xdr_base64(Limits::none()).map_err(tx::xdr_error)?)
+            .await?;
+        if let Some(error) = simulation.error {
+            return Err(RpcError::Simulation(error));
+        }
+        let result = simulation
+            .results
+            .first()
+            .ok_or_else(|| RpcError::Response(format!("{function}: simulation without result")))?;
+        ScVal::from_xdr_base64(&result.xdr, Limits::none()).map_err(tx::xdr_error)
+    }
+    
+    /// Build, simulate, assemble and sign an invocation from `source`
+    async fn prepare(
+        &self,
+        function: &str,
+        args: Vec<ScVal>,
+        source: &SigningKey,
+    ) -> Result<TransactionEnvelope, RpcError> {
+        let account = source.verifying_key().to_bytes();
+        let sequence = self.sequence(account).await?;
+        
+        let tx = tx::invoke(account, sequence + 1, &self.contract, function, args)?;
+        let simulation = self
+            .rpc
+            .simulate_transaction(tx::unsigned(tx.clone()).to_xdr_base64(Limits::none()).map_err(tx::xdr_error)?)
+            .await?;
+        let tx = tx::assemble(tx, &simulation, self.inclusion_fee)?;
+        
+        if let Some(max) = self.max_fee.filter(|max| tx.fee > *max) {
+            return Err(RpcError::FeeTooHigh { required: tx.fee.into(), max });
+        }
+        tx::sign(tx, &self.network_id, source)
+    }
+    
+    /// Current sequence number of an account
+    async fn sequence(&self, account: [u8; 32]) -> Result<i64, RpcError> {
+        let response = self.rpc.get_ledger_entries(vec![tx::account_ledger_key(account)?]).await?;
+        let entry = response
+            .entries
+            .unwrap_or_default()
+            .into_iter()
+            .next()
+            .ok_or_else(|| RpcError::AccountNotFound(sentinel_client::AnyAddress::Account(account).to_string()))?;
+        match LedgerEntryData::from_xdr_base64(&entry.xdr, Limits::none()).map_err(tx::xdr_error)? {
+            LedgerEntryData::Account(account) => Ok(account.seq_num.0),
+            _ => Err(RpcError::Response("account ledger key returned another entry".into())),
+        }
+    }
+    
+    /// Poll until a sent transaction lands or the confirm timeout passes
+    async fn confirm(&self, hash: &str) -> Result<SubmittedTx, RpcError> {
+        let deadline = Instant::now() + self.confirm_timeout;
+        loop {
+            let response = self.rpc.get_transaction(hash).await?;
+            match response.status.as_str() {
+                "SUCCESS" => {
+                    let fee_charged = match response.result_xdr.as_deref() {
+                        Some(result) => TransactionResult::from_xdr_base64(result, Limits::none())
+                            .map_err(tx::xdr_error)?
+                            .fee_charged,
+                        None => 0,
+                    };
+                    return Ok(SubmittedTx {
+                        hash: hash.to_string(),
+                        ledger: response.ledger.unwrap_or_default(),
+                        fee_charged,
+                    });
+                }
+                "FAILED" => {
+                    let result = match response.result_xdr.as_deref() {
+                        Some(result) => transaction_result(result)?.name().to_string(),
+                        None => "no result".to_string(),
+                    };
+                    return Err(RpcError::Failed { hash: hash.to_string(), result });
+                }
+                _ if Instant::now() >= deadline => return Err(RpcError::Timeout { hash: hash.to_string() }),
+                _ => tokio::time::sleep(POLL_INTERVAL).await,
+            }
+        }
+    }
+}
+
+/// Result code of a base64 `TransactionResult`
+fn transaction_result(xdr: &str) -> Result<TransactionResultResult, RpcError> {
+    Ok(TransactionResult::from_xdr_base64(xdr, Limits::none()).map_err(tx::xdr_error)?.result)
+}
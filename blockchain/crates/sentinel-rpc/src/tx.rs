@@ -0,0 +1,205 @@
+//! Transaction building, assembly and signing for contract invocations.
+
+use ed25519_dalek::{Signer, SigningKey};
+use sentinel_client::AnyAddress;
+use sha2::{Digest, Sha256};
+use soroban_sdk::xdr::{
+    AccountId, DecoratedSignature, Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, LedgerKey,
+    LedgerKeyAccount, Limits, Memo, MuxedAccount, Operation, OperationBody, Preconditions, PublicKey, ReadXdr,
+    ScAddress, ScSymbol, ScVal, SequenceNumber, Signature, SignatureHint, SorobanAuthorizationEntry,
+    SorobanCredentials, SorobanTransactionData, Transaction, TransactionEnvelope, TransactionExt,
+    TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction, TransactionV1Envelope, Uint256,
+    WriteXdr,
+};
+
+use crate::jsonrpc::SimulateResponse;
+use crate::RpcError;
+
+/// Network id a passphrase signs for (SHA-256 of the passphrase)
+pub(crate) fn network_id(passphrase: &str) -> [u8; 32] {
+    Sha256::digest(passphrase.as_bytes()).into()
+}
+
+/// Contract id strkey (`C...`) as a hash
+pub(crate) fn contract_hash(contract_id: &str) -> Result<Hash, RpcError> {
+    match AnyAddress::parse(contract_id)? {
+        AnyAddress::Contract(key) => Ok(Hash(key)),
+        other => Err(RpcError::NotAContract(other.to_string())),
+    }
+}
+
+/// `Address` argument for a wallet strkey; muxed accounts resolve to their
+/// base account, which is what the contract stores risk under
+pub(crate) fn address(wallet: &str) -> Result<ScVal, RpcError> {
+    let address = match AnyAddress::parse(wallet)? {
+        AnyAddress::Account(key) | AnyAddress::Muxed { key, .. } => ScAddress::Account(account_id(key)),
+        AnyAddress::Contract(key) => ScAddress::Contract(Hash(key)),
+        other => return Err(RpcError::NotStellar(other.to_string())),
+    };
+    Ok(ScVal::Address(address))
+}
+
+pub(crate) fn account_id(key: [u8; 32]) -> AccountId {
+    AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key)))
+}
+
+/// Base64 ledger key of an account, for `getLedgerEntries`
+pub(crate) fn account_ledger_key(key: [u8; 32]) -> Result<String, RpcError> {
+    LedgerKey::Account(LedgerKeyAccount { account_id: account_id(key) })
+        .to_xdr_base64(Limits::none())
+        .map_err(xdr_error)
+}
+
+/// Unsigned single-operation transaction invoking `function`
+///
+/// Fees and Soroban resources are filled in by `assemble` from a
+/// simulation.
+pub(crate) fn invoke(
+    source: [u8; 32],
+    sequence: i64,
+    contract: &Hash,
+    function: &str,
+    args: Vec<ScVal>,
+) -> Result<Transaction, RpcError> {
+    let host_function = HostFunction::InvokeContract(InvokeContractArgs {
+        contract_address: ScAddress::Contract(contract.clone()),
+        function_name: ScSymbol(function.try_into().map_err(xdr_error)?),
+        args: args.try_into().map_err(xdr_error)?,
+    });
+    let operation = Operation {
+        source_account: None,
+        body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+            host_function,
+            auth: Default::default(),
+        }),
+    };
+    Ok(Transaction {
+        source_account: MuxedAccount::Ed25519(Uint256(source)),
+        fee: 0,
+        seq_num: SequenceNumber(sequence),
+        cond: Preconditions::None,
+        memo: Memo::None,
+        operations: vec![operation].try_into().map_err(xdr_error)?,
+        ext: TransactionExt::V0,
+    })
+}
+
+/// Apply a simulation: Soroban resources, authorization entries and the
+/// fee (`inclusion_fee` plus the minimum resource fee)
+///
+/// # Errors
+/// * `Simulation` if the invocation failed or needs a state restore
+/// * `AuthRequired` if an address other than the source must authorize
+pub(crate) fn assemble(
+    mut tx: Transaction,
+    simulation: &SimulateResponse,
+    inclusion_fee: u32,
+) -> Result<Transaction, RpcError> {
+    if let Some(error) = &simulation.error {
+        return Err(RpcError::Simulation(error.clone()));
+    }
+    if simulation.restore_preamble.is_some() {
+        return Err(RpcError::Simulation("archived contract state must be restored first".into()));
+    }
+    
+    let data = simulation
+        .transaction_data
+        .as_deref()
+        .ok_or_else(|| RpcError::Response("simulation without transactionData".into()))?;
+    let data = SorobanTransactionData::from_xdr_base64(data, Limits::none()).map_err(xdr_error)?;
+    let resource_fee: u64 = simulation
+        .min_resource_fee
+        .as_deref()
+        .unwrap_or("0")
+        .parse()
+        .map_err(|_| RpcError::Response("minResourceFee is not a number".into()))?;
+    
+    let auth = simulation
+        .results
+        .first()
+        .map(|result| result.auth.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| SorobanAuthorizationEntry::from_xdr_base64(entry, Limits::none()).map_err(xdr_error))
+        .collect::<Result<Vec<_>, _>>()?;
+    if auth.iter().any(|entry| !matches!(entry.credentials, SorobanCredentials::SourceAccount)) {
+        return Err(RpcError::AuthRequired);
+    }
+    
+    let fee = u64::from(inclusion_fee) + resource_fee;
+    tx.fee = u32::try_from(fee).map_err(|_| RpcError::FeeTooHigh { required: fee, max: u32::MAX })?;
+    tx.ext = TransactionExt::V1(data);
+    let mut operations = tx.operations.to_vec();
+    if let Some(OperationBody::InvokeHostFunction(op)) = operations.first_mut().map(|operation| &mut operation.body) {
+        op.auth = auth.try_into().map_err(xdr_error)?;
+    }
+    tx.operations = operations.try_into().map_err(xdr_error)?;
+    Ok(tx)
+}
+
+/// Hash a transaction signs over on `network_id` (also its id on chain)
+pub(crate) fn hash(tx: &Transaction, network_id: &[u8; 32]) -> Result<[u8; 32], RpcError> {
+    let payload = TransactionSignaturePayload {
+        network_id: Hash(*network_id),
+        tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+    };
+    let bytes = payload.to_xdr(Limits::none()).map_err(xdr_error)?;
+    Ok(Sha256::digest(bytes).into())
+}
+
+/// Envelope without signatures, for simulation
+pub(crate) fn unsigned(tx: Transaction) -> TransactionEnvelope {
+    TransactionEnvelope::Tx(TransactionV1Envelope { tx, signatures: Default::default() })
+}
+
+/// Envelope signed by the source account's key
+pub(crate) fn sign(tx: Transaction, network_id: &[u8; 32], key: &SigningKey) -> Result<TransactionEnvelope, RpcError> {
+    let signature = key.sign(&hash(&tx, network_id)?).to_bytes();
+    let public_key = key.verifying_key().to_bytes();
+    let decorated = DecoratedSignature {
+        // Hint: last 4 bytes of the signer's public key
+        hint: SignatureHint(public_key[28..].try_into().expect("4 bytes")),
+        signature: Signature(signature.to_vec().try_into().map_err(xdr_error)?),
+    };
+    Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+        tx,
+        signatures: vec![decorated].try_into().map_err(xdr_error)?,
+    }))
+}
+
+pub(crate) fn xdr_error(error: soroban_sdk::xdr::Error) -> RpcError {
+    RpcError::Xdr(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Verifier;
+    
+    const TESTNET: &str = "Test SDF Network ; September 2015";
+    
+    #[test]
+    fn test_signed_envelope_verifies_for_network() {
+        assert_eq!(
+            hex(&network_id(TESTNET)),
+            "cee0302d59844d32bdca915c8203dd44b33fbb7edc19051ea37abedf28ecd472"
+        );
+        
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let contract = Hash([1; 32]);
+        let tx = invoke(key.verifying_key().to_bytes(), 42, &contract, "check_permission", vec![]).unwrap();
+        let network = network_id(TESTNET);
+        
+        let TransactionEnvelope::Tx(envelope) = sign(tx.clone(), &network, &key).unwrap() else {
+            panic!("expected a v1 envelope");
+        };
+        let signature = ed25519_dalek::Signature::from_slice(&envelope.signatures[0].signature.0).unwrap();
+        key.verifying_key().verify(&hash(&tx, &network).unwrap(), &signature).unwrap();
+        assert_eq!(envelope.signatures[0].hint.0, key.verifying_key().to_bytes()[28..]);
+        assert_eq!(envelope.tx.seq_num, SequenceNumber(42));
+    }
+    
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
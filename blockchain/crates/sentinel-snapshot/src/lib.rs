@@ -0,0 +1,385 @@
+//! Signed read-replica snapshots of Sentinel decisions.
+//!
+//! Low-latency systems (trading engines, matching engines) cannot afford
+//! an RPC round-trip per order. An exporter (the indexer) serializes the
+//! decisions it has indexed into a compact binary snapshot and signs it;
+//! the consumer verifies the signature, loads the snapshot into a
+//! `Replica` and refreshes it with signed deltas. Every snapshot names the
+//! contract and ledger it reflects, so any entry can be spot-checked
+//! against the chain.
+//!
+//! # Format (all integers big-endian)
+//!
+//! ```text
+//! magic        4   b"SNTL"
+//! version      1   FORMAT_VERSION
+//! kind         1   0 = full, 1 = delta
+//! contract    32   contract ID (raw 32 bytes)
+//! ledger       4   ledger sequence the snapshot reflects
+//! base_ledger  4   ledger of the snapshot a delta applies to (0 for full)
+//! count        4   number of entries
+//! entries          count x entry
+//! signature   64   Ed25519 over every preceding byte
+//!
+//! entry:
+//! wallet_len   1   strkey length
+//! wallet       n   strkey bytes ("G..." / "C...")
+//! tag          1   0 = Allow, 1 = Limit, 2 = Freeze, 3 = Removed (delta only)
+//! limit        4   limit amount (0 unless Limit)
+//! score        1   risk score 0-100
+//! updated      8   last_updated (unix seconds)
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+
+/// Leading bytes of every snapshot
+pub const MAGIC: &[u8; 4] = b"SNTL";
+
+/// Format version written by this crate
+pub const FORMAT_VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 4 + 1 + 1 + 32 + 4 + 4 + 4;
+const SIGNATURE_LEN: usize = 64;
+
+/// Decision as carried in a snapshot
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Decision {
+    Allow,
+    Limit(u32),
+    Freeze,
+}
+
+/// One wallet's replicated state
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub wallet: String,
+    /// `None` in a delta removes the wallet (e.g. after `clear_risk`)
+    pub decision: Option<Decision>,
+    pub risk_score: u8,
+    pub last_updated: u64,
+}
+
+/// Full snapshot or incremental delta
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    Full,
+    Delta,
+}
+
+/// Decoded, signature-checked snapshot
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Snapshot {
+    pub kind: Kind,
+    pub contract: [u8; 32],
+    pub ledger: u32,
+    pub base_ledger: u32,
+    pub entries: Vec<Entry>,
+}
+
+/// Why a snapshot was rejected
+#[derive(Debug, Eq, PartialEq)]
+pub enum SnapshotError {
+    /// Input ended early or has trailing bytes
+    Truncated,
+    /// Not a snapshot (bad magic)
+    BadMagic,
+    /// Written by an unknown format version
+    UnsupportedVersion(u8),
+    /// Unknown kind or decision tag, or removal in a full snapshot
+    Malformed,
+    /// Signature does not verify under the exporter key
+    BadSignature,
+    /// Snapshot is for a different contract
+    WrongContract,
+    /// Delta does not apply to the replica's current ledger
+    BaseMismatch { expected: u32, found: u32 },
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Truncated => write!(f, "snapshot truncated"),
+            SnapshotError::BadMagic => write!(f, "not a Sentinel snapshot"),
+            SnapshotError::UnsupportedVersion(v) => write!(f, "unsupported snapshot version {v}"),
+            SnapshotError::Malformed => write!(f, "malformed snapshot"),
+            SnapshotError::BadSignature => write!(f, "snapshot signature invalid"),
+            SnapshotError::WrongContract => write!(f, "snapshot is for another contract"),
+            SnapshotError::BaseMismatch { expected, found } => {
+                write!(f, "delta base ledger {found} does not match replica ledger {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl Snapshot {
+    /// Serialize and sign (exporter side)
+    pub fn encode(&self, key: &SigningKey) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_LEN + self.entries.len() * 48 + SIGNATURE_LEN);
+        out.extend_from_slice(MAGIC);
+        out.push(FORMAT_VERSION);
+        out.push(match self.kind {
+            Kind::Full => 0,
+            Kind::Delta => 1,
+        });
+        out.extend_from_slice(&self.contract);
+        out.extend_from_slice(&self.ledger.to_be_bytes());
+        out.extend_from_slice(&self.base_ledger.to_be_bytes());
+        out.extend_from_slice(&(self.entries.len() as u32).to_be_bytes());
+        
+        for entry in &self.entries {
+            out.push(entry.wallet.len() as u8);
+            out.extend_from_slice(entry.wallet.as_bytes());
+            let (tag, limit) = match entry.decision {
+                Some(Decision::Allow) => (0, 0),
+                Some(Decision::Limit(amount)) => (1, amount),
+                Some(Decision::Freeze) => (2, 0),
+                None => (3, 0),
+            };
+            out.push(tag);
+            out.extend_from_slice(&limit.to_be_bytes());
+            out.push(entry.risk_score);
+            out.extend_from_slice(&entry.last_updated.to_be_bytes());
+        }
+        
+        let signature = key.sign(&out);
+        out.extend_from_slice(&signature.to_bytes());
+        out
+    }
+    
+    /// Verify the exporter signature and decode (consumer side)
+    pub fn decode(bytes: &[u8], exporter: &VerifyingKey) -> Result<Snapshot, SnapshotError> {
+        if bytes.len() < HEADER_LEN + SIGNATURE_LEN {
+            return Err(SnapshotError::Truncated);
+        }
+        let (body, signature) = bytes.split_at(bytes.len() - SIGNATURE_LEN);
+        let signature = Signature::from_slice(signature).map_err(|_| SnapshotError::BadSignature)?;
+        exporter
+            .verify(body, &signature)
+            .map_err(|_| SnapshotError::BadSignature)?;
+        
+        let mut r = Reader { bytes: body, pos: 0 };
+        if r.take(4)? != MAGIC {
+            return Err(SnapshotError::BadMagic);
+        }
+        let version = r.u8()?;
+        if version != FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        let kind = match r.u8()? {
+            0 => Kind::Full,
+            1 => Kind::Delta,
+            _ => return Err(SnapshotError::Malformed),
+        };
+        let mut contract = [0u8; 32];
+        contract.copy_from_slice(r.take(32)?);
+        let ledger = r.u32()?;
+        let base_ledger = r.u32()?;
+        let count = r.u32()?;
+        
+        let mut entries = Vec::with_capacity(count.min(1 << 16) as usize);
+        for _ in 0..count {
+            let wallet_len = r.u8()? as usize;
+            let wallet = std::str::from_utf8(r.take(wallet_len)?)
+                .map_err(|_| SnapshotError::Malformed)?
+                .to_string();
+            let tag = r.u8()?;
+            let limit = r.u32()?;
+            let decision = match tag {
+                0 => Some(Decision::Allow),
+                1 => Some(Decision::Limit(limit)),
+                2 => Some(Decision::Freeze),
+                3 if kind == Kind::Delta => None,
+                _ => return Err(SnapshotError::Malformed),
+            };
+            entries.push(Entry {
+                wallet,
+                decision,
+                risk_score: r.u8()?,
+                last_updated: r.u64()?,
+            });
+        }
+        if r.pos != body.len() {
+            return Err(SnapshotError::Truncated);
+        }
+        
+        Ok(Snapshot { kind, contract, ledger, base_ledger, entries })
+    }
+}
+
+/// In-memory decision table loaded from snapshots
+///
+/// Lookups are a single hash-map read. Unknown wallets return `None`; the
+/// caller applies the deployment's unknown-wallet default.
+pub struct Replica {
+    contract: [u8; 32],
+    exporter: VerifyingKey,
+    ledger: u32,
+    entries: HashMap<String, Entry>,
+}
+
+impl Replica {
+    /// Load a full snapshot
+    pub fn load(bytes: &[u8], contract: [u8; 32], exporter: VerifyingKey) -> Result<Self, SnapshotError> {
+        let snapshot = Snapshot::decode(bytes, &exporter)?;
+        if snapshot.contract != contract {
+            return Err(SnapshotError::WrongContract);
+        }
+        if snapshot.kind != Kind::Full {
+            return Err(SnapshotError::Malformed);
+        }
+        
+        let entries = snapshot
+            .entries
+            .into_iter()
+            .map(|entry| (entry.wallet.clone(), entry))
+            .collect();
+        Ok(Replica { contract, exporter, ledger: snapshot.ledger, entries })
+    }
+    
+    /// Apply a signed delta on top of the current ledger
+    pub fn apply(&mut self, bytes: &[u8]) -> Result<(), SnapshotError> {
+        let delta = Snapshot::decode(bytes, &self.exporter)?;
+        if delta.contract != self.contract {
+            return Err(SnapshotError::WrongContract);
+        }
+        if delta.kind != Kind::Delta {
+            return Err(SnapshotError::Malformed);
+        }
+        if delta.base_ledger != self.ledger {
+            return Err(SnapshotError::BaseMismatch { expected: self.ledger, found: delta.base_ledger });
+        }
+        
+        for entry in delta.entries {
+            if entry.decision.is_some() {
+                self.entries.insert(entry.wallet.clone(), entry);
+            } else {
+                self.entries.remove(&entry.wallet);
+            }
+        }
+        self.ledger = delta.ledger;
+        Ok(())
+    }
+    
+    /// Replicated decision for a wallet (`None` if not in the snapshot)
+    pub fn decision(&self, wallet: &str) -> Option<Decision> {
+        self.entries.get(wallet).and_then(|entry| entry.decision)
+    }
+    
+    /// Full replicated entry, for spot-checks against the chain
+    pub fn entry(&self, wallet: &str) -> Option<&Entry> {
+        self.entries.get(wallet)
+    }
+    
+    /// Ledger sequence the replica reflects
+    pub fn ledger(&self) -> u32 {
+        self.ledger
+    }
+    
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], SnapshotError> {
+        let end = self.pos.checked_add(n).ok_or(SnapshotError::Truncated)?;
+        let slice = self.bytes.get(self.pos..end).ok_or(SnapshotError::Truncated)?;
+        self.pos = end;
+        Ok(slice)
+    }
+    
+    fn u8(&mut self) -> Result<u8, SnapshotError> {
+        Ok(self.take(1)?[0])
+    }
+    
+    fn u32(&mut self) -> Result<u32, SnapshotError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+    
+    fn u64(&mut self) -> Result<u64, SnapshotError> {
+        Ok(u64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const CONTRACT: [u8; 32] = [9u8; 32];
+    
+    fn entry(wallet: &str, decision: Option<Decision>) -> Entry {
+        Entry { wallet: wallet.to_string(), decision, risk_score: 60, last_updated: 1_700_000_000 }
+    }
+    
+    fn full(key: &SigningKey) -> Vec<u8> {
+        Snapshot {
+            kind: Kind::Full,
+            contract: CONTRACT,
+            ledger: 100,
+            base_ledger: 0,
+            entries: vec![entry("GAAA", Some(Decision::Limit(5000))), entry("GBBB", Some(Decision::Freeze))],
+        }
+        .encode(key)
+    }
+    
+    #[test]
+    fn test_round_trip_and_delta() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut replica = Replica::load(&full(&key), CONTRACT, key.verifying_key()).unwrap();
+        assert_eq!(replica.decision("GAAA"), Some(Decision::Limit(5000)));
+        assert_eq!(replica.decision("GCCC"), None);
+        
+        let delta = Snapshot {
+            kind: Kind::Delta,
+            contract: CONTRACT,
+            ledger: 105,
+            base_ledger: 100,
+            entries: vec![entry("GBBB", None), entry("GCCC", Some(Decision::Allow))],
+        }
+        .encode(&key);
+        replica.apply(&delta).unwrap();
+        
+        assert_eq!(replica.ledger(), 105);
+        assert_eq!(replica.decision("GBBB"), None);
+        assert_eq!(replica.decision("GCCC"), Some(Decision::Allow));
+        assert_eq!(replica.len(), 2);
+        
+        // Replaying the same delta no longer matches the base ledger
+        assert_eq!(
+            replica.apply(&delta),
+            Err(SnapshotError::BaseMismatch { expected: 105, found: 100 })
+        );
+    }
+    
+    #[test]
+    fn test_tampering_and_wrong_exporter_rejected() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut bytes = full(&key);
+        bytes[HEADER_LEN + 6] ^= 1;
+        assert_eq!(
+            Snapshot::decode(&bytes, &key.verifying_key()),
+            Err(SnapshotError::BadSignature)
+        );
+        
+        let other = SigningKey::from_bytes(&[8u8; 32]);
+        assert!(Replica::load(&full(&key), CONTRACT, other.verifying_key()).is_err());
+        assert_eq!(
+            Replica::load(&full(&key), [0u8; 32], key.verifying_key()).err(),
+            Some(SnapshotError::WrongContract)
+        );
+    }
+}
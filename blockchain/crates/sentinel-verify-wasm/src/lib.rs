@@ -0,0 +1,190 @@
+//! Browser-side verification of Sentinel Oracle attestations.
+//!
+//! Compiles the contract's own canonicalization (`sentinel-canonical`) and
+//! score bands (`bands.rs`) to plain `wasm32-unknown-unknown` with a small
+//! JS-friendly interface, so wallets can:
+//! - Rebuild the exact message the Oracle signed
+//! - Verify the Oracle signature locally
+//! - Preview the decision a score produces
+//!
+//! The canonical layout is a shared crate and the bands are included by
+//! path rather than copied, so the browser and the contract always run the
+//! same code.
+
+use ed25519_dalek::{Signature, VerifyingKey};
+use wasm_bindgen::prelude::*;
+
+// Shared module exports items only the contract uses
+#[allow(dead_code)]
+#[path = "../../../contracts/sentinel-sdk/src/bands.rs"]
+mod bands;
+
+use bands::{
+    band_for_score, Band, ALLOW_MAX_SCORE, DEFAULT_LIMIT_AMOUNT, LIMIT_MAX_SCORE, REVIEW_MAX_SCORE,
+};
+use sentinel_canonical::{write_payload_json, ByteSink, OptionalFields};
+
+/// Payload format version the messages are built for
+pub const PAYLOAD_VERSION: u32 = 2;
+
+/// `Vec<u8>` sink for the shared canonical writer
+struct VecSink(Vec<u8>);
+
+impl ByteSink for VecSink {
+    fn put(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+/// Build the canonical JSON message the Oracle signs
+///
+/// `contract` is the Sentinel deployment (C... strkey) the payload is
+/// signed for. `freeze_duration` may be 0 and `reason_codes` empty, in
+/// which case the fields are omitted. Payloads carrying per-category
+/// scores or model provenance are not supported here yet.
+#[wasm_bindgen]
+pub fn canonical_message(
+    contract: &str,
+    wallet: &str,
+    risk_score: u32,
+    timestamp: u64,
+    freeze_duration: u64,
+    reason_codes: Vec<u32>,
+) -> Vec<u8> {
+    let mut sink = VecSink(Vec::new());
+    write_payload_json(
+        &mut sink,
+        core::iter::empty(),
+        contract.as_bytes(),
+        OptionalFields { freeze_duration, ..Default::default() },
+        reason_codes.into_iter(),
+        risk_score,
+        timestamp,
+        PAYLOAD_VERSION,
+        wallet.as_bytes(),
+    );
+    sink.0
+}
+
+/// Verify an Oracle attestation locally
+///
+/// Returns `false` for malformed keys/signatures as well as for invalid
+/// signatures, mirroring the contract's accept/reject outcome.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn verify_attestation(
+    oracle_pubkey_hex: &str,
+    signature_hex: &str,
+    contract: &str,
+    wallet: &str,
+    risk_score: u32,
+    timestamp: u64,
+    freeze_duration: u64,
+    reason_codes: Vec<u32>,
+) -> bool {
+    let Some(key) = decode_fixed::<32>(oracle_pubkey_hex) else {
+        return false;
+    };
+    let Some(signature) = decode_fixed::<64>(signature_hex) else {
+        return false;
+    };
+    let Ok(key) = VerifyingKey::from_bytes(&key) else {
+        return false;
+    };
+    
+    let message = canonical_message(contract, wallet, risk_score, timestamp, freeze_duration, reason_codes);
+    
+    // Soroban's ed25519_verify uses strict verification
+    key.verify_strict(&message, &Signature::from_bytes(&signature))
+        .is_ok()
+}
+
+/// Decision a score would produce under the default bands
+///
+/// Deployments may configure other bands on-chain; this preview uses the
+/// defaults every deployment starts with.
+#[wasm_bindgen]
+pub struct DecisionPreview {
+    kind: String,
+    limit: u32,
+}
+
+#[wasm_bindgen]
+impl DecisionPreview {
+    /// "allow", "limit", "review" or "freeze"
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> String {
+        self.kind.clone()
+    }
+    
+    /// Limit amount (0 unless kind is "limit")
+    #[wasm_bindgen(getter)]
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+}
+
+/// Preview the decision for a score (`undefined` if out of range)
+#[wasm_bindgen]
+pub fn preview_decision(risk_score: u32) -> Option<DecisionPreview> {
+    let band = band_for_score(risk_score, ALLOW_MAX_SCORE, LIMIT_MAX_SCORE, REVIEW_MAX_SCORE)?;
+    let (kind, limit) = match band {
+        Band::Allow => ("allow", 0),
+        Band::Limit => ("limit", DEFAULT_LIMIT_AMOUNT),
+        Band::Review => ("review", 0),
+        Band::Freeze => ("freeze", 0),
+    };
+    
+    Some(DecisionPreview {
+        kind: kind.to_string(),
+        limit,
+    })
+}
+
+/// Decode a hex string into a fixed-size array
+fn decode_fixed<const N: usize>(hex_str: &str) -> Option<[u8; N]> {
+    let bytes = hex::decode(hex_str).ok()?;
+    bytes.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    
+    const CONTRACT: &str = "CDG43TONZXG43TONZXG43TONZXG43TONZXG43TONZXG43TONZXG42EVB";
+    const WALLET: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+    
+    #[test]
+    fn test_canonical_message_layout() {
+        let message = canonical_message(CONTRACT, WALLET, 87, 1737718800, 0, vec![]);
+        assert_eq!(
+            message,
+            format!(
+                "{{\"domain\":\"sentinel-risk:{CONTRACT}\",\"risk_score\":87,\"timestamp\":1737718800,\"version\":2,\"wallet\":\"{WALLET}\"}}"
+            )
+            .into_bytes()
+        );
+    }
+    
+    #[test]
+    fn test_verify_attestation_round_trip() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let message = canonical_message(CONTRACT, WALLET, 87, 1737718800, 0, vec![1, 3]);
+        let signature = hex::encode(key.sign(&message).to_bytes());
+        let pubkey = hex::encode(key.verifying_key().to_bytes());
+        
+        assert!(verify_attestation(&pubkey, &signature, CONTRACT, WALLET, 87, 1737718800, 0, vec![1, 3]));
+        assert!(!verify_attestation(&pubkey, &signature, CONTRACT, WALLET, 12, 1737718800, 0, vec![1, 3]));
+        assert!(!verify_attestation(&pubkey, &signature, WALLET, WALLET, 87, 1737718800, 0, vec![1, 3]));
+        assert!(!verify_attestation("zz", &signature, CONTRACT, WALLET, 87, 1737718800, 0, vec![1, 3]));
+    }
+    
+    #[test]
+    fn test_preview_decision_bands() {
+        assert_eq!(preview_decision(10).unwrap().kind(), "allow");
+        assert_eq!(preview_decision(60).unwrap().limit(), DEFAULT_LIMIT_AMOUNT);
+        assert_eq!(preview_decision(95).unwrap().kind(), "freeze");
+        assert!(preview_decision(101).is_none());
+    }
+}
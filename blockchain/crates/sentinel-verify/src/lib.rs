@@ -0,0 +1,316 @@
+//! In-contract verification of Oracle-signed risk payloads.
+//!
+//! A protocol on a latency- or fee-sensitive path can take the signed
+//! `RiskPayload` from its caller and check it in-process against the
+//! Oracle key it is configured with, instead of calling the Sentinel
+//! contract. `verify` applies the contract's acceptance rules (payload
+//! version, score ranges, replay window, clock skew) and returns the
+//! decision under the default score bands.
+//!
+//! The signed message is built by `sentinel-canonical`, the layout the
+//! contract uses, so the bytes checked here are the bytes the contract
+//! checks.
+//!
+//! What the in-process check does not see: overrides, self-locks, custom
+//! bands and any newer score stored on-chain. Use it where a fresh Oracle
+//! attestation is good enough; call the contract where those matter.
+
+#![no_std]
+
+// Shared module exports items only the contract uses
+#[allow(dead_code)]
+#[path = "../../../contracts/sentinel-sdk/src/bands.rs"]
+mod bands;
+
+use soroban_sdk::{contracterror, contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, Vec};
+
+use bands::{band_for_score, Band, ALLOW_MAX_SCORE, DEFAULT_LIMIT_AMOUNT, LIMIT_MAX_SCORE, REVIEW_MAX_SCORE};
+use sentinel_canonical::{write_payload_json, ByteSink, OptionalFields, MAX_WALLET_LEN};
+
+pub use sentinel_client::{RiskCategory, RiskDecision};
+
+/// Payload schema the Oracle signs today (see the contract's `RiskPayload`)
+pub const PAYLOAD_VERSION_V2: u32 = 2;
+
+/// Default replay window of the contract: payloads older than this are
+/// rejected (seconds)
+pub const DEFAULT_MAX_AGE: u64 = 300;
+
+/// Default forward clock-skew tolerance of the contract (seconds)
+pub const DEFAULT_MAX_CLOCK_SKEW: u64 = 60;
+
+/// Oracle-signed risk payload, field for field the contract's `RiskPayload`
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskPayload {
+    /// Payload schema version (selects the signing format)
+    pub version: u32,
+    /// Wallet address being scored
+    pub wallet: Address,
+    /// Risk score from 0-100
+    pub risk_score: u32,
+    /// Unix timestamp when Oracle signed this
+    pub timestamp: u64,
+    /// Reason codes behind the score; empty if none
+    pub reason_codes: Vec<u32>,
+    /// Seconds a Freeze lasts before it is released (0 = indefinite)
+    pub freeze_duration: u64,
+    /// Per-category scores from 0-100; empty if none
+    pub category_scores: Map<RiskCategory, u32>,
+    /// Release of the ML model that produced the score (0 = not reported)
+    pub model_version: u32,
+    /// Model confidence in basis points, 0-10000 (0 = not reported)
+    pub confidence: u32,
+    /// Digest of the off-chain evidence report (all zeros if none)
+    pub evidence_hash: BytesN<32>,
+}
+
+/// Why a payload was not accepted
+///
+/// Codes start at 910 to stay clear of the calling contract's own codes
+/// and of `GuardError` (900-909).
+#[contracterror(export = false)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerifyError {
+    /// Payload is about another wallet than the caller's
+    WalletMismatch = 910,
+    /// Payload version has no known signing format
+    UnsupportedPayloadVersion = 911,
+    /// Payload is older than the accepted age
+    PayloadTooOld = 912,
+    /// Payload is dated beyond the clock-skew tolerance
+    PayloadFromFuture = 913,
+    /// Risk or category score is above 100
+    RiskScoreOutOfRange = 914,
+    /// Confidence is above 10000 bps
+    ConfidenceOutOfRange = 915,
+}
+
+/// Check a payload for `wallet` and return its decision
+///
+/// Applies the contract's acceptance rules, with `max_age` as the replay
+/// window and the contract's default clock skew, then verifies the Ed25519
+/// signature with the host (the cheapest check, which traps on a bad
+/// signature like the contract's own `verify_signature`).
+///
+/// # Arguments
+/// * `wallet` - Wallet the caller acts for; the payload must be about it
+/// * `oracle_key` - Ed25519 key of the Oracle the protocol trusts
+/// * `sentinel` - Sentinel deployment the Oracle signs for (payloads are
+///   bound to it by their signed `domain`)
+/// * `max_age` - Oldest payload accepted, in seconds
+///
+/// # Returns
+/// * Decision for the payload's worst score under the default bands
+///
+/// # Panics
+/// * If the signature does not verify
+pub fn verify(
+    env: &Env,
+    wallet: &Address,
+    payload: &RiskPayload,
+    signature: &BytesN<64>,
+    oracle_key: &BytesN<32>,
+    sentinel: &Address,
+    max_age: u64,
+) -> Result<RiskDecision, VerifyError> {
+    if payload.wallet != *wallet {
+        return Err(VerifyError::WalletMismatch);
+    }
+    
+    let now = env.ledger().timestamp();
+    if now > payload.timestamp && now - payload.timestamp > max_age {
+        return Err(VerifyError::PayloadTooOld);
+    }
+    if payload.timestamp > now.saturating_add(DEFAULT_MAX_CLOCK_SKEW) {
+        return Err(VerifyError::PayloadFromFuture);
+    }
+    
+    let worst = payload.category_scores.values().iter().fold(payload.risk_score, u32::max);
+    let decision = match band_for_score(worst, ALLOW_MAX_SCORE, LIMIT_MAX_SCORE, REVIEW_MAX_SCORE) {
+        Some(Band::Allow) => RiskDecision::Allow,
+        Some(Band::Limit) => RiskDecision::Limit(DEFAULT_LIMIT_AMOUNT),
+        Some(Band::Review) => RiskDecision::Review,
+        Some(Band::Freeze) => RiskDecision::Freeze,
+        None => return Err(VerifyError::RiskScoreOutOfRange),
+    };
+    if payload.confidence > 10_000 {
+        return Err(VerifyError::ConfidenceOutOfRange);
+    }
+    
+    env.crypto().ed25519_verify(oracle_key, &signed_message(env, sentinel, payload)?, signature);
+    Ok(decision)
+}
+
+/// Build the exact bytes the Oracle signed for a payload
+///
+/// `sentinel` is the deployment the payload is meant for; its address is
+/// part of the signed message.
+///
+/// # Errors
+/// * `UnsupportedPayloadVersion` if the version is unknown
+pub fn signed_message(env: &Env, sentinel: &Address, payload: &RiskPayload) -> Result<Bytes, VerifyError> {
+    if payload.version != PAYLOAD_VERSION_V2 {
+        return Err(VerifyError::UnsupportedPayloadVersion);
+    }
+    
+    let mut message = Bytes::new(env);
+    let mut wallet = [0u8; MAX_WALLET_LEN];
+    let wallet_len = copy_address_string(env, &payload.wallet, &mut wallet);
+    let mut domain = [0u8; MAX_WALLET_LEN];
+    let domain_len = copy_address_string(env, sentinel, &mut domain);
+    
+    let category_scores = [
+        RiskCategory::Aml,
+        RiskCategory::BotActivity,
+        RiskCategory::Fraud,
+        RiskCategory::Sanctions,
+    ]
+    .into_iter()
+    .filter_map(|category| payload.category_scores.get(category).map(|score| (json_key(category), score)));
+    
+    write_payload_json(
+        &mut BytesSink(&mut message),
+        category_scores,
+        &domain[..domain_len],
+        OptionalFields {
+            confidence: payload.confidence,
+            evidence_hash: Some(payload.evidence_hash.to_array()).filter(|hash| *hash != [0; 32]),
+            freeze_duration: payload.freeze_duration,
+            model_version: payload.model_version,
+        },
+        payload.reason_codes.iter(),
+        payload.risk_score,
+        payload.timestamp,
+        payload.version,
+        &wallet[..wallet_len],
+    );
+    Ok(message)
+}
+
+/// Key of a category in the canonical JSON (canonical key order is the
+/// order of `signed_message`'s list)
+fn json_key(category: RiskCategory) -> &'static [u8] {
+    match category {
+        RiskCategory::Aml => b"aml",
+        RiskCategory::BotActivity => b"bot_activity",
+        RiskCategory::Fraud => b"fraud",
+        RiskCategory::Sanctions => b"sanctions",
+    }
+}
+
+/// Adapter that lets `sentinel-canonical` write into Soroban `Bytes`
+struct BytesSink<'a>(&'a mut Bytes);
+
+impl ByteSink for BytesSink<'_> {
+    fn put(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+/// Copy a Stellar Address's string form ("GBXXX...") into `buf`
+///
+/// Returns the number of bytes written. The characters are read from the
+/// XDR of the host string (4 bytes tag, 4 bytes length, then content).
+fn copy_address_string(env: &Env, address: &Address, buf: &mut [u8; MAX_WALLET_LEN]) -> usize {
+    let addr_str = address.to_string();
+    let str_len = (addr_str.len() as usize).min(MAX_WALLET_LEN);
+    
+    let xdr_bytes = addr_str.to_xdr(env);
+    for (i, slot) in buf.iter_mut().enumerate().take(str_len) {
+        *slot = xdr_bytes.get(8 + i as u32).unwrap_or(0);
+    }
+    str_len
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    
+    fn payload(env: &Env, wallet: &Address, risk_score: u32, timestamp: u64) -> RiskPayload {
+        RiskPayload {
+            version: PAYLOAD_VERSION_V2,
+            wallet: wallet.clone(),
+            risk_score,
+            timestamp,
+            reason_codes: Vec::new(env),
+            freeze_duration: 0,
+            category_scores: Map::new(env),
+            model_version: 0,
+            confidence: 0,
+            evidence_hash: BytesN::from_array(env, &[0; 32]),
+        }
+    }
+    
+    fn sign(env: &Env, signing_key: &SigningKey, sentinel: &Address, payload: &RiskPayload) -> BytesN<64> {
+        let message: std::vec::Vec<u8> = signed_message(env, sentinel, payload).unwrap().iter().collect();
+        BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+    }
+    
+    #[test]
+    fn test_verify_signed_payload() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let oracle_key = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+        let wallet = Address::generate(&env);
+        let sentinel = Address::generate(&env);
+        
+        let mut limited = payload(&env, &wallet, 60, 990);
+        let signature = sign(&env, &signing_key, &sentinel, &limited);
+        assert_eq!(
+            verify(&env, &wallet, &limited, &signature, &oracle_key, &sentinel, DEFAULT_MAX_AGE),
+            Ok(RiskDecision::Limit(DEFAULT_LIMIT_AMOUNT))
+        );
+        
+        // A category score drives the decision like on-chain
+        limited.category_scores.set(RiskCategory::Sanctions, 95);
+        let signature = sign(&env, &signing_key, &sentinel, &limited);
+        assert_eq!(verify(&env, &wallet, &limited, &signature, &oracle_key, &sentinel, 60), Ok(RiskDecision::Freeze));
+        
+        let other = Address::generate(&env);
+        assert_eq!(
+            verify(&env, &other, &limited, &signature, &oracle_key, &sentinel, 60),
+            Err(VerifyError::WalletMismatch)
+        );
+        assert_eq!(
+            verify(&env, &wallet, &limited, &signature, &oracle_key, &sentinel, 5),
+            Err(VerifyError::PayloadTooOld)
+        );
+    }
+    
+    #[test]
+    #[should_panic]
+    fn test_verify_traps_on_other_deployment() {
+        let env = Env::default();
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let oracle_key = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+        let wallet = Address::generate(&env);
+        
+        let payload = payload(&env, &wallet, 90, 0);
+        let signature = sign(&env, &signing_key, &Address::generate(&env), &payload);
+        
+        let _ = verify(&env, &wallet, &payload, &signature, &oracle_key, &Address::generate(&env), DEFAULT_MAX_AGE);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn test_verify_traps_on_forged_payload() {
+        let env = Env::default();
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let oracle_key = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+        let wallet = Address::generate(&env);
+        let sentinel = Address::generate(&env);
+        
+        let mut payload = payload(&env, &wallet, 90, 0);
+        let signature = sign(&env, &signing_key, &sentinel, &payload);
+        payload.risk_score = 10;
+        
+        let _ = verify(&env, &wallet, &payload, &signature, &oracle_key, &sentinel, DEFAULT_MAX_AGE);
+    }
+}
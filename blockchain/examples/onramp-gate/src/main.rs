@@ -0,0 +1,142 @@
+//! Fiat on-ramp payout gate backed by Sentinel risk decisions.
+//!
+//! This is a reference for non-contract (custodial) integrators:
+//! - Looks up the destination wallet's decision
+//! - Caps payouts under `Limit`, holds them under `Review`, blocks them
+//!   under `Freeze`
+//! - Records an enforcement report for every payout attempt
+
+use std::collections::HashMap;
+
+use sentinel_contract_sdk_miraculous65::RiskDecision;
+
+/// Source of Sentinel decisions for a wallet (strkey, e.g. "GBXXX...")
+///
+/// Production services implement this on top of a Soroban RPC
+/// simulation of `check_permission(wallet)`.
+pub trait DecisionSource {
+    fn check_permission(&self, wallet: &str) -> RiskDecision;
+}
+
+/// In-memory decision source for demos and tests
+///
+/// Unknown wallets are allowed, matching the contract's default.
+#[derive(Default)]
+pub struct StaticDecisions {
+    decisions: HashMap<String, RiskDecision>,
+}
+
+impl StaticDecisions {
+    pub fn with(mut self, wallet: &str, decision: RiskDecision) -> Self {
+        self.decisions.insert(wallet.to_string(), decision);
+        self
+    }
+}
+
+impl DecisionSource for StaticDecisions {
+    fn check_permission(&self, wallet: &str) -> RiskDecision {
+        self.decisions
+            .get(wallet)
+            .cloned()
+            .unwrap_or(RiskDecision::Allow)
+    }
+}
+
+/// Outcome of a single payout attempt
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutOutcome {
+    /// Full amount released
+    Paid,
+    /// Amount capped by a Limit decision; remainder held
+    Capped,
+    /// Wallet awaiting manual review; nothing released until cleared
+    PendingReview,
+    /// Wallet frozen; nothing released
+    Blocked,
+}
+
+/// Audit record for a payout attempt
+#[derive(Clone, Debug)]
+pub struct EnforcementReport {
+    pub payout_id: String,
+    pub wallet: String,
+    pub decision: RiskDecision,
+    pub requested: i128,
+    pub paid: i128,
+    pub held: i128,
+    pub outcome: PayoutOutcome,
+}
+
+/// Payout gate that enforces Sentinel decisions
+pub struct OnRampGate<S: DecisionSource> {
+    source: S,
+    reports: Vec<EnforcementReport>,
+}
+
+impl<S: DecisionSource> OnRampGate<S> {
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            reports: Vec::new(),
+        }
+    }
+
+    /// Gate a payout and return the amount that may be released
+    pub fn process_payout(&mut self, payout_id: &str, wallet: &str, amount: i128) -> i128 {
+        let decision = self.source.check_permission(wallet);
+
+        let (paid, outcome) = match decision {
+            RiskDecision::Allow => (amount, PayoutOutcome::Paid),
+            RiskDecision::Limit(max) => {
+                let max = max as i128;
+                if amount > max {
+                    (max, PayoutOutcome::Capped)
+                } else {
+                    (amount, PayoutOutcome::Paid)
+                }
+            }
+            RiskDecision::Review => (0, PayoutOutcome::PendingReview),
+            RiskDecision::Freeze => (0, PayoutOutcome::Blocked),
+        };
+
+        self.reports.push(EnforcementReport {
+            payout_id: payout_id.to_string(),
+            wallet: wallet.to_string(),
+            decision,
+            requested: amount,
+            paid,
+            held: amount - paid,
+            outcome,
+        });
+
+        paid
+    }
+
+    /// All enforcement reports recorded so far
+    pub fn reports(&self) -> &[EnforcementReport] {
+        &self.reports
+    }
+}
+
+fn main() {
+    let source = StaticDecisions::default()
+        .with("GCLEAN", RiskDecision::Allow)
+        .with("GRISKY", RiskDecision::Limit(5000))
+        .with("GFROZEN", RiskDecision::Freeze);
+
+    let mut gate = OnRampGate::new(source);
+
+    gate.process_payout("payout-1", "GCLEAN", 10_000);
+    gate.process_payout("payout-2", "GRISKY", 10_000);
+    gate.process_payout("payout-3", "GRISKY", 2_000);
+    gate.process_payout("payout-4", "GFROZEN", 10_000);
+    gate.process_payout("payout-5", "GUNKNOWN", 750);
+
+    println!("payout_id,wallet,decision,requested,paid,held,outcome");
+    for r in gate.reports() {
+        println!(
+            "{},{},{:?},{},{},{},{:?}",
+            r.payout_id, r.wallet, r.decision, r.requested, r.paid, r.held, r.outcome
+        );
+    }
+}
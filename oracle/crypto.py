@@ -9,6 +9,7 @@ This module handles:
 
 import os
 import json
+import hashlib
 from nacl.signing import SigningKey, VerifyKey
 from nacl.encoding import HexEncoder
 
@@ -80,12 +81,72 @@ def canonical_json(data: dict) -> bytes:
     return json.dumps(data, sort_keys=True, separators=(',', ':')).encode('utf-8')
 
 
+# Serialization parameters this signer produces (must match the contract)
+PAYLOAD_FORMAT_VERSION = 2
+DOMAIN_TAG = "sentinel-risk"
+TIMESTAMP_UNITS = "s"
+
+
+def risk_payload(wallet: str, risk_score: int, timestamp: int, contract_id: str = None) -> dict:
+    """
+    Signed fields of a risk payload for one Sentinel deployment.
+
+    The deployment's contract id (`C...`) and the format version are part
+    of the signed JSON, so the signature is only accepted by that contract.
+
+    Args:
+        contract_id: Sentinel contract id; defaults to SENTINEL_CONTRACT_ID
+
+    Raises:
+        RuntimeError: if no contract id is given or configured
+    """
+    contract_id = contract_id or os.getenv("SENTINEL_CONTRACT_ID")
+    if not contract_id:
+        raise RuntimeError("Set SENTINEL_CONTRACT_ID to the Sentinel contract id (C...)")
+    
+    return {
+        "domain": f"{DOMAIN_TAG}:{contract_id}",
+        "risk_score": risk_score,
+        "timestamp": timestamp,
+        "version": PAYLOAD_FORMAT_VERSION,
+        "wallet": wallet,
+    }
+
+
+def conformance_hash(format_version: int = PAYLOAD_FORMAT_VERSION) -> str:
+    """
+    Hex SHA-256 over the signer's serialization parameters.
+
+    Mirrors the contract's `conformance_hash()`; a mismatch means the
+    contract would reject (or misread) what this signer produces.
+    """
+    descriptor = (
+        f"sentinel-conformance|format={format_version}"
+        f"|domain={DOMAIN_TAG}|timestamp={TIMESTAMP_UNITS}"
+    )
+    return hashlib.sha256(descriptor.encode("utf-8")).hexdigest()
+
+
+def check_conformance(deployment_hash: str) -> None:
+    """
+    Refuse to run against a deployment with different serialization parameters.
+
+    Raises:
+        RuntimeError: if `deployment_hash` differs from this signer's hash
+    """
+    expected = conformance_hash()
+    if deployment_hash.strip().lower() != expected:
+        raise RuntimeError(
+            f"Conformance mismatch: deployment {deployment_hash} != signer {expected}"
+        )
+
+
 def sign_payload(payload: dict, signing_key: SigningKey) -> str:
     """
     Sign a payload dict with Ed25519.
     
     Args:
-        payload: Dict containing {wallet, risk_score, timestamp}
+        payload: Dict built by `risk_payload` (plus any optional fields)
         signing_key: Ed25519 signing key
         
     Returns:
@@ -108,7 +169,7 @@ def verify_signature(payload: dict, signature_hex: str, verify_key: VerifyKey) -
     Verify a signature against a payload.
     
     Args:
-        payload: Dict containing {wallet, risk_score, timestamp}
+        payload: Dict built by `risk_payload` (plus any optional fields)
         signature_hex: Hex-encoded signature
         verify_key: Ed25519 verification key
         
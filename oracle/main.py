@@ -13,9 +13,10 @@ Key Design Principles:
 
 from fastapi import FastAPI, HTTPException
 from fastapi.middleware.cors import CORSMiddleware
+import os
 import time
 from models import RiskInput, SignedRiskResponse, SignedPayload, HealthResponse
-from crypto import load_keys, sign_payload
+from crypto import load_keys, sign_payload, check_conformance, risk_payload
 
 app = FastAPI(
     title="Sentinel Oracle Service",
@@ -32,6 +33,13 @@ app.add_middleware(
     allow_headers=["*"],
 )
 
+# Refuse to sign for a deployment with a different serialization format.
+# SENTINEL_CONFORMANCE_HASH is the contract's `conformance_hash()` (hex).
+deployment_hash = os.getenv("SENTINEL_CONFORMANCE_HASH")
+if deployment_hash:
+    check_conformance(deployment_hash)
+    print(f"✅ Conformance hash matches deployment")
+
 # Load Oracle keypair on startup
 try:
     signing_key, verify_key = load_keys()
@@ -81,13 +89,9 @@ async def sign_risk(risk_input: RiskInput):
     # Add timestamp (Unix timestamp in seconds)
     current_timestamp = int(time.time())
     
-    # Create the payload that will be signed
+    # Create the payload that will be signed, bound to SENTINEL_CONTRACT_ID
     # NOTE: 'reason' is intentionally excluded from signature
-    payload_dict = {
-        "wallet": risk_input.wallet,
-        "risk_score": risk_input.risk_score,
-        "timestamp": current_timestamp
-    }
+    payload_dict = risk_payload(risk_input.wallet, risk_input.risk_score, current_timestamp)
     
     # Sign the payload
     signature = sign_payload(payload_dict, signing_key)
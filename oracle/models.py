@@ -27,10 +27,12 @@ class RiskInput(BaseModel):
 
 
 class SignedPayload(BaseModel):
-    """The payload that gets signed (wallet + risk_score + timestamp)."""
+    """The payload that gets signed (wallet + risk_score + timestamp, bound to a deployment)."""
+    domain: str
     wallet: str
     risk_score: int
     timestamp: int
+    version: int
 
 
 class SignedRiskResponse(BaseModel):
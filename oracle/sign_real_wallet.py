@@ -2,7 +2,7 @@
 Sign payload with real wallet address
 """
 
-from crypto import load_keys, sign_payload, canonical_json
+from crypto import load_keys, sign_payload, risk_payload, canonical_json
 import json
 import time
 
@@ -22,11 +22,7 @@ def main():
     risk_score = 87
     timestamp = int(time.time())
     
-    payload = {
-        "wallet": real_wallet,
-        "risk_score": risk_score,
-        "timestamp": timestamp
-    }
+    payload = risk_payload(real_wallet, risk_score, timestamp)
     
     print(f"\n[2] Payload:")
     print(f"  Wallet: {real_wallet}")
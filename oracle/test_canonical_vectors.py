@@ -0,0 +1,35 @@
+"""
+Check the Oracle's canonical JSON against the shared golden vectors.
+
+The vectors live in `blockchain/crates/sentinel-canonical/vectors/`; the
+Rust crate the contract serializes with checks the same file, so a
+byte-level drift on either side fails here instead of on-chain.
+
+Run: python test_canonical_vectors.py
+"""
+
+import json
+from pathlib import Path
+
+from crypto import canonical_json
+
+VECTORS_FILE = (
+    Path(__file__).resolve().parent.parent
+    / "blockchain" / "crates" / "sentinel-canonical" / "vectors" / "canonical.json"
+)
+
+
+def test_golden_vectors():
+    vectors = json.loads(VECTORS_FILE.read_text())["vectors"]
+    assert vectors, "no vectors"
+    
+    for vector in vectors:
+        message = canonical_json(vector["object"])
+        assert message == vector["message"].encode("utf-8"), (
+            f"{vector['name']}: {message!r} != {vector['message']!r}"
+        )
+
+
+if __name__ == "__main__":
+    test_golden_vectors()
+    print("✅ Canonical JSON matches all golden vectors")
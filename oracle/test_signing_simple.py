@@ -3,7 +3,7 @@ Direct Oracle Crypto Test
 Tests Oracle signing functionality
 """
 
-from crypto import load_keys, sign_payload, canonical_json
+from crypto import load_keys, sign_payload, risk_payload, canonical_json
 import json
 import time
 
@@ -23,11 +23,7 @@ def main():
     test_risk_score = 87
     timestamp = int(time.time())
     
-    payload = {
-        "wallet": test_wallet,
-        "risk_score": test_risk_score,
-        "timestamp": timestamp
-    }
+    payload = risk_payload(test_wallet, test_risk_score, timestamp)
     
     print(f"\n[2] Payload to sign:")
     print(f"  {json.dumps(payload, indent=2)}")